@@ -4,6 +4,9 @@
 //! `*_generated_from_entities.sql` exists in that folder, new runs emit **deltas**
 //! (`ALTER TABLE ... ADD COLUMN IF NOT EXISTS`, new `CREATE INDEX IF NOT EXISTS`) instead
 //! of duplicating full `CREATE TABLE` bodies for unchanged tables.
+//!
+//! Native enum types used by `#[life_enum]` columns are written as `-- Type:` sections ahead
+//! of the tables (`CREATE TYPE`, then `ALTER TYPE ... ADD VALUE` for new labels).
 
 // Import modules, not structs, so we can access Entity nested structs
 use example_entities::inventory::{category, inventory_item, product};
@@ -12,9 +15,24 @@ use lifeguard::LifeEntityName;
 use lifeguard_migrate::generated_migration_diff;
 use lifeguard_migrate::sql_dependency_order;
 use lifeguard_migrate::sql_generator;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+/// Enum types per service, deduplicated by type name (first entity to declare one wins).
+fn add_enum_types(
+    enum_types_by_service: &mut HashMap<String, Vec<(String, Vec<String>)>>,
+    service: &str,
+    types: Vec<(String, Vec<String>)>,
+) {
+    let entry = enum_types_by_service.entry(service.to_string()).or_default();
+    for (type_name, labels) in types {
+        if !entry.iter().any(|(existing, _)| *existing == type_name) {
+            entry.push((type_name, labels));
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Anchor to this crate so runs work regardless of process cwd (e.g. `cargo run` from repo root).
     let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../migrations/generated");
@@ -30,6 +48,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Group entities by service
     let mut sql_by_service: std::collections::HashMap<String, Vec<(String, String)>> =
         std::collections::HashMap::new();
+    let mut enum_types_by_service: HashMap<String, Vec<(String, Vec<String>)>> = HashMap::new();
 
     // Helper to generate SQL for each entity using its concrete Entity type
     // We'll call Entity::table_definition() directly on each Entity type
@@ -39,6 +58,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     {
         type Entity = category::Entity;
         let entity = Entity::default();
+        add_enum_types(
+            &mut enum_types_by_service,
+            "inventory",
+            sql_generator::enum_types_for_entity::<Entity>(),
+        );
         let table_name = entity.table_name();
         let table_def = Entity::table_definition();
         match sql_generator::generate_create_table_sql::<Entity>(table_def) {
@@ -56,6 +80,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     {
         type Entity = product::Entity;
         let entity = Entity::default();
+        add_enum_types(
+            &mut enum_types_by_service,
+            "inventory",
+            sql_generator::enum_types_for_entity::<Entity>(),
+        );
         let table_name = entity.table_name();
         let table_def = Entity::table_definition();
         match sql_generator::generate_create_table_sql::<Entity>(table_def) {
@@ -73,6 +102,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     {
         type Entity = inventory_item::Entity;
         let entity = Entity::default();
+        add_enum_types(
+            &mut enum_types_by_service,
+            "inventory",
+            sql_generator::enum_types_for_entity::<Entity>(),
+        );
         let table_name = entity.table_name();
         let table_def = Entity::table_definition();
         match sql_generator::generate_create_table_sql::<Entity>(table_def) {
@@ -96,9 +130,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             fs::create_dir_all(&service_dir)?;
         }
 
-        let body = generated_migration_diff::build_service_migration_body_from_service_dir(
+        let types = enum_types_by_service.remove(&service).unwrap_or_default();
+        let mut body = generated_migration_diff::build_enum_types_body_from_service_dir(
             &service_dir,
-            &tables,
+            &types,
+        );
+        body.push_str(
+            &generated_migration_diff::build_service_migration_body_from_service_dir(
+                &service_dir,
+                &tables,
+            ),
         );
 
         if generated_migration_diff::service_migration_is_empty(&body) {
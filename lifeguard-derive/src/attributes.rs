@@ -3,7 +3,7 @@
 use std::collections::HashSet;
 
 use proc_macro2::Span;
-use syn::{Attribute, ExprLit, Field, Lit};
+use syn::{Attribute, ExprLit, Field, Lit, LitStr};
 
 use crate::utils;
 
@@ -115,6 +115,33 @@ pub fn extract_cursor_tiebreak(attrs: &[Attribute]) -> Option<syn::Ident> {
     None
 }
 
+/// `#[cache_ttl = N]` on the `LifeModel` struct (forwarded to `DeriveEntity`) — seconds a
+/// write-through cache entry lives; `0` keeps the entity out of the cache.
+pub fn extract_cache_ttl(attrs: &[Attribute]) -> Result<Option<u64>, syn::Error> {
+    for attr in attrs {
+        if attr.path().is_ident("cache_ttl") {
+            let ttl = attr.meta.require_name_value().ok().and_then(|meta| {
+                if let syn::Expr::Lit(ExprLit {
+                    lit: Lit::Int(i), ..
+                }) = &meta.value
+                {
+                    i.base10_parse::<u64>().ok()
+                } else {
+                    None
+                }
+            });
+            return match ttl {
+                Some(ttl) => Ok(Some(ttl)),
+                None => Err(syn::Error::new_spanned(
+                    attr,
+                    "cache_ttl must be a number of seconds: #[cache_ttl = 600]",
+                )),
+            };
+        }
+    }
+    Ok(None)
+}
+
 /// Check if field has a specific attribute
 pub fn has_attribute(field: &Field, attr_name: &str) -> bool {
     field
@@ -123,30 +150,250 @@ pub fn has_attribute(field: &Field, attr_name: &str) -> bool {
         .any(|attr| attr.path().is_ident(attr_name))
 }
 
-/// Parse `#[validate(custom = path)]` on a model field (PRD V-5).
+/// Numeric bound of a `#[validate(range(...))]` rule, kept as written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParsedRangeBound {
+    Int(i64),
+    Float(f64),
+}
+
+/// One built-in rule from `#[validate(...)]` (maps to `lifeguard::ValidationRule` in codegen).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedValidationRule {
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    Range {
+        min: Option<ParsedRangeBound>,
+        max: Option<ParsedRangeBound>,
+    },
+    Regex(String),
+    Email,
+    Url,
+    Uuid {
+        version: Option<usize>,
+    },
+    NonEmpty,
+    OneOf(Vec<String>),
+}
+
+/// Everything `#[validate(...)]` declares on one field.
+#[derive(Debug, Default)]
+pub struct FieldValidators {
+    /// `custom = path` items: `fn(&sea_query::Value) -> Result<(), String>`
+    pub custom: Vec<syn::Path>,
+    /// Built-in rules, in declaration order
+    pub rules: Vec<ParsedValidationRule>,
+    /// `unique` / `unique_with = "col"`: `Some` with the partner columns (empty for plain `unique`)
+    pub unique: Option<Vec<String>>,
+    /// `exists = "path::Entity"`: the referenced entity
+    pub exists: Option<syn::Path>,
+}
+
+impl FieldValidators {
+    /// No in-memory rules (`custom` or built-in); database-backed items are not counted.
+    pub fn is_empty(&self) -> bool {
+        self.custom.is_empty() && self.rules.is_empty()
+    }
+}
+
+const VALIDATE_ITEMS: &str = "expected `custom = path`, `length(min = .., max = ..)`, `range(min = .., max = ..)`, `regex = \"..\"`, `email`, `url`, `uuid`, `uuid(version = N)`, `non_empty`, `one_of(\"a\", \"b\", ..)`, `unique`, `unique_with = \"col\"` or `exists = \"path::Entity\"`";
+
+/// Parse `#[validate(...)]` on a model field (PRD V-5).
 ///
-/// Multiple attributes or `custom = a, custom = b` in one `#[validate(...)]` are supported.
-/// The generated code calls each path as `path(&sea_query::Value) -> Result<(), String>`.
-pub fn parse_field_validate_custom_paths(field: &Field) -> syn::Result<Vec<syn::Path>> {
-    let mut paths = Vec::new();
+/// Multiple attributes and several items per attribute are supported, e.g.
+/// `#[validate(length(min = 1, max = 80), regex = "^[a-z-]+$")]`. `custom = path` calls
+/// `path(&sea_query::Value) -> Result<(), String>`; `unique`, `unique_with` and `exists` query the
+/// database (`lifeguard::active_model::db_rules`); every other item is a built-in rule checked by
+/// `lifeguard::ValidationRule`.
+pub fn parse_field_validators(field: &Field) -> syn::Result<FieldValidators> {
+    let mut validators = FieldValidators::default();
     for attr in &field.attrs {
         if !attr.path().is_ident("validate") {
             continue;
         }
         attr.parse_nested_meta(|meta| {
-            if meta.path.is_ident("custom") {
-                let value = meta.value()?;
-                let path: syn::Path = value.parse()?;
-                paths.push(path);
-                Ok(())
-            } else {
-                Err(meta.error(
-                    "unknown `validate` item; expected `custom = path` (function `fn(&sea_query::Value) -> Result<(), String>`)",
-                ))
+            let Some(ident) = meta.path.get_ident().map(ToString::to_string) else {
+                return Err(meta.error(format!("unknown `validate` item; {VALIDATE_ITEMS}")));
+            };
+            match ident.as_str() {
+                "custom" => {
+                    let path: syn::Path = meta.value()?.parse()?;
+                    validators.custom.push(path);
+                }
+                "length" => {
+                    let (mut min, mut max) = (None, None);
+                    meta.parse_nested_meta(|bound| {
+                        let lit: syn::LitInt = bound.value()?.parse()?;
+                        let n = lit.base10_parse::<usize>()?;
+                        if bound.path.is_ident("min") {
+                            min = Some(n);
+                        } else if bound.path.is_ident("max") {
+                            max = Some(n);
+                        } else {
+                            return Err(bound.error("expected `min` or `max`"));
+                        }
+                        Ok(())
+                    })?;
+                    if min.is_none() && max.is_none() {
+                        return Err(meta.error("`length` needs `min`, `max` or both"));
+                    }
+                    if let (Some(lo), Some(hi)) = (min, max) {
+                        if lo > hi {
+                            return Err(meta.error("`length` has `min` greater than `max`"));
+                        }
+                    }
+                    validators
+                        .rules
+                        .push(ParsedValidationRule::Length { min, max });
+                }
+                "range" => {
+                    let (mut min, mut max) = (None, None);
+                    meta.parse_nested_meta(|bound| {
+                        let expr: syn::Expr = bound.value()?.parse()?;
+                        let value = parse_range_bound(&expr)?;
+                        if bound.path.is_ident("min") {
+                            min = Some(value);
+                        } else if bound.path.is_ident("max") {
+                            max = Some(value);
+                        } else {
+                            return Err(bound.error("expected `min` or `max`"));
+                        }
+                        Ok(())
+                    })?;
+                    if min.is_none() && max.is_none() {
+                        return Err(meta.error("`range` needs `min`, `max` or both"));
+                    }
+                    if let (Some(lo), Some(hi)) = (min, max) {
+                        if range_bound_f64(lo) > range_bound_f64(hi) {
+                            return Err(meta.error("`range` has `min` greater than `max`"));
+                        }
+                    }
+                    validators
+                        .rules
+                        .push(ParsedValidationRule::Range { min, max });
+                }
+                "regex" => {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new_spanned(lit, "`regex` pattern is empty"));
+                    }
+                    if let Err(e) = regex::Regex::new(&lit.value()) {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            format!("invalid `regex` pattern: {e}"),
+                        ));
+                    }
+                    validators
+                        .rules
+                        .push(ParsedValidationRule::Regex(lit.value()));
+                }
+                "email" => validators.rules.push(ParsedValidationRule::Email),
+                "url" => validators.rules.push(ParsedValidationRule::Url),
+                "non_empty" => validators.rules.push(ParsedValidationRule::NonEmpty),
+                "uuid" => {
+                    let mut version = None;
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|item| {
+                            if !item.path.is_ident("version") {
+                                return Err(item.error("expected `version = N`"));
+                            }
+                            let lit: syn::LitInt = item.value()?.parse()?;
+                            let v = lit.base10_parse::<usize>()?;
+                            if !(1..=8).contains(&v) {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "UUID version must be between 1 and 8",
+                                ));
+                            }
+                            version = Some(v);
+                            Ok(())
+                        })?;
+                    }
+                    validators
+                        .rules
+                        .push(ParsedValidationRule::Uuid { version });
+                }
+                "one_of" => {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let values =
+                        syn::punctuated::Punctuated::<LitStr, syn::Token![,]>::parse_terminated(
+                            &content,
+                        )?;
+                    if values.is_empty() {
+                        return Err(meta.error("`one_of` needs at least one value"));
+                    }
+                    validators.rules.push(ParsedValidationRule::OneOf(
+                        values.iter().map(LitStr::value).collect(),
+                    ));
+                }
+                "unique" => {
+                    validators.unique.get_or_insert_with(Vec::new);
+                }
+                "unique_with" => {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    if lit.value().is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "`unique_with` column is empty",
+                        ));
+                    }
+                    validators
+                        .unique
+                        .get_or_insert_with(Vec::new)
+                        .push(lit.value());
+                }
+                "exists" => {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    if validators.exists.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "only one `exists` item is allowed per field",
+                        ));
+                    }
+                    validators.exists = Some(lit.parse()?);
+                }
+                _ => {
+                    return Err(meta.error(format!("unknown `validate` item; {VALIDATE_ITEMS}")));
+                }
             }
+            Ok(())
         })?;
     }
-    Ok(paths)
+    Ok(validators)
+}
+
+fn parse_range_bound(expr: &syn::Expr) -> syn::Result<ParsedRangeBound> {
+    match expr {
+        syn::Expr::Lit(ExprLit {
+            lit: Lit::Int(i), ..
+        }) => Ok(ParsedRangeBound::Int(i.base10_parse()?)),
+        syn::Expr::Lit(ExprLit {
+            lit: Lit::Float(f), ..
+        }) => Ok(ParsedRangeBound::Float(f.base10_parse()?)),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => Ok(match parse_range_bound(inner)? {
+            ParsedRangeBound::Int(n) => ParsedRangeBound::Int(-n),
+            ParsedRangeBound::Float(x) => ParsedRangeBound::Float(-x),
+        }),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "`range` bounds must be integer or float literals",
+        )),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // Only used to order the two declared bounds.
+fn range_bound_f64(bound: ParsedRangeBound) -> f64 {
+    match bound {
+        ParsedRangeBound::Int(n) => n as f64,
+        ParsedRangeBound::Float(x) => x,
+    }
 }
 
 /// Holds the configuration extracted from `#[has_many]`, `#[belongs_to]`, etc.
@@ -176,6 +423,10 @@ pub struct ColumnAttributes {
     pub is_nullable: bool,
     pub is_auto_increment: bool,
     pub enum_name: Option<String>,
+    /// `#[life_enum]`: the field's type (or `Option` of it) implements `lifeguard::LifeEnum`.
+    pub is_life_enum: bool,
+    /// `#[life_value]`: the field's type (or `Option` of it) implements `lifeguard::LifeValue`.
+    pub is_life_value: bool,
     pub is_ignored: bool,
     /// The expression for a GENERATED ALWAYS AS column.
     pub generated_always_as: Option<String>,
@@ -184,6 +435,8 @@ pub struct ColumnAttributes {
     /// via `RETURNING` clauses so the `ActiveModel` response accurately reflects the
     /// database-generated value without throwing strict constraint errors on write.
     pub is_readonly: bool,
+    /// `#[version]`: optimistic-lock counter checked and bumped by `LifeRecord` writes.
+    pub is_version: bool,
     pub select_as: Option<String>,
     pub save_as: Option<String>,
     pub comment: Option<String>,
@@ -336,10 +589,16 @@ pub fn parse_column_attributes(field: &Field) -> Result<ColumnAttributes, syn::E
                     attrs.enum_name = Some(s.value());
                 }
             }
+        } else if attr.path().is_ident("life_enum") {
+            attrs.is_life_enum = true;
+        } else if attr.path().is_ident("life_value") {
+            attrs.is_life_value = true;
         } else if attr.path().is_ident("ignore") || attr.path().is_ident("skip") {
             attrs.is_ignored = true;
         } else if attr.path().is_ident("readonly") || attr.path().is_ident("generated") {
             attrs.is_readonly = true;
+        } else if attr.path().is_ident("version") {
+            attrs.is_version = true;
         } else if attr.path().is_ident("select_as") {
             if let Ok(meta) = attr.meta.require_name_value() {
                 if let syn::Expr::Lit(ExprLit {
@@ -503,6 +762,39 @@ pub(crate) struct ParsedIndexSpec {
     pub include_columns: Vec<String>,
 }
 
+/// Parsed `#[exclude(...)]` before codegen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedExclusionSpec {
+    pub name: Option<String>,
+    pub using: String,
+    /// `(column, operator)` pairs
+    pub elements: Vec<(String, String)>,
+    pub predicate: Option<String>,
+}
+
+/// Split `"room_id WITH =, during WITH &&"` into `(column, operator)` pairs.
+fn parse_exclusion_elements(spec: &str) -> Result<Vec<(String, String)>, String> {
+    let mut elements = Vec::new();
+    for element in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let tokens: Vec<&str> = element.split_whitespace().collect();
+        let [column, with, operator] = tokens.as_slice() else {
+            return Err(format!(
+                "exclude element {element:?} must be `column WITH operator`"
+            ));
+        };
+        if !with.eq_ignore_ascii_case("with") {
+            return Err(format!(
+                "exclude element {element:?} must be `column WITH operator`"
+            ));
+        }
+        elements.push(((*column).to_string(), (*operator).to_string()));
+    }
+    if elements.is_empty() {
+        return Err("exclude requires at least one `column WITH operator` element".to_string());
+    }
+    Ok(elements)
+}
+
 /// Table-level attributes for entity definitions
 #[derive(Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)] // Independent `#[derive(LifeModel)]` attribute flags, not a state machine
@@ -553,6 +845,8 @@ pub struct TableAttributes {
     pub notify_on_update: bool,
     /// SPIKE: row operations that fire the notify.
     pub notify_on_delete: bool,
+    /// `EXCLUDE` constraints from `#[exclude(using = "gist", elements = "...")]`
+    pub exclusion_constraints: Vec<ParsedExclusionSpec>,
 }
 
 /// Parse table-level attributes from struct attributes
@@ -832,6 +1126,74 @@ pub fn parse_table_attributes(
                     "notify requires at least one operation: on = \"insert\" / \"update\" / \"delete\"",
                 ));
             }
+        } else if attr.path().is_ident("exclude") {
+            // #[exclude(using = "gist", elements = "room_id WITH =, during WITH &&",
+            //           name = "bookings_no_overlap", predicate = "NOT cancelled")]
+            let usage =
+                r#"exclude must be a list: #[exclude(using = "gist", elements = "col WITH &&")]"#;
+            let meta = attr
+                .meta
+                .require_list()
+                .map_err(|_| syn::Error::new_spanned(attr, usage))?;
+            let nested = meta.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+            )?;
+            let mut using = None;
+            let mut elements = None;
+            let mut name = None;
+            let mut predicate = None;
+            for nested_meta in nested {
+                let syn::Meta::NameValue(nv) = &nested_meta else {
+                    return Err(syn::Error::new_spanned(nested_meta, usage));
+                };
+                let syn::Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = &nv.value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "expected a string literal",
+                    ));
+                };
+                if nv.path.is_ident("using") {
+                    using = Some(s.value());
+                } else if nv.path.is_ident("elements") {
+                    let parsed = parse_exclusion_elements(&s.value())
+                        .map_err(|e| syn::Error::new_spanned(s, e))?;
+                    for (col, _) in &parsed {
+                        if !valid_columns.contains(col) {
+                            return Err(syn::Error::new_spanned(
+                                s,
+                                format!("Column '{}' in exclude does not exist on this struct. Available columns: {}",
+                                    col,
+                                    valid_columns.iter().map(String::as_str).collect::<Vec<_>>().join(", "))
+                            ));
+                        }
+                    }
+                    elements = Some(parsed);
+                } else if nv.path.is_ident("name") {
+                    name = Some(s.value());
+                } else if nv.path.is_ident("predicate") {
+                    predicate = Some(s.value());
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.path,
+                        "unknown exclude key; expected using, elements, name or predicate",
+                    ));
+                }
+            }
+            let Some(elements) = elements else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    r#"exclude requires elements: #[exclude(using = "gist", elements = "col WITH &&")]"#,
+                ));
+            };
+            table_attrs.exclusion_constraints.push(ParsedExclusionSpec {
+                name,
+                using: using.unwrap_or_else(|| "gist".to_string()),
+                elements,
+                predicate,
+            });
         } else if attr.path().is_ident("view") {
             table_attrs.is_view = true;
             if let Ok(meta) = attr.meta.require_list() {
@@ -1368,6 +1730,87 @@ mod generated_column_attribute_tests {
     }
 }
 
+#[cfg(test)]
+mod validator_parse_tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn regex_patterns_compile_at_expansion() {
+        let field: Field = parse_quote! {
+            #[validate(regex = "^[a-z]+$")]
+            slug: String
+        };
+        let parsed = parse_field_validators(&field).expect("valid pattern");
+        assert!(matches!(
+            parsed.rules.as_slice(),
+            [ParsedValidationRule::Regex(p)] if p == "^[a-z]+$"
+        ));
+
+        let field: Field = parse_quote! {
+            #[validate(regex = "[a-z")]
+            slug: String
+        };
+        let err = parse_field_validators(&field).expect_err("unclosed class");
+        assert!(err.to_string().contains("invalid `regex` pattern"), "{err}");
+    }
+}
+
+#[cfg(test)]
+mod exclusion_parse_tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn columns() -> HashSet<String> {
+        ["room_id", "during"]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn parses_elements_name_and_predicate() {
+        let attrs: Vec<Attribute> = vec![parse_quote! {
+            #[exclude(elements = "room_id WITH =, during with &&", name = "no_overlap", predicate = "true")]
+        }];
+        let parsed = parse_table_attributes(&attrs, &columns()).expect("parse");
+        assert_eq!(
+            parsed.exclusion_constraints,
+            vec![ParsedExclusionSpec {
+                name: Some("no_overlap".into()),
+                using: "gist".into(),
+                elements: vec![
+                    ("room_id".into(), "=".into()),
+                    ("during".into(), "&&".into()),
+                ],
+                predicate: Some("true".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_columns_and_malformed_elements() {
+        for (attr, message) in [
+            (
+                parse_quote! { #[exclude(elements = "room WITH =")] },
+                "Column 'room' in exclude",
+            ),
+            (
+                parse_quote! { #[exclude(elements = "during &&")] },
+                "column WITH operator",
+            ),
+            (
+                parse_quote! { #[exclude(using = "gist")] },
+                "exclude requires elements",
+            ),
+        ] {
+            let attr: Attribute = attr;
+            let err = parse_table_attributes(&[attr], &columns()).expect_err(message);
+            assert!(err.to_string().contains(message), "{err}");
+        }
+    }
+}
+
 #[cfg(test)]
 mod index_definition_parse_tests {
     use super::*;
@@ -20,7 +20,16 @@ use proc_macro::TokenStream;
 /// Note: This macro is typically used internally by `LifeModel`. See `LifeModel` for usage examples.
 #[proc_macro_derive(
     DeriveEntity,
-    attributes(table_name, model, column, schema_name, soft_delete, cursor_tiebreak)
+    attributes(
+        table_name,
+        model,
+        column,
+        schema_name,
+        soft_delete,
+        auto_timestamp,
+        cache_ttl,
+        cursor_tiebreak
+    )
 )]
 pub fn derive_entity(input: TokenStream) -> TokenStream {
     macros::derive_entity(input)
@@ -53,6 +62,12 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
 /// - `#[readonly]`: Excludes the field from `INSERT` operations. Critical for Postgres `GENERATED ALWAYS` columns which strictly reject explicit values, even `NULL`.
 /// - `#[generated]`: Marks the column as database-generated (e.g. sequences, triggers).
 /// - `#[generated_always_as = "<expr>"]`: Explicitly defines the deterministic, immutable SQL expression used by the database to hydrate the field upon insert.
+/// - `#[life_enum]`: The field's type (or `Option` of it) derives `LifeEnum`; the column type is the enum's PostgreSQL type and values bind as its labels.
+/// - `#[life_value]`: The field's type (or `Option` of it) derives `LifeValue`; the column type is the newtype's `COLUMN_TYPE` and values bind as the wrapped type.
+/// - `PgRange<T>` fields (no attribute): the column type is the range over `T` (`tstzrange` for `DateTime<Utc>`, `daterange` for `NaiveDate`, `int8range` for `i64`, …).
+/// - `NaiveTime`, `PgInterval`, `IpAddr`, `PgCidr`, `PgMacAddr`, `CiText` and `PgBitString` fields (no attribute): `TIME`, `INTERVAL`, `INET`, `CIDR`, `MACADDR`, `CITEXT` and `VARBIT` columns.
+/// - `#[exclude(using = "gist", elements = "room_id WITH =, during WITH &&", name = "...", predicate = "...")]` (struct): an `EXCLUDE` table constraint; `name` and the partial `predicate` are optional, `using` defaults to `gist`.
+/// - `#[cache_ttl = N]` (struct): TTL in seconds for the entity's `lifeguard:model:{table}:{id}` cache entries (default 3600; `0` evicts on write instead of re-populating).
 ///
 /// See `lifeguard-derive/tests/test_minimal.rs` for usage examples.
 #[proc_macro_derive(
@@ -74,10 +89,13 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
         nullable,
         auto_increment,
         enum_name,
+        life_enum,
+        life_value,
         skip,
         readonly,
         generated,
         generated_always_as,
+        version,
         table_comment,
         index,
         foreign_key,
@@ -86,6 +104,7 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
         skip_from_row,
         soft_delete,
         auto_timestamp,
+        cache_ttl,
         lifecycle_hook,
         has_many,
         belongs_to,
@@ -95,7 +114,8 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
         validation_strategy,
         require_index_coverage,
         view,
-        notify
+        notify,
+        exclude
     )
 )]
 pub fn derive_life_model(input: TokenStream) -> TokenStream {
@@ -112,6 +132,8 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
 /// - `is_dirty()` method (checks if any fields changed)
 /// - Setter methods for each field
 /// - Optional `#[validate(custom = path)]` on fields: `path` is `fn(&sea_query::Value) -> Result<(), String>`; runs when the field is set (`get` is `Some`) during `validate_fields`.
+/// - Built-in field rules in the same `#[validate(...)]` list: `length(min = 2, max = 64)`, `range(min = 0, max = 100)` (integer or float bounds), `regex = "^[a-z]+$"`, `email`, `url`, `uuid` / `uuid(version = 4)`, `non_empty`, `one_of("draft", "published")`. Each maps to a [`lifeguard::ValidationRule`]; failures carry the rule `code` and its `params` on `ValidationError`. NULL values are skipped.
+/// - Database-backed items: `unique`, `unique_with = "other_col"` (composite; implies `unique`) and `exists = "parent::Entity"` (a `parent` row with this primary key). They run in `validate_database` on the write's executor during `insert` / `update`; an update excludes its own primary key and only re-checks staged columns. Failures are field errors with code `unique` / `exists`.
 /// - Optional `#[validation_strategy = "aggregate"]` or `"fail_fast"` on the struct: controls how multiple field validators combine (default: fail fast).
 /// - F-style **`UPDATE`**: `set_<field>_expr(sea_query::SimpleExpr)` schedules `SET col = <expr>` (e.g. `Column::n.f_add(1)`); stored in `__update_exprs` until `reset` / `from_model`. Literal `set_*` clears the expression for that column.
 ///
@@ -119,6 +141,7 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
 /// - `#[readonly]`: Excludes the field from `UPDATE` (and `INSERT`) operations. Vital for Postgres `GENERATED ALWAYS` columns.
 /// - `#[generated]`: Marks the column as database-generated.
 /// - `#[generated_always_as = "<expr>"]`: Explicitly defines the immutable SQL expression used by the database to hydrate the field.
+/// - `#[version]`: Optimistic locking on an `i16`, `i32` or `i64` column. `update`/`save` add `WHERE version = <loaded>` and `SET version = version + 1` (`RETURNING version`), `delete` checks it too, and a write that matches no row while the row still exists fails with `ActiveModelError::StaleRecord`. `insert` writes `1` when the field is unset.
 #[proc_macro_derive(
     LifeRecord,
     attributes(
@@ -134,12 +157,16 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
         nullable,
         auto_increment,
         enum_name,
+        life_enum,
+        life_value,
         skip,
         readonly,
         generated,
         generated_always_as,
+        version,
         soft_delete,
         auto_timestamp,
+        cache_ttl,
         lifecycle_hook,
         has_many,
         belongs_to,
@@ -177,6 +204,22 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
 /// // impl Related<super::posts::Entity> for Entity { ... }
 /// // impl Related<super::users::Entity> for Entity { ... }
 /// ```
+///
+/// `has_many_through` guesses the join table columns from the table names (`posts` gives
+/// `post_id`); `through_from` / `through_to` name them instead:
+///
+/// ```ignore
+/// #[derive(DeriveRelation)]
+/// pub enum Relation {
+///     #[lifeguard(
+///         has_many_through = "super::tags::Entity",
+///         through = "super::post_tags::Entity",
+///         through_from = "super::post_tags::Column::PostId",
+///         through_to = "super::post_tags::Column::TagId"
+///     )]
+///     Tags,
+/// }
+/// ```
 #[proc_macro_derive(DeriveRelation, attributes(lifeguard))]
 pub fn derive_relation(input: TokenStream) -> TokenStream {
     macros::derive_relation(input)
@@ -300,6 +343,74 @@ pub fn derive_migration_name(input: TokenStream) -> TokenStream {
     macros::derive_migration_name(input)
 }
 
+/// Derive macro for `LifeEnum` - maps a fieldless enum to a native PostgreSQL `ENUM` type
+///
+/// Implements [`lifeguard::LifeEnum`], `ToSql` / `FromSql`, `lifeguard::ValueType` and
+/// `From<Self> for sea_query::Value` (the variant's label as `Value::String`).
+///
+/// - `#[enum_name = "order_status"]` (enum): PostgreSQL type name, optionally schema-qualified
+///   (default: `snake_case` of the enum name).
+/// - `#[enum_value = "in_transit"]` (variant): PostgreSQL label (default: `snake_case` of the
+///   variant name). Labels must be unique.
+///
+/// The enum must be `Copy`. Mark `LifeModel` fields of the type (or `Option` of it) with
+/// `#[life_enum]`; as a `Model` field it also needs `Clone`, `Debug` and serde's
+/// `Serialize` / `Deserialize`.
+///
+/// ```ignore
+/// #[derive(LifeEnum, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+/// #[enum_name = "order_status"]
+/// pub enum OrderStatus {
+///     Pending,
+///     #[enum_value = "in_transit"]
+///     Shipped,
+/// }
+///
+/// #[derive(LifeModel, LifeRecord)]
+/// #[table_name = "orders"]
+/// pub struct Order {
+///     #[primary_key]
+///     pub id: i32,
+///     #[life_enum]
+///     pub status: OrderStatus,
+/// }
+/// ```
+#[proc_macro_derive(LifeEnum, attributes(enum_name, enum_value))]
+pub fn derive_life_enum(input: TokenStream) -> TokenStream {
+    macros::derive_life_enum(input)
+}
+
+/// Derive macro for `LifeValue` - a single-field tuple struct stored as its wrapped type
+///
+/// Generates `lifeguard::LifeValue`, `ToSql` / `FromSql`, `lifeguard::ValueType` and
+/// `From<Self> for sea_query::Value`, all delegating to the wrapped type, so the newtype binds,
+/// filters and validates exactly like it.
+///
+/// - `#[column_type = "VARCHAR(320)"]` (struct): the column type (default: inferred from the
+///   wrapped type as for a plain `LifeModel` field; required when it cannot be inferred).
+///
+/// The wrapped type must implement `lifeguard::ValueType`, `ToSql` and `FromSql`. Mark
+/// `LifeModel` fields of the newtype (or `Option` of it) with `#[life_value]`; as a `Model` field
+/// it also needs `Clone`, `Debug` and serde's `Serialize` / `Deserialize`.
+///
+/// ```ignore
+/// #[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+/// pub struct Money(rust_decimal::Decimal);
+///
+/// #[derive(LifeModel, LifeRecord)]
+/// #[table_name = "invoices"]
+/// pub struct Invoice {
+///     #[primary_key]
+///     pub id: i32,
+///     #[life_value]
+///     pub total: Money,
+/// }
+/// ```
+#[proc_macro_derive(LifeValue, attributes(column_type))]
+pub fn derive_life_value(input: TokenStream) -> TokenStream {
+    macros::derive_life_value(input)
+}
+
 /// Attribute for named query scopes on `impl Entity` (PRD Phase C).
 ///
 /// Transforms `fn active() -> …` into `pub fn scope_active() -> …` so call sites use
@@ -51,14 +51,28 @@ fn resolve_entity_column_enum_name(struct_name: &syn::Ident, attrs: &[Attribute]
     }
 }
 
+/// `LifeModelTrait` methods an entity overrides; each is empty when it keeps the trait default.
+struct LifeModelTraitOverrides {
+    cursor_tiebreak: TokenStream2,
+    soft_delete_column: TokenStream2,
+    updated_at_column: TokenStream2,
+    cache_ttl: TokenStream2,
+    find: TokenStream2,
+}
+
 fn generate_life_model_trait_impl(
     struct_name: &syn::Ident,
     model_name: &syn::Ident,
     column_name: &syn::Ident,
-    cursor_tiebreak_impl: &TokenStream2,
-    soft_delete_column_impl: &TokenStream2,
-    find_impl: &TokenStream2,
+    overrides: &LifeModelTraitOverrides,
 ) -> TokenStream2 {
+    let LifeModelTraitOverrides {
+        cursor_tiebreak,
+        soft_delete_column,
+        updated_at_column,
+        cache_ttl,
+        find,
+    } = overrides;
     quote! {
         impl lifeguard::LifeModelTrait for #struct_name {
             type Model = #model_name;
@@ -68,11 +82,15 @@ fn generate_life_model_trait_impl(
                 #column_name::all_columns()
             }
 
-            #cursor_tiebreak_impl
+            #cursor_tiebreak
+
+            #soft_delete_column
 
-            #soft_delete_column_impl
+            #updated_at_column
 
-            #find_impl
+            #cache_ttl
+
+            #find
         }
     }
 }
@@ -146,6 +164,32 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    // So `update_many` / soft `delete_many` bump `updated_at` like `LifeRecord`'s hooks do.
+    let auto_timestamp = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("auto_timestamp"));
+    let updated_at_column_impl = if auto_timestamp {
+        quote! {
+            fn updated_at_column() -> Option<Self::Column> {
+                Some(<Self as lifeguard::LifeModelTrait>::Column::UpdatedAt)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // `#[cache_ttl = N]`: lifetime of `find_by_id` / `LifeRecord` write-through entries.
+    let cache_ttl_impl = match attributes::extract_cache_ttl(&input.attrs) {
+        Ok(Some(ttl)) => quote! {
+            fn cache_ttl_seconds() -> u64 {
+                #ttl
+            }
+        },
+        Ok(None) => quote! {},
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let cursor_tiebreak_impl = match attributes::extract_cursor_tiebreak(&input.attrs) {
         Some(variant) => quote! {
             fn cursor_tiebreak_column() -> Option<Self::Column> {
@@ -159,9 +203,13 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
         struct_name,
         &model_name,
         &column_name,
-        &cursor_tiebreak_impl,
-        &soft_delete_column_impl,
-        &find_impl,
+        &LifeModelTraitOverrides {
+            cursor_tiebreak: cursor_tiebreak_impl,
+            soft_delete_column: soft_delete_column_impl,
+            updated_at_column: updated_at_column_impl,
+            cache_ttl: cache_ttl_impl,
+            find: find_impl,
+        },
     );
 
     let expanded: TokenStream2 = quote! {
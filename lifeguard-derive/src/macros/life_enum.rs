@@ -0,0 +1,168 @@
+//! `#[derive(LifeEnum)]` for fieldless enums stored as native PostgreSQL `ENUM` types.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Lit};
+
+pub fn derive_life_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// `#[name = "..."]` string value, if the attribute is present.
+pub(crate) fn string_attr(
+    attrs: &[Attribute],
+    name: &str,
+) -> Result<Option<syn::LitStr>, syn::Error> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident(name)) else {
+        return Ok(None);
+    };
+    let meta = attr.meta.require_name_value()?;
+    match &meta.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) if !s.value().is_empty() => Ok(Some(s.clone())),
+        other => Err(syn::Error::new_spanned(
+            other,
+            format!("#[{name} = \"...\"] expects a non-empty string"),
+        )),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "LifeEnum does not support generic parameters",
+        ));
+    }
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "LifeEnum only supports fieldless enums",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "LifeEnum requires at least one variant (PostgreSQL enums cannot be empty)",
+        ));
+    }
+
+    let ident = &input.ident;
+    let type_name = string_attr(&input.attrs, "enum_name")?.unwrap_or_else(|| {
+        syn::LitStr::new(&crate::utils::snake_case(&ident.to_string()), ident.span())
+    });
+
+    let mut variants = Vec::new();
+    let mut labels: Vec<syn::LitStr> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "LifeEnum variants cannot have fields",
+            ));
+        }
+        let label = string_attr(&variant.attrs, "enum_value")?.unwrap_or_else(|| {
+            syn::LitStr::new(
+                &crate::utils::snake_case(&variant.ident.to_string()),
+                variant.ident.span(),
+            )
+        });
+        if let Some(previous) = labels.iter().find(|l| l.value() == label.value()) {
+            return Err(syn::Error::new_spanned(
+                &label,
+                format!(
+                    "duplicate LifeEnum label {:?} (also used by an earlier variant)",
+                    previous.value()
+                ),
+            ));
+        }
+        variants.push(&variant.ident);
+        labels.push(label);
+    }
+
+    Ok(quote! {
+        impl lifeguard::LifeEnum for #ident {
+            const TYPE_NAME: &'static str = #type_name;
+            const VARIANTS: &'static [Self] = &[#(Self::#variants),*];
+
+            fn to_label(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #labels,)*
+                }
+            }
+        }
+
+        impl lifeguard::may_postgres::types::ToSql for #ident {
+            fn to_sql(
+                &self,
+                ty: &lifeguard::may_postgres::types::Type,
+                out: &mut lifeguard::value::BytesMut,
+            ) -> Result<
+                lifeguard::may_postgres::types::IsNull,
+                Box<dyn std::error::Error + Sync + Send>,
+            > {
+                lifeguard::value::life_enum::enum_to_sql(*self, ty, out)
+            }
+
+            fn accepts(ty: &lifeguard::may_postgres::types::Type) -> bool {
+                lifeguard::value::life_enum::enum_accepts::<Self>(ty)
+            }
+
+            fn to_sql_checked(
+                &self,
+                ty: &lifeguard::may_postgres::types::Type,
+                out: &mut lifeguard::value::BytesMut,
+            ) -> Result<
+                lifeguard::may_postgres::types::IsNull,
+                Box<dyn std::error::Error + Sync + Send>,
+            > {
+                lifeguard::value::life_enum::enum_to_sql_checked(*self, ty, out)
+            }
+        }
+
+        impl<'a> lifeguard::may_postgres::types::FromSql<'a> for #ident {
+            fn from_sql(
+                ty: &lifeguard::may_postgres::types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                lifeguard::value::life_enum::enum_from_sql(ty, raw)
+            }
+
+            fn accepts(ty: &lifeguard::may_postgres::types::Type) -> bool {
+                lifeguard::value::life_enum::enum_accepts::<Self>(ty)
+            }
+        }
+
+        impl From<#ident> for sea_query::Value {
+            fn from(value: #ident) -> Self {
+                sea_query::Value::String(Some(
+                    lifeguard::LifeEnum::to_label(value).to_string(),
+                ))
+            }
+        }
+
+        impl lifeguard::ValueType for #ident {
+            fn into_value(self) -> sea_query::Value {
+                self.into()
+            }
+
+            fn from_value(value: sea_query::Value) -> Option<Self> {
+                match value {
+                    sea_query::Value::String(Some(label)) => {
+                        <Self as lifeguard::LifeEnum>::from_label(&label)
+                    }
+                    _ => None,
+                }
+            }
+
+            fn null_value() -> sea_query::Value {
+                sea_query::Value::String(None)
+            }
+        }
+    })
+}
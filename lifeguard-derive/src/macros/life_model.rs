@@ -34,10 +34,20 @@ fn extract_option_inner_type(ty: &Type) -> Option<&Type> {
 }
 
 /// Infer SQL type from Rust type for automatic column type mapping
-fn infer_sql_type_from_rust_type(ty: &Type) -> Option<String> {
+pub(crate) fn infer_sql_type_from_rust_type(ty: &Type) -> Option<String> {
     // Extract inner type if it's Option<T>
     let inner_type = extract_option_inner_type(ty).unwrap_or(ty);
 
+    // Postgres arrays: `Vec<String>` → `TEXT[]`, `Vec<i32>` → `INTEGER[]`, …
+    if let Some(element) = type_conversion::array_element_sql_type(inner_type) {
+        return Some(format!("{element}[]"));
+    }
+
+    // `PgInterval` → `INTERVAL`, `IpAddr` → `INET`, `CiText` → `CITEXT`, …
+    if let Some(sql_type) = type_conversion::scalar_column_type(inner_type) {
+        return Some(sql_type.to_string());
+    }
+
     // Check if this is a path type (e.g., uuid::Uuid, chrono::NaiveDateTime)
     if let Type::Path(syn::TypePath {
         path: syn::Path { segments, .. },
@@ -71,6 +81,11 @@ fn infer_sql_type_from_rust_type(ty: &Type) -> Option<String> {
                 return Some("DATE".to_string());
             }
 
+            // Check for NaiveTime - last segment is "NaiveTime"
+            if last_ident == "NaiveTime" {
+                return Some("TIME".to_string());
+            }
+
             // Check for String - last segment is "String"
             if last_ident == "String" {
                 return Some("TEXT".to_string());
@@ -260,6 +275,7 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
     let mut primary_key_field_names = Vec::new(); // Store field names for value extraction
     let mut model_fields = Vec::new();
     let mut from_row_fields = Vec::new();
+    let mut from_row_prefixed_fields = Vec::new();
     let mut iden_impls = Vec::new();
 
     // Generate table definition expression
@@ -520,6 +536,38 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
         }
     };
 
+    let exclusion_defs: Vec<_> = table_attrs
+        .exclusion_constraints
+        .iter()
+        .map(|excl| {
+            let lit = |s: &str| syn::LitStr::new(s, struct_name.span());
+            let opt_lit = |s: Option<&String>| {
+                s.map_or_else(
+                    || quote! { None },
+                    |s| {
+                        let s = lit(s);
+                        quote! { Some(#s.to_string()) }
+                    },
+                )
+            };
+            let name_expr = opt_lit(excl.name.as_ref());
+            let predicate_expr = opt_lit(excl.predicate.as_ref());
+            let using_lit = lit(&excl.using);
+            let elements = excl.elements.iter().map(|(col, op)| {
+                let (col, op) = (lit(col), lit(op));
+                quote! { (#col.to_string(), #op.to_string()) }
+            });
+            quote! {
+                lifeguard::ExclusionDefinition {
+                    name: #name_expr,
+                    using: #using_lit.to_string(),
+                    elements: vec![#(#elements),*],
+                    partial_where: #predicate_expr,
+                }
+            }
+        })
+        .collect();
+
     let table_definition_expr = quote! {
         lifeguard::TableDefinition {
             table_comment: #table_comment_expr,
@@ -529,6 +577,7 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             is_view: #is_view_expr,
             view_query: #view_query_expr,
             notify: #notify_expr,
+            exclusion_constraints: vec![#(#exclusion_defs),*],
         }
     };
     let mut model_get_match_arms = Vec::new();
@@ -626,6 +675,9 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             from_row_fields.push(quote! {
                 #field_name: #default_expr,
             });
+            from_row_prefixed_fields.push(quote! {
+                #field_name: #default_expr,
+            });
 
             // Build auto-generated relation traits
             if let Some(rel) = &col_attrs.has_many {
@@ -910,6 +962,24 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             _ => quote! { sea_query::Value::String(None) },
         };
 
+        // `#[life_enum]`, `#[life_value]`, `PgRange<T>` and text-carried scalar fields (and
+        // `Option` of one) convert through `lifeguard::ValueType`.
+        let range_element = type_conversion::range_element_type(
+            extract_option_inner_type(field_type).unwrap_or(field_type),
+        );
+        let scalar_column_type = type_conversion::scalar_column_type(
+            extract_option_inner_type(field_type).unwrap_or(field_type),
+        );
+        let field_value_to_value = if col_attrs.is_life_enum
+            || col_attrs.is_life_value
+            || range_element.is_some()
+            || scalar_column_type.is_some()
+        {
+            type_conversion::generate_value_type_to_value(&quote! { self.#field_name })
+        } else {
+            field_value_to_value
+        };
+
         model_get_match_arms.push(quote! {
             Column::#column_variant => #field_value_to_value,
         });
@@ -1486,6 +1556,88 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             },
         };
 
+        // Postgres arrays (`Vec<String>`, `Vec<i32>`, …), ranges, text-carried scalars,
+        // `#[life_enum]` and `#[life_value]` fields convert through `lifeguard::ValueType`; only an
+        // `Option` field accepts the typed NULL.
+        let option_inner_type = extract_option_inner_type(field_type);
+        let field_dst = quote! { self.#field_name };
+        let model_error = quote! { lifeguard::ModelError };
+        let string_value_type = if col_attrs.is_life_enum {
+            Some((
+                "enum label".to_string(),
+                "String(Some(enum label))".to_string(),
+            ))
+        } else if range_element.is_some() {
+            Some((
+                "range literal".to_string(),
+                "String(Some(range literal))".to_string(),
+            ))
+        } else {
+            scalar_column_type.map(|sql_type| {
+                (
+                    format!("{sql_type} text"),
+                    format!("String(Some({sql_type} text))"),
+                )
+            })
+        };
+        let value_to_field_value = if col_attrs.is_life_value {
+            // A newtype's NULL is whatever its wrapped type uses.
+            let newtype = option_inner_type.unwrap_or(field_type);
+            let expected = type_conversion::type_to_string(newtype);
+            let null = quote! {
+                _ if value == <#newtype as lifeguard::ValueType>::null_value()
+            };
+            type_conversion::generate_value_to_value_type_field(
+                &field_dst,
+                newtype,
+                &column_variant,
+                option_inner_type.is_some().then_some(&null),
+                &expected,
+                &model_error,
+            )
+        } else if let Some((option_expected, expected)) = &string_value_type {
+            match option_inner_type {
+                Some(inner_type) => type_conversion::generate_value_to_value_type_field(
+                    &field_dst,
+                    inner_type,
+                    &column_variant,
+                    Some(&quote! { sea_query::Value::String(None) }),
+                    option_expected,
+                    &model_error,
+                ),
+                None => type_conversion::generate_value_to_value_type_field(
+                    &field_dst,
+                    field_type,
+                    &column_variant,
+                    None,
+                    expected,
+                    &model_error,
+                ),
+            }
+        } else if let Some(inner_type) =
+            option_inner_type.filter(|inner| type_conversion::is_array_type(inner))
+        {
+            type_conversion::generate_value_to_value_type_field(
+                &field_dst,
+                inner_type,
+                &column_variant,
+                Some(&quote! { sea_query::Value::Array(_, None) }),
+                "Array",
+                &model_error,
+            )
+        } else if type_conversion::is_array_type(field_type) {
+            type_conversion::generate_value_to_value_type_field(
+                &field_dst,
+                field_type,
+                &column_variant,
+                None,
+                "Array(Some(_))",
+                &model_error,
+            )
+        } else {
+            value_to_field_value
+        };
+
         model_set_match_arms.push(quote! {
             Column::#column_variant => #value_to_field_value,
         });
@@ -1509,15 +1661,22 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             let is_decimal = type_conversion::is_decimal_type(inner_type);
             let is_money = type_conversion::is_money_type(inner_type);
 
+            // `#[life_value]` newtypes decode with their own `FromSql` (the wrapped type's), even
+            // when the newtype's name matches one of the special cases below.
+            if col_attrs.is_life_value {
+                quote! {
+                    row.try_get::<&str, #field_type>(__lg_col)?
+                }
+            }
             // Handle uuid::Uuid — bind Postgres `uuid` via `FromSql` (not text parsing).
-            if is_uuid {
+            else if is_uuid {
                 if is_nullable {
                     quote! {
-                        row.try_get::<&str, Option<uuid::Uuid>>(#column_name_str)?
+                        row.try_get::<&str, Option<uuid::Uuid>>(__lg_col)?
                     }
                 } else {
                     quote! {
-                        row.try_get::<&str, uuid::Uuid>(#column_name_str)?
+                        row.try_get::<&str, uuid::Uuid>(__lg_col)?
                     }
                 }
             }
@@ -1525,11 +1684,11 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             else if is_datetime_utc {
                 if is_nullable {
                     quote! {
-                        row.try_get::<&str, Option<chrono::DateTime<chrono::Utc>>>(#column_name_str)?
+                        row.try_get::<&str, Option<chrono::DateTime<chrono::Utc>>>(__lg_col)?
                     }
                 } else {
                     quote! {
-                        row.try_get::<&str, chrono::DateTime<chrono::Utc>>(#column_name_str)?
+                        row.try_get::<&str, chrono::DateTime<chrono::Utc>>(__lg_col)?
                     }
                 }
             }
@@ -1537,11 +1696,11 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             else if is_datetime_local {
                 if is_nullable {
                     quote! {
-                        row.try_get::<&str, Option<chrono::DateTime<chrono::Local>>>(#column_name_str)?
+                        row.try_get::<&str, Option<chrono::DateTime<chrono::Local>>>(__lg_col)?
                     }
                 } else {
                     quote! {
-                        row.try_get::<&str, chrono::DateTime<chrono::Local>>(#column_name_str)?
+                        row.try_get::<&str, chrono::DateTime<chrono::Local>>(__lg_col)?
                     }
                 }
             }
@@ -1549,7 +1708,7 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             else if is_decimal {
                 // Decimal implements FromSql for NUMERIC, so we can use try_get directly
                 quote! {
-                    row.try_get::<&str, #field_type>(#column_name_str)?
+                    row.try_get::<&str, #field_type>(__lg_col)?
                 }
             }
             // Handle rusty_money::Money - needs special construction from amount + currency_code
@@ -1563,20 +1722,20 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
                     // Money type - requires amount (NUMERIC) + currency_code (VARCHAR)
                     // TODO: Implement proper Money construction
                     // For now, try direct FromSql (may fail if Money doesn't implement it)
-                    row.try_get::<&str, #field_type>(#column_name_str)?
+                    row.try_get::<&str, #field_type>(__lg_col)?
                 }
             }
             // Handle chrono::NaiveDateTime - get as SystemTime then convert
             else if is_naive_datetime {
                 if is_nullable {
                     quote! {
-                        row.try_get::<&str, Option<std::time::SystemTime>>(#column_name_str)?
+                        row.try_get::<&str, Option<std::time::SystemTime>>(__lg_col)?
                             .map(|st| chrono::DateTime::<chrono::Utc>::from(st).naive_utc())
                     }
                 } else {
                     quote! {
                         {
-                            let st = row.try_get::<&str, std::time::SystemTime>(#column_name_str)?;
+                            let st = row.try_get::<&str, std::time::SystemTime>(__lg_col)?;
                             chrono::DateTime::<chrono::Utc>::from(st).naive_utc()
                         }
                     }
@@ -1621,22 +1780,32 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
 
                     quote! {
                         {
-                            let val: #signed_type = row.try_get::<&str, #signed_type>(#column_name_str)?;
+                            let val: #signed_type = row.try_get::<&str, #signed_type>(__lg_col)?;
                             #field_type::try_from(val).map_err(|_| {
-                                lifeguard::from_row_unsigned_try_from_failed(row, #column_name_str)
+                                lifeguard::from_row_unsigned_try_from_failed(row, __lg_col)
                             })?
                         }
                     }
                 } else {
                     quote! {
-                        row.try_get::<&str, #field_type>(#column_name_str)?
+                        row.try_get::<&str, #field_type>(__lg_col)?
                     }
                 }
             }
         };
 
         from_row_fields.push(quote! {
-            #field_name: #get_expr,
+            #field_name: {
+                let __lg_col: &str = #column_name_str;
+                #get_expr
+            },
+        });
+        from_row_prefixed_fields.push(quote! {
+            #field_name: {
+                let __lg_col = format!("{prefix}{}", #column_name_str);
+                let __lg_col: &str = &__lg_col;
+                #get_expr
+            },
         });
 
         // Generate ColumnTrait::def() match arm
@@ -1646,9 +1815,19 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
 
         // Build ColumnDefinition struct literal
         // If column_type is not explicitly provided, infer it from Rust type
+        let life_enum_type = col_attrs
+            .is_life_enum
+            .then(|| extract_option_inner_type(field_type).unwrap_or(field_type));
         let column_type_expr = if let Some(ct) = col_attrs.column_type.as_ref() {
             let ct_lit = syn::LitStr::new(ct, field_name.span());
             quote! { Some(#ct_lit.to_string()) }
+        } else if let Some(enum_type) = life_enum_type {
+            quote! { Some(<#enum_type as lifeguard::LifeEnum>::TYPE_NAME.to_string()) }
+        } else if col_attrs.is_life_value {
+            let newtype = extract_option_inner_type(field_type).unwrap_or(field_type);
+            quote! { Some(<#newtype as lifeguard::LifeValue>::COLUMN_TYPE.to_string()) }
+        } else if let Some(element) = range_element {
+            quote! { Some(<#element as lifeguard::RangeElement>::RANGE_TYPE.to_string()) }
         } else {
             // Infer SQL type from Rust type
             let inferred_type = infer_sql_type_from_rust_type(field_type);
@@ -1732,6 +1911,11 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             },
         );
 
+        let enum_values_expr = life_enum_type.map_or_else(
+            || quote! { None },
+            |enum_type| quote! { Some(<#enum_type as lifeguard::LifeEnum>::labels()) },
+        );
+
         // Extract boolean attributes for use in quote! macro
         let is_primary_key_attr = col_attrs.is_primary_key;
         let is_unique_attr = col_attrs.is_unique;
@@ -1755,6 +1939,7 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
                 auto_increment: #is_auto_increment_attr,
                 foreign_key: #foreign_key_expr,
                 check: #check_expr,
+                enum_values: #enum_values_expr,
             },
         });
 
@@ -1764,6 +1949,10 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
             enum_type_name_match_arms.push(quote! {
                 Column::#column_variant => Some(#enum_name_lit.to_string()),
             });
+        } else if let Some(enum_type) = life_enum_type {
+            enum_type_name_match_arms.push(quote! {
+                Column::#column_variant => Some(<#enum_type as lifeguard::LifeEnum>::TYPE_NAME.to_string()),
+            });
         } else {
             enum_type_name_match_arms.push(quote! {
                 Column::#column_variant => None,
@@ -1999,6 +2188,11 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
                     #primary_key_arity_impl
                 }
             }
+
+            // Link the entity to its PrimaryKey for typed lookups (ModelManager::find_by_id)
+            impl lifeguard::EntityPrimaryKey for Entity {
+                type PrimaryKey = PrimaryKey;
+            }
         }
     } else {
         quote! {
@@ -2024,6 +2218,18 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
                     })
                 }
             }
+
+            #[automatically_derived]
+            impl lifeguard::FromRowPrefixed for #model_name {
+                fn from_row_prefixed(
+                    row: &lifeguard::may_postgres::Row,
+                    prefix: &str,
+                ) -> Result<Self, lifeguard::may_postgres::Error> {
+                    Ok(Self {
+                        #(#from_row_prefixed_fields)*
+                    })
+                }
+            }
         }
     };
 
@@ -2034,6 +2240,21 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    let auto_timestamp_attr = if table_attrs.auto_timestamp {
+        quote! { #[auto_timestamp] }
+    } else {
+        quote! {}
+    };
+
+    let cache_ttl_attr = match attributes::extract_cache_ttl(&input.attrs) {
+        Ok(Some(ttl)) => {
+            let lit = syn::LitInt::new(&ttl.to_string(), proc_macro2::Span::call_site());
+            quote! { #[cache_ttl = #lit] }
+        }
+        Ok(None) => quote! {},
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     let cursor_tiebreak_user = attributes::extract_cursor_tiebreak(&input.attrs);
     if let Some(ref tie) = cursor_tiebreak_user {
         if primary_key_variant_idents.len() != 1 {
@@ -2162,6 +2383,8 @@ pub fn derive_life_model(input: TokenStream) -> TokenStream {
         #[model = #model_name_lit]
         #schema_attr
         #soft_delete_attr
+        #auto_timestamp_attr
+        #cache_ttl_attr
         #cursor_tiebreak_attr
         pub struct Entity;
 
@@ -141,6 +141,10 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
     let mut primary_key_field_names = Vec::new();
     let mut primary_key_column_variants = Vec::new();
     let mut primary_key_auto_increment = Vec::new();
+    let mut primary_key_inner_types = Vec::new();
+
+    // `#[version]` column (field, Column variant, inner type) for optimistic locking
+    let mut version_field: Option<(Ident, Ident, syn::Type)> = None;
 
     // Track readonly fields for RETURNING mapping
     let mut readonly_field_names = Vec::new();
@@ -157,6 +161,10 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
     let mut to_json_field_conversions = Vec::new(); // Code to convert each field to JSON
     let mut field_validate_fail_fast_fragments: Vec<proc_macro2::TokenStream> = Vec::new(); // #[validate(custom = ...)] — FailFast (`?`)
     let mut field_validate_aggregate_fragments: Vec<proc_macro2::TokenStream> = Vec::new(); // same — Aggregate (collect into `errs`)
+    let mut columns_by_name: std::collections::HashMap<String, (Ident, Ident)> =
+        std::collections::HashMap::new(); // field / db column name -> (field, Column variant), for `unique_with`
+    let mut database_validators: Vec<(&syn::Field, Ident, Ident, attributes::FieldValidators)> =
+        Vec::new(); // fields with #[validate(unique / unique_with / exists)]
     let mut update_expr_setters: Vec<proc_macro2::TokenStream> = Vec::new(); // set_<field>_expr for UPDATE SET expr RHS (F-style)
     let mut null_setters: Vec<proc_macro2::TokenStream> = Vec::new(); // set_<field>_null for explicit SQL NULL
     let mut null_value_match_arms: Vec<proc_macro2::TokenStream> = Vec::new(); // typed NULL per column
@@ -255,20 +263,97 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         let column_variant_name = utils::pascal_case(&field_name.to_string());
         let column_variant = Ident::new(&column_variant_name, field_name.span());
 
-        let validate_custom_paths = match attributes::parse_field_validate_custom_paths(field) {
-            Ok(p) => p,
+        if col_attrs.is_version {
+            if is_primary_key || is_readonly || is_already_option {
+                return syn::Error::new_spanned(
+                    field,
+                    "`#[version]` must be a non-optional integer column that is neither a primary key nor read-only",
+                )
+                .to_compile_error()
+                .into();
+            }
+            if !type_conversion::is_signed_integer_type(inner_type) {
+                return syn::Error::new_spanned(
+                    &field.ty,
+                    "`#[version]` must be `i16`, `i32` or `i64`: PostgreSQL has no unsigned integer columns",
+                )
+                .to_compile_error()
+                .into();
+            }
+            if version_field.is_some() {
+                return syn::Error::new_spanned(field, "only one `#[version]` field is allowed")
+                    .to_compile_error()
+                    .into();
+            }
+            version_field = Some((
+                field_name.clone(),
+                column_variant.clone(),
+                inner_type.clone(),
+            ));
+        }
+
+        let validators = match attributes::parse_field_validators(field) {
+            Ok(v) => v,
             Err(e) => return e.to_compile_error().into(),
         };
-        if !validate_custom_paths.is_empty() {
+        columns_by_name.insert(
+            field_name.to_string(),
+            (field_name.clone(), column_variant.clone()),
+        );
+        columns_by_name.insert(
+            db_column_name.clone(),
+            (field_name.clone(), column_variant.clone()),
+        );
+        if validators.unique.is_some() || validators.exists.is_some() {
+            database_validators.push((
+                field,
+                field_name.clone(),
+                column_variant.clone(),
+                attributes::FieldValidators {
+                    custom: Vec::new(),
+                    rules: Vec::new(),
+                    unique: validators.unique.clone(),
+                    exists: validators.exists.clone(),
+                },
+            ));
+        }
+        if !validators.is_empty() {
             let col_name_lit = LitStr::new(&db_column_name, field_name.span());
-            let validator_calls_fail_fast = validate_custom_paths.iter().map(|path| {
+            let rules: Vec<proc_macro2::TokenStream> = validators
+                .rules
+                .iter()
+                .map(validation_rule_tokens)
+                .collect();
+            let (rules_fail_fast, rules_aggregate) = if rules.is_empty() {
+                (quote! {}, quote! {})
+            } else {
+                (
+                    quote! {
+                        const RULES: &[lifeguard::ValidationRule] = &[#(#rules),*];
+                        for rule in RULES {
+                            rule.check(#col_name_lit, &val)?;
+                        }
+                    },
+                    quote! {
+                        const RULES: &[lifeguard::ValidationRule] = &[#(#rules),*];
+                        for rule in RULES {
+                            match rule.check(#col_name_lit, &val) {
+                                Ok(()) => {}
+                                Err(lifeguard::ActiveModelError::Validation(v)) => errs.extend(v),
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    },
+                )
+            };
+            let validator_calls_fail_fast = validators.custom.iter().map(|path| {
                 quote! {
                     #path(&val).map_err(|msg| lifeguard::ActiveModelError::Validation(
                         vec![lifeguard::active_model::validate_op::ValidationError::field(#col_name_lit, msg)],
                     ))?;
                 }
             });
-            let validator_calls_aggregate = validate_custom_paths.iter().map(|path| {
+            let validator_calls_aggregate = validators.custom.iter().map(|path| {
                 quote! {
                     if let Err(msg) = #path(&val) {
                         errs.push(lifeguard::active_model::validate_op::ValidationError::field(#col_name_lit, msg));
@@ -280,6 +365,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                     self,
                     <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant,
                 ) {
+                    #rules_fail_fast
                     #(#validator_calls_fail_fast)*
                 }
             });
@@ -288,6 +374,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                     self,
                     <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant,
                 ) {
+                    #rules_aggregate
                     #(#validator_calls_aggregate)*
                 }
             });
@@ -298,6 +385,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
             primary_key_field_names.push(field_name.clone());
             primary_key_column_variants.push(column_variant.clone());
             primary_key_auto_increment.push(is_auto_increment);
+            primary_key_inner_types.push(inner_type.clone());
         }
 
         // Track readonly fields
@@ -458,8 +546,20 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         // Generate ActiveModelTrait match arms
         // For get(), convert directly from Option<T> to Option<Value> (optimized, no to_model() needed)
         // Use inner_type for type conversion (e.g., String from Option<String>)
-        let field_to_value_conversion =
-            type_conversion::generate_option_field_to_value(&quote! { __lg_field }, inner_type);
+        // `#[life_enum]`, `#[life_value]`, `PgRange<T>` and text-carried scalar fields convert
+        // through `lifeguard::ValueType` (see `LifeEnum`, `LifeValue`, `PgRange`,
+        // `lifeguard::value::scalar`).
+        let is_life_enum = col_attrs.is_life_enum;
+        let is_life_value = col_attrs.is_life_value;
+        let is_range = type_conversion::is_range_type(inner_type);
+        let scalar_column_type = type_conversion::scalar_column_type(inner_type);
+        let is_value_type =
+            is_life_enum || is_life_value || is_range || scalar_column_type.is_some();
+        let field_to_value_conversion = if is_value_type {
+            type_conversion::generate_option_value_type_to_value(&quote! { __lg_field })
+        } else {
+            type_conversion::generate_option_field_to_value(&quote! { __lg_field }, inner_type)
+        };
         active_model_get_match_arms.push(quote! {
             <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant => {
                 let __lg_field = self.#field_name.value().cloned();
@@ -471,10 +571,14 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         // `Value::X(None)` when the field is `None`, which is exactly the
         // right null for the column's type — a bare `Value::String(None)`
         // would be the wrong type for an INTEGER or UUID column.
-        let null_value_conversion = type_conversion::generate_option_field_to_value_with_default(
-            &quote! { __lg_field },
-            inner_type,
-        );
+        let null_value_conversion = if is_value_type {
+            type_conversion::generate_value_type_to_value(&quote! { __lg_field })
+        } else {
+            type_conversion::generate_option_field_to_value_with_default(
+                &quote! { __lg_field },
+                inner_type,
+            )
+        };
         null_column_collectors.push(quote! {
             if self.#field_name.is_null() {
                 out.push(<#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant);
@@ -499,11 +603,37 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
 
         // For set(), generate type conversion code
         // Use inner_type for type conversion (e.g., String from Option<String>)
-        let value_to_field_conversion = type_conversion::generate_value_to_option_field(
-            &quote! { __lg_converted },
-            inner_type,
-            &column_variant,
-        );
+        let value_to_field_conversion = if is_value_type {
+            let expected = if is_life_value {
+                type_conversion::type_to_string(inner_type)
+            } else if is_range {
+                "range literal".to_string()
+            } else if let Some(sql_type) = scalar_column_type {
+                format!("{sql_type} text")
+            } else {
+                "enum label".to_string()
+            };
+            // A newtype's NULL is whatever its wrapped type uses; the rest travel as text.
+            let null = if is_life_value {
+                quote! { _ if value == <#inner_type as lifeguard::ValueType>::null_value() }
+            } else {
+                quote! { sea_query::Value::String(None) }
+            };
+            type_conversion::generate_value_to_value_type_field(
+                &quote! { __lg_converted },
+                inner_type,
+                &column_variant,
+                Some(&null),
+                &expected,
+                &quote! { lifeguard::ActiveModelError },
+            )
+        } else {
+            type_conversion::generate_value_to_option_field(
+                &quote! { __lg_converted },
+                inner_type,
+                &column_variant,
+            )
+        };
         // Assigning a value through the dynamic API stages a write, and an
         // incoming NULL stages a NULL — consistent with the typed setters.
         let set_arm_body = quote! {
@@ -527,8 +657,11 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
 
         // For take(), convert directly from Option<T> to Option<Value> and set field to None (optimized)
         // Use inner_type for type conversion (e.g., String from Option<String>)
-        let field_to_value_conversion =
-            type_conversion::generate_option_field_to_value(&quote! { __lg_field }, inner_type);
+        let field_to_value_conversion = if is_value_type {
+            type_conversion::generate_option_value_type_to_value(&quote! { __lg_field })
+        } else {
+            type_conversion::generate_option_field_to_value(&quote! { __lg_field }, inner_type)
+        };
         if has_primary_keys {
             active_model_take_match_arms.push(quote! {
                 <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant => {
@@ -720,7 +853,8 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         // Generate UPDATE SET clause
         // Skip primary keys to prevent altering identity.
         // Skip readonly columns to prevent Postgres constraint violations on GENERATED fields.
-        if !is_primary_key && !is_readonly {
+        // Skip the `#[version]` column: `update()` always bumps it with `version = version + 1`.
+        if !is_primary_key && !is_readonly && !col_attrs.is_version {
             // SET clause built from `self` (kept for API compatibility).
             update_set_clauses.push(quote! {
                     match &self.#field_name {
@@ -1059,6 +1193,124 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Generated after the field loop so `unique_with` can name a later field; emitted in field
+    // order so aggregated errors follow the struct.
+    let mut database_validate_fragments: Vec<proc_macro2::TokenStream> = Vec::new();
+    let has_unique_validators = database_validators.iter().any(|(.., v)| v.unique.is_some());
+    for (field, field_name, column_variant, validators) in &database_validators {
+        let col_name_lit = LitStr::new(
+            &attributes::extract_column_name(field)
+                .unwrap_or_else(|| utils::snake_case(&field_name.to_string())),
+            field_name.span(),
+        );
+        if let Some(partners) = &validators.unique {
+            let mut partner_fields = Vec::new();
+            let mut partner_variants = Vec::new();
+            for partner in partners {
+                let Some((partner_field, partner_variant)) = columns_by_name.get(partner) else {
+                    return syn::Error::new_spanned(
+                        field,
+                        format!(
+                            "`unique_with = \"{partner}\"` does not name a column of this model"
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
+                };
+                partner_fields.push(partner_field.clone());
+                partner_variants.push(partner_variant.clone());
+            }
+            database_validate_fragments.push(quote! {
+                if !is_update || self.#field_name.is_staged() #(|| self.#partner_fields.is_staged())* {
+                    let columns: Option<Vec<(<#entity_name as lifeguard::LifeModelTrait>::Column, sea_query::Value)>> = [
+                        <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant,
+                        #(<#entity_name as lifeguard::LifeModelTrait>::Column::#partner_variants,)*
+                    ]
+                    .into_iter()
+                    .map(|c| lifeguard::ActiveModelTrait::get(self, c).map(|v| (c, v)))
+                    .collect();
+                    if let Some(columns) = columns {
+                        match lifeguard::active_model::db_rules::check_unique::<#entity_name>(
+                            executor,
+                            #col_name_lit,
+                            &columns,
+                            exclude_pk.as_deref(),
+                        ) {
+                            Ok(()) => {}
+                            Err(lifeguard::ActiveModelError::Validation(v)) if !fail_fast => errs.extend(v),
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+            });
+        }
+        if let Some(parent) = &validators.exists {
+            database_validate_fragments.push(quote! {
+                if !is_update || self.#field_name.is_staged() {
+                    if let Some(val) = lifeguard::ActiveModelTrait::get(
+                        self,
+                        <#entity_name as lifeguard::LifeModelTrait>::Column::#column_variant,
+                    ) {
+                        match lifeguard::active_model::db_rules::check_exists::<#parent>(
+                            executor,
+                            #col_name_lit,
+                            &val,
+                        ) {
+                            Ok(()) => {}
+                            Err(lifeguard::ActiveModelError::Validation(v)) if !fail_fast => errs.extend(v),
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    let validate_database_impl = if database_validate_fragments.is_empty() {
+        quote! {}
+    } else {
+        // Unique checks skip the record's own row on update.
+        let exclude_pk = if !has_unique_validators {
+            quote! {}
+        } else if primary_key_column_variants.is_empty() {
+            quote! { let exclude_pk: Option<Vec<sea_query::Value>> = None; }
+        } else {
+            quote! {
+                let exclude_pk: Option<Vec<sea_query::Value>> = if is_update {
+                    [#(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants),*]
+                        .into_iter()
+                        .map(|c| lifeguard::ActiveModelTrait::get(self, c))
+                        .collect()
+                } else {
+                    None
+                };
+            }
+        };
+        quote! {
+            fn validate_database(
+                &self,
+                op: lifeguard::active_model::validate_op::ValidateOp,
+                executor: &dyn lifeguard::LifeExecutor,
+            ) -> Result<(), lifeguard::ActiveModelError> {
+                use lifeguard::active_model::validate_op::{ValidateOp, ValidationStrategy};
+                if op == ValidateOp::Delete {
+                    return Ok(());
+                }
+                // On update, only columns staged in this change-set are re-checked.
+                let is_update = op == ValidateOp::Update;
+                let fail_fast = self.validation_strategy(op) == ValidationStrategy::FailFast;
+                #exclude_pk
+                let mut errs: Vec<lifeguard::active_model::validate_op::ValidationError> = Vec::new();
+                #(#database_validate_fragments)*
+                if errs.is_empty() {
+                    Ok(())
+                } else {
+                    Err(lifeguard::ActiveModelError::Validation(errs))
+                }
+            }
+        }
+    };
+
     let validation_strategy_impl = match table_attrs.validation_strategy {
         None => quote! {},
         Some(attributes::TableValidationStrategy::FailFast) => quote! {
@@ -1093,6 +1345,151 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
             type_conversion::generate_expr_val_now_for_field_type,
         );
 
+    if let (Some((version_name, _, _)), false) = (&version_field, has_primary_keys) {
+        return syn::Error::new_spanned(
+            version_name,
+            "`#[version]` needs a `#[primary_key]`: versioned writes target one row",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // Optimistic locking (`#[version]`): capture the loaded version before hooks run, guard every
+    // UPDATE/DELETE with it, and bump it on every UPDATE (including a soft delete).
+    let (
+        version_capture,
+        version_update_clauses,
+        version_delete_where,
+        version_soft_delete_bump,
+        version_insert_seed,
+        version_conflict_helper,
+    ) = if let Some((version_name, version_variant, version_type)) = &version_field {
+        (
+            quote! {
+                let original_version: #version_type = self.#version_name.value().cloned().ok_or_else(|| {
+                    lifeguard::ActiveModelError::FieldRequired(stringify!(#version_name).to_string())
+                })?;
+            },
+            quote! {
+                {
+                    use lifeguard::ColumnTrait;
+                    query.value(
+                        <#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant,
+                        sea_query::ExprTrait::add(
+                            sea_query::Expr::col(<#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant),
+                            1,
+                        ),
+                    );
+                    query.and_where(<#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant.eq(original_version.clone()));
+                    query.returning(sea_query::ReturningClause::Columns(vec![sea_query::ColumnRef::from(
+                        <#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant,
+                    )]));
+                }
+            },
+            quote! {
+                {
+                    use lifeguard::ColumnTrait;
+                    query.and_where(<#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant.eq(original_version.clone()));
+                }
+            },
+            quote! {
+                query.value(
+                    <#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant,
+                    sea_query::ExprTrait::add(
+                        sea_query::Expr::col(<#entity_name as lifeguard::LifeModelTrait>::Column::#version_variant),
+                        1,
+                    ),
+                );
+            },
+            quote! {
+                if record_for_hooks.#version_name.value().is_none() {
+                    record_for_hooks.#version_name = lifeguard::ActiveValue::Set(1);
+                }
+            },
+            quote! {
+                /// Classify a versioned write that matched no row: [`lifeguard::ActiveModelError::StaleRecord`]
+                /// when the row still exists under its primary key, otherwise
+                /// [`lifeguard::ActiveModelError::RecordNotFound`].
+                #[doc(hidden)]
+                fn __lg_version_conflict(
+                    executor: &dyn lifeguard::LifeExecutor,
+                    original_pk_values: &std::collections::HashMap<<#entity_name as lifeguard::LifeModelTrait>::Column, sea_query::Value>,
+                    expected_version: #version_type,
+                ) -> lifeguard::ActiveModelError {
+                    let mut find_query = <#entity_name as lifeguard::LifeModelTrait>::find();
+                    #(
+                        if let Some(pk_value) = original_pk_values.get(&<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants) {
+                            use lifeguard::ColumnTrait;
+                            find_query = find_query.filter(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants.eq(pk_value.clone()));
+                        }
+                    )*
+                    match find_query.find_one(&executor) {
+                        Ok(Some(_)) => lifeguard::ActiveModelError::StaleRecord {
+                            table: lifeguard::LifeEntityName::table_name(&#entity_name::default()).to_string(),
+                            expected_version: i64::from(expected_version),
+                            identity_key: None,
+                        },
+                        Ok(None) => lifeguard::ActiveModelError::RecordNotFound,
+                        Err(e) => lifeguard::ActiveModelError::DatabaseError(e.to_string()),
+                    }
+                }
+            },
+        )
+    } else {
+        (
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {},
+        )
+    };
+
+    let execute_error = quote! {
+        |e| lifeguard::ActiveModelError::from_life_error::<#entity_name>(e, &#entity_name::table_definition())
+    };
+    let (update_execute, delete_execute, nothing_staged) = if version_field.is_some() {
+        let version_name = version_field.as_ref().map(|(name, _, _)| name.to_string());
+        (
+            quote! {
+                // Zero rows back from `UPDATE ... WHERE version = $old RETURNING version`:
+                // either another writer got there first or the row is gone.
+                let returned = executor.query_all_values(&sql, &sql_values).map_err(#execute_error)?;
+                if returned.is_empty() {
+                    return Err(Self::__lg_version_conflict(executor, &original_pk_values, original_version));
+                }
+            },
+            quote! {
+                let rows_affected = executor.execute_values(&sql, &sql_values).map_err(#execute_error)?;
+                if rows_affected == 0 {
+                    // Deleting a row that is already gone stays a no-op; a changed version does not.
+                    let conflict = Self::__lg_version_conflict(executor, &original_pk_values, original_version);
+                    if conflict.is_stale() {
+                        return Err(conflict);
+                    }
+                }
+            },
+            // A staged `#[version]` only changes the expected version; it is not a write by itself.
+            quote! { record_for_hooks.dirty_fields().iter().all(|f| f == #version_name) },
+        )
+    } else {
+        (
+            quote! {
+                let rows_affected = executor.execute_values(&sql, &sql_values).map_err(#execute_error)?;
+
+                // Check if any rows were affected
+                if rows_affected == 0 {
+                    return Err(lifeguard::ActiveModelError::RecordNotFound);
+                }
+            },
+            quote! {
+                executor.execute_values(&sql, &sql_values).map_err(#execute_error)?;
+            },
+            quote! { record_for_hooks.dirty_fields().is_empty() },
+        )
+    };
+
     let build_delete_query_ts = if table_attrs.soft_delete {
         let set_updated_at = if table_attrs.auto_timestamp {
             quote! {
@@ -1114,8 +1511,10 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
             // Soft delete: set deleted_at to current timestamp (typed `Value` matches model field)
             query.value(<#entity_name as lifeguard::LifeModelTrait>::Column::DeletedAt, #soft_delete_deleted_at_expr);
             #set_updated_at
+            #version_soft_delete_bump
 
             #(#delete_where_clauses)*
+            #version_delete_where
         }
     } else {
         quote! {
@@ -1128,6 +1527,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
             }
 
             #(#delete_where_clauses)*
+            #version_delete_where
         }
     };
 
@@ -1264,6 +1664,10 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         }
     };
 
+    // insert_many always needs RETURNING for generated keys and readonly columns.
+    let bulk_insert_needs_returning =
+        primary_key_auto_increment.iter().any(|auto| *auto) || !readonly_field_names.is_empty();
+
     // Generate the expanded code
     let expanded = quote! {
         // Record struct (mutable change-set)
@@ -1275,6 +1679,8 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         }
 
         impl #record_name {
+            #version_conflict_helper
+
             /// Initialize GraphState if empty and return a mutable reference to it.
             pub fn graph_mut(&mut self) -> &mut lifeguard::active_model::graph::GraphState<Self> {
                 self.__graph.0
@@ -1448,9 +1854,11 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Call before_insert hook
                 let mut record_for_hooks = self.clone();
                 record_for_hooks.before_insert()?;
-                lifeguard::active_model::validation::run_validators(
+                #version_insert_seed
+                lifeguard::active_model::validation::run_validators_on(
                     &record_for_hooks,
                     lifeguard::active_model::validate_op::ValidateOp::Insert,
+                    executor,
                 )?;
 
                 // Build INSERT statement
@@ -1525,7 +1933,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Use *_values so pooled executors can marshal binds across the pool channel.
                 if needs_returning {
                     let mut rows = executor.query_all_values(&sql, &sql_values).map_err(|e| {
-                        lifeguard::ActiveModelError::DatabaseError(e.to_string())
+                        lifeguard::ActiveModelError::from_life_error::<#entity_name>(e, &#entity_name::table_definition())
                     })?;
                     if rows.is_empty() {
                         if conflict_handled {
@@ -1545,7 +1953,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                     #(#readonly_returning_extractors)*
                 } else {
                     let affected = executor.execute_values(&sql, &sql_values).map_err(|e| {
-                        lifeguard::ActiveModelError::DatabaseError(e.to_string())
+                        lifeguard::ActiveModelError::from_life_error::<#entity_name>(e, &#entity_name::table_definition())
                     })?;
                     if affected == 0 && conflict_handled {
                         return Ok(None);
@@ -1558,26 +1966,200 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Call after_insert hook
                 updated_record.after_insert(&model)?;
 
-                // Transparent Cache Write-Through
-                if let Some(cache) = executor.cache_provider() {
-                    let table_name = <#entity_name as lifeguard::LifeEntityName>::table_name(&#entity_name::default());
-                    if let Some(pk_value) = lifeguard::ModelTrait::get_primary_key_values(&model).first() {
-                        let id_str = match pk_value {
-                            sea_query::Value::BigInt(Some(v)) => v.to_string(),
-                            sea_query::Value::Int(Some(v)) => v.to_string(),
-                            _ => "".to_string(),
-                        };
-                        if !id_str.is_empty() {
-                            let cache_key = format!("lifeguard:model:{}:{}", table_name, id_str);
-                            if let Ok(json_str) = serde_json::to_string(&model) {
-                                let _ = cache.set(&cache_key, &json_str, Some(3600));
+                // Transparent cache write-through (deferred to COMMIT inside a transaction)
+                lifeguard::cache::write_through::write_through::<#entity_name>(executor, &model);
+
+                // Return the model
+                Ok(Some(model))
+            }
+
+            fn insert_many_inner(
+                records: &[Self],
+                executor: &dyn lifeguard::LifeExecutor,
+                on_conflict: Option<sea_query::OnConflict>,
+            ) -> Result<Vec<Option<Self::Model>>, lifeguard::ActiveModelError> {
+                use sea_query::{Query, PostgresQueryBuilder};
+                use lifeguard::{LifeEntityName, ActiveModelBehavior};
+
+                if records.is_empty() {
+                    return Ok(Vec::new());
+                }
+
+                // Run hooks and validators for every record before any SQL is sent,
+                // so a bad record fails the batch without a partial write.
+                let mut prepared: Vec<(
+                    Self,
+                    Vec<<#entity_name as lifeguard::LifeModelTrait>::Column>,
+                    Vec<sea_query::Expr>,
+                )> = Vec::with_capacity(records.len());
+                for record in records {
+                    let mut record_for_hooks = record.clone();
+                    record_for_hooks.before_insert()?;
+                #version_insert_seed
+                    lifeguard::active_model::validation::run_validators_on(
+                        &record_for_hooks,
+                        lifeguard::active_model::validate_op::ValidateOp::Insert,
+                        executor,
+                    )?;
+
+                    let mut columns = Vec::new();
+                    let mut exprs = Vec::new();
+
+                    #(#insert_column_checks)*
+
+                    if columns.is_empty() {
+                        return Err(lifeguard::ActiveModelError::Other("No fields set for insert".to_string()));
+                    }
+                    prepared.push((record_for_hooks, columns, exprs));
+                }
+
+                // Every VALUES row needs the same shape: take the union of the columns the
+                // records set, in declaration order. A record that left one of them unset
+                // gets DEFAULT, which is what omitting it from a single-row insert means.
+                let insert_columns: Vec<<#entity_name as lifeguard::LifeModelTrait>::Column> =
+                    <#entity_name as lifeguard::LifeModelTrait>::all_columns()
+                        .iter()
+                        .copied()
+                        .filter(|c| prepared.iter().any(|(_, columns, _)| columns.contains(c)))
+                        .collect();
+
+                // Primary keys always come back when anything does, so returned rows can be
+                // matched to records when DO NOTHING skips some of them.
+                let conflict_handled = on_conflict.is_some();
+                let needs_returning = conflict_handled || #bulk_insert_needs_returning;
+                let mut returning_cols: Vec<<#entity_name as lifeguard::LifeModelTrait>::Column> = Vec::new();
+                #(returning_cols.push(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants);)*
+                #(returning_cols.push(<#entity_name as lifeguard::LifeModelTrait>::Column::#readonly_column_variants);)*
+
+                let entity = #entity_name::default();
+                let rows_per_chunk = lifeguard::active_model::bulk::rows_per_chunk(insert_columns.len());
+                let mut models = Vec::with_capacity(prepared.len());
+
+                for chunk in prepared.chunks(rows_per_chunk) {
+                    let mut query = Query::insert();
+                    if let Some(schema) = lifeguard::LifeEntityName::schema_name(&entity) {
+                        query.into_table((sea_query::Alias::new(schema), entity.clone()));
+                    } else {
+                        query.into_table(entity.clone());
+                    }
+                    query.columns(insert_columns.iter().copied());
+                    for (_, columns, exprs) in chunk {
+                        let row_exprs: Vec<sea_query::Expr> = insert_columns
+                            .iter()
+                            .map(|c| {
+                                columns
+                                    .iter()
+                                    .position(|set| set == c)
+                                    .and_then(|i| exprs.get(i).cloned())
+                                    .unwrap_or_else(|| sea_query::Expr::cust("DEFAULT"))
+                            })
+                            .collect();
+                        query.values_panic(row_exprs);
+                    }
+                    if let Some(oc) = on_conflict.clone() {
+                        query.on_conflict(oc);
+                    }
+                    if needs_returning {
+                        use sea_query::{ReturningClause, ColumnRef};
+                        let returning_vec: Vec<ColumnRef> = returning_cols.iter().copied().map(|c| ColumnRef::from(c)).collect();
+                        query.returning(ReturningClause::Columns(returning_vec));
+                    }
+
+                    let (sql, sql_values) = query.build(PostgresQueryBuilder);
+                    if std::env::var("LIFEGUARD_DEBUG_SQL").is_ok() { eprintln!("[lifeguard-sql] {}", sql); }
+
+                    // One slot per record in the chunk, holding what RETURNING sent back for it.
+                    let mut returned_for: Vec<Option<Self>> = vec![None; chunk.len()];
+                    if needs_returning {
+                        let rows = executor.query_all_values(&sql, &sql_values).map_err(|e| {
+                            lifeguard::ActiveModelError::from_life_error::<#entity_name>(e, &#entity_name::table_definition())
+                        })?;
+                        let mut returned_records = Vec::with_capacity(rows.len());
+                        for row in &rows {
+                            let mut updated_record = Self::new();
+                            let mut returning_idx = 0usize;
+                            #(
+                                let pk_value: #primary_key_inner_types = row.get(returning_idx);
+                                returning_idx += 1;
+                                updated_record.#primary_key_field_names = lifeguard::ActiveValue::Unchanged(Some(pk_value));
+                            )*
+                            #(#readonly_returning_extractors)*
+                            let _ = returning_idx;
+                            returned_records.push(updated_record);
+                        }
+
+                        if returned_records.len() == chunk.len() {
+                            // PostgreSQL returns rows of a multi-row VALUES insert in input order.
+                            for (slot, returned) in returned_for.iter_mut().zip(returned_records) {
+                                *slot = Some(returned);
+                            }
+                        } else if !conflict_handled {
+                            return Err(lifeguard::ActiveModelError::DatabaseError(format!(
+                                "INSERT ... RETURNING produced {} rows for {} records",
+                                returned_records.len(),
+                                chunk.len()
+                            )));
+                        } else {
+                            // DO NOTHING skipped some rows, so positions no longer line up.
+                            // Match on the primary key the record was inserted with.
+                            for returned in returned_records {
+                                let returned_key: Vec<Option<sea_query::Value>> = vec![
+                                    #(returned.get(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants)),*
+                                ];
+                                let position = chunk.iter().enumerate().position(|(i, (record, _, _))| {
+                                    let record_key: Vec<Option<sea_query::Value>> = vec![
+                                        #(record.get(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants)),*
+                                    ];
+                                    returned_for.get(i).is_some_and(|slot| slot.is_none())
+                                        && !record_key.is_empty()
+                                        && record_key.iter().all(|v| v.is_some())
+                                        && record_key == returned_key
+                                });
+                                match position.and_then(|i| returned_for.get_mut(i)) {
+                                    Some(slot) => *slot = Some(returned),
+                                    None => {
+                                        return Err(lifeguard::ActiveModelError::Other(
+                                            "insert_many_on_conflict: rows were skipped and a returned row could not be matched to a record by primary key; set primary keys explicitly or use DO UPDATE".to_string(),
+                                        ));
+                                    }
+                                }
                             }
                         }
+                    } else {
+                        executor.execute_values(&sql, &sql_values).map_err(|e| {
+                            lifeguard::ActiveModelError::from_life_error::<#entity_name>(e, &#entity_name::table_definition())
+                        })?;
+                        for slot in returned_for.iter_mut() {
+                            *slot = Some(Self::new());
+                        }
+                    }
+
+                    for ((record, _, _), returned) in chunk.iter().zip(returned_for) {
+                        let Some(returned) = returned else {
+                            models.push(None);
+                            continue;
+                        };
+                        let mut updated_record = record.clone();
+                        if needs_returning {
+                            #(
+                                if updated_record.get(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants).is_none() {
+                                    updated_record.#primary_key_field_names = returned.#primary_key_field_names.clone();
+                                }
+                            )*
+                            #(updated_record.#readonly_field_names = returned.#readonly_field_names.clone();)*
+                        }
+
+                        let model = updated_record.to_model()?;
+                        updated_record.after_insert(&model)?;
+
+                        // Transparent cache write-through (deferred to COMMIT inside a transaction)
+                        lifeguard::cache::write_through::write_through::<#entity_name>(executor, &model);
+
+                        models.push(Some(model));
                     }
                 }
 
-                // Return the model
-                Ok(Some(model))
+                Ok(models)
             }
 
             fn update(&self, executor: &dyn lifeguard::LifeExecutor) -> Result<Self::Model, lifeguard::ActiveModelError> {
@@ -1598,13 +2180,15 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                         return Err(lifeguard::ActiveModelError::PrimaryKeyRequired);
                     }
                 )*
+                #version_capture
 
                 // Call before_update hook
                 let mut record_for_hooks = self.clone();
                 record_for_hooks.before_update()?;
-                lifeguard::active_model::validation::run_validators(
+                lifeguard::active_model::validation::run_validators_on(
                     &record_for_hooks,
                     lifeguard::active_model::validate_op::ValidateOp::Update,
+                    executor,
                 )?;
 
                 // Build UPDATE statement
@@ -1633,13 +2217,14 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                         return Err(lifeguard::ActiveModelError::PrimaryKeyRequired);
                     }
                 )*
+                #version_update_clauses
 
                 // An UPDATE with no assignments is not a no-op worth
                 // guessing at: either the caller forgot a setter, or they
                 // meant `overwrite`. Say so instead of emitting `UPDATE ...
                 // WHERE`, which the database rejects with a syntax error that
                 // points nowhere useful.
-                if record_for_hooks.dirty_fields().is_empty() {
+                if #nothing_staged {
                     return Err(lifeguard::ActiveModelError::Other(format!(
                         "update() on {}: no columns staged. `from_model` seeds values without staging writes — call the setters for the columns you mean to change, or use `overwrite` to write the whole model back.",
                         stringify!(#record_name)
@@ -1649,14 +2234,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Build SQL
                 let (sql, sql_values) = query.build(PostgresQueryBuilder);
 
-                let rows_affected = executor.execute_values(&sql, &sql_values).map_err(|e| {
-                    lifeguard::ActiveModelError::DatabaseError(e.to_string())
-                })?;
-
-                // Check if any rows were affected
-                if rows_affected == 0 {
-                    return Err(lifeguard::ActiveModelError::RecordNotFound);
-                }
+                #update_execute
 
                 // Construct the model by fetching it from the database to ensure all fields are properly loaded
                 let mut find_query = <#entity_name as lifeguard::LifeModelTrait>::find();
@@ -1677,23 +2255,8 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Call after_update hook
                 record_for_hooks.after_update(&model)?;
 
-                // Transparent Cache Write-Through
-                if let Some(cache) = executor.cache_provider() {
-                    let table_name = <#entity_name as lifeguard::LifeEntityName>::table_name(&#entity_name::default());
-                    if let Some(pk_value) = lifeguard::ModelTrait::get_primary_key_values(&model).first() {
-                        let id_str = match pk_value {
-                            sea_query::Value::BigInt(Some(v)) => v.to_string(),
-                            sea_query::Value::Int(Some(v)) => v.to_string(),
-                            _ => "".to_string(),
-                        };
-                        if !id_str.is_empty() {
-                            let cache_key = format!("lifeguard:model:{}:{}", table_name, id_str);
-                            if let Ok(json_str) = serde_json::to_string(&model) {
-                                let _ = cache.set(&cache_key, &json_str, Some(3600));
-                            }
-                        }
-                    }
-                }
+                // Transparent cache write-through (deferred to COMMIT inside a transaction)
+                lifeguard::cache::write_through::write_through::<#entity_name>(executor, &model);
 
                 // Return the updated model
                 Ok(model)
@@ -1781,6 +2344,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                         return Err(lifeguard::ActiveModelError::PrimaryKeyRequired);
                     }
                 )*
+                #version_capture
 
                 // Call before_delete hook
                 let mut record_for_hooks = self.clone();
@@ -1798,30 +2362,17 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
                 // Build SQL
                 let (sql, sql_values) = query.build(PostgresQueryBuilder);
 
-                executor.execute_values(&sql, &sql_values).map_err(|e| {
-                    lifeguard::ActiveModelError::DatabaseError(e.to_string())
-                })?;
+                #delete_execute
 
                 // Call after_delete hook
                 record_for_hooks.after_delete()?;
 
-                // Transparent Cache Invalidation
-                if let Some(cache) = executor.cache_provider() {
-                    let table_name = <#entity_name as lifeguard::LifeEntityName>::table_name(&#entity_name::default());
-                    #(
-                        if let Some(pk_value) = original_pk_values.get(&<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants) {
-                            let id_str = match pk_value {
-                                sea_query::Value::BigInt(Some(v)) => v.to_string(),
-                                sea_query::Value::Int(Some(v)) => v.to_string(),
-                                _ => "".to_string(),
-                            };
-                            if !id_str.is_empty() {
-                                let cache_key = format!("lifeguard:model:{}:{}", table_name, id_str);
-                                let _ = cache.invalidate(&cache_key);
-                            }
-                        }
-                    )*
-                }
+                // Transparent cache invalidation (deferred to COMMIT inside a transaction)
+                let pk_values: Vec<sea_query::Value> = [#(<#entity_name as lifeguard::LifeModelTrait>::Column::#primary_key_column_variants),*]
+                    .iter()
+                    .filter_map(|c| original_pk_values.get(c).cloned())
+                    .collect();
+                lifeguard::cache::write_through::evict::<#entity_name>(executor, &pk_values);
 
                 Ok(())
             }
@@ -1852,6 +2403,7 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
         // Implement ActiveModelBehavior with optionally customized hooks
         impl lifeguard::ActiveModelBehavior for #record_name {
             #validate_fields_impl
+            #validate_database_impl
             #validation_strategy_impl
             fn before_insert(&mut self) -> Result<(), lifeguard::ActiveModelError> {
                 #before_insert_impl
@@ -1876,3 +2428,54 @@ pub fn derive_life_record(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// `lifeguard::ValidationRule` constant for one parsed `#[validate(...)]` rule.
+fn validation_rule_tokens(rule: &attributes::ParsedValidationRule) -> proc_macro2::TokenStream {
+    use attributes::{ParsedRangeBound, ParsedValidationRule};
+
+    let opt = |v: Option<proc_macro2::TokenStream>| match v {
+        Some(v) => quote! { Some(#v) },
+        None => quote! { None },
+    };
+    match rule {
+        ParsedValidationRule::Length { min, max } => {
+            let min = opt(min.map(|n| quote! { #n }));
+            let max = opt(max.map(|n| quote! { #n }));
+            quote! { lifeguard::ValidationRule::Length { min: #min, max: #max } }
+        }
+        ParsedValidationRule::Range { min, max } => {
+            let is_float = matches!(min, Some(ParsedRangeBound::Float(_)))
+                || matches!(max, Some(ParsedRangeBound::Float(_)));
+            #[allow(clippy::cast_precision_loss)] // An integer bound next to a float bound.
+            let bound = |b: &Option<ParsedRangeBound>| {
+                opt(b.map(|b| match (b, is_float) {
+                    (ParsedRangeBound::Int(n), false) => quote! { #n },
+                    (ParsedRangeBound::Int(n), true) => {
+                        let x = n as f64;
+                        quote! { #x }
+                    }
+                    (ParsedRangeBound::Float(x), _) => quote! { #x },
+                }))
+            };
+            let (min, max) = (bound(min), bound(max));
+            if is_float {
+                quote! { lifeguard::ValidationRule::FloatRange { min: #min, max: #max } }
+            } else {
+                quote! { lifeguard::ValidationRule::IntRange { min: #min, max: #max } }
+            }
+        }
+        ParsedValidationRule::Regex(pattern) => {
+            quote! { lifeguard::ValidationRule::Regex(#pattern) }
+        }
+        ParsedValidationRule::Email => quote! { lifeguard::ValidationRule::Email },
+        ParsedValidationRule::Url => quote! { lifeguard::ValidationRule::Url },
+        ParsedValidationRule::Uuid { version } => {
+            let version = opt(version.map(|v| quote! { #v }));
+            quote! { lifeguard::ValidationRule::Uuid { version: #version } }
+        }
+        ParsedValidationRule::NonEmpty => quote! { lifeguard::ValidationRule::NonEmpty },
+        ParsedValidationRule::OneOf(values) => {
+            quote! { lifeguard::ValidationRule::OneOf(&[#(#values),*]) }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! `#[derive(LifeValue)]` for single-field tuple structs stored as their wrapped type.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+use super::life_enum::string_attr;
+use super::life_model::infer_sql_type_from_rust_type;
+
+pub fn derive_life_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "LifeValue does not support generic parameters",
+        ));
+    }
+    let inner = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "LifeValue only supports tuple structs with exactly one field",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "LifeValue only supports tuple structs with exactly one field",
+            ))
+        }
+    };
+    if matches!(inner, syn::Type::Path(p) if p.path.segments.last().is_some_and(|s| s.ident == "Option"))
+    {
+        return Err(syn::Error::new_spanned(
+            inner,
+            "LifeValue wraps a bare type; make the model field `Option<...>` instead",
+        ));
+    }
+
+    let ident = &input.ident;
+    let column_type = match string_attr(&input.attrs, "column_type")? {
+        Some(column_type) => column_type,
+        None => {
+            let Some(sql_type) = infer_sql_type_from_rust_type(inner) else {
+                return Err(syn::Error::new_spanned(
+                    inner,
+                    "cannot infer the column type of this field; add #[column_type = \"...\"]",
+                ));
+            };
+            syn::LitStr::new(&sql_type, ident.span())
+        }
+    };
+
+    Ok(quote! {
+        impl lifeguard::LifeValue for #ident {
+            type Inner = #inner;
+            const COLUMN_TYPE: &'static str = #column_type;
+
+            fn from_inner(inner: #inner) -> Self {
+                Self(inner)
+            }
+
+            fn as_inner(&self) -> &#inner {
+                &self.0
+            }
+
+            fn into_inner(self) -> #inner {
+                self.0
+            }
+        }
+
+        impl lifeguard::may_postgres::types::ToSql for #ident {
+            fn to_sql(
+                &self,
+                ty: &lifeguard::may_postgres::types::Type,
+                out: &mut lifeguard::value::BytesMut,
+            ) -> Result<
+                lifeguard::may_postgres::types::IsNull,
+                Box<dyn std::error::Error + Sync + Send>,
+            > {
+                lifeguard::may_postgres::types::ToSql::to_sql(&self.0, ty, out)
+            }
+
+            fn accepts(ty: &lifeguard::may_postgres::types::Type) -> bool {
+                <#inner as lifeguard::may_postgres::types::ToSql>::accepts(ty)
+            }
+
+            fn to_sql_checked(
+                &self,
+                ty: &lifeguard::may_postgres::types::Type,
+                out: &mut lifeguard::value::BytesMut,
+            ) -> Result<
+                lifeguard::may_postgres::types::IsNull,
+                Box<dyn std::error::Error + Sync + Send>,
+            > {
+                lifeguard::may_postgres::types::ToSql::to_sql_checked(&self.0, ty, out)
+            }
+        }
+
+        impl<'a> lifeguard::may_postgres::types::FromSql<'a> for #ident {
+            fn from_sql(
+                ty: &lifeguard::may_postgres::types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                <#inner as lifeguard::may_postgres::types::FromSql<'a>>::from_sql(ty, raw).map(Self)
+            }
+
+            fn accepts(ty: &lifeguard::may_postgres::types::Type) -> bool {
+                <#inner as lifeguard::may_postgres::types::FromSql<'a>>::accepts(ty)
+            }
+        }
+
+        impl From<#ident> for sea_query::Value {
+            fn from(value: #ident) -> Self {
+                lifeguard::ValueType::into_value(value.0)
+            }
+        }
+
+        impl lifeguard::ValueType for #ident {
+            fn into_value(self) -> sea_query::Value {
+                self.into()
+            }
+
+            fn from_value(value: sea_query::Value) -> Option<Self> {
+                <#inner as lifeguard::ValueType>::from_value(value).map(Self)
+            }
+
+            fn null_value() -> sea_query::Value {
+                <#inner as lifeguard::ValueType>::null_value()
+            }
+        }
+    })
+}
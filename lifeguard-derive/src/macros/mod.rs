@@ -2,8 +2,10 @@
 
 pub mod entity;
 pub mod from_row;
+pub mod life_enum;
 pub mod life_model;
 pub mod life_record;
+pub mod life_value;
 pub mod linked;
 pub mod migration_name_derive;
 pub mod partial_model;
@@ -14,8 +16,10 @@ pub mod try_into_model;
 
 pub use entity::derive_entity;
 pub use from_row::derive_from_row;
+pub use life_enum::derive_life_enum;
 pub use life_model::derive_life_model;
 pub use life_record::derive_life_record;
+pub use life_value::derive_life_value;
 pub use linked::derive_linked;
 pub use migration_name_derive::derive_migration_name;
 pub use partial_model::derive_partial_model;
@@ -108,8 +108,10 @@ fn build_identity_from_column_ref(
                 return Err(syn::Error::new(error_span, error_msg).to_compile_error());
             }
 
-            // Validate that the segment is a valid Rust identifier
-            if syn::parse_str::<syn::Ident>(segment).is_err() {
+            // Validate that the segment is a valid Rust identifier or a path keyword
+            if !matches!(*segment, "super" | "crate" | "self")
+                && syn::parse_str::<syn::Ident>(segment).is_err()
+            {
                 return Err(syn::Error::new(
                     error_span,
                     format!("Column reference path contains invalid identifier \"{}\" at position {} in \"{}\". Identifiers must be valid Rust identifiers (e.g., start with a letter or underscore, contain only alphanumeric characters and underscores).", segment, idx + 1, column_ref),
@@ -118,19 +120,17 @@ fn build_identity_from_column_ref(
             }
         }
 
-        let mut path_tokens = quote! {};
-        for segment in prefix_segments {
-            // At this point, we've validated that segment is not empty and is a valid identifier
-            let Ok(ident) = syn::parse_str::<syn::Ident>(segment) else {
-                return Err(syn::Error::new(
-                    error_span,
-                    format!("internal error: failed to parse path segment \"{segment}\" after validation"),
-                )
-                .to_compile_error());
-            };
-            path_tokens = quote! { #path_tokens::#ident };
-        }
-        quote! { #path_tokens::Column }
+        // Parse as a path so `super::` / `crate::` prefixes resolve relative to the caller
+        let Ok(prefix_path) = syn::parse_str::<syn::Path>(&prefix) else {
+            return Err(syn::Error::new(
+                error_span,
+                format!(
+                    "internal error: failed to parse column path \"{prefix}\" after validation"
+                ),
+            )
+            .to_compile_error());
+        };
+        quote! { #prefix_path::Column }
     } else {
         // No prefix - use Entity's Column type
         quote! { <Entity as lifeguard::LifeModelTrait>::Column }
@@ -667,6 +667,8 @@ fn process_relation_variant(
     let mut through_entity: Option<String> = None;
     let mut from_column: Option<String> = None;
     let mut to_column: Option<String> = None;
+    let mut through_from_column: Option<String> = None;
+    let mut through_to_column: Option<String> = None;
 
     for attr in &variant.attrs {
         if attr.path().is_ident("lifeguard") {
@@ -702,6 +704,16 @@ fn process_relation_variant(
                     let value: syn::LitStr = meta.value()?.parse()?;
                     to_column = Some(value.value());
                     Ok(())
+                } else if meta.path.is_ident("through_from") {
+                    // Join table column(s) referencing this entity, e.g. "super::post_tags::Column::PostId"
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    through_from_column = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("through_to") {
+                    // Join table column(s) referencing the target entity
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    through_to_column = Some(value.value());
+                    Ok(())
                 } else {
                     Ok(())
                 }
@@ -738,6 +750,24 @@ fn process_relation_variant(
             ));
         }
 
+        // through_from/through_to name join table columns, so they only apply to has_many_through
+        if rel_type_str != "has_many_through"
+            && (through_from_column.is_some() || through_to_column.is_some())
+        {
+            return Some((
+                syn::Error::new_spanned(
+                    &variant.ident,
+                    "'through_from' and 'through_to' only apply to has_many_through relationships",
+                )
+                .to_compile_error(),
+                parse_quote!(Entity), // Dummy path for error case
+                variant.ident.clone(),
+                None,      // from_col
+                None,      // to_col
+                quote! {}, // Empty RelationDef construction for error case
+            ));
+        }
+
         // Capture relationship type before move
         let rel_type = match rel_type_str.as_str() {
             "has_one" => quote! { lifeguard::RelationType::HasOne },
@@ -1058,33 +1088,69 @@ fn process_relation_variant(
             // through_from_col: FK in join table pointing to source (e.g., "post_id" in PostTags for Post -> PostTags -> Tags)
             // through_to_col: FK in join table pointing to target (e.g., "tag_id" in PostTags for Post -> PostTags -> Tags)
 
+            // Explicit `through_from` / `through_to` columns win over the inferred names
+            let explicit_through_col = |column_ref: Option<&String>| {
+                column_ref.map(|column_ref| {
+                    build_identity_from_column_ref(column_ref, variant.ident.span())
+                        .map(|identity| quote! { Some(#identity) })
+                })
+            };
+
             // Infer FK column name from source entity (Entity)
             // We need to get the entity name - assume it's "Entity" in the same module
             // Use the table name to infer the FK column name
-            let source_fk_col = quote! {
-                {
-                    use lifeguard::LifeEntityName;
-                    // Get source entity's table name and infer foreign key column
-                    // Foreign key in join table pointing to source (e.g., "post_id" in post_tags for Post -> PostTags -> Tags)
-                    let from_table = Entity::default().table_name();
-                    // Convert table name to singular and append "_id"
-                    // Simple heuristic: remove trailing 's' if present, then append "_id"
-                    let fk_name = if from_table.ends_with('s') && from_table.len() > 1 {
-                        format!("{}_id", &from_table[..from_table.len() - 1])
-                    } else {
-                        format!("{}_id", from_table)
-                    };
-                    // Use String directly - DynIden::from() accepts String
-                    Some(lifeguard::Identity::Unary(sea_query::DynIden::from(fk_name)))
+            let source_fk_col = match explicit_through_col(through_from_column.as_ref()) {
+                Some(Ok(col)) => col,
+                Some(Err(err)) => {
+                    return Some((
+                        err,
+                        target_entity_path.clone(),
+                        variant.ident.clone(),
+                        from_column.clone(),
+                        to_column.clone(),
+                        quote! {},
+                    ))
                 }
+                None => quote! {
+                    {
+                        use lifeguard::LifeEntityName;
+                        // Get source entity's table name and infer foreign key column
+                        // Foreign key in join table pointing to source (e.g., "post_id" in post_tags for Post -> PostTags -> Tags)
+                        let from_table = Entity::default().table_name();
+                        // Convert table name to singular and append "_id"
+                        // Simple heuristic: remove trailing 's' if present, then append "_id"
+                        let fk_name = if from_table.ends_with('s') && from_table.len() > 1 {
+                            format!("{}_id", &from_table[..from_table.len() - 1])
+                        } else {
+                            format!("{}_id", from_table)
+                        };
+                        // Use String directly - DynIden::from() accepts String
+                        Some(lifeguard::Identity::Unary(sea_query::DynIden::from(fk_name)))
+                    }
+                },
             };
 
             // Infer FK column name from target entity
-            let target_fk_col_name = infer_foreign_key_column_name(target);
-            let target_fk_col_name_lit =
-                syn::LitStr::new(&target_fk_col_name, proc_macro2::Span::call_site());
-            let target_fk_col = quote! {
-                Some(lifeguard::Identity::Unary(sea_query::DynIden::from(#target_fk_col_name_lit)))
+            let target_fk_col = match explicit_through_col(through_to_column.as_ref()) {
+                Some(Ok(col)) => col,
+                Some(Err(err)) => {
+                    return Some((
+                        err,
+                        target_entity_path.clone(),
+                        variant.ident.clone(),
+                        from_column.clone(),
+                        to_column.clone(),
+                        quote! {},
+                    ))
+                }
+                None => {
+                    let target_fk_col_name = infer_foreign_key_column_name(target);
+                    let target_fk_col_name_lit =
+                        syn::LitStr::new(&target_fk_col_name, proc_macro2::Span::call_site());
+                    quote! {
+                        Some(lifeguard::Identity::Unary(sea_query::DynIden::from(#target_fk_col_name_lit)))
+                    }
+                }
             };
 
             (source_fk_col, target_fk_col)
@@ -18,6 +18,16 @@
 //! - `chrono::DateTime<Local>` (via `sea_query::Value::ChronoDateTimeLocal`)
 //! - `chrono::NaiveDateTime` (via `sea_query::Value::ChronoDateTime`)
 //! - `chrono::NaiveDate` (via `sea_query::Value::ChronoDate`) — Postgres `DATE`
+//! - `chrono::NaiveTime` (via `sea_query::Value::ChronoTime`) — Postgres `TIME`
+//! - Arrays: `Vec<String>`, `Vec<i32>`, `Vec<i64>`, `Vec<Uuid>` (via `sea_query::Value::Array` and
+//!   `lifeguard::ValueType`) — Postgres `TEXT[]`, `INTEGER[]`, `BIGINT[]`, `UUID[]`
+//! - `#[life_enum]` fields (via `lifeguard::ValueType`, generated by `#[derive(LifeEnum)]`) —
+//!   native Postgres `ENUM` types; the callers route these through the `*_value_type` helpers
+//! - `lifeguard::PgRange<T>` (via `lifeguard::ValueType`, see [`range_element_type`]) — Postgres
+//!   range types (`tstzrange`, `daterange`, `int8range`, …), routed the same way
+//! - `lifeguard::PgInterval`, `std::net::IpAddr`, `lifeguard::PgCidr`, `lifeguard::PgMacAddr`,
+//!   `lifeguard::CiText`, `lifeguard::PgBitString` (via `lifeguard::ValueType`, see
+//!   [`scalar_column_type`]) — Postgres `INTERVAL`, `INET`, `CIDR`, `MACADDR`, `CITEXT`, `VARBIT`
 //!
 //! # Type Conversion Consistency
 //!
@@ -74,6 +84,14 @@ pub fn is_naive_date_type(ty: &Type) -> bool {
     false
 }
 
+/// `chrono::NaiveTime` / `Value::ChronoTime` — Postgres `TIME`.
+pub fn is_naive_time_type(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        return path.segments.last().is_some_and(|s| s.ident == "NaiveTime");
+    }
+    false
+}
+
 /// If `ty` is `...::DateTime<Tz>`, returns `Some(true)` when `Tz` resolves to `Utc`, `Some(false)` for `Local`.
 ///
 /// Matches `DateTime<Utc>`, `chrono::DateTime<chrono::Utc>`, etc., by taking the last path segment of the
@@ -176,6 +194,147 @@ pub fn is_vec_u8_type(ty: &Type) -> bool {
     false
 }
 
+/// Postgres element type of an array field: `Vec<String>` → `TEXT`, `Vec<i32>` → `INTEGER`,
+/// `Vec<i64>` → `BIGINT`, `Vec<Uuid>` → `UUID`. `None` for anything else, including `Vec<u8>`
+/// (`BYTEA`).
+pub fn array_element_sql_type(ty: &Type) -> Option<&'static str> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let Some(GenericArgument::Type(element)) = args.args.first() else {
+        return None;
+    };
+    if is_uuid_type(element) {
+        return Some("UUID");
+    }
+    let Type::Path(TypePath { path, .. }) = element else {
+        return None;
+    };
+    match path.segments.last()?.ident.to_string().as_str() {
+        "String" => Some("TEXT"),
+        "i32" => Some("INTEGER"),
+        "i64" => Some("BIGINT"),
+        _ => None,
+    }
+}
+
+/// Check if a type is a supported Postgres array (see [`array_element_sql_type`])
+pub fn is_array_type(ty: &Type) -> bool {
+    array_element_sql_type(ty).is_some()
+}
+
+/// Element type `T` of a `PgRange<T>` field (`tstzrange`, `daterange`, … column); `None` for
+/// anything else.
+pub fn range_element_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "PgRange" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(element)) => Some(element),
+        _ => None,
+    }
+}
+
+/// Check if a type is a `PgRange<T>` (see [`range_element_type`])
+pub fn is_range_type(ty: &Type) -> bool {
+    range_element_type(ty).is_some()
+}
+
+/// Column type of a scalar carried as `Value::String` text (`PgInterval` → `INTERVAL`,
+/// `IpAddr` → `INET`, …); `None` for anything else.
+pub fn scalar_column_type(ty: &Type) -> Option<&'static str> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    match path.segments.last()?.ident.to_string().as_str() {
+        "PgInterval" => Some("INTERVAL"),
+        "IpAddr" => Some("INET"),
+        "PgCidr" => Some("CIDR"),
+        "PgMacAddr" => Some("MACADDR"),
+        "CiText" => Some("CITEXT"),
+        "PgBitString" => Some("VARBIT"),
+        _ => None,
+    }
+}
+
+/// `T` or `Option<T>` → `Value` through `lifeguard::ValueType` (arrays, ranges, `#[life_enum]`
+/// fields, [`scalar_column_type`] types).
+pub fn generate_value_type_to_value(src: &TokenStream) -> TokenStream {
+    quote! {
+        lifeguard::ValueType::into_value(#src.clone())
+    }
+}
+
+/// `Option<T>` → `Option<Value>` through `lifeguard::ValueType` (unset stays `None`).
+pub fn generate_option_value_type_to_value(src: &TokenStream) -> TokenStream {
+    quote! {
+        #src.as_ref()
+            .map(|v| lifeguard::ValueType::into_value(v.clone()))
+    }
+}
+
+/// `Value` → field through `<ty as lifeguard::ValueType>::from_value`, assigning `dst`.
+///
+/// With `null` (the typed NULL pattern, e.g. `Value::String(None)`), `dst` is an `Option<ty>`
+/// and a NULL clears it; without, `dst` is a bare `ty`. A value that does not convert is
+/// `#error::InvalidValueType` (`ModelError` or `ActiveModelError`) naming `expected`.
+pub fn generate_value_to_value_type_field(
+    dst: &TokenStream,
+    ty: &Type,
+    column_variant: &syn::Ident,
+    null: Option<&TokenStream>,
+    expected: &str,
+    error: &TokenStream,
+) -> TokenStream {
+    let invalid = quote! {
+        Err(#error::InvalidValueType {
+            column: stringify!(#column_variant).to_string(),
+            expected: #expected.to_string(),
+            actual: format!("{:?}", value),
+        })
+    };
+    match null {
+        Some(null) => quote! {
+            match value {
+                #null => {
+                    #dst = None;
+                    Ok(())
+                }
+                _ => match <#ty as lifeguard::ValueType>::from_value(value.clone()) {
+                    Some(v) => {
+                        #dst = Some(v);
+                        Ok(())
+                    }
+                    None => #invalid,
+                },
+            }
+        },
+        None => quote! {
+            match <#ty as lifeguard::ValueType>::from_value(value.clone()) {
+                Some(v) => {
+                    #dst = v;
+                    Ok(())
+                }
+                None => #invalid,
+            }
+        },
+    }
+}
+
 /// Check if a type is `f32` (not Option<f32>)
 pub fn is_f32_type(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty {
@@ -196,6 +355,16 @@ pub fn is_f64_type(ty: &Type) -> bool {
     false
 }
 
+/// Check if a type is `i16`, `i32` or `i64`: the integer types PostgreSQL columns hold
+pub fn is_signed_integer_type(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(segment) = path.segments.last() {
+            return segment.ident == "i16" || segment.ident == "i32" || segment.ident == "i64";
+        }
+    }
+    false
+}
+
 /// Check if a type is `Option<f32>`
 pub fn is_option_f32_type(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty {
@@ -346,6 +515,10 @@ pub fn type_to_string(ty: &Type) -> String {
 ///
 /// Returns a `TokenStream` that generates code to convert the field to `Value`.
 pub fn generate_field_to_value(field_name: &syn::Ident, field_type: &Type) -> TokenStream {
+    if is_array_type(field_type) {
+        return generate_value_type_to_value(&quote! { self.#field_name });
+    }
+
     // Check for serde_json::Value first
     if is_json_value_type(field_type) {
         return quote! {
@@ -406,6 +579,12 @@ pub fn generate_field_to_value(field_name: &syn::Ident, field_type: &Type) -> To
         };
     }
 
+    if is_naive_time_type(field_type) {
+        return quote! {
+            sea_query::Value::ChronoTime(Some(self.#field_name))
+        };
+    }
+
     // Handle other types
     if let Type::Path(TypePath { path, .. }) = field_type {
         if let Some(segment) = path.segments.last() {
@@ -461,6 +640,10 @@ pub fn generate_option_field_to_value_with_default(
     src: &TokenStream,
     inner_type: &Type,
 ) -> TokenStream {
+    if is_array_type(inner_type) {
+        return generate_value_type_to_value(src);
+    }
+
     // Check for serde_json::Value first
     if is_json_value_type(inner_type) {
         return quote! {
@@ -519,6 +702,12 @@ pub fn generate_option_field_to_value_with_default(
         };
     }
 
+    if is_naive_time_type(inner_type) {
+        return quote! {
+            #src.map(|v| sea_query::Value::ChronoTime(Some(v))).unwrap_or(sea_query::Value::ChronoTime(None))
+        };
+    }
+
     // Handle other types
     if let Type::Path(TypePath { path, .. }) = inner_type {
         if let Some(segment) = path.segments.last() {
@@ -593,6 +782,10 @@ pub fn generate_option_field_to_value_with_default(
 /// `#src` rather than the record field, which is now an
 /// [`lifeguard::ActiveValue`] rather than a bare `Option`.
 pub fn generate_option_field_to_value(src: &TokenStream, inner_type: &Type) -> TokenStream {
+    if is_array_type(inner_type) {
+        return generate_option_value_type_to_value(src);
+    }
+
     // Check for serde_json::Value first
     if is_json_value_type(inner_type) {
         return quote! {
@@ -655,6 +848,12 @@ pub fn generate_option_field_to_value(src: &TokenStream, inner_type: &Type) -> T
         };
     }
 
+    if is_naive_time_type(inner_type) {
+        return quote! {
+            #src.map(|v| sea_query::Value::ChronoTime(Some(v)))
+        };
+    }
+
     // Handle other types
     if let Type::Path(TypePath { path, .. }) = inner_type {
         if let Some(segment) = path.segments.last() {
@@ -924,6 +1123,29 @@ pub fn generate_value_to_field(
         };
     }
 
+    if is_naive_time_type(field_type) {
+        return quote! {
+            match value {
+                sea_query::Value::ChronoTime(Some(v)) => {
+                    self.#field_name = v;
+                    Ok(())
+                }
+                sea_query::Value::ChronoTime(None) => {
+                    return Err(lifeguard::ActiveModelError::InvalidValueType {
+                        column: stringify!(#column_variant).to_string(),
+                        expected: "ChronoTime (non-null)".to_string(),
+                        actual: format!("{:?}", value),
+                    });
+                }
+                _ => Err(lifeguard::ActiveModelError::InvalidValueType {
+                    column: stringify!(#column_variant).to_string(),
+                    expected: "ChronoTime".to_string(),
+                    actual: format!("{:?}", value),
+                })
+            }
+        };
+    }
+
     // Note: Money type conversion requires currency_code field, handled in FromRow generation
     // This function is for Value-to-field conversion (used in Record setters)
     // Money will need special handling in FromRow to construct from amount + currency
@@ -1281,6 +1503,17 @@ pub fn generate_value_to_option_field(
     inner_type: &Type,
     column_variant: &syn::Ident,
 ) -> TokenStream {
+    if is_array_type(inner_type) {
+        return generate_value_to_value_type_field(
+            dst,
+            inner_type,
+            column_variant,
+            Some(&quote! { sea_query::Value::Array(_, None) }),
+            "Array",
+            &quote! { lifeguard::ActiveModelError },
+        );
+    }
+
     // Check for serde_json::Value first
     if is_json_value_type(inner_type) {
         return quote! {
@@ -1424,6 +1657,26 @@ pub fn generate_value_to_option_field(
         };
     }
 
+    if is_naive_time_type(inner_type) {
+        return quote! {
+            match value {
+                sea_query::Value::ChronoTime(Some(v)) => {
+                    #dst = Some(v);
+                    Ok(())
+                }
+                sea_query::Value::ChronoTime(None) => {
+                    #dst = None;
+                    Ok(())
+                }
+                _ => Err(lifeguard::ActiveModelError::InvalidValueType {
+                    column: stringify!(#column_variant).to_string(),
+                    expected: "ChronoTime (NaiveTime)".to_string(),
+                    actual: format!("{:?}", value),
+                })
+            }
+        };
+    }
+
     if is_naive_datetime_type(inner_type) {
         return quote! {
             match value {
@@ -1742,6 +1995,23 @@ mod tests {
     use super::*;
     use syn::parse_str;
 
+    #[test]
+    fn array_element_sql_type_maps_supported_vecs() {
+        let cases = [
+            ("Vec<String>", Some("TEXT")),
+            ("Vec<i32>", Some("INTEGER")),
+            ("Vec<i64>", Some("BIGINT")),
+            ("Vec<uuid::Uuid>", Some("UUID")),
+            ("Vec<u8>", None),
+            ("Vec<f64>", None),
+            ("Option<Vec<String>>", None),
+        ];
+        for (ty, expected) in cases {
+            let parsed: Type = parse_str(ty).unwrap();
+            assert_eq!(array_element_sql_type(&parsed), expected, "{ty}");
+        }
+    }
+
     #[test]
     fn test_type_to_string_simple_types() {
         // Test simple types
@@ -2007,6 +2277,21 @@ mod tests {
         assert!(!is_naive_date_type(&parse_str("String").unwrap()));
     }
 
+    #[test]
+    fn naive_time_and_scalar_column_type_detection() {
+        assert!(is_naive_time_type(&parse_str("chrono::NaiveTime").unwrap()));
+        assert!(!is_naive_time_type(&parse_str("NaiveDate").unwrap()));
+        assert_eq!(
+            scalar_column_type(&parse_str("std::net::IpAddr").unwrap()),
+            Some("INET")
+        );
+        assert_eq!(
+            scalar_column_type(&parse_str("lifeguard::PgInterval").unwrap()),
+            Some("INTERVAL")
+        );
+        assert_eq!(scalar_column_type(&parse_str("String").unwrap()), None);
+    }
+
     #[test]
     fn datetime_utc_and_local_type_detection() {
         assert!(is_datetime_utc_type(
@@ -9,6 +9,9 @@
 //! - Default values (no attributes)
 //! - Composite primary keys
 //! - Numeric and boolean types
+//! - `#[life_enum]` fields (native PostgreSQL enum types)
+//! - Time, interval, network, `citext` and bit string fields
+//! - `#[life_value]` newtype fields
 //!
 //! NOTE: These tests currently fail to compile due to E0223 (ambiguous associated type)
 //! errors from the LifeModel macro expansion. This is a known issue affecting all
@@ -80,6 +83,271 @@ fn test_column_type_attribute() {
     assert_eq!(def.column_type, Some("INTEGER".to_string()));
 }
 
+#[test]
+fn test_array_column_type_inference() {
+    #[derive(LifeModel)]
+    #[table_name = "test_array_column_type"]
+    pub struct TestArrayColumnType {
+        #[primary_key]
+        pub id: i32,
+        pub tags: Vec<String>,
+        pub scores: Vec<i32>,
+        pub counters: Vec<i64>,
+        pub labels: Option<Vec<String>>,
+    }
+
+    let def = <Entity as LifeModelTrait>::Column::Tags.column_def();
+    assert_eq!(def.column_type, Some("TEXT[]".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Scores.column_def();
+    assert_eq!(def.column_type, Some("INTEGER[]".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Counters.column_def();
+    assert_eq!(def.column_type, Some("BIGINT[]".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Labels.column_def();
+    assert_eq!(def.column_type, Some("TEXT[]".to_string()));
+    assert!(def.nullable);
+}
+
+#[test]
+fn test_life_enum_column() {
+    use lifeguard::LifeEnum;
+    use lifeguard_derive::LifeEnum;
+
+    #[derive(LifeEnum, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[enum_name = "ticket_state"]
+    pub enum TicketState {
+        Open,
+        #[enum_value = "in_review"]
+        Reviewing,
+        WontFix,
+    }
+
+    #[derive(LifeModel)]
+    #[table_name = "test_life_enum_column"]
+    pub struct TestLifeEnumColumn {
+        #[primary_key]
+        pub id: i32,
+        #[life_enum]
+        pub state: TicketState,
+        #[life_enum]
+        pub previous_state: Option<TicketState>,
+    }
+
+    assert_eq!(TicketState::TYPE_NAME, "ticket_state");
+    assert_eq!(TicketState::labels(), ["open", "in_review", "wont_fix"]);
+    assert_eq!(
+        TicketState::from_label("in_review"),
+        Some(TicketState::Reviewing)
+    );
+
+    let def = <Entity as LifeModelTrait>::Column::State.column_def();
+    assert_eq!(def.column_type, Some("ticket_state".to_string()));
+    assert_eq!(
+        def.enum_values,
+        Some(vec!["open".into(), "in_review".into(), "wont_fix".into()])
+    );
+    assert!(!def.nullable);
+    let enum_name = <Entity as LifeModelTrait>::Column::PreviousState.column_enum_type_name();
+    assert_eq!(enum_name, Some("ticket_state".to_string()));
+    assert!(
+        <Entity as LifeModelTrait>::Column::PreviousState
+            .column_def()
+            .nullable
+    );
+    assert_eq!(
+        <Entity as LifeModelTrait>::Column::Id
+            .column_def()
+            .enum_values,
+        None
+    );
+}
+
+#[test]
+fn test_range_columns_and_exclude() {
+    use lifeguard::PgRange;
+
+    #[derive(LifeModel)]
+    #[table_name = "test_range_columns"]
+    #[exclude(
+        using = "gist",
+        elements = "room_id WITH =, during WITH &&",
+        predicate = "NOT cancelled"
+    )]
+    pub struct TestRangeColumns {
+        #[primary_key]
+        pub id: i32,
+        pub room_id: i64,
+        pub during: PgRange<chrono::DateTime<chrono::Utc>>,
+        pub stay: Option<PgRange<chrono::NaiveDate>>,
+        pub seats: PgRange<i64>,
+        pub cancelled: bool,
+    }
+
+    let def = <Entity as LifeModelTrait>::Column::During.column_def();
+    assert_eq!(def.column_type, Some("tstzrange".to_string()));
+    assert!(!def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::Stay.column_def();
+    assert_eq!(def.column_type, Some("daterange".to_string()));
+    assert!(def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::Seats.column_def();
+    assert_eq!(def.column_type, Some("int8range".to_string()));
+
+    let table = Entity::table_definition();
+    assert_eq!(
+        table.exclusion_constraints,
+        vec![lifeguard::ExclusionDefinition {
+            name: None,
+            using: "gist".to_string(),
+            elements: vec![
+                ("room_id".to_string(), "=".to_string()),
+                ("during".to_string(), "&&".to_string()),
+            ],
+            partial_where: Some("NOT cancelled".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn test_time_network_and_text_scalar_columns() {
+    use lifeguard::{CiText, PgBitString, PgCidr, PgInterval, PgMacAddr};
+
+    #[derive(LifeModel)]
+    #[table_name = "test_scalar_columns"]
+    pub struct TestScalarColumns {
+        #[primary_key]
+        pub id: i32,
+        pub opens_at: chrono::NaiveTime,
+        pub grace: Option<PgInterval>,
+        pub client_ip: std::net::IpAddr,
+        pub subnet: PgCidr,
+        pub hw_addr: Option<PgMacAddr>,
+        pub email: CiText,
+        pub flags: PgBitString,
+    }
+
+    let def = <Entity as LifeModelTrait>::Column::OpensAt.column_def();
+    assert_eq!(def.column_type, Some("TIME".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Grace.column_def();
+    assert_eq!(def.column_type, Some("INTERVAL".to_string()));
+    assert!(def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::ClientIp.column_def();
+    assert_eq!(def.column_type, Some("INET".to_string()));
+    assert!(!def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::Subnet.column_def();
+    assert_eq!(def.column_type, Some("CIDR".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::HwAddr.column_def();
+    assert_eq!(def.column_type, Some("MACADDR".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Email.column_def();
+    assert_eq!(def.column_type, Some("CITEXT".to_string()));
+    let def = <Entity as LifeModelTrait>::Column::Flags.column_def();
+    assert_eq!(def.column_type, Some("VARBIT".to_string()));
+}
+
+#[test]
+fn test_life_value_column() {
+    use lifeguard::{LifeValue, ModelTrait, ValueType};
+    use lifeguard_derive::LifeValue;
+
+    #[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[column_type = "VARCHAR(320)"]
+    pub struct Email(String);
+
+    #[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct OrderRef(i64);
+
+    #[derive(LifeModel)]
+    #[table_name = "test_life_value_column"]
+    pub struct TestLifeValueColumn {
+        #[primary_key]
+        pub id: i32,
+        #[life_value]
+        pub email: Email,
+        #[life_value]
+        pub parent_order: Option<OrderRef>,
+        #[life_value]
+        #[column_type = "TEXT"]
+        pub backup_email: Option<Email>,
+    }
+
+    assert_eq!(Email::COLUMN_TYPE, "VARCHAR(320)");
+    assert_eq!(OrderRef::COLUMN_TYPE, "BIGINT");
+    assert_eq!(
+        sea_query::Value::from(OrderRef(7)),
+        sea_query::Value::BigInt(Some(7))
+    );
+    assert_eq!(
+        <Option<OrderRef> as ValueType>::from_value(sea_query::Value::BigInt(None)),
+        Some(None)
+    );
+
+    let def = <Entity as LifeModelTrait>::Column::Email.column_def();
+    assert_eq!(def.column_type, Some("VARCHAR(320)".to_string()));
+    assert!(!def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::ParentOrder.column_def();
+    assert_eq!(def.column_type, Some("BIGINT".to_string()));
+    assert!(def.nullable);
+    let def = <Entity as LifeModelTrait>::Column::BackupEmail.column_def();
+    assert_eq!(def.column_type, Some("TEXT".to_string()));
+
+    let mut model = TestLifeValueColumnModel {
+        id: 1,
+        email: Email("ada@example.com".to_string()),
+        parent_order: Some(OrderRef(7)),
+        backup_email: None,
+    };
+    assert_eq!(
+        model.get(<Entity as LifeModelTrait>::Column::Email),
+        sea_query::Value::String(Some("ada@example.com".to_string()))
+    );
+    model
+        .set(
+            <Entity as LifeModelTrait>::Column::ParentOrder,
+            sea_query::Value::BigInt(None),
+        )
+        .expect("NULL clears an Option newtype");
+    assert_eq!(model.parent_order, None);
+    assert!(model
+        .set(
+            <Entity as LifeModelTrait>::Column::Email,
+            sea_query::Value::String(None),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_life_value_binds_and_validates_like_the_inner_type() {
+    use lifeguard::value::BytesMut;
+    use lifeguard::{LifeValue, OwnedParam, ValidationRule, ValueType};
+    use lifeguard_derive::LifeValue;
+    use may_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+    #[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Email(String);
+
+    let email = Email::from_inner("ada@example.com".to_string());
+    assert_eq!(email.as_inner(), "ada@example.com");
+
+    let mut buf = BytesMut::new();
+    assert!(matches!(
+        email.to_sql_checked(&Type::VARCHAR, &mut buf),
+        Ok(IsNull::No)
+    ));
+    assert_eq!(&buf[..], b"ada@example.com");
+    assert!(!<Email as ToSql>::accepts(&Type::INT4));
+    assert_eq!(
+        <Email as FromSql>::from_sql(&Type::TEXT, b"bo@example.com").expect("decode"),
+        Email("bo@example.com".to_string())
+    );
+    assert!(OwnedParam::from_value_type(Some(email.clone())).is_ok());
+
+    assert!(ValidationRule::Email
+        .check("email", &email.clone().into_value())
+        .is_ok());
+    assert!(ValidationRule::Email
+        .check("email", &Email("not-an-address".to_string()).into_value())
+        .is_err());
+    assert_eq!(email.into_inner(), "ada@example.com");
+}
+
 #[test]
 fn test_default_value_attribute() {
     #[derive(LifeModel)]
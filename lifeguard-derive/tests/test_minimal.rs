@@ -229,6 +229,77 @@ mod validate_multi_aggregate {
     }
 }
 
+/// Built-in `#[validate(...)]` rules: codes and parameters on each `ValidationError`.
+mod validate_builtin_rules {
+    use lifeguard::{run_validators, ActiveModelError, ValidateOp};
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "validated_posts"]
+    #[validation_strategy = "aggregate"]
+    pub struct ValidatedPost {
+        #[primary_key]
+        pub id: i32,
+        #[validate(non_empty, length(min = 2, max = 5))]
+        pub slug: String,
+        #[validate(email)]
+        pub author_email: String,
+        #[validate(one_of("draft", "published"))]
+        pub status: String,
+        #[validate(range(min = -10, max = 10))]
+        pub score: i32,
+    }
+
+    fn valid_record() -> ValidatedPostRecord {
+        let mut r = ValidatedPostRecord::new();
+        r.set_id(1)
+            .set_slug("abc".to_string())
+            .set_author_email("a@example.com".to_string())
+            .set_status("draft".to_string())
+            .set_score(-3);
+        r
+    }
+
+    #[test]
+    fn builtin_rules_accept_valid_values() {
+        run_validators(&valid_record(), ValidateOp::Insert).expect("all rules pass");
+    }
+
+    #[test]
+    fn builtin_rules_report_code_and_params() {
+        let mut r = valid_record();
+        r.set_slug("toolong".to_string())
+            .set_author_email("not-an-email".to_string())
+            .set_status("archived".to_string())
+            .set_score(11);
+        let err = run_validators(&r, ValidateOp::Insert).expect_err("every field is invalid");
+        let ActiveModelError::Validation(v) = err else {
+            panic!("expected Validation error, got {err:?}");
+        };
+        let codes: Vec<(Option<&str>, Option<&str>)> = v
+            .iter()
+            .map(|e| (e.field.as_deref(), e.code.as_deref()))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                (Some("slug"), Some("length")),
+                (Some("author_email"), Some("email")),
+                (Some("status"), Some("one_of")),
+                (Some("score"), Some("range")),
+            ]
+        );
+        assert_eq!(v[0].params.get("max"), Some(&serde_json::json!(5)));
+        assert_eq!(v[3].params.get("min"), Some(&serde_json::json!(-10)));
+    }
+
+    #[test]
+    fn builtin_rules_skip_unset_fields() {
+        run_validators(&ValidatedPostRecord::new(), ValidateOp::Insert)
+            .expect("unset fields are not validated");
+    }
+}
+
 #[test]
 fn user_record_identity_map_key_matches_pk_fingerprint() {
     use lifeguard::session::fingerprint_pk_values;
@@ -0,0 +1,888 @@
+//! `LifeRecord` CRUD SQL asserted through `lifeguard::mock::MockExecutor` (no Postgres).
+
+#![allow(clippy::unwrap_used)]
+
+use lifeguard::mock::{MockExecutor, MockResult, SqlPattern, StatementKind};
+use lifeguard::{ActiveModelError, ActiveModelTrait};
+use lifeguard_derive::{LifeModel, LifeRecord};
+use sea_query::Value;
+
+#[derive(LifeModel, LifeRecord)]
+#[table_name = "mock_users"]
+pub struct MockUser {
+    #[primary_key]
+    pub id: i32,
+    pub name: String,
+}
+
+pub mod serial {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_serial_users"]
+    pub struct SerialUser {
+        #[primary_key]
+        #[auto_increment]
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+pub mod soft {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_soft_users"]
+    #[soft_delete]
+    pub struct SoftUser {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+        pub deleted_at: Option<chrono::NaiveDateTime>,
+    }
+}
+
+#[test]
+fn insert_with_explicit_pk_emits_plain_insert() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("INSERT INTO \"mock_users\""),
+        MockResult::RowsAffected(1),
+    );
+
+    let mut record = MockUserRecord::new();
+    record.set_id(7);
+    record.set_name("Ada".to_string());
+    let model = record.insert(&mock).unwrap();
+    assert_eq!(model.id, 7);
+
+    let stmt = mock.last_statement().unwrap();
+    assert_eq!(stmt.kind, StatementKind::Execute);
+    assert!(!stmt.sql.contains("RETURNING"));
+    let values = stmt.values.unwrap().0;
+    assert!(values.contains(&Value::Int(Some(7))));
+    assert!(values.contains(&Value::String(Some("Ada".to_string()))));
+    mock.verify().unwrap();
+}
+
+#[test]
+fn insert_with_auto_increment_pk_requests_returning() {
+    let mock = MockExecutor::new();
+    let mut record = serial::SerialUserRecord::new();
+    record.set_name("Grace".to_string());
+    let err = record.insert(&mock).unwrap_err();
+    assert!(matches!(err, ActiveModelError::DatabaseError(_)));
+
+    let stmt = mock.last_statement().unwrap();
+    assert_eq!(stmt.kind, StatementKind::QueryAll);
+    assert!(stmt.sql.contains("RETURNING \"id\""));
+    assert_eq!(stmt.param_count, 1);
+}
+
+#[test]
+fn update_with_no_matching_row_is_record_not_found() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("UPDATE \"mock_users\""),
+        MockResult::RowsAffected(0),
+    );
+
+    let mut record = MockUserRecord::new();
+    record.set_id(3);
+    record.set_name("Linus".to_string());
+    let err = record.update(&mock).unwrap_err();
+    assert_eq!(err, ActiveModelError::RecordNotFound);
+
+    let stmt = mock.last_statement().unwrap();
+    assert!(stmt.sql.contains("WHERE \"id\" = $"));
+    mock.verify().unwrap();
+}
+
+#[test]
+fn delete_emits_delete_by_pk() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("DELETE FROM \"mock_users\""),
+        MockResult::RowsAffected(1),
+    );
+
+    let mut record = MockUserRecord::new();
+    record.set_id(9);
+    record.delete(&mock).unwrap();
+
+    let stmt = mock.last_statement().unwrap();
+    assert_eq!(stmt.values.unwrap().0, vec![Value::Int(Some(9))]);
+    mock.verify().unwrap();
+}
+
+#[test]
+fn soft_delete_emits_update_of_deleted_at() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("UPDATE \"mock_soft_users\""),
+        MockResult::RowsAffected(1),
+    );
+
+    let mut record = soft::SoftUserRecord::new();
+    record.set_id(4);
+    record.delete(&mock).unwrap();
+
+    let stmt = mock.last_statement().unwrap();
+    assert!(stmt.sql.contains("\"deleted_at\""));
+    assert!(!stmt.sql.contains("DELETE"));
+    mock.verify().unwrap();
+}
+
+pub mod versioned {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_versioned_docs"]
+    pub struct VersionedDoc {
+        #[primary_key]
+        pub id: i32,
+        pub title: String,
+        #[version]
+        pub version: i64,
+    }
+}
+
+#[test]
+fn versioned_update_guards_on_the_loaded_version_and_reports_missing_rows() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("UPDATE \"mock_versioned_docs\""),
+        MockResult::empty(),
+    );
+    // Zero rows back: the existence probe decides between stale and missing.
+    mock.expect(
+        SqlPattern::contains("FROM \"mock_versioned_docs\""),
+        MockResult::empty(),
+    );
+
+    let mut record = versioned::VersionedDocRecord::from_model(&versioned::VersionedDocModel {
+        id: 4,
+        title: "draft".to_string(),
+        version: 3,
+    });
+    record.set_title("final".to_string());
+    let err = record.update(&mock).unwrap_err();
+    assert_eq!(err, ActiveModelError::RecordNotFound);
+
+    let update = &mock.statements()[0];
+    assert_eq!(update.kind, StatementKind::QueryAll);
+    assert!(update.sql.contains("\"version\" = \"version\" + $"));
+    assert!(update.sql.contains("AND \"version\" = $"));
+    assert!(update.sql.contains("RETURNING \"version\""));
+    assert!(update
+        .values
+        .clone()
+        .unwrap()
+        .0
+        .contains(&Value::BigInt(Some(3))));
+    mock.verify().unwrap();
+}
+
+#[test]
+fn versioned_update_needs_a_loaded_version() {
+    let mock = MockExecutor::new().strict();
+    let mut record = versioned::VersionedDocRecord::new();
+    record.set_id(4);
+    record.set_title("final".to_string());
+    let err = record.update(&mock).unwrap_err();
+    assert_eq!(err, ActiveModelError::FieldRequired("version".to_string()));
+    assert!(mock.statements().is_empty());
+}
+
+#[test]
+fn versioned_insert_seeds_version_and_delete_checks_it() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("INSERT INTO \"mock_versioned_docs\""),
+        MockResult::RowsAffected(1),
+    );
+    mock.expect(
+        SqlPattern::contains("DELETE FROM \"mock_versioned_docs\""),
+        MockResult::RowsAffected(1),
+    );
+
+    let mut record = versioned::VersionedDocRecord::new();
+    record.set_id(5);
+    record.set_title("new".to_string());
+    let model = record.insert(&mock).unwrap();
+    assert_eq!(model.version, 1);
+
+    versioned::VersionedDocRecord::from_model(&model)
+        .delete(&mock)
+        .unwrap();
+    let delete = mock.last_statement().unwrap();
+    assert!(delete.sql.contains("\"version\" = $"));
+    assert_eq!(
+        delete.values.unwrap().0,
+        vec![Value::Int(Some(5)), Value::BigInt(Some(1))]
+    );
+    mock.verify().unwrap();
+}
+
+#[test]
+fn database_errors_surface_from_mock() {
+    let mock = MockExecutor::new();
+    mock.expect(
+        SqlPattern::Any,
+        MockResult::Error(lifeguard::LifeError::QueryError(
+            "duplicate key".to_string(),
+        )),
+    );
+    let mut record = MockUserRecord::new();
+    record.set_id(1);
+    record.set_name("dup".to_string());
+    let err = record.insert(&mock).unwrap_err();
+    assert!(matches!(err, ActiveModelError::DatabaseError(ref m) if m.contains("duplicate key")));
+}
+
+pub mod unique {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_unique_users"]
+    pub struct UniqueUser {
+        #[primary_key]
+        pub id: i32,
+        #[unique]
+        pub email: String,
+    }
+}
+
+#[test]
+fn classified_errors_map_constraint_to_column() {
+    use lifeguard::{DbErrorInfo, DbErrorKind, LifeError};
+
+    let mock = MockExecutor::new();
+    mock.expect(
+        SqlPattern::Any,
+        MockResult::Error(LifeError::UniqueViolation(
+            DbErrorInfo::new("23505", "duplicate key value violates unique constraint")
+                .with_table("mock_unique_users")
+                .with_constraint("mock_unique_users_email_key"),
+        )),
+    );
+    let mut record = unique::UniqueUserRecord::new();
+    record.set_id(1);
+    record.set_email("a@b.c".to_string());
+    match record.insert(&mock).unwrap_err() {
+        ActiveModelError::Database {
+            kind,
+            info,
+            columns,
+        } => {
+            assert_eq!(kind, DbErrorKind::UniqueViolation);
+            assert_eq!(info.sqlstate, "23505");
+            assert_eq!(columns, vec!["email".to_string()]);
+        }
+        other => panic!("expected classified error, got {other:?}"),
+    }
+}
+
+#[test]
+fn insert_many_emits_one_multi_row_statement() {
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("INSERT INTO \"mock_soft_users\""),
+        MockResult::RowsAffected(3),
+    );
+
+    let records: Vec<soft::SoftUserRecord> = (1..=3)
+        .map(|id| {
+            let mut record = soft::SoftUserRecord::new();
+            record.set_id(id);
+            record.set_name(format!("user-{id}"));
+            if id == 1 {
+                record.set_deleted_at(Some(
+                    chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+                        .unwrap()
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap(),
+                ));
+            }
+            record
+        })
+        .collect();
+    let models = soft::SoftUserRecord::insert_many(records, &mock).unwrap();
+    assert_eq!(
+        models.iter().map(|m| m.id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert!(models[1].deleted_at.is_none());
+
+    let statements = mock.statements();
+    assert_eq!(statements.len(), 1);
+    let stmt = &statements[0];
+    assert_eq!(stmt.kind, StatementKind::Execute);
+    assert!(stmt
+        .sql
+        .contains("VALUES ($1, $2, $3), ($4, $5, DEFAULT), ($6, $7, DEFAULT)"));
+    assert_eq!(stmt.param_count, 7);
+    mock.verify().unwrap();
+}
+
+#[test]
+fn insert_many_with_auto_increment_pk_requests_returning() {
+    let mock = MockExecutor::new();
+    let records: Vec<serial::SerialUserRecord> = ["a", "b"]
+        .iter()
+        .map(|name| {
+            let mut record = serial::SerialUserRecord::new();
+            record.set_name((*name).to_string());
+            record
+        })
+        .collect();
+    let err = serial::SerialUserRecord::insert_many(records, &mock).unwrap_err();
+    assert!(
+        matches!(err, ActiveModelError::DatabaseError(ref m) if m.contains("0 rows for 2 records"))
+    );
+
+    let stmt = mock.last_statement().unwrap();
+    assert_eq!(stmt.kind, StatementKind::QueryAll);
+    assert!(stmt.sql.contains("VALUES ($1), ($2) RETURNING \"id\""));
+}
+
+#[test]
+fn insert_many_splits_at_bind_parameter_limit() {
+    let mock = MockExecutor::new();
+    let records: Vec<MockUserRecord> = (0..32_768)
+        .map(|id| {
+            let mut record = MockUserRecord::new();
+            record.set_id(id);
+            record.set_name("n".to_string());
+            record
+        })
+        .collect();
+    let models = MockUserRecord::insert_many(records, &mock).unwrap();
+    assert_eq!(models.len(), 32_768);
+    assert_eq!(models[32_767].id, 32_767);
+
+    let statements = mock.statements();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].param_count, 65_534);
+    assert_eq!(statements[1].param_count, 2);
+}
+
+#[test]
+fn insert_many_on_conflict_do_nothing_reports_skipped_rows() {
+    use lifeguard::LifeModelTrait;
+
+    let mock = MockExecutor::new();
+    let records: Vec<MockUserRecord> = (1..=2)
+        .map(|id| {
+            let mut record = MockUserRecord::new();
+            record.set_id(id);
+            record.set_name("dup".to_string());
+            record
+        })
+        .collect();
+    let results = Entity::insert_many_on_conflict(
+        records,
+        &mock,
+        sea_query::OnConflict::column(Column::Id)
+            .do_nothing()
+            .to_owned(),
+    )
+    .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(Option::is_none));
+
+    let stmt = mock.last_statement().unwrap();
+    assert!(stmt
+        .sql
+        .contains("ON CONFLICT (\"id\") DO NOTHING RETURNING \"id\""));
+}
+
+#[test]
+fn insert_many_of_nothing_sends_no_sql() {
+    let mock = MockExecutor::new().strict();
+    let models = MockUserRecord::insert_many(Vec::new(), &mock).unwrap();
+    assert!(models.is_empty());
+    assert!(mock.statements().is_empty());
+}
+
+pub mod stamped {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_stamped_jobs"]
+    #[soft_delete]
+    #[auto_timestamp]
+    pub struct StampedJob {
+        #[primary_key]
+        pub id: i32,
+        pub status: String,
+        pub created_at: String,
+        pub updated_at: String,
+        pub deleted_at: Option<chrono::NaiveDateTime>,
+    }
+}
+
+#[test]
+fn update_many_emits_single_statement() {
+    use lifeguard::{ColumnTrait, LifeModelTrait};
+
+    let mock = MockExecutor::new().strict();
+    mock.expect(
+        SqlPattern::contains("UPDATE \"mock_users\""),
+        MockResult::RowsAffected(4),
+    );
+    let affected = Entity::update_many()
+        .set(Column::Name, "renamed")
+        .filter(Column::Id.gt(3))
+        .exec(&mock)
+        .unwrap();
+    assert_eq!(affected, 4);
+
+    let stmt = mock.last_statement().unwrap();
+    assert_eq!(
+        stmt.sql,
+        "UPDATE \"mock_users\" SET \"name\" = $1 WHERE \"id\" > $2"
+    );
+    mock.verify().unwrap();
+}
+
+#[test]
+fn delete_many_soft_deletes_and_bumps_updated_at() {
+    use lifeguard::{ColumnTrait, LifeModelTrait};
+
+    let mock = MockExecutor::new();
+    stamped::Entity::delete_many()
+        .filter(stamped::Column::Status.eq("done"))
+        .exec(&mock)
+        .unwrap();
+    assert_eq!(
+        mock.last_statement().unwrap().sql,
+        "UPDATE \"mock_stamped_jobs\" SET \"deleted_at\" = now(), \"updated_at\" = now() \
+         WHERE \"status\" = $1 AND \"deleted_at\" IS NULL"
+    );
+
+    stamped::Entity::delete_many()
+        .filter(stamped::Column::Status.eq("done"))
+        .force()
+        .exec(&mock)
+        .unwrap();
+    assert_eq!(
+        mock.last_statement().unwrap().sql,
+        "DELETE FROM \"mock_stamped_jobs\" WHERE \"status\" = $1"
+    );
+}
+
+#[test]
+fn update_many_skips_trashed_rows_unless_asked() {
+    use lifeguard::LifeModelTrait;
+
+    let mock = MockExecutor::new();
+    stamped::Entity::update_many()
+        .set(stamped::Column::Status, "archived")
+        .exec(&mock)
+        .unwrap();
+    assert!(mock
+        .last_statement()
+        .unwrap()
+        .sql
+        .ends_with("\"updated_at\" = now() WHERE \"deleted_at\" IS NULL"));
+
+    stamped::Entity::update_many()
+        .set(stamped::Column::Status, "archived")
+        .with_trashed()
+        .exec(&mock)
+        .unwrap();
+    assert!(!mock.last_statement().unwrap().sql.contains("WHERE"));
+}
+
+pub mod cached {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_cached_users"]
+    #[soft_delete]
+    #[cache_ttl = 60]
+    pub struct CachedUser {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+        pub deleted_at: Option<chrono::NaiveDateTime>,
+    }
+}
+
+/// Records `set` (key, ttl) and `invalidate` (key) calls, and serves what was set.
+#[derive(Default)]
+struct RecordingCache {
+    sets: std::sync::Mutex<Vec<(String, Option<u64>)>>,
+    invalidated: std::sync::Mutex<Vec<String>>,
+    values: std::sync::Mutex<std::collections::HashMap<String, String>>,
+}
+
+impl lifeguard::cache::CacheProvider for RecordingCache {
+    fn get(
+        &self,
+        key: &str,
+    ) -> Result<lifeguard::cache::CachedResult<String>, lifeguard::cache::CacheError> {
+        Ok(match self.values.lock().unwrap().get(key) {
+            Some(json) => lifeguard::cache::CachedResult::Hit(json.clone()),
+            None => lifeguard::cache::CachedResult::Miss,
+        })
+    }
+
+    fn set(
+        &self,
+        key: &str,
+        value: &str,
+        ttl: Option<u64>,
+    ) -> Result<(), lifeguard::cache::CacheError> {
+        self.sets.lock().unwrap().push((key.to_string(), ttl));
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn invalidate(&self, key: &str) -> Result<(), lifeguard::cache::CacheError> {
+        self.invalidated.lock().unwrap().push(key.to_string());
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+#[test]
+fn writes_refresh_and_deletes_evict_the_cached_row() {
+    use lifeguard::LifeModelTrait;
+
+    assert_eq!(cached::Entity::cache_ttl_seconds(), 60);
+    assert_eq!(
+        Entity::cache_ttl_seconds(),
+        lifeguard::cache::DEFAULT_CACHE_TTL_SECONDS
+    );
+
+    let cache = std::sync::Arc::new(RecordingCache::default());
+    let mock = MockExecutor::new().with_cache_provider(cache.clone());
+
+    let mut record = cached::CachedUserRecord::new();
+    record.set_id(5);
+    record.set_name("Ada".to_string());
+    record.insert(&mock).unwrap();
+    assert_eq!(
+        *cache.sets.lock().unwrap(),
+        [(
+            "lifeguard:model:mock_cached_users:i:5".to_string(),
+            Some(60)
+        )]
+    );
+
+    record.delete(&mock).unwrap();
+    assert_eq!(
+        *cache.invalidated.lock().unwrap(),
+        ["lifeguard:model:mock_cached_users:i:5"]
+    );
+}
+
+pub mod keyed {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_translations"]
+    pub struct Translation {
+        #[primary_key]
+        pub post_id: i64,
+        #[primary_key]
+        pub locale: String,
+        pub body: String,
+    }
+
+    impl lifeguard::ModelManager for Entity {}
+}
+
+impl lifeguard::ModelManager for Entity {}
+
+#[test]
+fn find_by_id_filters_on_declared_primary_key_columns() {
+    use lifeguard::ModelManager;
+
+    let mock = MockExecutor::new();
+    assert!(Entity::find_by_id(&mock, 3).unwrap().is_none());
+    let stmt = mock.last_statement().unwrap();
+    assert!(stmt.sql.ends_with("WHERE \"id\" = $1"), "{}", stmt.sql);
+    assert_eq!(stmt.values.unwrap().0, vec![Value::Int(Some(3))]);
+
+    assert!(keyed::Entity::find_by_id(&mock, (7, "en".to_string()))
+        .unwrap()
+        .is_none());
+    let stmt = mock.last_statement().unwrap();
+    assert!(
+        stmt.sql.contains("\"post_id\" = $1 AND \"locale\" = $2"),
+        "{}",
+        stmt.sql
+    );
+    assert_eq!(
+        stmt.values.unwrap().0,
+        vec![
+            Value::BigInt(Some(7)),
+            Value::String(Some("en".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn composite_primary_keys_are_cached_under_every_key_column() {
+    let cache = std::sync::Arc::new(RecordingCache::default());
+    let mock = MockExecutor::new().with_cache_provider(cache.clone());
+    let mut record = keyed::TranslationRecord::new();
+    record.set_post_id(7);
+    record.set_locale("en".to_string());
+    record.set_body("hello".to_string());
+    record.insert(&mock).unwrap();
+
+    assert_eq!(
+        cache.sets.lock().unwrap()[0].0,
+        "lifeguard:model:mock_translations:i:7\u{1f}s:en"
+    );
+}
+
+pub mod dated {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_daily_totals"]
+    pub struct DailyTotal {
+        #[primary_key]
+        pub day: chrono::NaiveDate,
+        pub total: i64,
+    }
+
+    impl lifeguard::ModelManager for Entity {}
+}
+
+fn leap_day() -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+}
+
+#[test]
+fn find_by_id_serves_date_keyed_rows_from_the_cache() {
+    use lifeguard::ModelManager;
+
+    let cache = std::sync::Arc::new(RecordingCache::default());
+    let mock = MockExecutor::new().with_cache_provider(cache.clone());
+    let mut record = dated::DailyTotalRecord::new();
+    record.set_day(leap_day());
+    record.set_total(12);
+    record.insert(&mock).unwrap();
+    assert_eq!(
+        cache.sets.lock().unwrap()[0].0,
+        "lifeguard:model:mock_daily_totals:date:2024-02-29"
+    );
+
+    mock.take_statements();
+    let cached = dated::Entity::find_by_id(&mock, leap_day())
+        .unwrap()
+        .expect("the inserted row is cached");
+    assert_eq!((cached.day, cached.total), (leap_day(), 12));
+    assert!(mock.statements().is_empty(), "{:?}", mock.sql_log());
+}
+
+/// Never grants the lease; `LifeReflector::apply` does not consult it.
+struct NoLock;
+
+impl lifeguard::cache::LeaderLock for NoLock {
+    fn try_acquire(
+        &self,
+        _: &str,
+        _: std::time::Duration,
+    ) -> Result<bool, lifeguard::cache::CacheError> {
+        Ok(false)
+    }
+
+    fn renew(&self, _: &str, _: std::time::Duration) -> Result<bool, lifeguard::cache::CacheError> {
+        Ok(false)
+    }
+
+    fn release(&self, _: &str) -> Result<(), lifeguard::cache::CacheError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn tracked_entities_map_notifications_to_their_typed_cache_key() {
+    use lifeguard::cache::{LifeReflector, ReflectAction, ReflectorConfig};
+
+    let cache = std::sync::Arc::new(RecordingCache::default());
+    let key = "lifeguard:model:mock_daily_totals:date:2024-02-29";
+    lifeguard::cache::CacheProvider::set(&*cache, key, "{}", None).unwrap();
+    let event = lifeguard::NotifyEvent {
+        channel: "daily_totals_changed".to_string(),
+        table: "mock_daily_totals".to_string(),
+        operation: lifeguard::NotifyOperation::Update,
+        id: serde_json::json!("2024-02-29"),
+    };
+    let untracked = LifeReflector::new(
+        "postgresql://unused",
+        ReflectorConfig::default(),
+        cache.clone(),
+        std::sync::Arc::new(NoLock),
+    );
+
+    // Read from the JSON alone, a date is just text: a different key.
+    untracked.apply(&event, None).unwrap();
+    assert!(cache.values.lock().unwrap().contains_key(key));
+
+    let tracked = untracked.track::<dated::Entity>();
+    assert_eq!(
+        tracked.apply(&event, None).unwrap(),
+        ReflectAction::Invalidated
+    );
+    assert!(!cache.values.lock().unwrap().contains_key(key));
+}
+
+#[test]
+fn into_model_hides_soft_deleted_rows_unless_with_trashed() {
+    let mock = MockExecutor::new();
+    lifeguard::SelectQuery::<soft::Entity>::new()
+        .into_model::<soft::SoftUserModel>()
+        .all(&mock)
+        .unwrap();
+    let sql = mock.last_statement().unwrap().sql;
+    assert!(sql.contains("\"deleted_at\" IS NULL"), "{sql}");
+
+    lifeguard::SelectQuery::<soft::Entity>::new()
+        .with_trashed()
+        .into_model::<soft::SoftUserModel>()
+        .all(&mock)
+        .unwrap();
+    let sql = mock.last_statement().unwrap().sql;
+    assert!(!sql.contains("IS NULL"), "{sql}");
+}
+
+pub mod link_tag {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_tags"]
+    pub struct Tag {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+pub mod link_post_tag {
+    use lifeguard_derive::LifeModel;
+
+    #[derive(LifeModel)]
+    #[table_name = "mock_post_tags"]
+    pub struct PostTag {
+        #[primary_key]
+        pub post_id: i32,
+        #[primary_key]
+        pub tag_id: i32,
+    }
+}
+
+pub mod link_post {
+    use lifeguard_derive::{DeriveRelation, LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord)]
+    #[table_name = "mock_posts"]
+    pub struct Post {
+        #[primary_key]
+        pub id: i32,
+        pub title: String,
+    }
+
+    #[derive(DeriveRelation)]
+    pub enum Relation {
+        #[lifeguard(
+            has_many_through = "super::link_tag::Entity",
+            through = "super::link_post_tag::Entity",
+            through_from = "super::link_post_tag::Column::PostId",
+            through_to = "super::link_post_tag::Column::TagId"
+        )]
+        Tags,
+        #[lifeguard(has_many = "super::Entity")]
+        Users,
+    }
+}
+
+fn post() -> link_post::PostRecord {
+    let mut post = link_post::PostRecord::new();
+    post.set_id(7).set_title("p".to_string());
+    post
+}
+
+#[test]
+fn sync_links_deletes_extra_and_inserts_missing_join_rows_in_a_transaction() {
+    let mock = MockExecutor::new();
+    post()
+        .sync_links::<link_tag::Entity, _>([1, 2], &mock)
+        .unwrap();
+
+    let sql = mock.sql_log();
+    assert_eq!(sql.len(), 4, "{sql:?}");
+    assert_eq!(sql[0], "BEGIN");
+    assert!(
+        sql[1].starts_with(r#"DELETE FROM "mock_post_tags" WHERE "post_id" = $1 AND NOT"#),
+        "{}",
+        sql[1]
+    );
+    assert!(
+        sql[2].starts_with(r#"INSERT INTO "mock_post_tags" ("post_id", "tag_id") VALUES"#)
+            && sql[2].ends_with("ON CONFLICT DO NOTHING"),
+        "{}",
+        sql[2]
+    );
+    assert_eq!(sql[3], "COMMIT");
+}
+
+#[test]
+fn sync_links_inside_a_transaction_uses_a_savepoint() {
+    let mock = MockExecutor::new().transactional();
+    post()
+        .sync_links::<link_tag::Entity, _>(Vec::<i32>::new(), &mock)
+        .unwrap();
+    assert_eq!(
+        mock.sql_log(),
+        [
+            "SAVEPOINT sp_1",
+            r#"DELETE FROM "mock_post_tags" WHERE "post_id" = $1"#,
+            "RELEASE SAVEPOINT sp_1",
+        ]
+    );
+}
+
+#[test]
+fn failed_sync_links_rolls_back_its_delete() {
+    let mock = MockExecutor::new();
+    mock.expect(
+        "INSERT INTO \"mock_post_tags\"",
+        MockResult::Error(lifeguard::LifeError::QueryError(
+            "tag 99 does not exist".to_string(),
+        )),
+    );
+    let err = post()
+        .sync_links::<link_tag::Entity, _>([1, 99], &mock)
+        .unwrap_err();
+    assert!(matches!(err, ActiveModelError::DatabaseError(_)), "{err:?}");
+
+    let sql = mock.sql_log();
+    assert_eq!(sql.len(), 4, "{sql:?}");
+    assert_eq!(sql[0], "BEGIN");
+    assert!(sql[1].starts_with("DELETE"), "{}", sql[1]);
+    assert!(sql[2].starts_with("INSERT"), "{}", sql[2]);
+    assert_eq!(sql[3], "ROLLBACK");
+}
+
+#[test]
+fn links_need_a_has_many_through_relation() {
+    let mock = MockExecutor::new();
+    let user = MockUserModel {
+        id: 1,
+        name: "a".to_string(),
+    };
+    let err = post().link::<Entity>(&user, &mock).unwrap_err();
+    assert!(matches!(err, ActiveModelError::Other(_)), "{err:?}");
+    assert!(mock.statements().is_empty());
+}
@@ -6,6 +6,13 @@
 //! instead of duplicating full `CREATE TABLE` bodies. Delta-only files (ALTER without a new
 //! `CREATE TABLE` in that file) stay merged with the last full snapshot so the latest file is
 //! never misread as the whole baseline.
+//!
+//! Native enum types (`#[life_enum]` columns) live in `-- Type: name` sections written before
+//! the tables. Their labels are merged the same way ([`accumulate_enum_types_from_dir`]): a new
+//! type gets its re-runnable `CREATE TYPE`, a new label on an existing type becomes
+//! **`ALTER TYPE ... ADD VALUE IF NOT EXISTS`** positioned next to its neighbour. Labels removed
+//! from an entity are left alone — PostgreSQL cannot drop enum labels — and show up in
+//! `compare-schema` instead.
 
 use std::collections::BTreeMap;
 use std::fs;
@@ -401,20 +408,35 @@ pub fn build_service_migration_body_from_accumulated(
     out
 }
 
+const TABLE_SECTION_HEADER: &str = "-- Table: ";
+const TYPE_SECTION_HEADER: &str = "-- Type: ";
+
 /// Split a generated migration file into `-- Table: name` sections (value = body after the header line).
 #[must_use]
 pub fn extract_table_sections(sql: &str) -> BTreeMap<String, String> {
+    extract_sections(sql, TABLE_SECTION_HEADER)
+}
+
+/// Split a generated migration file into `-- Type: name` sections (native enum types).
+#[must_use]
+pub fn extract_type_sections(sql: &str) -> BTreeMap<String, String> {
+    extract_sections(sql, TYPE_SECTION_HEADER)
+}
+
+/// Sections under `header`; any `-- Table:` / `-- Type:` header ends the current section.
+fn extract_sections(sql: &str, header: &str) -> BTreeMap<String, String> {
     let mut map = BTreeMap::new();
     let mut current: Option<String> = None;
     let mut buf = String::new();
 
     for line in sql.lines() {
-        if let Some(rest) = line.strip_prefix("-- Table: ") {
+        if line.starts_with(TABLE_SECTION_HEADER) || line.starts_with(TYPE_SECTION_HEADER) {
             if let Some(name) = current.take() {
-                map.insert(name, buf);
-                buf = String::new();
+                map.insert(name, std::mem::take(&mut buf));
             }
-            current = Some(rest.trim().to_string());
+            current = line
+                .strip_prefix(header)
+                .map(|rest| rest.trim().to_string());
             continue;
         }
         if current.is_some() {
@@ -428,6 +450,140 @@ pub fn extract_table_sections(sql: &str) -> BTreeMap<String, String> {
     map
 }
 
+/// Enum labels per type after replaying every `*_generated_from_entities.sql` under `dir`
+/// (oldest first): `CREATE TYPE ... AS ENUM (...)` sets the labels, `ALTER TYPE ... ADD VALUE`
+/// inserts one.
+#[must_use]
+pub fn accumulate_enum_types_from_dir(dir: &Path) -> BTreeMap<String, Vec<String>> {
+    let mut map = BTreeMap::new();
+    for path in list_generated_migration_paths_chronological(dir) {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        merge_enum_type_sections(&mut map, &content);
+    }
+    map
+}
+
+fn merge_enum_type_sections(map: &mut BTreeMap<String, Vec<String>>, sql: &str) {
+    for (type_name, section) in extract_type_sections(sql) {
+        let labels = map.entry(type_name).or_default();
+        for line in section.lines() {
+            apply_enum_type_line(labels, line.trim());
+        }
+    }
+}
+
+fn apply_enum_type_line(labels: &mut Vec<String>, line: &str) {
+    let upper = line.to_ascii_uppercase();
+    if upper.starts_with("CREATE TYPE ") {
+        if let Some(start) = upper.find(" AS ENUM (") {
+            *labels = parse_sql_string_literals(&line[start..]);
+        }
+        return;
+    }
+    if !upper.starts_with("ALTER TYPE ") {
+        return;
+    }
+    let Some(start) = upper.find(" ADD VALUE ") else {
+        return;
+    };
+    let literals = parse_sql_string_literals(&line[start..]);
+    let Some(label) = literals.first() else {
+        return;
+    };
+    if labels.contains(label) {
+        return;
+    }
+    let neighbour = literals
+        .get(1)
+        .and_then(|n| labels.iter().position(|l| l == n));
+    match neighbour {
+        Some(at) if upper.contains(" BEFORE '") => labels.insert(at, label.clone()),
+        Some(at) => labels.insert(at + 1, label.clone()),
+        None => labels.push(label.clone()),
+    }
+}
+
+/// Every `'...'` literal in `s`, with `''` unescaped.
+fn parse_sql_string_literals(s: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+        let mut literal = String::new();
+        while let Some(c) = chars.next() {
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    chars.next();
+                    literal.push('\'');
+                    continue;
+                }
+                break;
+            }
+            literal.push(c);
+        }
+        out.push(literal);
+    }
+    out
+}
+
+/// `-- Type:` sections for `types` (`(type name, labels)`) against the enum labels merged from
+/// every migration file in `service_dir`. Write the result **before** the table body.
+#[must_use]
+pub fn build_enum_types_body_from_service_dir(
+    service_dir: &Path,
+    types: &[(String, Vec<String>)],
+) -> String {
+    build_enum_types_body_from_accumulated(&accumulate_enum_types_from_dir(service_dir), types)
+}
+
+/// `CREATE TYPE` for types missing from `acc`, `ALTER TYPE ... ADD VALUE IF NOT EXISTS` for labels
+/// missing from an existing type (placed after the previous label, or before the next one).
+#[must_use]
+pub fn build_enum_types_body_from_accumulated(
+    acc: &BTreeMap<String, Vec<String>>,
+    types: &[(String, Vec<String>)],
+) -> String {
+    let mut out = String::new();
+    for (type_name, labels) in types {
+        let Some(old) = acc.get(type_name).filter(|old| !old.is_empty()) else {
+            out.push_str(&format!(
+                "{TYPE_SECTION_HEADER}{type_name}\n{}\n",
+                crate::sql_generator::generate_create_enum_type_sql(type_name, labels)
+            ));
+            continue;
+        };
+        let mut delta = String::new();
+        for (i, label) in labels.iter().enumerate() {
+            if old.contains(label) {
+                continue;
+            }
+            let position = if i == 0 {
+                labels.iter().find(|l| old.contains(*l)).map(|next| {
+                    format!(" BEFORE {}", crate::sql_generator::quote_sql_literal(next))
+                })
+            } else {
+                Some(format!(
+                    " AFTER {}",
+                    crate::sql_generator::quote_sql_literal(&labels[i - 1])
+                ))
+            };
+            delta.push_str(&format!(
+                "ALTER TYPE {type_name} ADD VALUE IF NOT EXISTS {}{};\n",
+                crate::sql_generator::quote_sql_literal(label),
+                position.unwrap_or_default()
+            ));
+        }
+        if !delta.is_empty() {
+            out.push_str(&format!("{TYPE_SECTION_HEADER}{type_name}\n{delta}\n"));
+        }
+    }
+    out
+}
+
 /// Extract `(table_bare, create_body, tail_after_create)` from a single table section.
 fn extract_create_and_tail(section: &str) -> Option<(String, String, String)> {
     let key = "CREATE TABLE IF NOT EXISTS ";
@@ -512,6 +668,7 @@ fn parse_column_defs_from_create_body(body: &str) -> BTreeMap<String, String> {
             || upper.starts_with("UNIQUE(")
             || upper.starts_with("PRIMARY KEY")
             || upper.starts_with("FOREIGN KEY")
+            || upper.starts_with("EXCLUDE ")
         {
             continue;
         }
@@ -692,6 +849,15 @@ CREATE INDEX idx_widgets_sku ON widgets(sku);
         assert!(service_migration_is_empty(&body));
     }
 
+    #[test]
+    fn exclude_constraints_are_not_columns() {
+        let cols = parse_column_defs_from_create_body(
+            "id INTEGER NOT NULL,\n    during tstzrange NOT NULL,\n    \
+             EXCLUDE USING gist (id WITH =, during WITH &&)",
+        );
+        assert_eq!(cols.keys().collect::<Vec<_>>(), ["during", "id"]);
+    }
+
     #[test]
     fn parses_inventory_style_create_block() {
         let section = r"CREATE TABLE IF NOT EXISTS categories (
@@ -877,4 +1043,62 @@ CREATE INDEX IF NOT EXISTS idx_t_col ON t(col);
         assert!(body.contains("CREATE INDEX IF NOT EXISTS idx_t_col"));
         assert!(!body.contains("IF NOT EXISTS IF NOT EXISTS"));
     }
+
+    fn mood_type() -> Vec<(String, Vec<String>)> {
+        vec![(
+            "mood".to_string(),
+            vec!["happy".to_string(), "meh".to_string(), "sad".to_string()],
+        )]
+    }
+
+    #[test]
+    fn enum_type_sections_do_not_leak_into_tables() {
+        let sql = format!(
+            "-- Type: mood\n{}\n-- Table: widgets\n{WIDGETS_CREATE}\n",
+            crate::sql_generator::generate_create_enum_type_sql("mood", &["happy".into()])
+        );
+        let tables = extract_table_sections(&sql);
+        assert!(!tables["widgets"].contains("CREATE TYPE"));
+        let types = extract_type_sections(&sql);
+        assert!(types["mood"].contains("CREATE TYPE mood AS ENUM ('happy');"));
+        assert!(!types["mood"].contains("CREATE TABLE"));
+    }
+
+    #[test]
+    fn new_enum_type_gets_create_and_new_labels_get_add_value() {
+        let mut acc = BTreeMap::new();
+        let body = build_enum_types_body_from_accumulated(&acc, &mood_type());
+        assert!(body.starts_with("-- Type: mood\n"));
+        assert!(body.contains("CREATE TYPE mood AS ENUM ('happy', 'meh', 'sad');"));
+
+        merge_enum_type_sections(
+            &mut acc,
+            "-- Type: mood\nDO $$ BEGIN\n    CREATE TYPE mood AS ENUM ('meh', 'sad');\n\
+             EXCEPTION\n    WHEN duplicate_object THEN NULL;\nEND $$;\n",
+        );
+        let body = build_enum_types_body_from_accumulated(&acc, &mood_type());
+        assert!(
+            body.contains("ALTER TYPE mood ADD VALUE IF NOT EXISTS 'happy' BEFORE 'meh';"),
+            "{body}"
+        );
+        assert!(!body.contains("CREATE TYPE"));
+
+        merge_enum_type_sections(&mut acc, &body);
+        assert_eq!(acc["mood"], ["happy", "meh", "sad"]);
+        assert!(build_enum_types_body_from_accumulated(&acc, &mood_type()).is_empty());
+    }
+
+    #[test]
+    fn add_value_after_is_replayed_in_position_and_quotes_unescaped() {
+        let mut labels = vec!["a".to_string(), "c".to_string()];
+        apply_enum_type_line(
+            &mut labels,
+            "ALTER TYPE t ADD VALUE IF NOT EXISTS 'b' AFTER 'a';",
+        );
+        apply_enum_type_line(
+            &mut labels,
+            "ALTER TYPE t ADD VALUE IF NOT EXISTS 'it''s' AFTER 'c';",
+        );
+        assert_eq!(labels, ["a", "b", "c", "it's"]);
+    }
 }
@@ -264,7 +264,7 @@ fn handle_compare_schema(
     print!("{report}");
     if report.has_drift() {
         return Err(MigrationError::InvalidFormat(
-            "compare-schema: live database does not match merged generated migration baseline (tables, column names, index keys, index definitions, index presence, btree operator classes, expression vs simple index keys, normalized expression slot parity, explicit ordering/collation, and/or index access methods — see above)."
+            "compare-schema: live database does not match merged generated migration baseline (tables, column names, index keys, index definitions, index presence, btree operator classes, expression vs simple index keys, normalized expression slot parity, explicit ordering/collation, index access methods, and/or enum type labels — see above)."
                 .to_string(),
         ));
     }
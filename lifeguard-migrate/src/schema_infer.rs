@@ -559,6 +559,12 @@ fn map_pg_to_rust(
         "timestamp without time zone" => "chrono::NaiveDateTime".to_string(),
         "timestamp with time zone" => "chrono::DateTime<chrono::Utc>".to_string(),
         "date" => "chrono::NaiveDate".to_string(),
+        "time without time zone" => "chrono::NaiveTime".to_string(),
+        "interval" => "lifeguard::PgInterval".to_string(),
+        "inet" => "std::net::IpAddr".to_string(),
+        "cidr" => "lifeguard::PgCidr".to_string(),
+        "macaddr" => "lifeguard::PgMacAddr".to_string(),
+        "bit" | "bit varying" => "lifeguard::PgBitString".to_string(),
         "uuid" => "uuid::Uuid".to_string(),
         "json" | "jsonb" => "serde_json::Value".to_string(),
         "numeric" | "decimal" => "rust_decimal::Decimal".to_string(),
@@ -576,6 +582,19 @@ fn map_pg_to_rust(
             "numeric" => "rust_decimal::Decimal".to_string(),
             "timestamptz" => "chrono::DateTime<chrono::Utc>".to_string(),
             "timestamp" => "chrono::NaiveDateTime".to_string(),
+            "int4range" => "lifeguard::PgRange<i32>".to_string(),
+            "int8range" => "lifeguard::PgRange<i64>".to_string(),
+            "numrange" => "lifeguard::PgRange<rust_decimal::Decimal>".to_string(),
+            "daterange" => "lifeguard::PgRange<chrono::NaiveDate>".to_string(),
+            "tsrange" => "lifeguard::PgRange<chrono::NaiveDateTime>".to_string(),
+            "tstzrange" => "lifeguard::PgRange<chrono::DateTime<chrono::Utc>>".to_string(),
+            "time" => "chrono::NaiveTime".to_string(),
+            "interval" => "lifeguard::PgInterval".to_string(),
+            "inet" => "std::net::IpAddr".to_string(),
+            "cidr" => "lifeguard::PgCidr".to_string(),
+            "macaddr" => "lifeguard::PgMacAddr".to_string(),
+            "citext" => "lifeguard::CiText".to_string(),
+            "bit" | "varbit" => "lifeguard::PgBitString".to_string(),
             _ => return None,
         },
     };
@@ -871,6 +890,32 @@ mod tests {
         assert_eq!(t, "chrono::NaiveDateTime");
     }
 
+    #[test]
+    fn map_pg_range_types_to_pg_range() {
+        let (t, _) = map_pg_to_rust("tstzrange", "tstzrange", false).unwrap();
+        assert_eq!(t, "lifeguard::PgRange<chrono::DateTime<chrono::Utc>>");
+        let (t_opt, _) = map_pg_to_rust("daterange", "daterange", true).unwrap();
+        assert_eq!(t_opt, "Option<lifeguard::PgRange<chrono::NaiveDate>>");
+    }
+
+    #[test]
+    fn map_pg_time_interval_network_and_citext() {
+        for (data_type, udt_name, rust) in [
+            ("time without time zone", "time", "chrono::NaiveTime"),
+            ("interval", "interval", "lifeguard::PgInterval"),
+            ("inet", "inet", "std::net::IpAddr"),
+            ("cidr", "cidr", "lifeguard::PgCidr"),
+            ("macaddr", "macaddr", "lifeguard::PgMacAddr"),
+            ("USER-DEFINED", "citext", "lifeguard::CiText"),
+            ("bit varying", "varbit", "lifeguard::PgBitString"),
+        ] {
+            let (t, _) = map_pg_to_rust(data_type, udt_name, false).unwrap();
+            assert_eq!(t, rust, "{data_type}");
+        }
+        let (t_opt, _) = map_pg_to_rust("inet", "inet", true).unwrap();
+        assert_eq!(t_opt, "Option<std::net::IpAddr>");
+    }
+
     #[test]
     fn map_pg_date_matches_naive_date() {
         let (t, _) = map_pg_to_rust("date", "date", false).unwrap();
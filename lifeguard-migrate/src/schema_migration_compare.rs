@@ -6,8 +6,11 @@
 //!   to column lines from merged `CREATE TABLE` + `ADD COLUMN` fragments (see
 //!   [`crate::generated_migration_diff::column_map_from_merged_baseline`]).
 //!
-//! Does **not** compare SQL type text literally (PG `data_type` vs migration `INTEGER` spelling);
-//! name-level reconciliation is the Phase A column diff scope.
+//! - **Column types:** for columns in both, the live `format_type` and the merged column type are
+//!   normalized with [`canonical_column_type_for_compare`] (`INTEGER` / `SERIAL` → `integer`,
+//!   `TIME` → `time without time zone`, `VARCHAR(64)` → `character varying(64)`, …) and compared
+//!   → [`ColumnTypeDrift`]. Types the normalizer does not know (enums, domains, precision-qualified
+//!   timestamps) are not compared.
 //!
 //! **Index keys + `INCLUDE` (PRD §5.7a):** for shared tables, rows from
 //! [`pg_indexes`](https://www.postgresql.org/docs/current/view-pg-indexes.html) are parsed for
@@ -33,6 +36,11 @@
 //! `COLLATE`, `ASC`/`DESC`, `NULLS` in merged SQL are checked against `pg_index` (`indcollation`,
 //! `indoption`) on simple keys → [`IndexBtreeKeyOrderingCollationDrift`]. See
 //! [`MigrationDbCompareReport`] and **`compare-schema`** limits in `lifeguard-migrate/README.md`.
+//!
+//! **Enum labels:** for every native enum type in the merged `-- Type:` sections
+//! ([`accumulate_enum_types_from_dir`]), the live labels from `pg_enum` (in `enumsortorder`) must
+//! match the merged labels exactly → [`EnumTypeLabelDrift`]. Enum types only in the database are
+//! not reported.
 
 use lifeguard::LifeError;
 use lifeguard::LifeExecutor;
@@ -41,8 +49,8 @@ use std::fmt;
 use std::path::Path;
 
 use crate::generated_migration_diff::{
-    accumulate_table_baselines_from_dir, column_map_from_merged_baseline,
-    index_statements_for_table_from_merged_baseline,
+    accumulate_enum_types_from_dir, accumulate_table_baselines_from_dir,
+    column_map_from_merged_baseline, index_statements_for_table_from_merged_baseline,
 };
 
 /// Table names from merged `*_generated_from_entities.sql` in `dir` (from `-- Table:` headers).
@@ -98,6 +106,62 @@ pub fn fetch_live_table_column_names(
     Ok(set)
 }
 
+/// Column name → `format_type(atttypid, atttypmod)` for one table (`integer`,
+/// `character varying(64)`, `time without time zone`, …).
+pub fn fetch_live_table_column_types(
+    executor: &dyn LifeExecutor,
+    schema: &str,
+    table: &str,
+) -> Result<BTreeMap<String, String>, LifeError> {
+    let sql = r"
+        SELECT a.attname::text, format_type(a.atttypid, a.atttypmod)
+        FROM pg_attribute a
+        JOIN pg_class c ON c.oid = a.attrelid
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE n.nspname = $1 AND c.relname = $2 AND a.attnum > 0 AND NOT a.attisdropped
+        ORDER BY a.attnum
+    ";
+    let rows = executor.query_all(sql, &[&schema, &table])?;
+    let mut map = BTreeMap::new();
+    for row in rows {
+        let name: String = row
+            .try_get(0)
+            .map_err(|e| LifeError::Other(format!("compare-schema attname: {e}")))?;
+        let ty: String = row
+            .try_get(1)
+            .map_err(|e| LifeError::Other(format!("compare-schema format_type: {e}")))?;
+        map.insert(name, ty);
+    }
+    Ok(map)
+}
+
+/// Labels of every enum type in `schema`, in `pg_enum.enumsortorder` order.
+pub fn fetch_live_enum_labels(
+    executor: &dyn LifeExecutor,
+    schema: &str,
+) -> Result<BTreeMap<String, Vec<String>>, LifeError> {
+    let sql = r"
+        SELECT t.typname::text, e.enumlabel::text
+        FROM pg_type t
+        JOIN pg_namespace n ON n.oid = t.typnamespace
+        JOIN pg_enum e ON e.enumtypid = t.oid
+        WHERE n.nspname = $1
+        ORDER BY t.typname, e.enumsortorder
+    ";
+    let rows = executor.query_all(sql, &[&schema])?;
+    let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for row in rows {
+        let type_name: String = row
+            .try_get(0)
+            .map_err(|e| LifeError::Other(format!("compare-schema typname: {e}")))?;
+        let label: String = row
+            .try_get(1)
+            .map_err(|e| LifeError::Other(format!("compare-schema enumlabel: {e}")))?;
+        map.entry(type_name).or_default().push(label);
+    }
+    Ok(map)
+}
+
 /// One row from [`pg_indexes`](https://www.postgresql.org/docs/current/view-pg-indexes.html).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LiveIndexRow {
@@ -232,6 +296,27 @@ pub struct TableColumnDrift {
     pub only_in_migrations: Vec<String>,
 }
 
+/// Column whose normalized live type differs from its merged migration type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnTypeDrift {
+    pub table: String,
+    pub column: String,
+    /// Type as written in the merged baseline (`TIME`).
+    pub migration_type: String,
+    /// Live `format_type` (`time with time zone`).
+    pub live_type: String,
+}
+
+/// Enum type whose live `pg_enum` labels differ from the merged `-- Type:` sections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumTypeLabelDrift {
+    pub type_name: String,
+    /// Labels after replaying `CREATE TYPE` / `ALTER TYPE ... ADD VALUE`, in order.
+    pub migration_labels: Vec<String>,
+    /// Live labels in `enumsortorder`; `None` when the type does not exist in the schema.
+    pub live_labels: Option<Vec<String>>,
+}
+
 /// Result of [`compare_generated_dir_to_live_db`].
 #[derive(Debug, Clone)]
 pub struct MigrationDbCompareReport {
@@ -245,6 +330,8 @@ pub struct MigrationDbCompareReport {
     pub only_in_migrations: Vec<String>,
     /// Tables in both baselines where **column name** sets differ.
     pub column_drifts: Vec<TableColumnDrift>,
+    /// Columns in both baselines whose normalized SQL types differ.
+    pub column_type_drifts: Vec<ColumnTypeDrift>,
     /// Shared tables where a live index’s **parsed** key / `INCLUDE` names reference a column absent
     /// from the merged baseline.
     pub index_column_drifts: Vec<IndexColumnDrift>,
@@ -264,18 +351,22 @@ pub struct MigrationDbCompareReport {
     pub index_btree_nondefault_opclass_drifts: Vec<IndexBtreeNonDefaultOpclassDrift>,
     /// Explicit `COLLATE` / `ASC`/`DESC` / `NULLS` on a merged simple key vs live `pg_index` flags.
     pub index_btree_key_ordering_collation_drifts: Vec<IndexBtreeKeyOrderingCollationDrift>,
+    /// Native enum types whose live labels (or their order) differ from merged migrations.
+    pub enum_label_drifts: Vec<EnumTypeLabelDrift>,
 }
 
 impl MigrationDbCompareReport {
-    /// `true` when table sets differ, column names drift, an index’s parsed key / `INCLUDE`
+    /// `true` when table sets differ, column names or types drift, an index’s parsed key / `INCLUDE`
     /// names reference a column missing from the merged migration map, a live index is not
     /// **`btree`** (implicit or explicit), **T1** / only-in-one-side index names differ, a btree
-    /// key uses a **non-default** opclass (**T2b** catalog), **T3** / **T3 v2**, or ordering/collation drift.
+    /// key uses a **non-default** opclass (**T2b** catalog), **T3** / **T3 v2**, ordering/collation
+    /// drift, or an enum type's labels differ.
     #[must_use]
     pub fn has_drift(&self) -> bool {
         !self.only_in_database.is_empty()
             || !self.only_in_migrations.is_empty()
             || !self.column_drifts.is_empty()
+            || !self.column_type_drifts.is_empty()
             || !self.index_column_drifts.is_empty()
             || !self.index_access_method_drifts.is_empty()
             || !self
@@ -287,6 +378,7 @@ impl MigrationDbCompareReport {
             || !self.index_only_in_migration.is_empty()
             || !self.index_btree_nondefault_opclass_drifts.is_empty()
             || !self.index_btree_key_ordering_collation_drifts.is_empty()
+            || !self.enum_label_drifts.is_empty()
     }
 }
 
@@ -305,6 +397,7 @@ pub fn compare_generated_dir_to_live_db(
     only_mig.sort();
 
     let mut column_drifts = Vec::new();
+    let mut column_type_drifts = Vec::new();
     for table in on_disk.intersection(&live) {
         let Some(parts) = acc.get(table.as_str()) else {
             continue;
@@ -327,6 +420,26 @@ pub fn compare_generated_dir_to_live_db(
                 only_in_migrations: only_col_mig,
             });
         }
+        let live_types = fetch_live_table_column_types(executor, schema, table)?;
+        for (column, definition) in &mig_map {
+            let Some(live_type) = live_types.get(column) else {
+                continue;
+            };
+            let migration_type = migration_column_sql_type(definition);
+            if let (Some(mig), Some(live)) = (
+                canonical_column_type_for_compare(migration_type),
+                canonical_column_type_for_compare(live_type),
+            ) {
+                if mig != live {
+                    column_type_drifts.push(ColumnTypeDrift {
+                        table: table.clone(),
+                        column: column.clone(),
+                        migration_type: migration_type.to_string(),
+                        live_type: live_type.clone(),
+                    });
+                }
+            }
+        }
     }
     column_drifts.sort_by(|a, b| a.table.cmp(&b.table));
 
@@ -645,12 +758,28 @@ pub fn compare_generated_dir_to_live_db(
             .then_with(|| a.key_ordinal.cmp(&b.key_ordinal))
     });
 
+    let live_enums = fetch_live_enum_labels(executor, schema)?;
+    let schema_prefix = format!("{schema}.");
+    let mut enum_label_drifts = Vec::new();
+    for (type_name, migration_labels) in accumulate_enum_types_from_dir(generated_dir) {
+        let live_name = type_name.strip_prefix(&schema_prefix).unwrap_or(&type_name);
+        let live_labels = live_enums.get(live_name).cloned();
+        if live_labels.as_ref() != Some(&migration_labels) {
+            enum_label_drifts.push(EnumTypeLabelDrift {
+                type_name,
+                migration_labels,
+                live_labels,
+            });
+        }
+    }
+
     Ok(MigrationDbCompareReport {
         schema: schema.to_string(),
         generated_dir: generated_dir.to_path_buf(),
         only_in_database: only_in_db,
         only_in_migrations: only_mig,
         column_drifts,
+        column_type_drifts,
         index_column_drifts,
         index_access_method_drifts,
         index_expression_key_vs_simple_migration_drifts,
@@ -660,6 +789,7 @@ pub fn compare_generated_dir_to_live_db(
         index_only_in_migration,
         index_btree_nondefault_opclass_drifts,
         index_btree_key_ordering_collation_drifts,
+        enum_label_drifts,
     })
 }
 
@@ -1688,6 +1818,107 @@ fn collect_ordering_collation_drifts(
     out
 }
 
+/// Words that end the type in a column definition (`TIME NOT NULL DEFAULT …`).
+const COLUMN_CONSTRAINT_KEYWORDS: &[&str] = &[
+    "NOT",
+    "NULL",
+    "DEFAULT",
+    "PRIMARY",
+    "UNIQUE",
+    "REFERENCES",
+    "CHECK",
+    "GENERATED",
+    "COLLATE",
+    "CONSTRAINT",
+];
+
+/// The SQL type at the start of a merged column definition tail (`NUMERIC(19, 4)` from
+/// `NUMERIC(19, 4) NOT NULL DEFAULT 0`).
+#[must_use]
+pub fn migration_column_sql_type(definition: &str) -> &str {
+    let definition = definition.trim();
+    let mut depth = 0_i32;
+    let mut word_start = true;
+    for (i, c) in definition.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && word_start && c.is_ascii_alphabetic() {
+            let word: String = definition[i..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            if COLUMN_CONSTRAINT_KEYWORDS
+                .iter()
+                .any(|k| k.eq_ignore_ascii_case(&word))
+            {
+                return definition[..i].trim_end();
+            }
+        }
+        word_start = c.is_whitespace();
+    }
+    definition.trim_end_matches(',').trim_end()
+}
+
+/// PostgreSQL's `format_type` spelling of a column type, for comparing a migration type with a
+/// live one (`SERIAL` / `INT4` → `integer`, `TIMESTAMPTZ` → `timestamp with time zone`,
+/// `NUMERIC(19, 4)` → `numeric(19,4)`, `TEXT[]` → `text[]`). `None` for types it does not know
+/// (enums, domains, precision-qualified times), which are not compared.
+#[must_use]
+pub fn canonical_column_type_for_compare(sql_type: &str) -> Option<String> {
+    let lower = sql_type
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase();
+    if let Some(element) = lower.strip_suffix("[]") {
+        return Some(format!("{}[]", canonical_column_type_for_compare(element)?));
+    }
+    let (base, args) = match lower.split_once('(') {
+        Some((base, rest)) => (
+            base.trim_end(),
+            Some(rest.strip_suffix(')')?.replace(' ', "")),
+        ),
+        None => (lower.as_str(), None),
+    };
+    // `pg_catalog.int4`, `public.citext`
+    let base = base.rsplit('.').next()?;
+    let name = match (base, args) {
+        ("int" | "integer" | "int4" | "serial" | "serial4", None) => "integer",
+        ("bigint" | "int8" | "bigserial" | "serial8", None) => "bigint",
+        ("smallint" | "int2" | "smallserial" | "serial2", None) => "smallint",
+        ("boolean" | "bool", None) => "boolean",
+        ("real" | "float4", None) => "real",
+        ("double precision" | "float" | "float8", None) => "double precision",
+        ("numeric" | "decimal", None) => "numeric",
+        ("numeric" | "decimal", Some(args)) => return Some(format!("numeric({args})")),
+        ("varchar" | "character varying", None) => "character varying",
+        ("varchar" | "character varying", Some(args)) => {
+            return Some(format!("character varying({args})"))
+        }
+        ("char" | "character", None) => "character(1)",
+        ("char" | "character", Some(args)) => return Some(format!("character({args})")),
+        ("bit", None) => "bit(1)",
+        ("bit", Some(args)) => return Some(format!("bit({args})")),
+        ("varbit" | "bit varying", None) => "bit varying",
+        ("varbit" | "bit varying", Some(args)) => return Some(format!("bit varying({args})")),
+        ("timestamp" | "timestamp without time zone", None) => "timestamp without time zone",
+        ("timestamptz" | "timestamp with time zone", None) => "timestamp with time zone",
+        ("time" | "time without time zone", None) => "time without time zone",
+        ("timetz" | "time with time zone", None) => "time with time zone",
+        (
+            "text" | "uuid" | "json" | "jsonb" | "bytea" | "date" | "interval" | "inet" | "cidr"
+            | "macaddr" | "macaddr8" | "citext" | "int4range" | "int8range" | "numrange"
+            | "daterange" | "tsrange" | "tstzrange",
+            None,
+        ) => base,
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
 impl fmt::Display for MigrationDbCompareReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(
@@ -1751,6 +1982,19 @@ impl fmt::Display for MigrationDbCompareReport {
                 }
             }
         }
+        if !self.column_type_drifts.is_empty() {
+            writeln!(
+                f,
+                "  Column type differences (normalized; tables in both live DB and merged migrations):"
+            )?;
+            for d in &self.column_type_drifts {
+                writeln!(
+                    f,
+                    "    Table `{}` column `{}`: migrations `{}` vs database `{}`",
+                    d.table, d.column, d.migration_type, d.live_type
+                )?;
+            }
+        }
         if !self.index_column_drifts.is_empty() {
             writeln!(
                 f,
@@ -1891,6 +2135,25 @@ impl fmt::Display for MigrationDbCompareReport {
                 )?;
             }
         }
+        if !self.enum_label_drifts.is_empty() {
+            writeln!(
+                f,
+                "  Enum type labels differ from merged migrations (pg_enum, in sort order):"
+            )?;
+            for d in &self.enum_label_drifts {
+                let live = d
+                    .live_labels
+                    .as_ref()
+                    .map_or("(type missing)".to_string(), |l| l.join(", "));
+                writeln!(
+                    f,
+                    "    Type `{}`: migrations [{}] vs database [{}]",
+                    d.type_name,
+                    d.migration_labels.join(", "),
+                    live
+                )?;
+            }
+        }
         Ok(())
     }
 }
@@ -1907,6 +2170,7 @@ mod tests {
             only_in_database: vec![],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![],
             index_expression_key_vs_simple_migration_drifts: vec![],
@@ -1916,6 +2180,7 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(!r.has_drift());
         let s = r.to_string();
@@ -1930,6 +2195,7 @@ mod tests {
             only_in_database: vec!["orphan".into()],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![],
             index_expression_key_vs_simple_migration_drifts: vec![],
@@ -1939,6 +2205,7 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
     }
@@ -1955,6 +2222,7 @@ mod tests {
                 only_in_database: vec!["extra".into()],
                 only_in_migrations: vec![],
             }],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![],
             index_expression_key_vs_simple_migration_drifts: vec![],
@@ -1964,6 +2232,7 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
         let s = r.to_string();
@@ -1979,6 +2248,7 @@ mod tests {
             only_in_database: vec![],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![IndexColumnDrift {
                 table: "t".into(),
                 index_name: "ix".into(),
@@ -1992,6 +2262,7 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
         assert!(r.to_string().contains("Index key / INCLUDE columns"));
@@ -2072,6 +2343,7 @@ mod tests {
             only_in_database: vec![],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![IndexAccessMethodDrift {
                 table: "t".into(),
@@ -2085,6 +2357,7 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
         assert!(r.to_string().contains("access method not btree"));
@@ -2109,6 +2382,7 @@ mod tests {
             only_in_database: vec![],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![],
             index_expression_key_vs_simple_migration_drifts: vec![],
@@ -2126,6 +2400,7 @@ mod tests {
                 migration_explicit_opclass: None,
             }],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
         let s = r.to_string();
@@ -2141,6 +2416,7 @@ mod tests {
             only_in_database: vec![],
             only_in_migrations: vec![],
             column_drifts: vec![],
+            column_type_drifts: vec![],
             index_column_drifts: vec![],
             index_access_method_drifts: vec![],
             index_expression_key_vs_simple_migration_drifts: vec![
@@ -2158,10 +2434,123 @@ mod tests {
             index_only_in_migration: vec![],
             index_btree_nondefault_opclass_drifts: vec![],
             index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
         };
         assert!(r.has_drift());
         let s = r.to_string();
         assert!(s.contains("expression key"));
         assert!(s.contains("lower((email))"));
     }
+
+    #[test]
+    fn migration_column_sql_type_stops_at_constraints() {
+        assert_eq!(
+            migration_column_sql_type("NUMERIC(19, 4) NOT NULL DEFAULT 0"),
+            "NUMERIC(19, 4)"
+        );
+        assert_eq!(
+            migration_column_sql_type("TIMESTAMP WITH TIME ZONE DEFAULT now()"),
+            "TIMESTAMP WITH TIME ZONE"
+        );
+        assert_eq!(migration_column_sql_type("INET"), "INET");
+        assert_eq!(
+            migration_column_sql_type("INTEGER REFERENCES users(id)"),
+            "INTEGER"
+        );
+    }
+
+    #[test]
+    fn canonical_column_types_match_format_type_spellings() {
+        for (migration, live) in [
+            ("SERIAL", "integer"),
+            ("BIGINT", "bigint"),
+            ("NUMERIC(19, 4)", "numeric(19,4)"),
+            ("VARCHAR(64)", "character varying(64)"),
+            ("TIMESTAMPTZ", "timestamp with time zone"),
+            ("TIME", "time without time zone"),
+            ("INTERVAL", "interval"),
+            ("INET", "inet"),
+            ("CITEXT", "public.citext"),
+            ("VARBIT", "bit varying"),
+            ("TEXT[]", "text[]"),
+            ("DOUBLE PRECISION", "double precision"),
+        ] {
+            assert_eq!(
+                canonical_column_type_for_compare(migration),
+                canonical_column_type_for_compare(live),
+                "{migration} vs {live}"
+            );
+            assert!(canonical_column_type_for_compare(live).is_some(), "{live}");
+        }
+        assert_ne!(
+            canonical_column_type_for_compare("TIME"),
+            canonical_column_type_for_compare("time with time zone")
+        );
+        assert_eq!(canonical_column_type_for_compare("order_status"), None);
+        assert_eq!(
+            canonical_column_type_for_compare("timestamp(3) with time zone"),
+            None
+        );
+    }
+
+    #[test]
+    fn has_drift_when_column_types_differ_only() {
+        let r = MigrationDbCompareReport {
+            schema: "public".into(),
+            generated_dir: Path::new("/x").to_path_buf(),
+            only_in_database: vec![],
+            only_in_migrations: vec![],
+            column_drifts: vec![],
+            column_type_drifts: vec![ColumnTypeDrift {
+                table: "shifts".into(),
+                column: "starts_at".into(),
+                migration_type: "TIME".into(),
+                live_type: "time with time zone".into(),
+            }],
+            index_column_drifts: vec![],
+            index_access_method_drifts: vec![],
+            index_expression_key_vs_simple_migration_drifts: vec![],
+            index_key_normalized_slots_mismatch_drifts: vec![],
+            index_definition_text_drifts: vec![],
+            index_only_in_database: vec![],
+            index_only_in_migration: vec![],
+            index_btree_nondefault_opclass_drifts: vec![],
+            index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![],
+        };
+        assert!(r.has_drift());
+        let s = r.to_string();
+        assert!(s.contains("Table `shifts` column `starts_at`"));
+        assert!(s.contains("migrations `TIME` vs database `time with time zone`"));
+    }
+
+    #[test]
+    fn has_drift_when_enum_labels_differ_only() {
+        let r = MigrationDbCompareReport {
+            schema: "public".into(),
+            generated_dir: Path::new("/x").to_path_buf(),
+            only_in_database: vec![],
+            only_in_migrations: vec![],
+            column_drifts: vec![],
+            column_type_drifts: vec![],
+            index_column_drifts: vec![],
+            index_access_method_drifts: vec![],
+            index_expression_key_vs_simple_migration_drifts: vec![],
+            index_key_normalized_slots_mismatch_drifts: vec![],
+            index_definition_text_drifts: vec![],
+            index_only_in_database: vec![],
+            index_only_in_migration: vec![],
+            index_btree_nondefault_opclass_drifts: vec![],
+            index_btree_key_ordering_collation_drifts: vec![],
+            enum_label_drifts: vec![EnumTypeLabelDrift {
+                type_name: "order_status".into(),
+                migration_labels: vec!["pending".into(), "shipped".into()],
+                live_labels: Some(vec!["pending".into()]),
+            }],
+        };
+        assert!(r.has_drift());
+        let s = r.to_string();
+        assert!(s.contains("Type `order_status`"));
+        assert!(s.contains("[pending, shipped] vs database [pending]"));
+    }
 }
@@ -22,6 +22,15 @@
 //! - **Inline `UNIQUE` / composite `UNIQUE` / `REFERENCES` inside `CREATE TABLE`** are only applied
 //!   when the table is first created; they are **not** re-evaluated when `IF NOT EXISTS` skips.
 //! - **`COMMENT ON`** is naturally re-runnable (replaces the comment).
+//!
+//! ## Native enum types
+//!
+//! `#[life_enum]` columns use a PostgreSQL `ENUM` type. [`enum_types_for_entity`] lists the types
+//! an entity needs and [`generate_create_enum_type_sql`] emits them; callers write these
+//! `-- Type:` sections **before** the `-- Table:` sections that reference them. PostgreSQL has no
+//! `CREATE TYPE IF NOT EXISTS`, so the statement is wrapped in a `DO` block that ignores
+//! `duplicate_object`; label changes are deltas (`ALTER TYPE … ADD VALUE`, see
+//! [`crate::generated_migration_diff`]).
 
 use lifeguard::NotifyDefinition;
 use lifeguard::{
@@ -34,11 +43,14 @@ use std::fmt::Write;
 /// Generate SQL CREATE TABLE statement from entity metadata
 ///
 /// This function generates a complete SQL CREATE TABLE statement including:
-/// - Column definitions with types, nullability, defaults
+/// - Column definitions with types, nullability, defaults (`CITEXT` columns need
+///   `CREATE EXTENSION citext`)
 /// - Primary key constraints
 /// - Foreign key constraints
 /// - CHECK constraints (column and table level)
 /// - Unique constraints (single and composite)
+/// - `EXCLUDE` constraints (`#[exclude]`; `=` on scalar columns under `gist` needs
+///   `CREATE EXTENSION btree_gist`)
 /// - Indexes
 /// - Table comments
 ///
@@ -165,7 +177,7 @@ where
             if col_def
                 .column_type
                 .as_ref()
-                .map(|s| s.contains("UUID"))
+                .map(|s| s.contains("UUID") && !s.ends_with("[]"))
                 .unwrap_or(false)
             {
                 col_sql.push_str(" DEFAULT gen_random_uuid()");
@@ -219,8 +231,9 @@ where
     // Write column definitions
     for (i, col_def) in column_defs.iter().enumerate() {
         let is_last_column = i == column_defs.len() - 1;
-        let has_table_constraints =
-            !table_def.check_constraints.is_empty() || !table_def.composite_unique.is_empty();
+        let has_table_constraints = !table_def.check_constraints.is_empty()
+            || !table_def.composite_unique.is_empty()
+            || !table_def.exclusion_constraints.is_empty();
 
         if is_last_column && !has_table_constraints {
             // Last column and no table constraints - no comma
@@ -233,8 +246,9 @@ where
 
     // Add table-level CHECK constraints
     for (i, (constraint_name, check_expr)) in table_def.check_constraints.iter().enumerate() {
-        let is_last =
-            i == table_def.check_constraints.len() - 1 && table_def.composite_unique.is_empty();
+        let is_last = i == table_def.check_constraints.len() - 1
+            && table_def.composite_unique.is_empty()
+            && table_def.exclusion_constraints.is_empty();
         // Use custom name if provided, otherwise generate from table name
         let constraint_name_str = constraint_name
             .as_ref()
@@ -259,7 +273,8 @@ where
 
     // Add composite unique constraints
     for (i, unique_cols) in table_def.composite_unique.iter().enumerate() {
-        let is_last = i == table_def.composite_unique.len() - 1;
+        let is_last =
+            i == table_def.composite_unique.len() - 1 && table_def.exclusion_constraints.is_empty();
         let cols_str = unique_cols.join(", ");
         if is_last {
             writeln!(sql, "    UNIQUE({})", cols_str)
@@ -270,6 +285,17 @@ where
         }
     }
 
+    // Add EXCLUDE constraints (`#[exclude]`)
+    for (i, exclusion) in table_def.exclusion_constraints.iter().enumerate() {
+        let separator = if i == table_def.exclusion_constraints.len() - 1 {
+            ""
+        } else {
+            ","
+        };
+        writeln!(sql, "    {}{}", exclusion.constraint_sql(), separator)
+            .map_err(|e| format!("Failed to write SQL: {}", e))?;
+    }
+
     // Close CREATE TABLE
     writeln!(sql, ");").map_err(|e| format!("Failed to write SQL: {}", e))?;
     writeln!(sql).map_err(|e| format!("Failed to write SQL: {}", e))?;
@@ -434,6 +460,42 @@ where
     Ok(sql)
 }
 
+/// Native enum types used by `E`'s `#[life_enum]` columns as `(type name, labels)`, in column
+/// order and without duplicates.
+pub fn enum_types_for_entity<E>() -> Vec<(String, Vec<String>)>
+where
+    E: LifeModelTrait,
+{
+    let mut types: Vec<(String, Vec<String>)> = Vec::new();
+    for col in E::all_columns() {
+        let col_def = col.column_def();
+        let (Some(type_name), Some(labels)) = (col_def.column_type, col_def.enum_values) else {
+            continue;
+        };
+        if !types.iter().any(|(name, _)| *name == type_name) {
+            types.push((type_name, labels));
+        }
+    }
+    types
+}
+
+/// `CREATE TYPE <type_name> AS ENUM (...)`, re-runnable: an existing type is left as-is.
+pub fn generate_create_enum_type_sql(type_name: &str, labels: &[String]) -> String {
+    let labels = labels
+        .iter()
+        .map(|label| quote_sql_literal(label))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        "DO $$ BEGIN\n    CREATE TYPE {type_name} AS ENUM ({labels});\nEXCEPTION\n    WHEN duplicate_object THEN NULL;\nEND $$;\n"
+    )
+}
+
+/// `'text'` with embedded quotes doubled.
+pub(crate) fn quote_sql_literal(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
 fn index_covers_only_column(index: &lifeguard::IndexDefinition, col_name: &str) -> bool {
     let cov = if !index.key_parts.is_empty() {
         index_key_parts_coverage_columns(&index.key_parts)
@@ -679,6 +741,12 @@ mod tests {
             "DATE",
             "TIME",
             "BYTEA",
+            "INTERVAL",
+            "INET",
+            "CIDR",
+            "MACADDR",
+            "CITEXT",
+            "VARBIT",
         ] {
             assert_eq!(
                 infer_zero_default_for_sql_type(t),
@@ -688,6 +756,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn infer_zero_default_does_not_apply_to_arrays() {
+        for t in ["TEXT[]", "INTEGER[]", "BIGINT[]", "UUID[]"] {
+            assert_eq!(
+                infer_zero_default_for_sql_type(t),
+                None,
+                "`0` is not an array literal: {t}"
+            );
+        }
+    }
+
+    #[test]
+    fn create_enum_type_is_rerunnable_and_quotes_labels() {
+        let sql = generate_create_enum_type_sql(
+            "order_status",
+            &["pending".to_string(), "won't ship".to_string()],
+        );
+        assert_eq!(
+            sql,
+            "DO $$ BEGIN\n    CREATE TYPE order_status AS ENUM ('pending', 'won''t ship');\nEXCEPTION\n    WHEN duplicate_object THEN NULL;\nEND $$;\n"
+        );
+    }
+
     #[test]
     fn infer_zero_default_ignores_trailing_constraint_tokens() {
         // `column_type` is usually just the type, but be tolerant if a caller passes a snippet.
@@ -0,0 +1,42 @@
+//! `#[exclude(...)]` becomes an `EXCLUDE` table constraint inside the generated `CREATE TABLE`,
+//! and `PgRange<T>` fields get their range column types.
+
+#![allow(warnings)]
+
+use lifeguard::{LifeModelTrait, PgRange};
+use lifeguard_derive::LifeModel;
+use lifeguard_migrate::sql_generator;
+
+#[test]
+fn generate_sql_emits_exclude_constraint_after_composite_unique() {
+    #[derive(LifeModel)]
+    #[table_name = "bookings"]
+    #[composite_unique = "room_id, reference"]
+    #[exclude(
+        using = "gist",
+        elements = "room_id WITH =, during WITH &&",
+        name = "bookings_no_overlap"
+    )]
+    pub struct Booking {
+        #[primary_key]
+        pub id: i32,
+        pub room_id: i64,
+        pub reference: String,
+        pub during: PgRange<chrono::DateTime<chrono::Utc>>,
+    }
+
+    let sql = sql_generator::generate_create_table_sql::<Entity>(Entity::table_definition())
+        .expect("sql");
+
+    assert!(
+        sql.contains("during tstzrange NOT NULL,"),
+        "expected tstzrange column, got:\n{sql}"
+    );
+    assert!(
+        sql.contains(
+            "    UNIQUE(room_id, reference),\n    CONSTRAINT bookings_no_overlap EXCLUDE USING gist \
+             (room_id WITH =, during WITH &&)\n);"
+        ),
+        "expected EXCLUDE as the last table constraint, got:\n{sql}"
+    );
+}
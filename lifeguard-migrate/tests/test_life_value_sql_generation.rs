@@ -0,0 +1,56 @@
+//! `#[life_value]` newtype fields get their `LifeValue::COLUMN_TYPE` in the generated
+//! `CREATE TABLE` instead of the `TEXT` fallback.
+
+#![allow(warnings)]
+
+use lifeguard::LifeModelTrait;
+use lifeguard_derive::{LifeModel, LifeValue};
+use lifeguard_migrate::sql_generator;
+
+#[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[column_type = "VARCHAR(320)"]
+pub struct Email(String);
+
+#[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Money(rust_decimal::Decimal);
+
+#[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OrderId(uuid::Uuid);
+
+#[test]
+fn generate_sql_uses_newtype_column_types() {
+    #[derive(LifeModel)]
+    #[table_name = "invoices"]
+    pub struct Invoice {
+        #[primary_key]
+        pub id: i32,
+        #[life_value]
+        pub order_id: OrderId,
+        #[life_value]
+        pub customer_email: Email,
+        #[life_value]
+        pub total: Money,
+        #[life_value]
+        pub discount: Option<Money>,
+    }
+
+    let sql = sql_generator::generate_create_table_sql::<Entity>(Entity::table_definition())
+        .expect("sql");
+
+    let columns: Vec<&str> = sql
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .collect();
+    for column in [
+        "order_id UUID NOT NULL",
+        "customer_email VARCHAR(320) NOT NULL",
+        "total NUMERIC(19, 4) NOT NULL DEFAULT 0",
+        "discount NUMERIC(19, 4)",
+    ] {
+        assert!(
+            columns.contains(&column),
+            "expected `{column}`, got:\n{sql}"
+        );
+    }
+    assert!(!sql.contains("TEXT"), "no TEXT fallback, got:\n{sql}");
+}
@@ -4,6 +4,10 @@
 //! `lifeguard-migrate`, and other `lifeguard-*` packages. It may later be extracted to a
 //! standalone repository without changing the on-disk name.
 //!
-//! # Status
-//!
-//! Implementation is in progress; see the crate `README.md` and the repository root `README.md`.
+//! The worker itself is [`lifeguard::cache::LifeReflector`]; this crate packages it as the
+//! `lifeguard-reflector` binary and re-exports the pieces needed to embed it elsewhere.
+
+pub use lifeguard::cache::{
+    LeaderLock, LifeReflector, RedisLeaderLock, ReflectAction, ReflectorConfig, ReflectorHandle,
+    ReflectorMode,
+};
@@ -1,4 +1,71 @@
-fn main() {
-    println!("Lifeguard Reflector - Enterprise Component");
-    println!("Status: In Development");
+//! Lifeguard Reflector CLI
+//!
+//! Runs one `LifeReflector` replica. Start several for failover: they elect a leader through a
+//! Redis lease and only the leader `LISTEN`s. Configuration comes from the same
+//! `config/config.toml` / `LIFEGUARD__*` layout as the library: `[database] url` for PostgreSQL and
+//! `[reflector]` for Redis, channels, mode and lease.
+
+use clap::Parser;
+use lifeguard::DatabaseConfig;
+use lifeguard_reflector::{LifeReflector, ReflectorConfig};
+use std::process::ExitCode;
+use std::sync::atomic::AtomicBool;
+
+#[derive(Parser)]
+#[command(name = "lifeguard-reflector")]
+#[command(about = "Cache coherence worker for Lifeguard ORM (LISTEN/NOTIFY -> Redis)")]
+#[command(version = "0.1.0")]
+struct Cli {
+    /// Config file without extension (same layout as `config/config.toml`)
+    #[arg(long, default_value = "config/config")]
+    config: String,
+
+    /// Additional channel to LISTEN on (repeatable; added to `[reflector] channels`)
+    #[arg(long = "channel")]
+    channels: Vec<String>,
+
+    /// Verbose output
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let level = if cli.verbose { "debug" } else { "info" };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(level)).init();
+
+    let database = match DatabaseConfig::load_from(&cli.config) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error: failed to load [database] config: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let reflector_config = match ReflectorConfig::load_from(&cli.config) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error: failed to load [reflector] config: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let reflector = match LifeReflector::connect_redis(database.url, reflector_config) {
+        Ok(r) => cli.channels.into_iter().fold(r, |r, c| r.listen(c)),
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if reflector.channels().is_empty() {
+        eprintln!(
+            "Error: no channels configured. Set [reflector] channels, LIFEGUARD__REFLECTOR__CHANNELS, or pass --channel."
+        );
+        return ExitCode::FAILURE;
+    }
+
+    // Runs until the process is killed; the lease then expires and another replica takes over.
+    let stop = AtomicBool::new(false);
+    reflector.run(&stop);
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,42 @@
+//! Chunking arithmetic for multi-row `INSERT ... VALUES (...), (...)` statements.
+//!
+//! The PostgreSQL wire protocol encodes the parameter count of a prepared
+//! statement as an `Int16`, so a single statement can bind at most
+//! [`MAX_BIND_PARAMS`] values. `insert_many` splits its rows so that no
+//! generated statement crosses that limit.
+
+/// Largest number of bind parameters PostgreSQL accepts in one statement.
+pub const MAX_BIND_PARAMS: usize = 65_535;
+
+/// Number of rows that fit in one statement when each row binds
+/// `columns_per_row` parameters.
+///
+/// Always at least 1, so a caller iterating with `chunks()` makes progress
+/// even for a degenerate zero-column row.
+#[must_use]
+pub fn rows_per_chunk(columns_per_row: usize) -> usize {
+    if columns_per_row == 0 {
+        return MAX_BIND_PARAMS;
+    }
+    (MAX_BIND_PARAMS / columns_per_row).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_per_chunk_stays_under_bind_limit() {
+        assert_eq!(rows_per_chunk(1), 65_535);
+        assert_eq!(rows_per_chunk(2), 32_767);
+        assert_eq!(rows_per_chunk(7), 9_362);
+        assert!(rows_per_chunk(7) * 7 <= MAX_BIND_PARAMS);
+        assert!((rows_per_chunk(7) + 1) * 7 > MAX_BIND_PARAMS);
+    }
+
+    #[test]
+    fn rows_per_chunk_never_returns_zero() {
+        assert_eq!(rows_per_chunk(0), MAX_BIND_PARAMS);
+        assert_eq!(rows_per_chunk(100_000), 1);
+    }
+}
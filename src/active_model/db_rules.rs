@@ -0,0 +1,103 @@
+//! Database-backed field rules behind `#[validate(unique)]`, `#[validate(unique_with = "..")]`
+//! and `#[validate(exists = "path::Entity")]` on derived `LifeRecord` fields.
+//!
+//! The derive calls these from [`ActiveModelBehavior::validate_database`](super::ActiveModelBehavior::validate_database)
+//! on `insert` / `update`, through the executor the write runs on, so a duplicate or dangling
+//! reference is reported as a field [`ValidationError`] instead of an opaque constraint
+//! violation. The check and the write are separate statements: under concurrent writers the
+//! database constraint remains the guarantee, and its violation still surfaces as
+//! [`ActiveModelError::Database`].
+//!
+//! | Attribute item | Code | Query |
+//! |---|---|---|
+//! | `unique` | `unique` | `COUNT(*)` of rows with the same value, own primary key excluded on update |
+//! | `unique_with = "tenant_id"` | `unique` | as `unique`, over the field and the named columns |
+//! | `exists = "parent::Entity"` | `exists` | `COUNT(*)` of `parent` rows whose primary key is the value |
+//!
+//! **NULL values** are not checked: Postgres treats NULLs as distinct in unique constraints, and
+//! a NULL foreign key references nothing.
+
+use sea_query::{IdenStatic, Value};
+
+use super::error::ActiveModelError;
+use super::validate_op::ValidationError;
+use crate::executor::LifeExecutor;
+use crate::query::column::column_trait::ColumnTrait;
+use crate::query::manager::primary_key_condition;
+use crate::query::traits::{LifeEntityName, LifeModelTrait};
+use crate::value::types::is_null;
+
+/// Fail when another `E` row holds the same `columns` values.
+///
+/// `columns[0]` is the validated field; the rest are its `unique_with` partners. Soft-deleted
+/// rows count, since the unique constraint covers them too. `exclude_pk` is the record's own
+/// primary key on update.
+///
+/// # Errors
+///
+/// [`ActiveModelError::Validation`] with code `unique` when a matching row exists;
+/// [`ActiveModelError::DatabaseError`] when the query fails.
+pub fn check_unique<E: LifeModelTrait>(
+    executor: &dyn LifeExecutor,
+    field: &str,
+    columns: &[(E::Column, Value)],
+    exclude_pk: Option<&[Value]>,
+) -> Result<(), ActiveModelError> {
+    if columns.iter().any(|(_, value)| is_null(value)) {
+        return Ok(());
+    }
+    let mut condition = sea_query::Condition::all();
+    for (column, value) in columns {
+        condition = condition.add(column.eq(value.clone()));
+    }
+    if let Some(pk_values) = exclude_pk {
+        condition = condition.add(primary_key_condition::<E>(pk_values)?.not());
+    }
+    let taken = E::find()
+        .with_trashed()
+        .filter(condition)
+        .count()
+        .one(executor)?;
+    if taken == 0 {
+        return Ok(());
+    }
+
+    let err = if columns.len() > 1 {
+        let names: Vec<&str> = columns.iter().map(|(c, _)| c.as_str()).collect();
+        let message = format!("has already been taken for this {}", names[1..].join(", "));
+        ValidationError::rule(field, "unique", message).with_param("columns", names)
+    } else {
+        ValidationError::rule(field, "unique", "has already been taken")
+    };
+    Err(ActiveModelError::Validation(vec![err]))
+}
+
+/// Fail when no `P` row has `value` as its primary key.
+///
+/// Soft-deleted `P` rows do not count as existing.
+///
+/// # Errors
+///
+/// [`ActiveModelError::Validation`] with code `exists` when the row is missing;
+/// [`ActiveModelError::DatabaseError`] when `P` has no single-column primary key or the query
+/// fails.
+pub fn check_exists<P: LifeModelTrait>(
+    executor: &dyn LifeExecutor,
+    field: &str,
+    value: &Value,
+) -> Result<(), ActiveModelError> {
+    if is_null(value) {
+        return Ok(());
+    }
+    let condition = primary_key_condition::<P>(std::slice::from_ref(value))?;
+    if P::find().filter(condition).count().one(executor)? > 0 {
+        return Ok(());
+    }
+    let table = P::default().table_name();
+    Err(ActiveModelError::Validation(vec![ValidationError::rule(
+        field,
+        "exists",
+        format!("does not reference an existing {table} row"),
+    )
+    .with_param("table", table)]))
+}
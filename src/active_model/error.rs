@@ -4,6 +4,11 @@
 //! that occur during `ActiveModel` operations.
 
 use super::validate_op::ValidationError;
+use crate::db_error::{constraint_columns, DbErrorInfo, DbErrorKind};
+use crate::executor::LifeError;
+use crate::query::table::TableDefinition;
+use crate::query::traits::LifeModelTrait;
+use sea_query::IdenStatic;
 
 /// Error type for `ActiveModel` operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -22,8 +27,27 @@ pub enum ActiveModelError {
     FieldRequired(String),
     /// Record not found (e.g., UPDATE/DELETE affected zero rows)
     RecordNotFound,
+    /// Optimistic-lock conflict: the row still exists, but its `#[version]` column no longer
+    /// holds the version this record was loaded with.
+    StaleRecord {
+        /// Table of the conflicting row
+        table: String,
+        /// Version the write expected to find
+        expected_version: i64,
+        /// Identity-map key of the row, when the write came from a [`crate::session::Session`] flush
+        identity_key: Option<String>,
+    },
     /// Database operation failed
     DatabaseError(String),
+    /// Database operation failed with a classified server error (see [`crate::db_error`])
+    Database {
+        /// Error class (unique violation, deadlock, …)
+        kind: DbErrorKind,
+        /// SQLSTATE, constraint, table and column reported by the server
+        info: DbErrorInfo,
+        /// Entity column names the constraint was generated from (empty when unknown)
+        columns: Vec<String>,
+    },
     /// Other error
     Other(String),
     /// Validation failed (field-level and/or model-level rules)
@@ -53,9 +77,34 @@ impl std::fmt::Display for ActiveModelError {
             ActiveModelError::RecordNotFound => {
                 write!(f, "Record not found (no rows affected)")
             }
+            ActiveModelError::StaleRecord {
+                table,
+                expected_version,
+                identity_key,
+            } => {
+                write!(
+                    f,
+                    "Stale record in {table}: expected version {expected_version}, but the row was changed concurrently"
+                )?;
+                if let Some(key) = identity_key {
+                    write!(f, " [identity key: {key}]")?;
+                }
+                Ok(())
+            }
             ActiveModelError::DatabaseError(msg) => {
                 write!(f, "Database error: {msg}")
             }
+            ActiveModelError::Database {
+                kind,
+                info,
+                columns,
+            } => {
+                write!(f, "Database error ({kind}): {info}")?;
+                if !columns.is_empty() {
+                    write!(f, " [columns: {}]", columns.join(", "))?;
+                }
+                Ok(())
+            }
             ActiveModelError::Other(msg) => write!(f, "ActiveModel error: {msg}"),
             ActiveModelError::Validation(errors) => {
                 if errors.is_empty() {
@@ -78,3 +127,75 @@ impl std::fmt::Display for ActiveModelError {
 }
 
 impl std::error::Error for ActiveModelError {}
+
+/// Executor errors without entity context: typed server errors keep their classification
+/// (with no column mapping), everything else becomes [`ActiveModelError::DatabaseError`].
+impl From<LifeError> for ActiveModelError {
+    fn from(err: LifeError) -> Self {
+        match (err.db_error_kind(), err.db_error_info()) {
+            (Some(kind), Some(info)) => ActiveModelError::Database {
+                kind,
+                info: info.clone(),
+                columns: Vec::new(),
+            },
+            _ => ActiveModelError::DatabaseError(err.to_string()),
+        }
+    }
+}
+
+impl ActiveModelError {
+    /// Convert an executor error raised while writing entity `E`.
+    ///
+    /// Typed server errors become [`ActiveModelError::Database`] with `columns` resolved through
+    /// [`constraint_columns`]; anything else stays [`ActiveModelError::DatabaseError`].
+    /// Used by `#[derive(LifeRecord)]`.
+    pub fn from_life_error<E>(err: LifeError, table_def: &TableDefinition) -> Self
+    where
+        E: LifeModelTrait,
+    {
+        let Some(kind) = err.db_error_kind() else {
+            return ActiveModelError::DatabaseError(err.to_string());
+        };
+        let info = err.db_error_info().cloned().unwrap_or_default();
+        let columns = constraint_columns::<E>(&info, table_def)
+            .into_iter()
+            .map(|c| c.as_str().to_string())
+            .collect();
+        ActiveModelError::Database {
+            kind,
+            info,
+            columns,
+        }
+    }
+
+    /// Whether this is an optimistic-lock conflict ([`ActiveModelError::StaleRecord`]).
+    pub fn is_stale(&self) -> bool {
+        matches!(self, ActiveModelError::StaleRecord { .. })
+    }
+
+    /// Attach the identity-map key of the row being flushed to a [`ActiveModelError::StaleRecord`]
+    /// that does not carry one yet; other errors are returned unchanged.
+    #[must_use]
+    pub fn with_identity_key(self, key: &str) -> Self {
+        match self {
+            ActiveModelError::StaleRecord {
+                table,
+                expected_version,
+                identity_key: None,
+            } => ActiveModelError::StaleRecord {
+                table,
+                expected_version,
+                identity_key: Some(key.to_string()),
+            },
+            other => other,
+        }
+    }
+
+    /// Error class when this is a classified database error.
+    pub fn db_error_kind(&self) -> Option<DbErrorKind> {
+        match self {
+            ActiveModelError::Database { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Join-table writes for `has_many_through` relations behind
+//! [`ActiveModelTrait::link`](super::ActiveModelTrait::link),
+//! [`unlink`](super::ActiveModelTrait::unlink),
+//! [`sync_links`](super::ActiveModelTrait::sync_links) and many-to-many children queued with
+//! [`add_child`](super::ActiveModelTrait::add_child).
+//!
+//! Everything is driven by the relation's [`RelationDef`]: `through_tbl` is the join table,
+//! `through_from_col` holds the record's `from_col` key and `through_to_col` the related row's
+//! `to_col` key.
+
+use sea_query::{
+    Condition, Expr, ExprTrait, OnConflict, PostgresQueryBuilder, Query, TableRef, Value, Values,
+};
+
+use super::error::ActiveModelError;
+use super::traits::ActiveModelTrait;
+use crate::executor::LifeExecutor;
+use crate::model::ModelTrait;
+use crate::relation::def::{RelationDef, RelationType};
+use crate::relation::identity::Identity;
+
+/// Join table and its two key column lists, or an error for anything but `has_many_through`.
+fn through(def: &RelationDef) -> Result<(&TableRef, &Identity, &Identity), ActiveModelError> {
+    if def.rel_type != RelationType::HasManyThrough {
+        return Err(ActiveModelError::Other(format!(
+            "link management needs a has_many_through relation, got {:?}",
+            def.rel_type
+        )));
+    }
+    match (&def.through_tbl, &def.through_from_col, &def.through_to_col) {
+        (Some(table), Some(from), Some(to))
+            if from.arity() == def.from_col.arity() && to.arity() == def.to_col.arity() =>
+        {
+            Ok((table, from, to))
+        }
+        _ => Err(ActiveModelError::Other(
+            "has_many_through relation is missing its through table or key columns".to_string(),
+        )),
+    }
+}
+
+/// The record's `from_col` values (its side of every join row).
+pub(crate) fn record_key<A: ActiveModelTrait>(
+    record: &A,
+    def: &RelationDef,
+) -> Result<Vec<Value>, ActiveModelError> {
+    through(def)?;
+    def.from_col
+        .iter()
+        .map(|col| {
+            record
+                .get_col(&col.to_string())
+                .ok_or(ActiveModelError::PrimaryKeyRequired)
+        })
+        .collect()
+}
+
+/// The related model's `to_col` values.
+pub(crate) fn related_key<M: ModelTrait>(
+    model: &M,
+    def: &RelationDef,
+) -> Result<Vec<Value>, ActiveModelError> {
+    def.to_col
+        .iter()
+        .map(|col| {
+            let name = col.to_string();
+            model.get_by_column_name(&name).ok_or_else(|| {
+                ActiveModelError::Other(format!("related model has no `{name}` key column"))
+            })
+        })
+        .collect()
+}
+
+/// A built statement and its bound values.
+type Statement = (String, Values);
+
+/// `INSERT INTO through (...) VALUES ... ON CONFLICT DO NOTHING`; existing links are kept.
+pub(crate) fn insert_links(
+    executor: &dyn LifeExecutor,
+    def: &RelationDef,
+    record: &[Value],
+    targets: &[Vec<Value>],
+) -> Result<u64, ActiveModelError> {
+    match insert_statement(def, record, targets)? {
+        Some((sql, values)) => Ok(executor.execute_values(&sql, &values)?),
+        None => Ok(0),
+    }
+}
+
+/// Delete the record's join rows whose target key is in `targets` (`keep == false`) or not in
+/// `targets` (`keep == true`).
+pub(crate) fn delete_links(
+    executor: &dyn LifeExecutor,
+    def: &RelationDef,
+    record: &[Value],
+    targets: &[Vec<Value>],
+    keep: bool,
+) -> Result<u64, ActiveModelError> {
+    match delete_statement(def, record, targets, keep)? {
+        Some((sql, values)) => Ok(executor.execute_values(&sql, &values)?),
+        None => Ok(0),
+    }
+}
+
+/// Make the record's join rows exactly `targets`: delete the others, insert the missing ones.
+///
+/// Both statements run in one [`LifeExecutor::atomically`] scope — a transaction of their own on
+/// a connection or pool, a savepoint inside an open transaction — so a failed insert never leaves
+/// the delete applied.
+pub(crate) fn sync_links(
+    executor: &dyn LifeExecutor,
+    def: &RelationDef,
+    record: &[Value],
+    targets: &[Vec<Value>],
+) -> Result<(), ActiveModelError> {
+    let delete = delete_statement(def, record, targets, true)?;
+    let insert = insert_statement(def, record, targets)?;
+    executor.atomically(&mut |tx| {
+        for (sql, values) in delete.iter().chain(&insert) {
+            tx.execute_values(sql, values)?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn insert_statement(
+    def: &RelationDef,
+    record: &[Value],
+    targets: &[Vec<Value>],
+) -> Result<Option<Statement>, ActiveModelError> {
+    let (table, from, to) = through(def)?;
+    if targets.is_empty() {
+        return Ok(None);
+    }
+    let mut query = Query::insert();
+    query.into_table(table.clone());
+    query.columns(from.iter().chain(to.iter()).cloned());
+    for target in targets {
+        check_target_arity(to, target)?;
+        query
+            .values(record.iter().chain(target).cloned().map(Expr::val))
+            .map_err(|e| ActiveModelError::Other(format!("join row: {e}")))?;
+    }
+    query.on_conflict(OnConflict::new().do_nothing().to_owned());
+    Ok(Some(query.build(PostgresQueryBuilder)))
+}
+
+fn delete_statement(
+    def: &RelationDef,
+    record: &[Value],
+    targets: &[Vec<Value>],
+    keep: bool,
+) -> Result<Option<Statement>, ActiveModelError> {
+    let (table, from, to) = through(def)?;
+    if targets.is_empty() && !keep {
+        return Ok(None);
+    }
+    let mut condition = Condition::all();
+    for (col, value) in from.iter().zip(record) {
+        condition = condition.add(Expr::col(col.clone()).eq(value.clone()));
+    }
+    if !targets.is_empty() {
+        let mut any_target = Condition::any();
+        for target in targets {
+            check_target_arity(to, target)?;
+            let mut this_target = Condition::all();
+            for (col, value) in to.iter().zip(target) {
+                this_target = this_target.add(Expr::col(col.clone()).eq(value.clone()));
+            }
+            any_target = any_target.add(this_target);
+        }
+        condition = condition.add(if keep { any_target.not() } else { any_target });
+    }
+    let mut query = Query::delete();
+    query.from_table(table.clone());
+    query.cond_where(condition);
+    Ok(Some(query.build(PostgresQueryBuilder)))
+}
+
+fn check_target_arity(to: &Identity, target: &[Value]) -> Result<(), ActiveModelError> {
+    if target.len() == to.arity() {
+        return Ok(());
+    }
+    Err(ActiveModelError::Other(format!(
+        "related key has {} value(s), the join table expects {}",
+        target.len(),
+        to.arity()
+    )))
+}
@@ -58,11 +58,25 @@ pub use error::ActiveModelError;
 // Validation orchestration (`run_validators` after lifecycle hooks)
 pub mod validation;
 #[doc(inline)]
-pub use validation::{run_validators, run_validators_with_strategy};
+pub use validation::{run_validators, run_validators_on, run_validators_with_strategy};
 
 /// Built-in `len` / `range`-style validators on [`sea_query::Value`] (PRD Phase B follow-on).
 pub mod predicates;
 
+// Built-in `#[validate(...)]` rules (length, range, regex, email, …)
+pub mod rules;
+#[doc(inline)]
+pub use rules::ValidationRule;
+
+// Database-backed `#[validate(unique)]` / `#[validate(exists = ..)]` checks
+pub mod db_rules;
+
+// Statement chunking for multi-row inserts
+pub mod bulk;
+
+// Join-table writes for `has_many_through` (`link` / `unlink` / `sync_links`)
+pub(crate) mod links;
+
 // Graph sorting and nesting mechanics
 pub mod graph;
 #[doc(inline)]
@@ -0,0 +1,452 @@
+//! Built-in declarative field rules behind `#[validate(...)]` on derived `LifeRecord` fields.
+//!
+//! The derive turns each rule item into a [`ValidationRule`] constant and calls
+//! [`ValidationRule::check`] from `validate_fields`, under the record's
+//! [`ValidationStrategy`](super::ValidationStrategy). Failures are
+//! [`ActiveModelError::Validation`] carrying one [`ValidationError`] with the column name, the
+//! rule [`code`](ValidationRule::code) and its parameters.
+//!
+//! | Attribute item | Code | Applies to |
+//! |---|---|---|
+//! | `length(min = 1, max = 80)` | `length` | strings (characters), bytes (bytes) |
+//! | `range(min = 0, max = 150)` | `range` | integers, floats, decimals |
+//! | `regex = "^[a-z]+$"` | `regex` | strings |
+//! | `email` | `email` | strings |
+//! | `url` | `url` | strings |
+//! | `uuid` / `uuid(version = 4)` | `uuid` | strings, UUIDs |
+//! | `non_empty` | `non_empty` | strings (after trimming), bytes |
+//! | `one_of("draft", "published")` | `one_of` | strings |
+//!
+//! **NULL and unset values** are not checked (as in [`super::predicates`]); nullability is the
+//! column's `NOT NULL` constraint. Values of a type a rule does not apply to pass.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use sea_query::Value;
+
+use super::error::ActiveModelError;
+use super::validate_op::ValidationError;
+
+/// One built-in field rule. Constructed by `#[derive(LifeRecord)]`; usable directly from a
+/// hand-written [`ActiveModelBehavior::validate_fields`](super::ActiveModelBehavior::validate_fields).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationRule {
+    /// Character count (strings) or byte count (bytes) within the inclusive bounds.
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    /// Numeric value within inclusive integer bounds.
+    IntRange { min: Option<i64>, max: Option<i64> },
+    /// Numeric value within inclusive float bounds; non-finite values fail.
+    FloatRange { min: Option<f64>, max: Option<f64> },
+    /// String matches the pattern (unanchored, like [`Regex::is_match`]).
+    Regex(&'static str),
+    /// Plausible e-mail address: `local@domain.tld`, no whitespace, at most 254 characters.
+    Email,
+    /// Absolute URL: `scheme://authority…`, no whitespace.
+    Url,
+    /// Parses as a UUID, optionally of one version (1–8).
+    Uuid { version: Option<usize> },
+    /// Not empty; strings must contain a non-whitespace character.
+    NonEmpty,
+    /// String is one of the listed values.
+    OneOf(&'static [&'static str]),
+}
+
+impl ValidationRule {
+    /// Stable rule identifier reported as [`ValidationError::code`].
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationRule::Length { .. } => "length",
+            ValidationRule::IntRange { .. } | ValidationRule::FloatRange { .. } => "range",
+            ValidationRule::Regex(_) => "regex",
+            ValidationRule::Email => "email",
+            ValidationRule::Url => "url",
+            ValidationRule::Uuid { .. } => "uuid",
+            ValidationRule::NonEmpty => "non_empty",
+            ValidationRule::OneOf(_) => "one_of",
+        }
+    }
+
+    /// Check `value` of column `field`.
+    ///
+    /// # Errors
+    ///
+    /// [`ActiveModelError::Validation`] with one [`ValidationError`] when the rule fails;
+    /// [`ActiveModelError::Other`] when a [`ValidationRule::Regex`] pattern does not compile.
+    pub fn check(&self, field: &str, value: &Value) -> Result<(), ActiveModelError> {
+        match self.failure(value)? {
+            None => Ok(()),
+            Some(message) => Err(ActiveModelError::Validation(vec![
+                self.error(field, message)
+            ])),
+        }
+    }
+
+    /// `Some(message)` when `value` breaks the rule.
+    fn failure(&self, value: &Value) -> Result<Option<String>, ActiveModelError> {
+        let failure = match *self {
+            ValidationRule::Length { min, max } => {
+                let (n, unit) = match value {
+                    Value::String(Some(s)) => (s.chars().count(), "characters"),
+                    Value::Bytes(Some(b)) => (b.len(), "bytes"),
+                    _ => return Ok(None),
+                };
+                out_of_bounds(n, min, max).then(|| match (min, max) {
+                    (Some(min), Some(max)) => {
+                        format!("must be between {min} and {max} {unit} (got {n})")
+                    }
+                    (Some(min), None) => format!("must be at least {min} {unit} (got {n})"),
+                    (None, _) => format!(
+                        "must be at most {} {unit} (got {n})",
+                        max.unwrap_or_default()
+                    ),
+                })
+            }
+            ValidationRule::IntRange { min, max } => match value_as_number(value) {
+                Some(Number::Int(n)) => {
+                    out_of_bounds(n, min, max).then(|| range_message(min, max, n))
+                }
+                Some(Number::Float(x)) => {
+                    #[allow(clippy::cast_precision_loss)]
+                    // Bounds are declared in source; precision is not at stake.
+                    let (fmin, fmax) = (min.map(|m| m as f64), max.map(|m| m as f64));
+                    float_failure(x, fmin, fmax).map(|()| range_message(min, max, x))
+                }
+                None => None,
+            },
+            ValidationRule::FloatRange { min, max } => match value_as_number(value) {
+                #[allow(clippy::cast_precision_loss)]
+                Some(Number::Int(n)) => {
+                    float_failure(n as f64, min, max).map(|()| range_message(min, max, n))
+                }
+                Some(Number::Float(x)) => {
+                    if x.is_finite() {
+                        float_failure(x, min, max).map(|()| range_message(min, max, x))
+                    } else {
+                        Some("must be a finite number".to_string())
+                    }
+                }
+                None => None,
+            },
+            ValidationRule::Regex(pattern) => match value {
+                Value::String(Some(s)) => {
+                    (!compiled(pattern)?.is_match(s)).then(|| format!("must match `{pattern}`"))
+                }
+                _ => None,
+            },
+            ValidationRule::Email => match value {
+                Value::String(Some(s)) => {
+                    (!is_email(s)).then(|| "must be a valid email address".to_string())
+                }
+                _ => None,
+            },
+            ValidationRule::Url => match value {
+                Value::String(Some(s)) => (!is_url(s)).then(|| "must be a valid URL".to_string()),
+                _ => None,
+            },
+            ValidationRule::Uuid { version } => {
+                let parsed = match value {
+                    Value::Uuid(Some(u)) => Some(*u),
+                    Value::String(Some(s)) => uuid::Uuid::parse_str(s).ok(),
+                    _ => return Ok(None),
+                };
+                match (parsed, version) {
+                    (None, Some(v)) => Some(format!("must be a version {v} UUID")),
+                    (None, None) => Some("must be a valid UUID".to_string()),
+                    (Some(u), Some(v)) if u.get_version_num() != v => {
+                        Some(format!("must be a version {v} UUID"))
+                    }
+                    (Some(_), _) => None,
+                }
+            }
+            ValidationRule::NonEmpty => match value {
+                Value::String(Some(s)) => {
+                    s.trim().is_empty().then(|| "must not be empty".to_string())
+                }
+                Value::Bytes(Some(b)) => b.is_empty().then(|| "must not be empty".to_string()),
+                _ => None,
+            },
+            ValidationRule::OneOf(allowed) => match value {
+                Value::String(Some(s)) => (!allowed.contains(&s.as_str()))
+                    .then(|| format!("must be one of: {}", allowed.join(", "))),
+                _ => None,
+            },
+        };
+        Ok(failure)
+    }
+
+    /// Structured error for a failure of this rule on `field`.
+    fn error(&self, field: &str, message: String) -> ValidationError {
+        let mut err = ValidationError::rule(field, self.code(), message);
+        match *self {
+            ValidationRule::Length { min, max } => {
+                if let Some(min) = min {
+                    err = err.with_param("min", min);
+                }
+                if let Some(max) = max {
+                    err = err.with_param("max", max);
+                }
+            }
+            ValidationRule::IntRange { min, max } => {
+                if let Some(min) = min {
+                    err = err.with_param("min", min);
+                }
+                if let Some(max) = max {
+                    err = err.with_param("max", max);
+                }
+            }
+            ValidationRule::FloatRange { min, max } => {
+                if let Some(min) = min {
+                    err = err.with_param("min", min);
+                }
+                if let Some(max) = max {
+                    err = err.with_param("max", max);
+                }
+            }
+            ValidationRule::Regex(pattern) => err = err.with_param("pattern", pattern),
+            ValidationRule::Uuid { version: Some(v) } => err = err.with_param("version", v),
+            ValidationRule::OneOf(allowed) => err = err.with_param("values", allowed.to_vec()),
+            ValidationRule::Email
+            | ValidationRule::Url
+            | ValidationRule::Uuid { version: None }
+            | ValidationRule::NonEmpty => {}
+        }
+        err
+    }
+}
+
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+fn value_as_number(value: &Value) -> Option<Number> {
+    match value {
+        Value::TinyInt(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::SmallInt(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::Int(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::BigInt(Some(v)) => Some(Number::Int(*v)),
+        Value::TinyUnsigned(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::SmallUnsigned(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::Unsigned(Some(v)) => Some(Number::Int(i64::from(*v))),
+        Value::BigUnsigned(Some(v)) => Some(match i64::try_from(*v) {
+            Ok(n) => Number::Int(n),
+            #[allow(clippy::cast_precision_loss)]
+            Err(_) => Number::Float(*v as f64),
+        }),
+        Value::Float(Some(v)) => Some(Number::Float(f64::from(*v))),
+        Value::Double(Some(v)) => Some(Number::Float(*v)),
+        Value::Decimal(Some(d)) => d.to_string().parse().ok().map(Number::Float),
+        _ => None,
+    }
+}
+
+fn out_of_bounds<T: PartialOrd>(n: T, min: Option<T>, max: Option<T>) -> bool {
+    min.is_some_and(|min| n < min) || max.is_some_and(|max| n > max)
+}
+
+/// `Some(())` when `x` is outside the bounds.
+fn float_failure(x: f64, min: Option<f64>, max: Option<f64>) -> Option<()> {
+    out_of_bounds(x, min, max).then_some(())
+}
+
+fn range_message<B: std::fmt::Display, N: std::fmt::Display>(
+    min: Option<B>,
+    max: Option<B>,
+    n: N,
+) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) => format!("must be between {min} and {max} (got {n})"),
+        (Some(min), None) => format!("must be at least {min} (got {n})"),
+        (None, Some(max)) => format!("must be at most {max} (got {n})"),
+        (None, None) => format!("is out of range (got {n})"),
+    }
+}
+
+fn is_email(s: &str) -> bool {
+    if s.len() > 254 || s.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((local, domain)) = s.rsplit_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !local.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with(['.', '-'])
+        && !domain.ends_with(['.', '-'])
+        && !domain.contains("..")
+}
+
+fn is_url(s: &str) -> bool {
+    if s.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let Some((scheme, rest)) = s.split_once("://") else {
+        return false;
+    };
+    let mut scheme_chars = scheme.chars();
+    let scheme_ok = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    scheme_ok && !authority.is_empty()
+}
+
+/// Patterns come from `#[validate(regex = "...")]` literals, so the cache is bounded by the
+/// number of distinct patterns in the program.
+fn compiled(pattern: &'static str) -> Result<Regex, ActiveModelError> {
+    static CACHE: Lazy<Mutex<HashMap<&'static str, Regex>>> =
+        Lazy::new(|| Mutex::new(HashMap::new()));
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(re) = cache.get(pattern) {
+        return Ok(re.clone());
+    }
+    let re = Regex::new(pattern).map_err(|e| {
+        ActiveModelError::Other(format!(
+            "invalid `#[validate(regex)]` pattern `{pattern}`: {e}"
+        ))
+    })?;
+    cache.insert(pattern, re.clone());
+    Ok(re)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn errors(rule: ValidationRule, value: Value) -> Vec<ValidationError> {
+        match rule.check("col", &value) {
+            Ok(()) => Vec::new(),
+            Err(ActiveModelError::Validation(v)) => v,
+            Err(e) => panic!("unexpected error: {e}"),
+        }
+    }
+
+    fn s(v: &str) -> Value {
+        Value::String(Some(v.to_string()))
+    }
+
+    #[test]
+    fn length_reports_code_and_bounds() {
+        let rule = ValidationRule::Length {
+            min: Some(2),
+            max: Some(4),
+        };
+        assert!(errors(rule, s("abc")).is_empty());
+        assert!(errors(rule, Value::String(None)).is_empty());
+        let errs = errors(rule, s("éééée"));
+        assert_eq!(
+            errs,
+            vec![ValidationError::rule(
+                "col",
+                "length",
+                "must be between 2 and 4 characters (got 5)"
+            )
+            .with_param("min", 2)
+            .with_param("max", 4)]
+        );
+        assert_eq!(
+            serde_json::to_value(&errs[0]).unwrap(),
+            serde_json::json!({
+                "field": "col",
+                "code": "length",
+                "message": "must be between 2 and 4 characters (got 5)",
+                "params": {"max": 4, "min": 2}
+            })
+        );
+    }
+
+    #[test]
+    fn range_compares_ints_floats_and_decimals() {
+        let ints = ValidationRule::IntRange {
+            min: Some(0),
+            max: Some(150),
+        };
+        assert!(errors(ints, Value::Int(Some(150))).is_empty());
+        assert_eq!(
+            errors(ints, Value::BigInt(Some(-1)))[0].code.as_deref(),
+            Some("range")
+        );
+        assert_eq!(errors(ints, Value::Double(Some(150.5))).len(), 1);
+
+        let floats = ValidationRule::FloatRange {
+            min: Some(0.5),
+            max: None,
+        };
+        assert!(errors(floats, Value::Int(Some(1))).is_empty());
+        assert_eq!(
+            errors(floats, Value::Double(Some(0.25)))[0].message,
+            "must be at least 0.5 (got 0.25)"
+        );
+        assert_eq!(
+            errors(floats, Value::Double(Some(f64::NAN)))[0].message,
+            "must be a finite number"
+        );
+        assert_eq!(
+            errors(floats, Value::from(rust_decimal::Decimal::new(1, 1))).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn regex_matches_and_rejects_bad_patterns() {
+        assert!(errors(ValidationRule::Regex("^[a-z]+$"), s("slug")).is_empty());
+        let errs = errors(ValidationRule::Regex("^[a-z]+$"), s("Slug"));
+        assert_eq!(errs[0].params["pattern"], "^[a-z]+$");
+        assert!(matches!(
+            ValidationRule::Regex("(").check("col", &s("x")),
+            Err(ActiveModelError::Other(_))
+        ));
+    }
+
+    #[test]
+    fn email_and_url_shapes() {
+        for ok in ["a@b.co", "first.last+tag@sub.example.org"] {
+            assert!(errors(ValidationRule::Email, s(ok)).is_empty(), "{ok}");
+        }
+        for bad in ["", "a@b", "@b.co", "a b@c.io", "a@.io", "a@b..io"] {
+            assert_eq!(errors(ValidationRule::Email, s(bad)).len(), 1, "{bad}");
+        }
+        for ok in ["https://example.com", "postgres://u@h:5432/db?x=1"] {
+            assert!(errors(ValidationRule::Url, s(ok)).is_empty(), "{ok}");
+        }
+        for bad in ["example.com", "https://", "1http://x", "http://a b"] {
+            assert_eq!(errors(ValidationRule::Url, s(bad)).len(), 1, "{bad}");
+        }
+    }
+
+    #[test]
+    fn uuid_version_is_checked() {
+        let v4 = uuid::Uuid::new_v4();
+        let any = ValidationRule::Uuid { version: None };
+        let only_v4 = ValidationRule::Uuid { version: Some(4) };
+        assert!(errors(any, s(&v4.to_string())).is_empty());
+        assert!(errors(only_v4, Value::Uuid(Some(v4))).is_empty());
+        assert_eq!(errors(any, s("nope"))[0].message, "must be a valid UUID");
+        let errs = errors(
+            ValidationRule::Uuid { version: Some(7) },
+            Value::Uuid(Some(v4)),
+        );
+        assert_eq!(errs[0].params["version"], 7);
+    }
+
+    #[test]
+    fn non_empty_and_one_of() {
+        assert_eq!(errors(ValidationRule::NonEmpty, s("  ")).len(), 1);
+        assert!(errors(ValidationRule::NonEmpty, s(" x ")).is_empty());
+        let rule = ValidationRule::OneOf(&["draft", "published"]);
+        assert!(errors(rule, s("draft")).is_empty());
+        let errs = errors(rule, s("archived"));
+        assert_eq!(errs[0].message, "must be one of: draft, published");
+        assert_eq!(
+            errs[0].params["values"],
+            serde_json::json!(["draft", "published"])
+        );
+    }
+}
@@ -236,6 +236,94 @@ pub trait ActiveModelTrait: Clone + Send + std::fmt::Debug {
         ))
     }
 
+    /// Insert many records with multi-row `INSERT ... VALUES (...), (...)` statements.
+    ///
+    /// `before_insert` and the validators run for every record before any SQL is
+    /// sent, so one invalid record fails the batch without a partial write. Rows
+    /// are split across statements so none exceeds PostgreSQL's 65535
+    /// bind-parameter limit (see [`crate::active_model::bulk`]); run the call
+    /// inside a transaction if the chunks must commit together.
+    ///
+    /// Columns one record sets and another leaves unset are written as
+    /// `DEFAULT` for the latter, exactly as a single-row insert would omit them.
+    /// `#[auto_increment]` primary keys and `#[readonly]` columns are read back
+    /// with `RETURNING`. Models come back in input order and `after_insert`
+    /// runs for each.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if validation, a hook, or the database
+    /// operation fails.
+    fn insert_many<I>(
+        records: I,
+        executor: &dyn crate::executor::LifeExecutor,
+    ) -> Result<Vec<Self::Model>, ActiveModelError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let records: Vec<Self> = records.into_iter().collect();
+        Self::insert_many_inner(&records, executor, None)?
+            .into_iter()
+            .map(|model| {
+                model.ok_or_else(|| {
+                    ActiveModelError::Other(
+                        "INSERT returned no row and no ON CONFLICT clause was set".to_string(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// [`ActiveModelTrait::insert_many`] with a PostgreSQL `ON CONFLICT` clause.
+    ///
+    /// The result has one entry per input record, in input order. An entry is
+    /// `None` when that record was **not** written because `DO NOTHING` skipped
+    /// it, mirroring [`ActiveModelTrait::insert_on_conflict`].
+    ///
+    /// Skipped rows are absent from `RETURNING`, so returned rows are matched
+    /// back to their records by primary key. That needs every record's primary
+    /// key to be set; a batch relying on `#[auto_increment]` keys that has rows
+    /// skipped is reported as an error. Use a `DO UPDATE` action when the keys
+    /// are database-generated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if validation, a hook, or the database
+    /// operation fails, or if skipped rows cannot be matched back to records.
+    fn insert_many_on_conflict<I>(
+        records: I,
+        executor: &dyn crate::executor::LifeExecutor,
+        on_conflict: sea_query::OnConflict,
+    ) -> Result<Vec<Option<Self::Model>>, ActiveModelError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let records: Vec<Self> = records.into_iter().collect();
+        Self::insert_many_inner(&records, executor, Some(on_conflict))
+    }
+
+    /// Shared body behind [`ActiveModelTrait::insert_many`] and
+    /// [`ActiveModelTrait::insert_many_on_conflict`].
+    ///
+    /// `#[derive(LifeRecord)]` overrides this with the multi-row statement. The
+    /// default falls back to one [`ActiveModelTrait::insert_inner`] per record.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if validation, a hook, or the database
+    /// operation fails.
+    #[doc(hidden)]
+    fn insert_many_inner(
+        records: &[Self],
+        executor: &dyn crate::executor::LifeExecutor,
+        on_conflict: Option<sea_query::OnConflict>,
+    ) -> Result<Vec<Option<Self::Model>>, ActiveModelError> {
+        records
+            .iter()
+            .map(|record| record.insert_inner(executor, on_conflict.clone()))
+            .collect()
+    }
+
     /// Update the `ActiveModel` in the database
     ///
     /// # Arguments
@@ -357,6 +445,9 @@ pub trait ActiveModelTrait: Clone + Send + std::fmt::Debug {
 
     /// Binds a child record to be saved LAST after this root record is saved.
     /// Automatically grabs the root's generated primary key, assigning it to the child's foreign key constraint.
+    ///
+    /// For a `HasManyThrough` relation the child is saved as is and then linked to the root
+    /// through the join table, as with [`ActiveModelTrait::link`].
     fn add_child<C>(&mut self, mut child: C) -> &mut Self
     where
         C: crate::ActiveModelTrait + Send + 'static,
@@ -364,13 +455,24 @@ pub trait ActiveModelTrait: Clone + Send + std::fmt::Debug {
         Self: Sized,
     {
         let def = <Self::Entity as crate::Related<C::Entity>>::to();
+        if def.rel_type == crate::RelationType::HasManyThrough {
+            self.graph_mut()
+                .add_has_many(Box::new(move |root_model, db| {
+                    let saved_child = child.save_graph(db)?;
+                    let record = super::links::record_key(root_model, &def)?;
+                    let target = super::links::related_key(&saved_child, &def)?;
+                    super::links::insert_links(db, &def, &record, &[target])?;
+                    Ok(())
+                }));
+            return self;
+        }
         let is_has_many = matches!(
             def.rel_type,
             crate::RelationType::HasMany | crate::RelationType::HasOne
         );
         assert!(
             is_has_many,
-            "Relation must be HasMany or HasOne for add_child()"
+            "Relation must be HasMany, HasOne or HasManyThrough for add_child()"
         );
 
         // In HasMany / HasOne:
@@ -415,6 +517,94 @@ pub trait ActiveModelTrait: Clone + Send + std::fmt::Debug {
         self
     }
 
+    /// Link this record to `related` through the join table of the `has_many_through` relation
+    /// `Self::Entity: Related<R>`, e.g. `post.link::<tag::Entity>(&tag, executor)`.
+    ///
+    /// Inserts one join row with `ON CONFLICT DO NOTHING`, so linking an already linked pair is a
+    /// no-op when the join table has a unique key. This record's `from_col` key must be set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if the relation is not `has_many_through`, a key is missing,
+    /// or the insert fails.
+    fn link<R>(
+        &self,
+        related: &R::Model,
+        executor: &dyn crate::executor::LifeExecutor,
+    ) -> Result<(), ActiveModelError>
+    where
+        R: LifeModelTrait,
+        R::Model: ModelTrait,
+        Self::Entity: crate::Related<R>,
+        Self: Sized,
+    {
+        let def = <Self::Entity as crate::Related<R>>::to();
+        let record = super::links::record_key(self, &def)?;
+        let target = super::links::related_key(related, &def)?;
+        super::links::insert_links(executor, &def, &record, &[target])?;
+        Ok(())
+    }
+
+    /// Remove the join row between this record and `related`; see [`ActiveModelTrait::link`].
+    ///
+    /// Unlinking a pair that is not linked is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if the relation is not `has_many_through`, a key is missing,
+    /// or the delete fails.
+    fn unlink<R>(
+        &self,
+        related: &R::Model,
+        executor: &dyn crate::executor::LifeExecutor,
+    ) -> Result<(), ActiveModelError>
+    where
+        R: LifeModelTrait,
+        R::Model: ModelTrait,
+        Self::Entity: crate::Related<R>,
+        Self: Sized,
+    {
+        let def = <Self::Entity as crate::Related<R>>::to();
+        let record = super::links::record_key(self, &def)?;
+        let target = super::links::related_key(related, &def)?;
+        super::links::delete_links(executor, &def, &record, &[target], false)?;
+        Ok(())
+    }
+
+    /// Make this record's links exactly `ids`, the related rows' `to_col` keys (tuples for
+    /// composite keys), e.g. `post.sync_links::<tag::Entity, _>([1, 2], executor)`.
+    ///
+    /// Join rows for other ids are deleted and missing ones inserted, in one transaction: see
+    /// [`LifeExecutor::atomically`](crate::LifeExecutor::atomically). On a connection or pool it
+    /// is a transaction of its own; inside an open transaction it is a savepoint, so a failure
+    /// leaves the outer transaction usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if the relation is not `has_many_through`, a key is missing or
+    /// has the wrong arity, a statement fails, or `executor` can open neither a transaction nor a
+    /// savepoint.
+    fn sync_links<R, I>(
+        &self,
+        ids: I,
+        executor: &dyn crate::executor::LifeExecutor,
+    ) -> Result<(), ActiveModelError>
+    where
+        R: LifeModelTrait,
+        Self::Entity: crate::Related<R>,
+        I: IntoIterator,
+        I::Item: crate::IntoPrimaryKeyValues,
+        Self: Sized,
+    {
+        let def = <Self::Entity as crate::Related<R>>::to();
+        let record = super::links::record_key(self, &def)?;
+        let targets: Vec<Vec<Value>> = ids
+            .into_iter()
+            .map(crate::IntoPrimaryKeyValues::into_primary_key_values)
+            .collect();
+        super::links::sync_links(executor, &def, &record, &targets)
+    }
+
     /// Recursively save the `ActiveModel` alongside its connected parent and child graph relations.
     /// Operates within a single `may_postgres` transaction, topologically sorting dependencies.
     ///
@@ -754,6 +944,20 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     fn validate_model(&self, _op: ValidateOp) -> Result<(), ActiveModelError> {
         Ok(())
     }
+
+    /// Database-backed validation after [`validate_model`](Self::validate_model), run on the
+    /// executor the write goes through (`#[validate(unique)]`, `#[validate(exists = ..)]`).
+    /// Default: no-op.
+    ///
+    /// Called by [`run_validators_on`](super::validation::run_validators_on) for inserts and
+    /// updates; combined with the other hooks under the same [`ValidationStrategy`].
+    fn validate_database(
+        &self,
+        _op: ValidateOp,
+        _executor: &dyn crate::executor::LifeExecutor,
+    ) -> Result<(), ActiveModelError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 //! Operation discriminator for [`super::traits::ActiveModelBehavior::validate_fields`] /
 //! [`super::traits::ActiveModelBehavior::validate_model`] (PRD Phase B).
 
+use std::collections::BTreeMap;
+
 /// How [`super::validation::run_validators`] combines errors from [`super::traits::ActiveModelBehavior::validate_fields`]
 /// and [`super::traits::ActiveModelBehavior::validate_model`] (PRD V-3).
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
@@ -26,11 +28,24 @@ pub enum ValidateOp {
 }
 
 /// One failed validation rule (field-level or model-level).
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Serializes to `{"field": .., "code": .., "message": .., "params": {..}}` (absent `field` / `code`
+/// and empty `params` are omitted), ready to embed in an API error envelope. Build one with
+/// [`Self::field`], [`Self::model`] or [`Self::rule`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
 pub struct ValidationError {
     /// `None` for model-level rules that are not tied to a single column.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub field: Option<String>,
+    /// Machine-readable rule identifier (`length`, `range`, `email`, …) for built-in
+    /// `#[validate(...)]` rules; `None` for free-form errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
     pub message: String,
+    /// Rule parameters (e.g. `min` / `max` for `length`), keyed by parameter name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, serde_json::Value>,
 }
 
 impl ValidationError {
@@ -39,7 +54,9 @@ impl ValidationError {
     pub fn field(field: impl Into<String>, message: impl Into<String>) -> Self {
         Self {
             field: Some(field.into()),
+            code: None,
             message: message.into(),
+            params: BTreeMap::new(),
         }
     }
 
@@ -48,7 +65,33 @@ impl ValidationError {
     pub fn model(message: impl Into<String>) -> Self {
         Self {
             field: None,
+            code: None,
             message: message.into(),
+            params: BTreeMap::new(),
         }
     }
+
+    /// Field-scoped error raised by the rule identified by `code`.
+    #[must_use]
+    pub fn rule(
+        field: impl Into<String>,
+        code: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            code: Some(code.into()),
+            ..Self::field(field, message)
+        }
+    }
+
+    /// Record a rule parameter under `name`.
+    #[must_use]
+    pub fn with_param(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
 }
@@ -1,9 +1,11 @@
 //! Orchestrates [`super::traits::ActiveModelBehavior::validate_fields`] then
-//! [`super::traits::ActiveModelBehavior::validate_model`] (PRD ordering: field → model).
+//! [`super::traits::ActiveModelBehavior::validate_model`] (PRD ordering: field → model), and
+//! [`super::traits::ActiveModelBehavior::validate_database`] last when an executor is supplied.
 
 use super::error::ActiveModelError;
 use super::traits::ActiveModelBehavior;
 use super::validate_op::{ValidateOp, ValidationStrategy};
+use crate::executor::LifeExecutor;
 
 /// Run field-level then model-level validation using [`ActiveModelBehavior::validation_strategy`].
 #[inline]
@@ -20,24 +22,53 @@ pub fn run_validators_with_strategy<R: ActiveModelBehavior>(
     record: &R,
     op: ValidateOp,
     strategy: ValidationStrategy,
+) -> Result<(), ActiveModelError> {
+    run_stages(record, op, strategy, None)
+}
+
+/// [`run_validators`], then [`ActiveModelBehavior::validate_database`] on `executor`
+/// (field → model → database). Used by `#[derive(LifeRecord)]` for `insert` / `update`.
+///
+/// Under [`ValidationStrategy::FailFast`] the database hook only runs when the in-memory hooks
+/// passed, so an invalid record costs no queries.
+#[inline]
+pub fn run_validators_on<R: ActiveModelBehavior>(
+    record: &R,
+    op: ValidateOp,
+    executor: &dyn LifeExecutor,
+) -> Result<(), ActiveModelError> {
+    run_stages(record, op, record.validation_strategy(op), Some(executor))
+}
+
+fn run_stages<R: ActiveModelBehavior>(
+    record: &R,
+    op: ValidateOp,
+    strategy: ValidationStrategy,
+    executor: Option<&dyn LifeExecutor>,
 ) -> Result<(), ActiveModelError> {
     match strategy {
         ValidationStrategy::FailFast => {
             record.validate_fields(op)?;
             record.validate_model(op)?;
+            if let Some(executor) = executor {
+                record.validate_database(op, executor)?;
+            }
             Ok(())
         }
         ValidationStrategy::Aggregate => {
             let mut errs = Vec::new();
-            match record.validate_fields(op) {
-                Ok(()) => {}
-                Err(ActiveModelError::Validation(v)) => errs.extend(v),
-                Err(e) => return Err(e),
-            }
-            match record.validate_model(op) {
-                Ok(()) => {}
-                Err(ActiveModelError::Validation(v)) => errs.extend(v),
-                Err(e) => return Err(e),
+            let mut collect = |result: Result<(), ActiveModelError>| match result {
+                Ok(()) => Ok(()),
+                Err(ActiveModelError::Validation(v)) => {
+                    errs.extend(v);
+                    Ok(())
+                }
+                Err(e) => Err(e),
+            };
+            collect(record.validate_fields(op))?;
+            collect(record.validate_model(op))?;
+            if let Some(executor) = executor {
+                collect(record.validate_database(op, executor))?;
             }
             if errs.is_empty() {
                 Ok(())
@@ -6,6 +6,12 @@
 pub mod provider;
 pub mod redis_provider;
 pub mod reflector;
+pub mod write_through;
 
 pub use provider::{CacheError, CacheProvider, CachedResult, DefaultCacheProvider};
 pub use redis_provider::RedisCacheProvider;
+pub use reflector::{
+    LeaderLock, LifeReflector, RedisLeaderLock, ReflectAction, ReflectorConfig, ReflectorHandle,
+    ReflectorMode,
+};
+pub use write_through::{cache_key, cache_key_for_event, DEFAULT_CACHE_TTL_SECONDS};
@@ -1,80 +1,720 @@
-//! `LifeReflector` Architecture Placeholder
+//! `LifeReflector`: cache coherence driven by `#[notify]` triggers.
 //!
-//! Provides the core `LifeReflector` background worker loop. This relies on `PostgreSQL`
-//! LISTEN/NOTIFY and Redis Distributed Locks (Redlock) for leader election.
+//! Every process that writes through [`crate::ModelManager::find_by_id`] or `LifeRecord` caches
+//! rows under `lifeguard:model:{table}:{id}`. Writes made elsewhere (other services, SQL consoles,
+//! set-based `update_many`) leave those keys stale until their TTL runs out. The reflector closes
+//! that gap: one elected leader `LISTEN`s on the `#[notify]` channels and, for every change,
+//! either deletes the key ([`ReflectorMode::Invalidate`]) or re-reads the row and overwrites the
+//! key if it is still cached ([`ReflectorMode::Refresh`]).
+//!
+//! # Leader election
+//!
+//! Replicas compete for a lease in Redis (`SET key node NX PX lease`). The holder renews every
+//! third of the lease with a compare-and-`PEXPIRE` script; if it dies or cannot renew before the
+//! lease expires, another replica acquires the key on its next attempt and starts listening.
+//! This is a single-instance lease, not multi-node Redlock: it assumes one Redis primary.
+//!
+//! Notifications sent while no leader is listening (failover, reconnect) are lost. The reflector
+//! logs those gaps; affected keys are corrected by the next write or by TTL expiry.
 
-use crate::cache::provider::CacheProvider;
-use log::{error, info};
+use crate::cache::provider::{CacheError, CacheProvider, CachedResult};
+use crate::cache::redis_provider::RedisCacheProvider;
+use crate::cache::write_through::{cache_key, cache_key_for_event, DEFAULT_CACHE_TTL_SECONDS};
+use crate::executor::{LifeError, LifeExecutor, MayPostgresExecutor};
+use crate::notify::{ListenerEvent, NotificationListener, NotifyEvent, NotifyOperation};
+use crate::query::manager::primary_key_condition;
+use crate::query::{
+    EntityPrimaryKey, FromRow, IntoPrimaryKeyValues, LifeEntityName, PrimaryKeyValue,
+    TableDefinition,
+};
+use config::{Config, ConfigError, Environment, File};
+use log::{debug, error, info, warn};
 use may::coroutine;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// What the leader does with a cached row after a change notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReflectorMode {
+    /// Delete the key; the next read repopulates it.
+    #[default]
+    Invalidate,
+    /// Re-read the row and overwrite the key, but only if it is currently cached. Tables without a
+    /// registered refresher (see [`LifeReflector::refresh`]) fall back to invalidation.
+    Refresh,
+}
+
+/// `[reflector]` section of `config/config.toml`, overlaid by `LIFEGUARD__REFLECTOR__*` env vars.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReflectorConfig {
+    /// Redis URL used for both the cache and the leader lease.
+    #[serde(default = "default_redis_url")]
+    pub redis_url: String,
+    /// `LISTEN` channels (the `channel` values of `#[notify]`).
+    #[serde(default)]
+    pub channels: Vec<String>,
+    /// Identity written into the lease; defaults to `$HOSTNAME-<pid>`.
+    #[serde(default = "default_node_id")]
+    pub node_id: String,
+    /// Redis key holding the lease.
+    #[serde(default = "default_lock_key")]
+    pub lock_key: String,
+    /// Lease length in milliseconds; the leader renews every third of it.
+    #[serde(default = "default_lease_ms")]
+    pub lease_ms: u64,
+    /// Invalidate or refresh on change.
+    #[serde(default)]
+    pub mode: ReflectorMode,
+    /// TTL written with refreshed keys (matches the write-through default).
+    #[serde(default = "default_ttl_seconds")]
+    pub ttl_seconds: u64,
+}
+
+fn default_redis_url() -> String {
+    "redis://127.0.0.1:6379".to_string()
+}
+
+fn default_node_id() -> String {
+    let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "reflector".to_string());
+    format!("{host}-{}", std::process::id())
+}
+
+fn default_lock_key() -> String {
+    "lifeguard:reflector:leader".to_string()
+}
+
+fn default_lease_ms() -> u64 {
+    10_000
+}
+
+fn default_ttl_seconds() -> u64 {
+    DEFAULT_CACHE_TTL_SECONDS
+}
+
+impl Default for ReflectorConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: default_redis_url(),
+            channels: Vec::new(),
+            node_id: default_node_id(),
+            lock_key: default_lock_key(),
+            lease_ms: default_lease_ms(),
+            mode: ReflectorMode::default(),
+            ttl_seconds: default_ttl_seconds(),
+        }
+    }
+}
+
+/// Matches the `[reflector]` table next to `[database]` in `config/config.toml`.
+#[derive(Debug, Deserialize)]
+struct ConfigRoot {
+    #[serde(default)]
+    reflector: ReflectorConfig,
+}
+
+impl ReflectorConfig {
+    /// Loads `[reflector]` from `config/config.toml` and overlays `LIFEGUARD__REFLECTOR__*`.
+    ///
+    /// Same file and prefix as [`crate::DatabaseConfig::load`]. `channels` may be given in the
+    /// environment as a comma-separated list (`LIFEGUARD__REFLECTOR__CHANNELS=users,orders`).
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_from("config/config")
+    }
+
+    /// Like [`load`](Self::load) with an explicit config file path (extension optional).
+    pub fn load_from(path: &str) -> Result<Self, ConfigError> {
+        let root: ConfigRoot = Config::builder()
+            .add_source(File::with_name(path).required(false))
+            .add_source(
+                Environment::with_prefix("LIFEGUARD")
+                    .separator("__")
+                    .try_parsing(true)
+                    .list_separator(",")
+                    .with_list_parse_key("reflector.channels"),
+            )
+            .build()?
+            .try_deserialize()?;
+        Ok(root.reflector)
+    }
+
+    /// Lease length.
+    pub fn lease(&self) -> Duration {
+        Duration::from_millis(self.lease_ms.max(3))
+    }
+}
+
+/// A lease shared by reflector replicas; only the holder processes notifications.
+pub trait LeaderLock: Send + Sync {
+    /// Take the lease for `holder` if it is free (or already ours). Returns whether we hold it.
+    fn try_acquire(&self, holder: &str, lease: Duration) -> Result<bool, CacheError>;
+
+    /// Extend the lease if `holder` still owns it. `false` means leadership was lost.
+    fn renew(&self, holder: &str, lease: Duration) -> Result<bool, CacheError>;
+
+    /// Give up the lease if `holder` owns it.
+    fn release(&self, holder: &str) -> Result<(), CacheError>;
+}
+
+/// Compare-and-extend: only the current holder may push the expiry out.
+const RENEW_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0";
 
-/// `LifeReflector` Background Worker
-/// Ensures Cache Coherence dynamically via Change Data Capture
+/// Compare-and-delete: a stale leader must not drop its successor's lease.
+const RELEASE_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0";
+
+/// [`LeaderLock`] on a single Redis key.
+pub struct RedisLeaderLock {
+    client: redis::Client,
+    key: String,
+}
+
+impl RedisLeaderLock {
+    /// Lease stored under `key` on the Redis server at `connection_url`.
+    pub fn new(connection_url: &str, key: impl Into<String>) -> Result<Self, CacheError> {
+        let client = redis::Client::open(connection_url)
+            .map_err(|e| CacheError::Connection(e.to_string()))?;
+        Ok(Self {
+            client,
+            key: key.into(),
+        })
+    }
+
+    fn get_conn(&self) -> Result<redis::Connection, CacheError> {
+        self.client
+            .get_connection()
+            .map_err(|e| CacheError::Connection(e.to_string()))
+    }
+}
+
+fn lease_millis(lease: Duration) -> u64 {
+    u64::try_from(lease.as_millis()).unwrap_or(u64::MAX).max(1)
+}
+
+impl LeaderLock for RedisLeaderLock {
+    fn try_acquire(&self, holder: &str, lease: Duration) -> Result<bool, CacheError> {
+        let mut conn = self.get_conn()?;
+        let set: Option<String> = redis::cmd("SET")
+            .arg(&self.key)
+            .arg(holder)
+            .arg("NX")
+            .arg("PX")
+            .arg(lease_millis(lease))
+            .query(&mut conn)
+            .map_err(|e| CacheError::Internal(format!("Redis SET NX error: {e}")))?;
+        if set.is_some() {
+            return Ok(true);
+        }
+        // Restarted with the same node id while our previous lease is still live.
+        self.renew(holder, lease)
+    }
+
+    fn renew(&self, holder: &str, lease: Duration) -> Result<bool, CacheError> {
+        let mut conn = self.get_conn()?;
+        let extended: i64 = redis::Script::new(RENEW_SCRIPT)
+            .key(&self.key)
+            .arg(holder)
+            .arg(lease_millis(lease))
+            .invoke(&mut conn)
+            .map_err(|e| CacheError::Internal(format!("Redis lease renew error: {e}")))?;
+        Ok(extended == 1)
+    }
+
+    fn release(&self, holder: &str) -> Result<(), CacheError> {
+        let mut conn = self.get_conn()?;
+        let _: i64 = redis::Script::new(RELEASE_SCRIPT)
+            .key(&self.key)
+            .arg(holder)
+            .invoke(&mut conn)
+            .map_err(|e| CacheError::Internal(format!("Redis lease release error: {e}")))?;
+        Ok(())
+    }
+}
+
+/// Loads the current JSON for one row, or `None` if it no longer exists.
+type Refresher =
+    Arc<dyn Fn(&dyn LifeExecutor, &NotifyEvent) -> Result<Option<String>, LifeError> + Send + Sync>;
+
+/// Decodes a notification `id` into the table's primary key values.
+type KeyDecoder =
+    Arc<dyn Fn(&serde_json::Value) -> Result<Vec<sea_query::Value>, LifeError> + Send + Sync>;
+
+/// `E`'s primary key values from a notification `id`.
+fn decode_primary_key<E>(id: &serde_json::Value) -> Result<Vec<sea_query::Value>, LifeError>
+where
+    E: EntityPrimaryKey,
+    PrimaryKeyValue<E>: serde::de::DeserializeOwned + IntoPrimaryKeyValues,
+{
+    serde_json::from_value::<PrimaryKeyValue<E>>(id.clone())
+        .map(IntoPrimaryKeyValues::into_primary_key_values)
+        .map_err(|e| LifeError::ParseError(format!("notification id {id}: {e}")))
+}
+
+/// What [`LifeReflector::apply`] did with one notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectAction {
+    /// The key was deleted.
+    Invalidated,
+    /// The key was overwritten with the current row.
+    Refreshed,
+    /// Nothing to do: refresh mode and the key was not cached, or the notification's `id` does not
+    /// name a cache key.
+    Skipped,
+}
+
+/// Leader-elected worker keeping `lifeguard:model:*` keys in line with the database.
 pub struct LifeReflector {
     pg_url: String,
-    _redis_url: String,
-    _cache_provider: Arc<dyn CacheProvider>,
+    config: ReflectorConfig,
+    cache: Arc<dyn CacheProvider>,
+    lock: Arc<dyn LeaderLock>,
+    keys: HashMap<String, KeyDecoder>,
+    refreshers: HashMap<String, Refresher>,
 }
 
 impl LifeReflector {
-    /// Initialize a new `LifeReflector` instance
-    pub fn new(pg_url: String, redis_url: String, cache_provider: Arc<dyn CacheProvider>) -> Self {
+    /// Reflector with explicit cache and lease backends.
+    pub fn new(
+        pg_url: impl Into<String>,
+        config: ReflectorConfig,
+        cache: Arc<dyn CacheProvider>,
+        lock: Arc<dyn LeaderLock>,
+    ) -> Self {
         Self {
-            pg_url,
-            _redis_url: redis_url,
-            _cache_provider: cache_provider,
+            pg_url: pg_url.into(),
+            config,
+            cache,
+            lock,
+            keys: HashMap::new(),
+            refreshers: HashMap::new(),
+        }
+    }
+
+    /// Reflector using [`RedisCacheProvider`] and [`RedisLeaderLock`] on `config.redis_url`.
+    pub fn connect_redis(
+        pg_url: impl Into<String>,
+        config: ReflectorConfig,
+    ) -> Result<Self, CacheError> {
+        let cache = Arc::new(RedisCacheProvider::new(&config.redis_url)?);
+        let lock = Arc::new(RedisLeaderLock::new(&config.redis_url, &config.lock_key)?);
+        Ok(Self::new(pg_url, config, cache, lock))
+    }
+
+    /// Add a `LISTEN` channel. Duplicates are ignored.
+    #[must_use]
+    pub fn listen(mut self, channel: impl Into<String>) -> Self {
+        let channel = channel.into();
+        if !self.config.channels.contains(&channel) {
+            self.config.channels.push(channel);
+        }
+        self
+    }
+
+    /// Add the channel declared by an entity's `#[notify]` attribute, if any.
+    #[must_use]
+    pub fn listen_table(self, table: &TableDefinition) -> Self {
+        match &table.notify {
+            Some(notify) => self.listen(notify.channel.clone()),
+            None => self,
+        }
+    }
+
+    /// Decode `E`'s notifications as its own [`PrimaryKeyValue`], so the key they address is
+    /// exactly the one [`crate::ModelManager::find_by_id`] and `LifeRecord` writes use.
+    ///
+    /// Without this, keys are read from the JSON `id` alone ([`cache_key_for_event`]), which only
+    /// covers integer, UUID, text and boolean keys; dates, decimals and composite keys need it.
+    #[must_use]
+    pub fn track<E>(mut self) -> Self
+    where
+        E: EntityPrimaryKey + LifeEntityName + 'static,
+        PrimaryKeyValue<E>: serde::de::DeserializeOwned + IntoPrimaryKeyValues,
+    {
+        let table = E::default().table_name().to_string();
+        self.keys.insert(table, Arc::new(decode_primary_key::<E>));
+        self
+    }
+
+    /// Register `E` for [`ReflectorMode::Refresh`]: changed rows are re-read with `E::find()` and
+    /// serialized the same way [`crate::ModelManager::find_by_id`] caches them.
+    ///
+    /// Like `find_by_id`, the row is looked up by its primary key: the notification `id` is parsed
+    /// as `E`'s [`PrimaryKeyValue`]. Implies [`track`](Self::track).
+    #[must_use]
+    pub fn refresh<E>(self) -> Self
+    where
+        E: EntityPrimaryKey + LifeEntityName + 'static,
+        E::Model: FromRow + serde::Serialize,
+        PrimaryKeyValue<E>: serde::de::DeserializeOwned + IntoPrimaryKeyValues,
+    {
+        let mut this = self.track::<E>();
+        let table = E::default().table_name().to_string();
+        let refresher: Refresher = Arc::new(|executor, event| {
+            let pk = decode_primary_key::<E>(&event.id)?;
+            let model = E::find()
+                .filter(primary_key_condition::<E>(&pk)?)
+                .find_one(&executor)?;
+            model
+                .map(|m| {
+                    serde_json::to_string(&m)
+                        .map_err(|e| LifeError::ParseError(format!("serialize refreshed row: {e}")))
+                })
+                .transpose()
+        });
+        this.refreshers.insert(table, refresher);
+        this
+    }
+
+    /// Cache key a notification refers to, decoded as the table's tracked key type if any.
+    fn key_for(&self, event: &NotifyEvent) -> Result<Option<String>, LifeError> {
+        match self.keys.get(&event.table) {
+            Some(decode) => Ok(cache_key(&event.table, &decode(&event.id)?)),
+            None => Ok(cache_key_for_event(event)),
+        }
+    }
+
+    /// Channels the leader will `LISTEN` on.
+    pub fn channels(&self) -> &[String] {
+        &self.config.channels
+    }
+
+    /// Apply one notification to the cache.
+    ///
+    /// Deletes always invalidate. In refresh mode, a cached key is overwritten with the row read
+    /// through `executor`; if there is no executor, no refresher for the table, or the read fails,
+    /// the key is invalidated instead so it can never stay stale.
+    pub fn apply(
+        &self,
+        event: &NotifyEvent,
+        executor: Option<&dyn LifeExecutor>,
+    ) -> Result<ReflectAction, CacheError> {
+        let key = match self.key_for(event) {
+            Ok(Some(key)) => key,
+            Ok(None) => {
+                warn!(
+                    "LifeReflector: {}:{} does not name a cache key; register the entity with `track`",
+                    event.table,
+                    event.id_string()
+                );
+                return Ok(ReflectAction::Skipped);
+            }
+            Err(e) => {
+                warn!("LifeReflector: {e}");
+                return Ok(ReflectAction::Skipped);
+            }
+        };
+        let refresher = self.refreshers.get(&event.table);
+
+        let (ReflectorMode::Refresh, Some(refresher), Some(executor)) =
+            (self.config.mode, refresher, executor)
+        else {
+            self.cache.invalidate(&key)?;
+            return Ok(ReflectAction::Invalidated);
+        };
+        if event.operation == NotifyOperation::Delete {
+            self.cache.invalidate(&key)?;
+            return Ok(ReflectAction::Invalidated);
+        }
+        if let CachedResult::Miss = self.cache.get(&key)? {
+            return Ok(ReflectAction::Skipped);
+        }
+        match refresher(executor, event) {
+            Ok(Some(json)) => {
+                self.cache.set(&key, &json, Some(self.config.ttl_seconds))?;
+                Ok(ReflectAction::Refreshed)
+            }
+            Ok(None) => {
+                self.cache.invalidate(&key)?;
+                Ok(ReflectAction::Invalidated)
+            }
+            Err(e) => {
+                warn!("LifeReflector: refresh of {key} failed, invalidating: {e}");
+                self.cache.invalidate(&key)?;
+                Ok(ReflectAction::Invalidated)
+            }
+        }
+    }
+
+    /// Run in a coroutine until the returned handle is stopped or dropped.
+    pub fn spawn(self) -> ReflectorHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop);
+        let _reflector_co = may::go!(move || self.run(&worker_stop));
+        ReflectorHandle { stop }
+    }
+
+    /// Compete for leadership and reflect notifications while leader, until `stop` is set.
+    ///
+    /// Blocks the calling coroutine (or thread).
+    pub fn run(&self, stop: &AtomicBool) {
+        let lease = self.config.lease();
+        let renew_every = lease / 3;
+        info!(
+            "LifeReflector {} started (channels {:?}, mode {:?})",
+            self.config.node_id, self.config.channels, self.config.mode
+        );
+
+        while !stop.load(Ordering::Relaxed) {
+            match self.lock.try_acquire(&self.config.node_id, lease) {
+                Ok(true) => {
+                    info!("LifeReflector {} acquired leadership", self.config.node_id);
+                    self.lead(stop, lease, renew_every);
+                }
+                Ok(false) => debug!("LifeReflector {}: follower", self.config.node_id),
+                Err(e) => warn!("LifeReflector: leader election failed: {e}"),
+            }
+            if !stop.load(Ordering::Relaxed) {
+                coroutine::sleep(renew_every);
+            }
         }
+
+        if let Err(e) = self.lock.release(&self.config.node_id) {
+            warn!("LifeReflector: releasing leadership failed: {e}");
+        }
+        info!("LifeReflector {} stopped", self.config.node_id);
     }
 
-    /// Spawns the background worker for `LifeReflector`
-    pub fn spawn(self) {
-        unsafe {
-            coroutine::spawn::<_, ()>(move || {
-                info!("LifeReflector background worker started.");
+    /// Leader loop: listen, apply, and renew the lease until it is lost or `stop` is set.
+    fn lead(&self, stop: &AtomicBool, lease: Duration, renew_every: Duration) {
+        let notifications = self
+            .config
+            .channels
+            .iter()
+            .fold(NotificationListener::new(self.pg_url.clone()), |l, c| {
+                l.listen(c.clone())
+            })
+            .with_poll_interval(renew_every.min(Duration::from_secs(1)))
+            .spawn();
+        warn!(
+            "LifeReflector {}: changes committed before this leader started listening are not reflected",
+            self.config.node_id
+        );
 
-                // Loop aggressively aiming to acquire Leader Election via Redis
-                loop {
-                    // 1. Attempt to acquire Distributed Lock (Redlock)
-                    // If we don't get the lock, we are a follower.
-                    // We sleep and try again.
-                    // let is_leader = try_acquire_redis_lock(&self.redis_url, "lifeguard:reflector:leader");
-                    let is_leader = true; // Scaffold simulated
+        let mut executor: Option<MayPostgresExecutor> = None;
+        let mut renewed_at = Instant::now();
 
-                    if !is_leader {
-                        coroutine::sleep(Duration::from_secs(5));
-                        continue;
+        while !stop.load(Ordering::Relaxed) {
+            match notifications.recv_timeout(renew_every) {
+                Some(ListenerEvent::Notify(event)) => {
+                    if self.config.mode == ReflectorMode::Refresh && executor.is_none() {
+                        executor = self.connect_executor();
+                    }
+                    let exec = executor.as_ref().map(|e| e as &dyn LifeExecutor);
+                    match self.apply(&event, exec) {
+                        Ok(action) => debug!(
+                            "LifeReflector: {} {}:{} -> {action:?}",
+                            event.operation,
+                            event.table,
+                            event.id_string()
+                        ),
+                        Err(e) => error!(
+                            "LifeReflector: cache update for {}:{} failed: {e}",
+                            event.table,
+                            event.id_string()
+                        ),
                     }
+                }
+                Some(ListenerEvent::Reconnected) => {
+                    warn!("LifeReflector: LISTEN connection was re-established; changes in the gap are not reflected");
+                    executor = None;
+                }
+                None => {}
+            }
 
-                    // 2. We are the Leader! Connect to PostgreSQL and LISTEN
-                    match may_postgres::connect(&self.pg_url) {
-                        Ok(client) => {
-                            info!("LifeReflector acquired leader lock and connected to Postgres.");
-
-                            // Execute LISTEN
-                            // This is a placeholder since may_postgres's exact LISTEN API might differ
-                            let _ = client.query_one("LISTEN lifeguard_updates", &[]);
-
-                            // 3. Process Notifications (Blocking wait)
-                            loop {
-                                // Dummy loop to represent processing
-                                // Inside real loop:
-                                // a. Parse NOTIFY payload (table, pk)
-                                // b. Check if key exists in cache
-                                // c. If exists -> read Postgres -> update Redis
-                                // d. Extend Redis Leader lock TTL
-
-                                // To prevent this scaffold from hot-looping CPU:
-                                coroutine::sleep(Duration::from_secs(10));
-                            }
-                        }
-                        Err(e) => {
-                            error!("LifeReflector Leader could not connect to Postgres: {e:?}");
-                            coroutine::sleep(Duration::from_secs(5));
-                        }
+            if renewed_at.elapsed() >= renew_every {
+                match self.lock.renew(&self.config.node_id, lease) {
+                    Ok(true) => renewed_at = Instant::now(),
+                    Ok(false) => {
+                        warn!("LifeReflector {}: leadership lost", self.config.node_id);
+                        return;
                     }
+                    Err(e) if renewed_at.elapsed() >= lease => {
+                        warn!(
+                            "LifeReflector {}: lease expired without renewal, stepping down: {e}",
+                            self.config.node_id
+                        );
+                        return;
+                    }
+                    Err(e) => warn!("LifeReflector: lease renewal failed, will retry: {e}"),
                 }
-            });
+            }
+        }
+    }
+
+    fn connect_executor(&self) -> Option<MayPostgresExecutor> {
+        match crate::connection::connect(&self.pg_url) {
+            Ok(client) => Some(MayPostgresExecutor::new(client)),
+            Err(e) => {
+                error!("LifeReflector: refresh connection failed, invalidating instead: {e}");
+                None
+            }
+        }
+    }
+}
+
+/// Stop switch for a spawned [`LifeReflector`]. Dropping it stops the worker.
+pub struct ReflectorHandle {
+    stop: Arc<AtomicBool>,
+}
+
+impl ReflectorHandle {
+    /// Ask the worker to release leadership and exit.
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for ReflectorHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryCache(Mutex<HashMap<String, String>>);
+
+    impl CacheProvider for MemoryCache {
+        fn get(&self, key: &str) -> Result<CachedResult<String>, CacheError> {
+            Ok(match self.0.lock().unwrap().get(key) {
+                Some(v) => CachedResult::Hit(v.clone()),
+                None => CachedResult::Miss,
+            })
+        }
+
+        fn set(&self, key: &str, value: &str, _ttl: Option<u64>) -> Result<(), CacheError> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    /// Never grants the lease; `apply` does not consult it.
+    struct NoLock;
+
+    impl LeaderLock for NoLock {
+        fn try_acquire(&self, _: &str, _: Duration) -> Result<bool, CacheError> {
+            Ok(false)
+        }
+
+        fn renew(&self, _: &str, _: Duration) -> Result<bool, CacheError> {
+            Ok(false)
+        }
+
+        fn release(&self, _: &str) -> Result<(), CacheError> {
+            Ok(())
+        }
+    }
+
+    fn reflector(mode: ReflectorMode, cache: Arc<MemoryCache>) -> LifeReflector {
+        let config = ReflectorConfig {
+            mode,
+            ..ReflectorConfig::default()
+        };
+        LifeReflector::new("postgresql://unused", config, cache, Arc::new(NoLock))
+    }
+
+    fn event(op: NotifyOperation, id: serde_json::Value) -> NotifyEvent {
+        NotifyEvent {
+            channel: "users_changed".to_string(),
+            table: "users".to_string(),
+            operation: op,
+            id,
         }
     }
+
+    #[test]
+    fn cache_key_matches_write_through_layout() {
+        assert_eq!(
+            cache_key_for_event(&event(NotifyOperation::Update, serde_json::json!(42))).as_deref(),
+            Some("lifeguard:model:users:i:42")
+        );
+        assert_eq!(
+            cache_key("users", &[sea_query::Value::Int(Some(42))]).as_deref(),
+            Some("lifeguard:model:users:i:42")
+        );
+    }
+
+    #[test]
+    fn invalidate_mode_deletes_key() {
+        let cache = Arc::new(MemoryCache::default());
+        cache.set("lifeguard:model:users:i:42", "{}", None).unwrap();
+        let r = reflector(ReflectorMode::Invalidate, Arc::clone(&cache));
+
+        let action = r
+            .apply(&event(NotifyOperation::Update, serde_json::json!(42)), None)
+            .unwrap();
+        assert_eq!(action, ReflectAction::Invalidated);
+        assert!(matches!(
+            cache.get("lifeguard:model:users:i:42").unwrap(),
+            CachedResult::Miss
+        ));
+    }
+
+    #[test]
+    fn ids_without_a_cache_key_are_skipped() {
+        let cache = Arc::new(MemoryCache::default());
+        cache.set("lifeguard:model:users:i:1", "{}", None).unwrap();
+        let r = reflector(ReflectorMode::Invalidate, Arc::clone(&cache));
+
+        let action = r
+            .apply(
+                &event(NotifyOperation::Update, serde_json::json!(1.5)),
+                None,
+            )
+            .unwrap();
+        assert_eq!(action, ReflectAction::Skipped);
+        assert_eq!(cache.0.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn string_ids_use_unquoted_key() {
+        let cache = Arc::new(MemoryCache::default());
+        cache
+            .set("lifeguard:model:users:s:abc", "{}", None)
+            .unwrap();
+        let r = reflector(ReflectorMode::Invalidate, Arc::clone(&cache));
+
+        r.apply(
+            &event(NotifyOperation::Insert, serde_json::json!("abc")),
+            None,
+        )
+        .unwrap();
+        assert!(cache.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn refresh_mode_without_refresher_falls_back_to_invalidate() {
+        let cache = Arc::new(MemoryCache::default());
+        cache.set("lifeguard:model:users:i:1", "{}", None).unwrap();
+        let r = reflector(ReflectorMode::Refresh, Arc::clone(&cache));
+
+        let action = r
+            .apply(&event(NotifyOperation::Update, serde_json::json!(1)), None)
+            .unwrap();
+        assert_eq!(action, ReflectAction::Invalidated);
+        assert!(cache.0.lock().unwrap().is_empty());
+    }
 }
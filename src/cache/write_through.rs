@@ -0,0 +1,257 @@
+//! Keeping `lifeguard:model:{table}:{id}` entries in line with `LifeRecord` writes.
+//!
+//! `{id}` is [`fingerprint_pk_values`] of the primary key ([`cache_key`]), the same encoding the
+//! session identity map uses, so every key type an entity can declare is cached. The reflector
+//! rebuilds it from a `#[notify]` payload ([`cache_key_for_event`]).
+//!
+//! [`crate::ModelManager::find_by_id`] populates these keys; the derived `LifeRecord`
+//! `insert` / `update` / `delete` call [`write_through`] and [`evict`] afterwards so a cached row
+//! never outlives the write that changed it.
+//!
+//! Outside a transaction the fresh model is written straight back (or the key is evicted when
+//! the entity's TTL is `0`). Inside one ([`LifeExecutor::in_transaction`]) nothing is written:
+//! the key is queued with [`LifeExecutor::invalidate_cache_key`] and evicted only after the
+//! outermost `COMMIT`, so neither uncommitted data nor — via a concurrent reader re-caching the
+//! old row before commit — stale data ends up in the cache. A rollback drops the queue.
+
+use crate::cache::provider::CacheProvider;
+use crate::executor::LifeExecutor;
+use crate::model::ModelTrait;
+use crate::notify::NotifyEvent;
+use crate::query::{LifeEntityName, LifeModelTrait};
+use crate::session::fingerprint_pk_values;
+use crate::value::types::is_null;
+use sea_query::Value;
+use std::sync::{Arc, Mutex};
+
+/// TTL for write-through entries when the entity has no `#[cache_ttl = N]`.
+pub const DEFAULT_CACHE_TTL_SECONDS: u64 = 3600;
+
+/// Cache key for the row of `table` whose primary key columns hold `pk_values`, in column order;
+/// `None` when there are no values or one of them is NULL.
+pub fn cache_key(table: &str, pk_values: &[Value]) -> Option<String> {
+    if pk_values.is_empty() || pk_values.iter().any(is_null) {
+        return None;
+    }
+    Some(format!(
+        "lifeguard:model:{table}:{}",
+        fingerprint_pk_values(pk_values)
+    ))
+}
+
+/// Cache key of the row a `#[notify]` notification reports, read from the JSON `id` alone.
+///
+/// The payload does not say which Rust type the key column has, so this covers integer, UUID,
+/// text and boolean keys (a string that parses as a UUID is taken to be one) and returns `None`
+/// otherwise. [`crate::cache::LifeReflector::track`] registers an entity so its notifications
+/// are decoded as its own primary key type instead.
+pub fn cache_key_for_event(event: &NotifyEvent) -> Option<String> {
+    let value = match &event.id {
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                Value::BigInt(Some(i))
+            } else {
+                Value::BigUnsigned(Some(n.as_u64()?))
+            }
+        }
+        serde_json::Value::String(s) => match uuid::Uuid::parse_str(s) {
+            Ok(u) => Value::Uuid(Some(u)),
+            Err(_) => Value::String(Some(s.clone())),
+        },
+        serde_json::Value::Bool(b) => Value::Bool(Some(*b)),
+        _ => return None,
+    };
+    cache_key(&event.table, &[value])
+}
+
+/// Key for an entity row identified by its primary key values.
+fn entity_key<E: LifeEntityName>(pk_values: &[Value]) -> Option<String> {
+    cache_key(E::default().table_name(), pk_values)
+}
+
+/// Bring the cached copy of `model` up to date after an `INSERT` or `UPDATE` through `executor`.
+pub fn write_through<E>(executor: &dyn LifeExecutor, model: &E::Model)
+where
+    E: LifeModelTrait + LifeEntityName,
+    E::Model: ModelTrait + serde::Serialize,
+{
+    let Some(cache) = executor.cache_provider() else {
+        return;
+    };
+    let Some(key) = entity_key::<E>(&model.get_primary_key_values()) else {
+        return;
+    };
+    let ttl = E::cache_ttl_seconds();
+    if executor.in_transaction() || ttl == 0 {
+        executor.invalidate_cache_key(&key);
+        return;
+    }
+    let stored = serde_json::to_string(model)
+        .map_err(|e| e.to_string())
+        .and_then(|json| cache.set(&key, &json, Some(ttl)).map_err(|e| e.to_string()));
+    if let Err(e) = stored {
+        log::warn!("lifeguard cache: write-through of {key} failed, evicting: {e}");
+        invalidate_now(cache.as_ref(), &key);
+    }
+}
+
+/// Drop the cached row identified by `pk_values` after a `DELETE` or soft delete through
+/// `executor`.
+pub fn evict<E>(executor: &dyn LifeExecutor, pk_values: &[Value])
+where
+    E: LifeModelTrait + LifeEntityName,
+{
+    if executor.cache_provider().is_none() {
+        return;
+    }
+    if let Some(key) = entity_key::<E>(pk_values) {
+        executor.invalidate_cache_key(&key);
+    }
+}
+
+/// Evict `key`, logging instead of failing: the database write already succeeded.
+pub(crate) fn invalidate_now(cache: &dyn CacheProvider, key: &str) {
+    if let Err(e) = cache.invalidate(key) {
+        log::warn!("lifeguard cache: eviction of {key} failed: {e}");
+    }
+}
+
+/// Keys a transaction evicts once it commits.
+///
+/// Shared between a transaction and its savepoints; a savepoint remembers the queue length when
+/// it opened and truncates back to it if rolled back.
+#[derive(Clone, Default)]
+pub(crate) struct PendingInvalidations(Arc<Mutex<Vec<String>>>);
+
+impl PendingInvalidations {
+    pub(crate) fn push(&self, key: &str) {
+        let mut keys = self.0.lock().unwrap_or_else(|p| p.into_inner());
+        if !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+    }
+
+    /// Current queue length, for a savepoint to roll back to.
+    pub(crate) fn mark(&self) -> usize {
+        self.0.lock().unwrap_or_else(|p| p.into_inner()).len()
+    }
+
+    /// Forget keys queued after `mark` (savepoint rolled back).
+    pub(crate) fn truncate(&self, mark: usize) {
+        self.0
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .truncate(mark);
+    }
+
+    /// Evict every queued key (outermost `COMMIT` succeeded).
+    pub(crate) fn flush(&self, cache: Option<&dyn CacheProvider>) {
+        let keys = std::mem::take(&mut *self.0.lock().unwrap_or_else(|p| p.into_inner()));
+        if let Some(cache) = cache {
+            for key in keys {
+                invalidate_now(cache, &key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::cache::provider::{CacheError, CachedResult};
+
+    #[derive(Default)]
+    struct Evictions(Mutex<Vec<String>>);
+
+    impl CacheProvider for Evictions {
+        fn get(&self, _: &str) -> Result<CachedResult<String>, CacheError> {
+            Ok(CachedResult::Miss)
+        }
+
+        fn set(&self, _: &str, _: &str, _: Option<u64>) -> Result<(), CacheError> {
+            Ok(())
+        }
+
+        fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+            self.0.lock().unwrap().push(key.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn keys_are_primary_key_fingerprints() {
+        assert_eq!(
+            cache_key("users", &[Value::BigInt(Some(42))]).as_deref(),
+            Some("lifeguard:model:users:i:42")
+        );
+        assert_eq!(
+            cache_key("users", &[Value::String(Some("ab".into()))]).as_deref(),
+            Some("lifeguard:model:users:s:ab")
+        );
+        assert_eq!(
+            cache_key(
+                "translations",
+                &[Value::Int(Some(1)), Value::String(Some("en".into()))]
+            )
+            .as_deref(),
+            Some("lifeguard:model:translations:i:1\x1fs:en")
+        );
+        let day = chrono::NaiveDate::from_ymd_opt(2024, 2, 29);
+        assert_eq!(
+            cache_key("daily_totals", &[Value::ChronoDate(day)]).as_deref(),
+            Some("lifeguard:model:daily_totals:date:2024-02-29")
+        );
+        assert_eq!(
+            cache_key(
+                "prices",
+                &[Value::Decimal(Some(rust_decimal::Decimal::new(1250, 2)))]
+            )
+            .as_deref(),
+            Some("lifeguard:model:prices:dec:12.5")
+        );
+        assert_eq!(cache_key("users", &[]), None);
+        assert_eq!(cache_key("users", &[Value::Int(None)]), None);
+    }
+
+    #[test]
+    fn notifications_name_the_written_key() {
+        let event = |id| NotifyEvent {
+            channel: "users_changed".to_string(),
+            table: "users".to_string(),
+            operation: crate::notify::NotifyOperation::Update,
+            id,
+        };
+        assert_eq!(
+            cache_key_for_event(&event(serde_json::json!(42))),
+            cache_key("users", &[Value::Int(Some(42))])
+        );
+        let id = uuid::Uuid::new_v4();
+        assert_eq!(
+            cache_key_for_event(&event(serde_json::json!(id.to_string()))),
+            cache_key("users", &[Value::Uuid(Some(id))])
+        );
+        assert_eq!(
+            cache_key_for_event(&event(serde_json::json!("ada"))),
+            cache_key("users", &[Value::String(Some("ada".into()))])
+        );
+        assert_eq!(cache_key_for_event(&event(serde_json::json!(1.5))), None);
+        assert_eq!(cache_key_for_event(&event(serde_json::Value::Null)), None);
+    }
+
+    #[test]
+    fn pending_invalidations_flush_once_and_honour_savepoints() {
+        let cache = Evictions::default();
+        let pending = PendingInvalidations::default();
+        pending.push("a");
+        pending.push("a");
+        let savepoint = pending.mark();
+        pending.push("b");
+        pending.truncate(savepoint);
+        pending.push("c");
+
+        pending.flush(Some(&cache));
+        pending.flush(Some(&cache));
+        assert_eq!(*cache.0.lock().unwrap(), ["a", "c"]);
+    }
+}
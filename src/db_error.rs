@@ -0,0 +1,492 @@
+//! Typed classification of `PostgreSQL` server errors.
+//!
+//! `may_postgres` reports every server-side failure as one opaque [`may_postgres::Error`].
+//! Lifeguard inspects the SQLSTATE of that error when it is converted into a
+//! [`LifeError`](crate::LifeError) and, for the classes applications routinely branch on, produces
+//! a dedicated variant carrying a [`DbErrorInfo`]:
+//!
+//! | SQLSTATE | [`DbErrorKind`] | [`LifeError`](crate::LifeError) variant |
+//! |----------|-----------------|-----------------------------------------|
+//! | `23505` | `UniqueViolation` | `UniqueViolation` |
+//! | `23503` | `ForeignKeyViolation` | `ForeignKeyViolation` |
+//! | `23502` | `NotNullViolation` | `NotNullViolation` |
+//! | `23514` | `CheckViolation` | `CheckViolation` |
+//! | `23P01` | `ExclusionViolation` | `ExclusionViolation` |
+//! | `40001` | `SerializationFailure` | `SerializationFailure` |
+//! | `40P01` | `Deadlock` | `Deadlock` |
+//! | `55P03` | `LockTimeout` | `LockTimeout` |
+//! | `57014` | `QueryCanceled` | `QueryCanceled` |
+//!
+//! Everything else stays [`LifeError::PostgresError`](crate::LifeError::PostgresError).
+//!
+//! ## Mapping constraints back to columns
+//!
+//! [`constraint_columns`] resolves the constraint name reported by the server to the entity's
+//! `Column` variants when the constraint came from entity metadata. Names follow what
+//! `lifeguard-migrate` emits:
+//!
+//! - `#[unique]` → `PostgreSQL` default `{table}_{column}_key`
+//! - `#[composite_unique]` → `{table}_{col1}_{col2}_key`
+//! - `#[foreign_key]` → `{table}_{column}_fkey`
+//! - column `#[check]` → `check_{table}_{column}`
+//! - `#[exclude]` → its `name`, else `PostgreSQL` default `{table}_{col1}_{col2}_excl`
+//!
+//! `LifeRecord` uses this to fill [`ActiveModelError::Database`](crate::ActiveModelError::Database).
+
+use crate::query::column::column_trait::ColumnDefHelper;
+use crate::query::table::TableDefinition;
+use crate::query::traits::LifeModelTrait;
+use sea_query::IdenStatic;
+use std::fmt;
+
+/// `PostgreSQL` identifier limit (`NAMEDATALEN - 1`).
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// Class of a classified server error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DbErrorKind {
+    /// `23505` `unique_violation`
+    UniqueViolation,
+    /// `23503` `foreign_key_violation`
+    ForeignKeyViolation,
+    /// `23502` `not_null_violation`
+    NotNullViolation,
+    /// `23514` `check_violation`
+    CheckViolation,
+    /// `23P01` `exclusion_violation`
+    ExclusionViolation,
+    /// `40001` `serialization_failure`
+    SerializationFailure,
+    /// `40P01` `deadlock_detected`
+    Deadlock,
+    /// `55P03` `lock_not_available` (`lock_timeout`, `NOWAIT`)
+    LockTimeout,
+    /// `57014` `query_canceled` (`statement_timeout`, `pg_cancel_backend`)
+    QueryCanceled,
+}
+
+impl DbErrorKind {
+    /// Classify a five-character SQLSTATE. Returns `None` for codes Lifeguard does not type.
+    pub fn from_sqlstate(code: &str) -> Option<Self> {
+        match code {
+            "23505" => Some(DbErrorKind::UniqueViolation),
+            "23503" => Some(DbErrorKind::ForeignKeyViolation),
+            "23502" => Some(DbErrorKind::NotNullViolation),
+            "23514" => Some(DbErrorKind::CheckViolation),
+            "23P01" => Some(DbErrorKind::ExclusionViolation),
+            "40001" => Some(DbErrorKind::SerializationFailure),
+            "40P01" => Some(DbErrorKind::Deadlock),
+            "55P03" => Some(DbErrorKind::LockTimeout),
+            "57014" => Some(DbErrorKind::QueryCanceled),
+            _ => None,
+        }
+    }
+
+    /// Canonical SQLSTATE for this kind.
+    pub fn sqlstate(self) -> &'static str {
+        match self {
+            DbErrorKind::UniqueViolation => "23505",
+            DbErrorKind::ForeignKeyViolation => "23503",
+            DbErrorKind::NotNullViolation => "23502",
+            DbErrorKind::CheckViolation => "23514",
+            DbErrorKind::ExclusionViolation => "23P01",
+            DbErrorKind::SerializationFailure => "40001",
+            DbErrorKind::Deadlock => "40P01",
+            DbErrorKind::LockTimeout => "55P03",
+            DbErrorKind::QueryCanceled => "57014",
+        }
+    }
+
+    /// `true` for integrity-constraint violations (SQLSTATE class `23`).
+    pub fn is_constraint_violation(self) -> bool {
+        matches!(
+            self,
+            DbErrorKind::UniqueViolation
+                | DbErrorKind::ForeignKeyViolation
+                | DbErrorKind::NotNullViolation
+                | DbErrorKind::CheckViolation
+                | DbErrorKind::ExclusionViolation
+        )
+    }
+
+    /// `true` when rerunning the whole transaction may succeed (serialization failure, deadlock).
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            DbErrorKind::SerializationFailure | DbErrorKind::Deadlock
+        )
+    }
+}
+
+impl fmt::Display for DbErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            DbErrorKind::UniqueViolation => "unique violation",
+            DbErrorKind::ForeignKeyViolation => "foreign key violation",
+            DbErrorKind::NotNullViolation => "not-null violation",
+            DbErrorKind::CheckViolation => "check violation",
+            DbErrorKind::ExclusionViolation => "exclusion violation",
+            DbErrorKind::SerializationFailure => "serialization failure",
+            DbErrorKind::Deadlock => "deadlock detected",
+            DbErrorKind::LockTimeout => "lock timeout",
+            DbErrorKind::QueryCanceled => "query canceled",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Server-reported fields of a classified error.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DbErrorInfo {
+    /// Five-character SQLSTATE.
+    pub sqlstate: String,
+    /// Primary error message.
+    pub message: String,
+    /// `DETAIL` field (e.g. `Key (email)=(a@b.c) already exists.`).
+    pub detail: Option<String>,
+    /// Schema of the table involved.
+    pub schema: Option<String>,
+    /// Table involved.
+    pub table: Option<String>,
+    /// Column involved (set for not-null violations).
+    pub column: Option<String>,
+    /// Constraint name (unique, foreign-key and check violations).
+    pub constraint: Option<String>,
+}
+
+impl DbErrorInfo {
+    /// Info with only SQLSTATE and message set.
+    pub fn new(sqlstate: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            sqlstate: sqlstate.into(),
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the constraint name.
+    #[must_use]
+    pub fn with_constraint(mut self, constraint: impl Into<String>) -> Self {
+        self.constraint = Some(constraint.into());
+        self
+    }
+
+    /// Set the table name.
+    #[must_use]
+    pub fn with_table(mut self, table: impl Into<String>) -> Self {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Set the column name.
+    #[must_use]
+    pub fn with_column(mut self, column: impl Into<String>) -> Self {
+        self.column = Some(column.into());
+        self
+    }
+
+    fn from_db_error(db: &may_postgres::error::DbError) -> Self {
+        Self {
+            sqlstate: db.code().code().to_string(),
+            message: db.message().to_string(),
+            detail: db.detail().map(str::to_string),
+            schema: db.schema().map(str::to_string),
+            table: db.table().map(str::to_string),
+            column: db.column().map(str::to_string),
+            constraint: db.constraint().map(str::to_string),
+        }
+    }
+}
+
+impl fmt::Display for DbErrorInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (SQLSTATE {})", self.message, self.sqlstate)?;
+        if let Some(constraint) = &self.constraint {
+            write!(f, ", constraint {constraint}")?;
+        }
+        if let Some(detail) = &self.detail {
+            write!(f, ": {detail}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Classify a raw driver error. `None` when it is not a server error of a typed class.
+pub(crate) fn classify_postgres_error(
+    err: &may_postgres::Error,
+) -> Option<(DbErrorKind, DbErrorInfo)> {
+    let db = err.as_db_error()?;
+    let kind = DbErrorKind::from_sqlstate(db.code().code())?;
+    Some((kind, DbErrorInfo::from_db_error(db)))
+}
+
+/// Entity columns covered by the constraint named in `info`.
+///
+/// Returns an empty vector when the constraint was not generated from this entity's metadata,
+/// or when `info.table` names another table. For not-null violations the server reports the
+/// column directly and that column is returned.
+pub fn constraint_columns<E>(info: &DbErrorInfo, table_def: &TableDefinition) -> Vec<E::Column>
+where
+    E: LifeModelTrait,
+{
+    let table = E::default().table_name();
+    if info.table.as_deref().is_some_and(|t| t != table) {
+        return Vec::new();
+    }
+    let columns = E::all_columns();
+    let by_name = |name: &str| columns.iter().copied().find(|c| c.as_str() == name);
+
+    let Some(constraint) = info.constraint.as_deref() else {
+        return info
+            .column
+            .as_deref()
+            .and_then(by_name)
+            .into_iter()
+            .collect();
+    };
+
+    for &col in columns {
+        let def = col.column_def();
+        let name = col.as_str();
+        let matched = (def.unique && constraint == default_constraint_name(table, &[name], "key"))
+            || (def.foreign_key.is_some()
+                && constraint == default_constraint_name(table, &[name], "fkey"))
+            || (def.check.is_some() && constraint == column_check_constraint_name(table, name));
+        if matched {
+            return vec![col];
+        }
+    }
+
+    for group in &table_def.composite_unique {
+        let names: Vec<&str> = group.iter().map(String::as_str).collect();
+        if constraint == default_constraint_name(table, &names, "key") {
+            return names.into_iter().filter_map(by_name).collect();
+        }
+    }
+
+    for exclusion in &table_def.exclusion_constraints {
+        let names: Vec<&str> = exclusion
+            .elements
+            .iter()
+            .map(|(column, _)| column.as_str())
+            .collect();
+        let matched = match &exclusion.name {
+            Some(name) => constraint == name,
+            None => constraint == default_constraint_name(table, &names, "excl"),
+        };
+        if matched {
+            return names.into_iter().filter_map(by_name).collect();
+        }
+    }
+
+    info.column
+        .as_deref()
+        .and_then(by_name)
+        .into_iter()
+        .collect()
+}
+
+/// `PostgreSQL`'s default constraint name (`makeObjectName`): `{table}_{cols}_{label}`, with the
+/// longer of table / column part trimmed until the result fits in 63 bytes.
+pub(crate) fn default_constraint_name(table: &str, columns: &[&str], label: &str) -> String {
+    let cols = columns.join("_");
+    let overhead = label.len() + 2;
+    let avail = MAX_IDENTIFIER_LEN.saturating_sub(overhead);
+    let mut table_len = table.len();
+    let mut cols_len = cols.len();
+    while table_len + cols_len > avail {
+        if table_len > cols_len {
+            table_len -= 1;
+        } else {
+            cols_len -= 1;
+        }
+    }
+    format!(
+        "{}_{}_{label}",
+        truncate_on_char_boundary(table, table_len),
+        truncate_on_char_boundary(&cols, cols_len)
+    )
+}
+
+/// Name `lifeguard-migrate` gives a column-level `#[check]` constraint.
+pub(crate) fn column_check_constraint_name(table: &str, column: &str) -> String {
+    format!(
+        "check_{}_{}",
+        sanitize_constraint_name(table),
+        sanitize_constraint_name(column)
+    )
+}
+
+fn sanitize_constraint_name(name: &str) -> String {
+    name.replace(['-', '.'], "_").to_lowercase()
+}
+
+fn truncate_on_char_boundary(s: &str, mut len: usize) -> &str {
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    &s[..len]
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::query::column::definition::ColumnDefinition;
+    use crate::query::table::ExclusionDefinition;
+    use crate::query::traits::LifeEntityName;
+
+    #[derive(Copy, Clone, Default, Debug)]
+    struct Users;
+
+    impl LifeEntityName for Users {
+        fn table_name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    enum UsersColumn {
+        Email,
+        OrgId,
+        Slug,
+        Age,
+    }
+
+    impl sea_query::Iden for UsersColumn {
+        fn unquoted(&self) -> &'static str {
+            self.as_str()
+        }
+    }
+
+    impl sea_query::IdenStatic for UsersColumn {
+        fn as_str(&self) -> &'static str {
+            match self {
+                UsersColumn::Email => "email",
+                UsersColumn::OrgId => "org_id",
+                UsersColumn::Slug => "slug",
+                UsersColumn::Age => "age",
+            }
+        }
+    }
+
+    impl ColumnDefHelper for UsersColumn {
+        fn column_def(self) -> ColumnDefinition {
+            match self {
+                UsersColumn::Email => ColumnDefinition {
+                    unique: true,
+                    ..ColumnDefinition::default()
+                },
+                UsersColumn::OrgId => ColumnDefinition {
+                    foreign_key: Some("orgs(id)".to_string()),
+                    ..ColumnDefinition::default()
+                },
+                UsersColumn::Age => ColumnDefinition {
+                    check: Some("age >= 0".to_string()),
+                    ..ColumnDefinition::default()
+                },
+                UsersColumn::Slug => ColumnDefinition::default(),
+            }
+        }
+    }
+
+    impl LifeModelTrait for Users {
+        type Model = ();
+        type Column = UsersColumn;
+
+        fn all_columns() -> &'static [UsersColumn] {
+            &[
+                UsersColumn::Email,
+                UsersColumn::OrgId,
+                UsersColumn::Slug,
+                UsersColumn::Age,
+            ]
+        }
+    }
+
+    fn table_def() -> TableDefinition {
+        TableDefinition {
+            composite_unique: vec![vec!["org_id".to_string(), "slug".to_string()]],
+            exclusion_constraints: vec![ExclusionDefinition {
+                name: None,
+                using: "gist".to_string(),
+                elements: vec![
+                    ("org_id".to_string(), "=".to_string()),
+                    ("age".to_string(), "&&".to_string()),
+                ],
+                partial_where: None,
+            }],
+            ..TableDefinition::default()
+        }
+    }
+
+    fn info(code: &str, constraint: &str) -> DbErrorInfo {
+        DbErrorInfo::new(code, "violation")
+            .with_table("users")
+            .with_constraint(constraint)
+    }
+
+    #[test]
+    fn sqlstate_round_trip() {
+        for code in [
+            "23505", "23503", "23502", "23514", "23P01", "40001", "40P01", "55P03", "57014",
+        ] {
+            assert_eq!(DbErrorKind::from_sqlstate(code).unwrap().sqlstate(), code);
+        }
+        assert_eq!(DbErrorKind::from_sqlstate("42P01"), None);
+        assert!(DbErrorKind::Deadlock.is_retryable());
+        assert!(!DbErrorKind::UniqueViolation.is_retryable());
+        assert!(DbErrorKind::CheckViolation.is_constraint_violation());
+        assert!(DbErrorKind::ExclusionViolation.is_constraint_violation());
+    }
+
+    #[test]
+    fn maps_generated_constraint_names_to_columns() {
+        let def = table_def();
+        assert_eq!(
+            constraint_columns::<Users>(&info("23505", "users_email_key"), &def),
+            vec![UsersColumn::Email]
+        );
+        assert_eq!(
+            constraint_columns::<Users>(&info("23503", "users_org_id_fkey"), &def),
+            vec![UsersColumn::OrgId]
+        );
+        assert_eq!(
+            constraint_columns::<Users>(&info("23514", "check_users_age"), &def),
+            vec![UsersColumn::Age]
+        );
+        assert_eq!(
+            constraint_columns::<Users>(&info("23505", "users_org_id_slug_key"), &def),
+            vec![UsersColumn::OrgId, UsersColumn::Slug]
+        );
+        assert_eq!(
+            constraint_columns::<Users>(&info("23P01", "users_org_id_age_excl"), &def),
+            vec![UsersColumn::OrgId, UsersColumn::Age]
+        );
+        assert!(constraint_columns::<Users>(&info("23505", "hand_made_idx"), &def).is_empty());
+    }
+
+    #[test]
+    fn other_table_or_not_null_column() {
+        let def = table_def();
+        let foreign = info("23505", "users_email_key").with_table("accounts");
+        assert!(constraint_columns::<Users>(&foreign, &def).is_empty());
+
+        let not_null = DbErrorInfo::new("23502", "null value")
+            .with_table("users")
+            .with_column("slug");
+        assert_eq!(
+            constraint_columns::<Users>(&not_null, &def),
+            vec![UsersColumn::Slug]
+        );
+    }
+
+    #[test]
+    fn default_name_truncates_like_postgres() {
+        let table = "a".repeat(40);
+        let column = "b".repeat(40);
+        let name = default_constraint_name(&table, &[&column], "key");
+        assert_eq!(name.len(), MAX_IDENTIFIER_LEN);
+        assert_eq!(name, format!("{}_{}_key", "a".repeat(29), "b".repeat(29)));
+    }
+}
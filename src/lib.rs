@@ -89,12 +89,18 @@ pub mod pool;
 // Available for integration tests
 pub mod test_helpers;
 
+// In-memory scripted executor for unit tests (feature `mock`)
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "mock")]
+pub use mock::{MockExecutor, MockResult, RecordedStatement, SqlPattern, StatementKind};
+
 // Entity tests will be rebuilt in Epic 03
 // mod tests_cfg;
 
 pub use pool::{
     DatabaseConfig, ExclusivePrimaryLifeExecutor, LifeguardPool, LifeguardPoolSettings, OwnedParam,
-    PooledLifeExecutor, ReadPreference, WalLagPolicy,
+    PooledLifeExecutor, PooledTransaction, ReadPreference, WalLagPolicy,
 };
 
 #[doc(inline)]
@@ -124,15 +130,27 @@ pub use connection::{
 // Re-export executor types for convenience
 pub use executor::{LifeError, LifeExecutor, MayPostgresExecutor, SessionContext};
 
+// Typed SQLSTATE classification for `LifeError` / `ActiveModelError`
+pub mod db_error;
+pub use db_error::{constraint_columns, DbErrorInfo, DbErrorKind};
+
+// LISTEN/NOTIFY consumer for `#[notify]` triggers
+pub mod notify;
+pub use notify::{
+    ListenerEvent, NotificationHandle, NotificationListener, NotifyEvent, NotifyOperation,
+};
+
 // Query builder - Epic 02 Story 03
 pub mod query;
 pub use query::{
     format_index_key_list_derive_value, format_index_key_list_sql,
     from_row_unsigned_try_from_failed, index_definition_to_derive_index_value,
-    index_key_parts_coverage_columns, ColumnDefinition, ColumnTrait, FromRow, IndexBtreeNulls,
-    IndexBtreeSort, IndexDefinition, IndexKeyPart, IntoScope, LifeEntityName, LifeModelTrait,
+    index_key_parts_coverage_columns, ColumnDefinition, ColumnTrait, DeleteMany, EntityPrimaryKey,
+    ExclusionDefinition, FromRow, FromRowPrefixed, IndexBtreeNulls, IndexBtreeSort,
+    IndexDefinition, IndexKeyPart, IntoPrimaryKeyValues, IntoScope, LifeEntityName, LifeModelTrait,
     ModelManager, NotifyDefinition, PrimaryKeyArity, PrimaryKeyArityTrait, PrimaryKeyToColumn,
-    PrimaryKeyTrait, SelectModel, SelectQuery, StoredProcedure, TableDefinition,
+    PrimaryKeyTrait, PrimaryKeyValue, RelationAggregate, RelationAggregates, SelectAlsoRelated,
+    SelectModel, SelectQuery, SelectWithRelated, StoredProcedure, TableDefinition, UpdateMany,
 };
 
 // query_old.rs has been removed - all code migrated to query/ modules
@@ -140,9 +158,9 @@ pub use query::{
 // ActiveModel operations - Epic 02 Story 07
 pub mod active_model;
 pub use active_model::{
-    predicates, run_validators, run_validators_with_strategy, with_converted_params,
-    ActiveModelBehavior, ActiveModelError, ActiveModelTrait, ActiveValue, ColumnValue, ValidateOp,
-    ValidationError, ValidationStrategy,
+    predicates, run_validators, run_validators_on, run_validators_with_strategy,
+    with_converted_params, ActiveModelBehavior, ActiveModelError, ActiveModelTrait, ActiveValue,
+    ColumnValue, ValidateOp, ValidationError, ValidationRule, ValidationStrategy,
 };
 
 // Model trait - Core Traits & Types
@@ -186,12 +204,13 @@ pub use raw_sql::{execute_statement, find_all_by_statement, find_by_statement, q
 // Value type system - Epic 02 Story 10 (Phase 4: Value Type Infrastructure)
 pub mod value;
 pub use value::{
-    FromValueTuple, IntoValueTuple, TextParam, TryFromU64, TryGetable, TryGetableMany,
+    CiText, FromValueTuple, IntoValueTuple, LifeEnum, LifeValue, PgBitString, PgCidr, PgInterval,
+    PgMacAddr, PgRange, RangeElement, TextParam, TryFromU64, TryGetable, TryGetableMany,
     ValueExtractionError, ValueType,
 };
 
 // Re-export transaction types for convenience
-pub use transaction::{IsolationLevel, Transaction, TransactionError};
+pub use transaction::{IsolationLevel, RetryPolicy, RetryableError, Transaction, TransactionError};
 
 // Migration system - Epic 03
 pub mod migration;
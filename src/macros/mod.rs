@@ -9,8 +9,8 @@
 // - query.rs -> LifeQuery query macros
 // - txn.rs -> may_postgres transaction macros
 // - go.rs -> Coroutine helpers
-// - insert_many.rs -> LifeRecord batch operations
-// - mock.rs -> Test helpers for may_postgres
+// - insert_many.rs -> replaced by `ActiveModelTrait::insert_many` / `LifeModelTrait::insert_many`
+// - mock.rs -> replaced by `crate::mock::MockExecutor` (feature `mock`)
 // - temp_table.rs -> Test helpers
 // - test_data.rs -> Test helpers
 // - seed_test.rs -> Test helpers
@@ -18,11 +18,9 @@
 // Temporarily disabled - will be rebuilt
 // pub mod execute;
 // pub mod go;
-// pub mod insert_many;
 // pub mod query;
 // mod seed_test;
 // mod temp_table;
 // mod test_data;
 // pub mod txn;
-// mod mock;
 // mod db_pool;
@@ -0,0 +1,652 @@
+//! In-memory [`LifeExecutor`] for unit tests (feature **`mock`**).
+//!
+//! [`MockExecutor`] never touches a database. It records every statement it receives together
+//! with its bound [`sea_query::Values`], and answers from a queue of scripted expectations keyed
+//! by a [`SqlPattern`]. That is enough to assert on the SQL that [`crate::SelectQuery`],
+//! `LifeRecord::insert` / `update` / `delete` and `save_graph` emit without a testcontainers
+//! Postgres.
+//!
+//! `may_postgres::Row` cannot be constructed outside a live connection, so scripted rows are
+//! rows the test already holds (for example from a fixture query). Most tests only need
+//! [`MockResult::RowsAffected`], [`MockResult::Rows`] with an empty vector, or
+//! [`MockResult::Error`].
+//!
+//! # Matching
+//!
+//! Each incoming statement is matched against the pending expectations in the order they were
+//! registered; the first expectation whose pattern matches is consumed. When nothing matches:
+//!
+//! - the default (lenient) executor answers `0` rows affected, an empty row set, or a
+//!   "no rows" error for `query_one` (so `find_one` yields `None`);
+//! - a [`MockExecutor::strict`] executor returns [`LifeError::QueryError`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use lifeguard::mock::{MockExecutor, MockResult, SqlPattern, StatementKind};
+//! use lifeguard::LifeExecutor;
+//!
+//! # fn main() -> Result<(), lifeguard::LifeError> {
+//! let executor = MockExecutor::new().strict();
+//! executor.expect(SqlPattern::contains("DELETE FROM \"users\""), MockResult::RowsAffected(1));
+//!
+//! let affected = executor.execute_values(
+//!     r#"DELETE FROM "users" WHERE "id" = $1"#,
+//!     &sea_query::Values(vec![sea_query::Value::Int(Some(7))]),
+//! )?;
+//! assert_eq!(affected, 1);
+//!
+//! let stmt = executor.last_statement().expect("recorded");
+//! assert_eq!(stmt.kind, StatementKind::Execute);
+//! assert_eq!(stmt.values.as_ref().map(|v| v.0.len()), Some(1));
+//! executor.verify()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cache::CacheProvider;
+use crate::executor::{LifeError, LifeExecutor};
+use may_postgres::types::ToSql;
+use may_postgres::Row;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Which [`LifeExecutor`] entry point received a statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementKind {
+    /// [`LifeExecutor::execute`] / [`LifeExecutor::execute_values`]
+    Execute,
+    /// [`LifeExecutor::query_one`] / [`LifeExecutor::query_one_values`]
+    QueryOne,
+    /// [`LifeExecutor::query_all`] / [`LifeExecutor::query_all_values`]
+    QueryAll,
+}
+
+/// One statement observed by a [`MockExecutor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedStatement {
+    /// Entry point used.
+    pub kind: StatementKind,
+    /// SQL text exactly as passed to the executor.
+    pub sql: String,
+    /// Bound values when the statement came through a `*_values` method.
+    ///
+    /// `None` for the raw `&[&dyn ToSql]` entry points, whose parameters cannot be inspected.
+    pub values: Option<sea_query::Values>,
+    /// Number of bound parameters.
+    pub param_count: usize,
+}
+
+/// How an expectation selects the statement it answers.
+#[derive(Debug, Clone)]
+pub enum SqlPattern {
+    /// Matches every statement.
+    Any,
+    /// SQL must equal this string exactly.
+    Exact(String),
+    /// SQL must contain this substring.
+    Contains(String),
+    /// SQL must match this regular expression.
+    Regex(regex::Regex),
+}
+
+impl SqlPattern {
+    /// Exact-match pattern.
+    pub fn exact(sql: impl Into<String>) -> Self {
+        SqlPattern::Exact(sql.into())
+    }
+
+    /// Substring pattern.
+    pub fn contains(fragment: impl Into<String>) -> Self {
+        SqlPattern::Contains(fragment.into())
+    }
+
+    /// Regular-expression pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::Other`] if `pattern` is not a valid regex.
+    pub fn regex(pattern: &str) -> Result<Self, LifeError> {
+        regex::Regex::new(pattern)
+            .map(SqlPattern::Regex)
+            .map_err(|e| LifeError::Other(format!("invalid mock SQL pattern: {e}")))
+    }
+
+    /// Whether `sql` satisfies this pattern.
+    pub fn matches(&self, sql: &str) -> bool {
+        match self {
+            SqlPattern::Any => true,
+            SqlPattern::Exact(s) => s == sql,
+            SqlPattern::Contains(s) => sql.contains(s.as_str()),
+            SqlPattern::Regex(re) => re.is_match(sql),
+        }
+    }
+}
+
+impl From<&str> for SqlPattern {
+    /// `&str` converts to [`SqlPattern::Contains`].
+    fn from(fragment: &str) -> Self {
+        SqlPattern::Contains(fragment.to_string())
+    }
+}
+
+impl fmt::Display for SqlPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlPattern::Any => write!(f, "<any>"),
+            SqlPattern::Exact(s) => write!(f, "exact {s:?}"),
+            SqlPattern::Contains(s) => write!(f, "contains {s:?}"),
+            SqlPattern::Regex(re) => write!(f, "regex {:?}", re.as_str()),
+        }
+    }
+}
+
+/// Scripted answer for one expectation.
+pub enum MockResult {
+    /// Rows to return. `execute` reports `rows.len()` as rows affected; `query_one` returns the
+    /// first row, a "no rows" error when empty, or an error when more than one row is scripted.
+    Rows(Vec<Row>),
+    /// Rows affected for `execute`; `query_all` returns an empty set and `query_one` a "no rows" error.
+    RowsAffected(u64),
+    /// Error returned from whichever entry point consumed the expectation.
+    Error(LifeError),
+}
+
+impl MockResult {
+    /// Empty row set (equivalent to `MockResult::Rows(Vec::new())`).
+    pub fn empty() -> Self {
+        MockResult::Rows(Vec::new())
+    }
+}
+
+impl fmt::Debug for MockResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MockResult::Rows(rows) => write!(f, "Rows(len = {})", rows.len()),
+            MockResult::RowsAffected(n) => write!(f, "RowsAffected({n})"),
+            MockResult::Error(e) => write!(f, "Error({e})"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Expectation {
+    pattern: SqlPattern,
+    result: MockResult,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    expectations: VecDeque<Expectation>,
+    statements: Vec<RecordedStatement>,
+    /// [`LifeExecutor::atomically`] scopes currently running.
+    open_scopes: u32,
+}
+
+/// Scriptable, recording [`LifeExecutor`] for unit tests.
+///
+/// Interior mutability lets tests keep a shared reference while ORM code executes through
+/// `&dyn LifeExecutor`. See the [module docs](self) for matching rules.
+pub struct MockExecutor {
+    state: Mutex<MockState>,
+    strict: bool,
+    in_transaction: bool,
+    cache_provider: Option<Arc<dyn CacheProvider>>,
+}
+
+impl Default for MockExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MockExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("MockExecutor")
+            .field("strict", &self.strict)
+            .field("in_transaction", &self.in_transaction)
+            .field("pending_expectations", &state.expectations.len())
+            .field("recorded_statements", &state.statements.len())
+            .field("cache_provider", &self.cache_provider.is_some())
+            .finish()
+    }
+}
+
+impl MockExecutor {
+    /// Lenient executor with no expectations and no cache provider.
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(MockState::default()),
+            strict: false,
+            in_transaction: false,
+            cache_provider: None,
+        }
+    }
+
+    /// Fail statements that match no pending expectation instead of answering defaults.
+    #[must_use]
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Report `true` from [`LifeExecutor::in_transaction`], as a transaction scope would, so
+    /// [`LifeExecutor::atomically`] opens savepoints instead of transactions.
+    #[must_use]
+    pub fn transactional(mut self) -> Self {
+        self.in_transaction = true;
+        self
+    }
+
+    /// Report `provider` from [`LifeExecutor::cache_provider`] so write-through paths run.
+    #[must_use]
+    pub fn with_cache_provider(mut self, provider: Arc<dyn CacheProvider>) -> Self {
+        self.cache_provider = Some(provider);
+        self
+    }
+
+    /// Queue an expectation. Returns `&self` so calls can be chained.
+    pub fn expect(&self, pattern: impl Into<SqlPattern>, result: MockResult) -> &Self {
+        self.lock().expectations.push_back(Expectation {
+            pattern: pattern.into(),
+            result,
+        });
+        self
+    }
+
+    /// All statements recorded so far, oldest first.
+    pub fn statements(&self) -> Vec<RecordedStatement> {
+        self.lock().statements.clone()
+    }
+
+    /// SQL text of every recorded statement, oldest first.
+    pub fn sql_log(&self) -> Vec<String> {
+        self.lock()
+            .statements
+            .iter()
+            .map(|s| s.sql.clone())
+            .collect()
+    }
+
+    /// Most recently recorded statement.
+    pub fn last_statement(&self) -> Option<RecordedStatement> {
+        self.lock().statements.last().cloned()
+    }
+
+    /// Drain and return the recorded statements.
+    pub fn take_statements(&self) -> Vec<RecordedStatement> {
+        std::mem::take(&mut self.lock().statements)
+    }
+
+    /// Number of expectations not yet consumed.
+    pub fn pending_expectations(&self) -> usize {
+        self.lock().expectations.len()
+    }
+
+    /// Forget recorded statements and pending expectations.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.expectations.clear();
+        state.statements.clear();
+    }
+
+    /// Check that every queued expectation was consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::Other`] listing the patterns still pending.
+    pub fn verify(&self) -> Result<(), LifeError> {
+        let state = self.lock();
+        if state.expectations.is_empty() {
+            return Ok(());
+        }
+        let pending: Vec<String> = state
+            .expectations
+            .iter()
+            .map(|e| e.pattern.to_string())
+            .collect();
+        Err(LifeError::Other(format!(
+            "MockExecutor: {} expectation(s) not consumed: {}",
+            pending.len(),
+            pending.join(", ")
+        )))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record the statement and pop the first matching expectation.
+    fn dispatch(
+        &self,
+        kind: StatementKind,
+        sql: &str,
+        values: Option<&sea_query::Values>,
+        param_count: usize,
+    ) -> Option<MockResult> {
+        let mut state = self.lock();
+        state.statements.push(RecordedStatement {
+            kind,
+            sql: sql.to_string(),
+            values: values.cloned(),
+            param_count,
+        });
+        let idx = state
+            .expectations
+            .iter()
+            .position(|e| e.pattern.matches(sql))?;
+        state.expectations.remove(idx).map(|e| e.result)
+    }
+
+    fn unmatched(&self, sql: &str) -> Result<(), LifeError> {
+        if self.strict {
+            return Err(LifeError::QueryError(format!(
+                "MockExecutor: unexpected statement: {sql}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn run_execute(
+        &self,
+        sql: &str,
+        values: Option<&sea_query::Values>,
+        param_count: usize,
+    ) -> Result<u64, LifeError> {
+        match self.dispatch(StatementKind::Execute, sql, values, param_count) {
+            Some(MockResult::RowsAffected(n)) => Ok(n),
+            Some(MockResult::Rows(rows)) => Ok(rows.len() as u64),
+            Some(MockResult::Error(e)) => Err(e),
+            None => self.unmatched(sql).map(|()| 0),
+        }
+    }
+
+    fn run_query_one(
+        &self,
+        sql: &str,
+        values: Option<&sea_query::Values>,
+        param_count: usize,
+    ) -> Result<Row, LifeError> {
+        let rows = match self.dispatch(StatementKind::QueryOne, sql, values, param_count) {
+            Some(MockResult::Rows(rows)) => rows,
+            Some(MockResult::RowsAffected(_)) => Vec::new(),
+            Some(MockResult::Error(e)) => return Err(e),
+            None => {
+                self.unmatched(sql)?;
+                Vec::new()
+            }
+        };
+        let count = rows.len();
+        let mut iter = rows.into_iter();
+        match (iter.next(), count) {
+            (Some(row), 1) => Ok(row),
+            (None, _) => Err(LifeError::QueryError(
+                "MockExecutor: query returned no rows".to_string(),
+            )),
+            (Some(_), n) => Err(LifeError::QueryError(format!(
+                "MockExecutor: query_one returned {n} rows"
+            ))),
+        }
+    }
+
+    fn run_query_all(
+        &self,
+        sql: &str,
+        values: Option<&sea_query::Values>,
+        param_count: usize,
+    ) -> Result<Vec<Row>, LifeError> {
+        match self.dispatch(StatementKind::QueryAll, sql, values, param_count) {
+            Some(MockResult::Rows(rows)) => Ok(rows),
+            Some(MockResult::RowsAffected(_)) => Ok(Vec::new()),
+            Some(MockResult::Error(e)) => Err(e),
+            None => self.unmatched(sql).map(|()| Vec::new()),
+        }
+    }
+}
+
+impl LifeExecutor for MockExecutor {
+    fn execute(&self, query: &str, params: &[&dyn ToSql]) -> Result<u64, LifeError> {
+        self.run_execute(query, None, params.len())
+    }
+
+    fn query_one(&self, query: &str, params: &[&dyn ToSql]) -> Result<Row, LifeError> {
+        self.run_query_one(query, None, params.len())
+    }
+
+    fn query_all(&self, query: &str, params: &[&dyn ToSql]) -> Result<Vec<Row>, LifeError> {
+        self.run_query_all(query, None, params.len())
+    }
+
+    fn execute_values(&self, query: &str, values: &sea_query::Values) -> Result<u64, LifeError> {
+        self.run_execute(query, Some(values), values.0.len())
+    }
+
+    fn query_one_values(&self, query: &str, values: &sea_query::Values) -> Result<Row, LifeError> {
+        self.run_query_one(query, Some(values), values.0.len())
+    }
+
+    fn query_all_values(
+        &self,
+        query: &str,
+        values: &sea_query::Values,
+    ) -> Result<Vec<Row>, LifeError> {
+        self.run_query_all(query, Some(values), values.0.len())
+    }
+
+    fn cache_provider(&self) -> Option<Arc<dyn CacheProvider>> {
+        self.cache_provider.clone()
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.in_transaction || self.lock().open_scopes > 0
+    }
+
+    /// Records `BEGIN` / `COMMIT` around `work`, or `SAVEPOINT sp_N` / `RELEASE SAVEPOINT sp_N`
+    /// when already in a transaction; a failing `work` ends with `ROLLBACK` /
+    /// `ROLLBACK TO SAVEPOINT sp_N` instead. Scripted errors for these statements are returned.
+    fn atomically(
+        &self,
+        work: &mut dyn FnMut(&dyn LifeExecutor) -> Result<(), LifeError>,
+    ) -> Result<(), LifeError> {
+        let depth = self.lock().open_scopes + u32::from(self.in_transaction);
+        let (open, commit, rollback) = if depth == 0 {
+            (
+                "BEGIN".to_string(),
+                "COMMIT".to_string(),
+                "ROLLBACK".to_string(),
+            )
+        } else {
+            (
+                format!("SAVEPOINT sp_{depth}"),
+                format!("RELEASE SAVEPOINT sp_{depth}"),
+                format!("ROLLBACK TO SAVEPOINT sp_{depth}"),
+            )
+        };
+        self.execute(&open, &[])?;
+        self.lock().open_scopes += 1;
+        let result = work(self);
+        self.lock().open_scopes -= 1;
+        match result {
+            Ok(()) => self.execute(&commit, &[]).map(|_| ()),
+            Err(error) => {
+                let _ = self.execute(&rollback, &[]);
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::query::error_handling::is_no_rows_error;
+    use crate::query::select::SelectQuery;
+    use crate::query::traits::{FromRow, LifeEntityName, LifeModelTrait};
+    use sea_query::{Expr, ExprTrait, Value, Values};
+
+    fn vals(n: i32) -> Values {
+        Values(vec![Value::Int(Some(n))])
+    }
+
+    #[test]
+    fn records_values_and_param_count() {
+        let mock = MockExecutor::new();
+        mock.execute_values("UPDATE t SET a = $1", &vals(3))
+            .unwrap();
+        let stmt = mock.last_statement().unwrap();
+        assert_eq!(stmt.kind, StatementKind::Execute);
+        assert_eq!(stmt.sql, "UPDATE t SET a = $1");
+        assert_eq!(stmt.values, Some(vals(3)));
+        assert_eq!(stmt.param_count, 1);
+    }
+
+    #[test]
+    fn raw_entry_points_record_without_values() {
+        let mock = MockExecutor::new();
+        mock.execute("SELECT $1", &[&1i32]).unwrap();
+        let stmt = mock.last_statement().unwrap();
+        assert_eq!(stmt.values, None);
+        assert_eq!(stmt.param_count, 1);
+    }
+
+    #[test]
+    fn expectations_consumed_first_match_in_order() {
+        let mock = MockExecutor::new().strict();
+        mock.expect("DELETE", MockResult::RowsAffected(2))
+            .expect(SqlPattern::Any, MockResult::RowsAffected(5));
+        assert_eq!(mock.execute_values("UPDATE t", &Values(vec![])).unwrap(), 5);
+        assert_eq!(
+            mock.execute_values("DELETE FROM t", &Values(vec![]))
+                .unwrap(),
+            2
+        );
+        assert!(mock.verify().is_ok());
+        assert!(mock
+            .execute_values("DELETE FROM t", &Values(vec![]))
+            .is_err());
+    }
+
+    #[test]
+    fn lenient_defaults() {
+        let mock = MockExecutor::new();
+        assert_eq!(mock.execute_values("x", &Values(vec![])).unwrap(), 0);
+        assert!(mock
+            .query_all_values("x", &Values(vec![]))
+            .unwrap()
+            .is_empty());
+        let err = mock.query_one_values("x", &Values(vec![])).unwrap_err();
+        assert!(is_no_rows_error(&err));
+    }
+
+    #[test]
+    fn scripted_error_is_returned() {
+        let mock = MockExecutor::new();
+        mock.expect(
+            SqlPattern::exact("SELECT 1"),
+            MockResult::Error(LifeError::QueryError("boom".into())),
+        );
+        let err = mock.query_all("SELECT 1", &[]).unwrap_err();
+        assert!(matches!(err, LifeError::QueryError(ref m) if m == "boom"));
+    }
+
+    #[test]
+    fn regex_pattern_and_verify_reports_pending() {
+        let mock = MockExecutor::new();
+        mock.expect(
+            SqlPattern::regex(r#"^INSERT INTO "\w+""#).unwrap(),
+            MockResult::RowsAffected(1),
+        );
+        assert!(SqlPattern::regex("(").is_err());
+        let err = mock.verify().unwrap_err();
+        assert!(err.to_string().contains("regex"));
+        assert_eq!(
+            mock.execute_values(r#"INSERT INTO "users" ("a") VALUES ($1)"#, &vals(1))
+                .unwrap(),
+            1
+        );
+        assert_eq!(mock.pending_expectations(), 0);
+    }
+
+    #[test]
+    fn reset_and_take_statements() {
+        let mock = MockExecutor::new();
+        mock.expect(SqlPattern::Any, MockResult::empty());
+        mock.execute("a", &[]).unwrap();
+        mock.execute("b", &[]).unwrap();
+        assert_eq!(mock.sql_log(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(mock.take_statements().len(), 2);
+        assert!(mock.statements().is_empty());
+        mock.expect(SqlPattern::Any, MockResult::empty());
+        mock.reset();
+        assert_eq!(mock.pending_expectations(), 0);
+    }
+
+    #[derive(Copy, Clone, Default, Debug)]
+    struct Widget;
+
+    impl LifeEntityName for Widget {
+        fn table_name(&self) -> &'static str {
+            "widgets"
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    enum WidgetColumn {
+        Id,
+    }
+
+    impl sea_query::Iden for WidgetColumn {
+        fn unquoted(&self) -> &'static str {
+            "id"
+        }
+    }
+
+    impl sea_query::IdenStatic for WidgetColumn {
+        fn as_str(&self) -> &'static str {
+            "id"
+        }
+    }
+
+    crate::impl_column_def_helper_for_test!(WidgetColumn);
+
+    #[derive(Debug, Clone)]
+    struct WidgetModel;
+
+    impl FromRow for WidgetModel {
+        fn from_row(_row: &Row) -> Result<Self, may_postgres::Error> {
+            Ok(WidgetModel)
+        }
+    }
+
+    impl LifeModelTrait for Widget {
+        type Model = WidgetModel;
+        type Column = WidgetColumn;
+    }
+
+    #[test]
+    fn select_query_sql_and_values_are_recorded() {
+        let mock = MockExecutor::new().strict();
+        mock.expect("FROM \"widgets\"", MockResult::empty());
+        let rows = SelectQuery::<Widget>::new()
+            .filter(Expr::col("id").eq(7))
+            .all(&mock)
+            .unwrap();
+        assert!(rows.is_empty());
+        let stmt = mock.last_statement().unwrap();
+        assert_eq!(stmt.kind, StatementKind::QueryAll);
+        assert!(stmt.sql.contains("\"id\" = $1"));
+        assert_eq!(stmt.values, Some(vals(7)));
+        mock.verify().unwrap();
+    }
+
+    #[test]
+    fn find_one_without_rows_is_none() {
+        let mock = MockExecutor::new();
+        let found = SelectQuery::<Widget>::new().find_one(&mock).unwrap();
+        assert!(found.is_none());
+        assert_eq!(mock.last_statement().unwrap().kind, StatementKind::QueryOne);
+    }
+}
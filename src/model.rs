@@ -52,6 +52,7 @@ use sea_query::Value;
 /// - Primitives: `i32`, `i64`, `i16`, `u8`, `u16`, `u32`, `u64`, `f32`, `f64`, `bool`, `String`
 /// - Option types: `Option<T>` where `T` is any supported type
 /// - JSON: `serde_json::Value` and `Option<serde_json::Value>`
+/// - Newtypes: `#[life_value]` fields whose type derives [`LifeValue`](crate::LifeValue)
 ///
 /// Unknown types will fall back to `Value::String(None)` in `get()` operations, which may hide bugs.
 /// Consider using only supported types or extending the macro to support additional types.
@@ -0,0 +1,618 @@
+//! `LISTEN` / `NOTIFY` consumer for `#[notify]` triggers.
+//!
+//! `#[notify(channel = "...", on = "...")]` makes the migration generator emit a trigger that calls
+//! `pg_notify(channel, json_build_object('table', TG_TABLE_NAME, 'op', TG_OP, 'id', <pk>)::text)`.
+//! [`NotificationListener`] is the receiving side: it holds one dedicated connection, `LISTEN`s on
+//! every configured channel, and forwards each payload as a typed [`NotifyEvent`] over a
+//! `may::sync::mpsc` channel.
+//!
+//! # Reconnects
+//!
+//! PostgreSQL does not queue notifications for a session that is not connected. When the
+//! connection drops the listener reconnects with exponential backoff, re-issues every `LISTEN`, and
+//! then sends [`ListenerEvent::Reconnected`] so consumers that mirror table state (caches, search
+//! indexes) know to resynchronise instead of trusting the gap.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use lifeguard::{ListenerEvent, NotificationListener};
+//!
+//! let handle = NotificationListener::new("postgresql://localhost/app")
+//!     .listen("orders_changed")
+//!     .spawn();
+//!
+//! while let Some(event) = handle.recv() {
+//!     match event {
+//!         ListenerEvent::Notify(e) => println!("{} {} {}", e.table, e.operation, e.id),
+//!         ListenerEvent::Reconnected => println!("missed events possible; resync"),
+//!     }
+//! }
+//! ```
+
+use crate::executor::LifeError;
+use crate::query::TableDefinition;
+use crate::transaction::RetryPolicy;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Row operation reported by a trigger (`TG_OP`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotifyOperation {
+    /// `INSERT`
+    Insert,
+    /// `UPDATE`
+    Update,
+    /// `DELETE`
+    Delete,
+}
+
+impl NotifyOperation {
+    /// Parse a `TG_OP` value (`INSERT` / `UPDATE` / `DELETE`, case-insensitive).
+    pub fn parse(op: &str) -> Option<Self> {
+        if op.eq_ignore_ascii_case("INSERT") {
+            Some(Self::Insert)
+        } else if op.eq_ignore_ascii_case("UPDATE") {
+            Some(Self::Update)
+        } else if op.eq_ignore_ascii_case("DELETE") {
+            Some(Self::Delete)
+        } else {
+            None
+        }
+    }
+
+    /// The `TG_OP` spelling.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Insert => "INSERT",
+            Self::Update => "UPDATE",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for NotifyOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One row change decoded from a `#[notify]` trigger payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotifyEvent {
+    /// Channel the notification arrived on.
+    pub channel: String,
+    /// `TG_TABLE_NAME` of the changed row.
+    pub table: String,
+    /// Which operation fired the trigger.
+    pub operation: NotifyOperation,
+    /// Primary key of the changed row, as serialized by `json_build_object`.
+    pub id: serde_json::Value,
+}
+
+impl NotifyEvent {
+    /// Decode a trigger payload of the form `{"table": "...", "op": "...", "id": ...}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::ParseError`] when the payload is not JSON or a field is missing.
+    pub fn parse(channel: &str, payload: &str) -> Result<Self, LifeError> {
+        let value: serde_json::Value = serde_json::from_str(payload).map_err(|e| {
+            LifeError::ParseError(format!(
+                "notification on `{channel}` is not JSON: {e} (payload: {payload})"
+            ))
+        })?;
+        let field = |name: &str| {
+            value.get(name).ok_or_else(|| {
+                LifeError::ParseError(format!(
+                    "notification on `{channel}` has no `{name}` field (payload: {payload})"
+                ))
+            })
+        };
+        let table = field("table")?
+            .as_str()
+            .ok_or_else(|| {
+                LifeError::ParseError(format!(
+                    "notification on `{channel}`: `table` is not a string"
+                ))
+            })?
+            .to_string();
+        let op = field("op")?.as_str().unwrap_or_default();
+        let operation = NotifyOperation::parse(op).ok_or_else(|| {
+            LifeError::ParseError(format!(
+                "notification on `{channel}`: unknown operation `{op}`"
+            ))
+        })?;
+        let id = field("id")?.clone();
+        Ok(Self {
+            channel: channel.to_string(),
+            table,
+            operation,
+            id,
+        })
+    }
+
+    /// The primary key as text: strings unquoted, everything else in its JSON form.
+    pub fn id_string(&self) -> String {
+        match &self.id {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// What a [`NotificationHandle`] delivers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListenerEvent {
+    /// A decoded trigger payload.
+    Notify(NotifyEvent),
+    /// The connection was lost and re-established; notifications sent in between are gone.
+    Reconnected,
+}
+
+/// A notification as received from the server, before decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawNotification {
+    /// Channel name.
+    pub channel: String,
+    /// Payload text.
+    pub payload: String,
+}
+
+/// A connected session that can subscribe to channels and wait for notifications.
+pub trait NotificationSource: Send {
+    /// Subscribe to `channel` (`LISTEN`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the command fails; the listener then reconnects.
+    fn listen(&mut self, channel: &str) -> Result<(), LifeError>;
+
+    /// Wait up to `timeout` for the next notification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the connection is unusable; the listener then reconnects.
+    fn poll(&mut self, timeout: Duration) -> Result<Option<RawNotification>, LifeError>;
+}
+
+/// Opens [`NotificationSource`]s; called once at start and again after every failure.
+pub trait NotificationConnector: Send + 'static {
+    /// Session type produced by [`connect`](Self::connect).
+    type Source: NotificationSource;
+
+    /// Open a new session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the connection cannot be established.
+    fn connect(&self) -> Result<Self::Source, LifeError>;
+}
+
+/// Connects to PostgreSQL through `may_postgres` using a connection URL.
+#[derive(Debug, Clone)]
+pub struct PostgresNotificationConnector {
+    url: String,
+}
+
+impl PostgresNotificationConnector {
+    /// Connector for `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+impl NotificationConnector for PostgresNotificationConnector {
+    type Source = PostgresNotificationSource;
+
+    fn connect(&self) -> Result<Self::Source, LifeError> {
+        let client = may_postgres::connect(&self.url).map_err(LifeError::from)?;
+        Ok(PostgresNotificationSource { client })
+    }
+}
+
+/// A dedicated `may_postgres` client used only for `LISTEN`.
+pub struct PostgresNotificationSource {
+    client: may_postgres::Client,
+}
+
+impl NotificationSource for PostgresNotificationSource {
+    fn listen(&mut self, channel: &str) -> Result<(), LifeError> {
+        self.client
+            .execute(&listen_sql(channel), &[])
+            .map(|_| ())
+            .map_err(LifeError::from)
+    }
+
+    fn poll(&mut self, timeout: Duration) -> Result<Option<RawNotification>, LifeError> {
+        use fallible_iterator::FallibleIterator;
+
+        let mut notifications = self.client.notifications();
+        let next = notifications
+            .timeout_iter(timeout)
+            .next()
+            .map_err(LifeError::from)?;
+        Ok(next.map(|n| RawNotification {
+            channel: n.channel().to_string(),
+            payload: n.payload().to_string(),
+        }))
+    }
+}
+
+/// `LISTEN "<channel>"` with the identifier quoted, so channel names keep their case exactly as
+/// passed to `pg_notify`.
+fn listen_sql(channel: &str) -> String {
+    format!("LISTEN \"{}\"", channel.replace('"', "\"\""))
+}
+
+/// Builder for a background `LISTEN` loop.
+///
+/// Configure channels, then [`spawn`](Self::spawn) to start the coroutine.
+pub struct NotificationListener<C: NotificationConnector = PostgresNotificationConnector> {
+    connector: C,
+    channels: Vec<String>,
+    backoff: RetryPolicy,
+    poll_interval: Duration,
+}
+
+impl NotificationListener<PostgresNotificationConnector> {
+    /// Listener on a dedicated PostgreSQL connection to `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_connector(PostgresNotificationConnector::new(url))
+    }
+}
+
+impl<C: NotificationConnector> NotificationListener<C> {
+    /// Listener over a custom connector (tests, proxies, alternative drivers).
+    ///
+    /// Reconnect backoff defaults to 100ms doubling up to 30s; the stop flag is checked once per
+    /// second while idle.
+    pub fn with_connector(connector: C) -> Self {
+        Self {
+            connector,
+            channels: Vec::new(),
+            backoff: RetryPolicy::none()
+                .with_backoff(Duration::from_millis(100), Duration::from_secs(30)),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Add a channel. Duplicates are ignored.
+    #[must_use]
+    pub fn listen(mut self, channel: impl Into<String>) -> Self {
+        let channel = channel.into();
+        if !self.channels.contains(&channel) {
+            self.channels.push(channel);
+        }
+        self
+    }
+
+    /// Add the channel declared by an entity's `#[notify]` attribute, if any.
+    #[must_use]
+    pub fn listen_table(self, table: &TableDefinition) -> Self {
+        match &table.notify {
+            Some(notify) => self.listen(notify.channel.clone()),
+            None => self,
+        }
+    }
+
+    /// Reconnect delays: `initial` after the first failure, doubling up to `max`.
+    #[must_use]
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = self.backoff.with_backoff(initial, max);
+        self
+    }
+
+    /// How long a single wait for notifications blocks before checking for shutdown.
+    #[must_use]
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Channels this listener will subscribe to.
+    pub fn channels(&self) -> &[String] {
+        &self.channels
+    }
+
+    /// Start the loop in a coroutine and return the receiving end.
+    ///
+    /// The loop exits when the handle is dropped (or [`NotificationHandle::stop`] is called); it
+    /// notices within one poll interval.
+    pub fn spawn(self) -> NotificationHandle {
+        let (tx, rx) = may::sync::mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop);
+        let _listener_co = may::go!(move || {
+            run_listener(
+                &self.connector,
+                &self.channels,
+                &self.backoff,
+                self.poll_interval,
+                &tx,
+                &worker_stop,
+            );
+        });
+        NotificationHandle { receiver: rx, stop }
+    }
+}
+
+/// Receiving end of a spawned [`NotificationListener`].
+///
+/// Dropping the handle stops the listener.
+pub struct NotificationHandle {
+    receiver: may::sync::mpsc::Receiver<ListenerEvent>,
+    stop: Arc<AtomicBool>,
+}
+
+impl NotificationHandle {
+    /// Block (yielding the coroutine) until the next event; `None` once the listener has exited.
+    pub fn recv(&self) -> Option<ListenerEvent> {
+        self.receiver.recv().ok()
+    }
+
+    /// Wait up to `timeout` for the next event.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ListenerEvent> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// Next event if one is already queued.
+    pub fn try_recv(&self) -> Option<ListenerEvent> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// The underlying channel, for `select`-style consumers.
+    pub fn receiver(&self) -> &may::sync::mpsc::Receiver<ListenerEvent> {
+        &self.receiver
+    }
+
+    /// Ask the listener to exit after its current poll.
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for NotificationHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Connect, subscribe, and forward events until `stop` is set or the receiver goes away.
+fn run_listener<C: NotificationConnector>(
+    connector: &C,
+    channels: &[String],
+    backoff: &RetryPolicy,
+    poll_interval: Duration,
+    tx: &may::sync::mpsc::Sender<ListenerEvent>,
+    stop: &AtomicBool,
+) {
+    let mut failures: u32 = 0;
+    let mut subscribed_before = false;
+
+    while !stop.load(Ordering::Relaxed) {
+        let subscribed = connector.connect().and_then(|mut source| {
+            for channel in channels {
+                source.listen(channel)?;
+            }
+            Ok(source)
+        });
+        let mut source = match subscribed {
+            Ok(source) => source,
+            Err(e) => {
+                failures = failures.saturating_add(1);
+                let delay = backoff.backoff(failures);
+                log::warn!(
+                    "lifeguard notify: connect/LISTEN failed (attempt {failures}), retrying in {delay:?}: {e}"
+                );
+                if !delay.is_zero() {
+                    may::coroutine::sleep(delay);
+                }
+                continue;
+            }
+        };
+
+        failures = 0;
+        if subscribed_before && tx.send(ListenerEvent::Reconnected).is_err() {
+            return;
+        }
+        subscribed_before = true;
+        log::debug!("lifeguard notify: listening on {channels:?}");
+
+        while !stop.load(Ordering::Relaxed) {
+            match source.poll(poll_interval) {
+                Ok(None) => {}
+                Ok(Some(raw)) => match NotifyEvent::parse(&raw.channel, &raw.payload) {
+                    Ok(event) => {
+                        if tx.send(ListenerEvent::Notify(event)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(e) => log::warn!("lifeguard notify: skipping payload: {e}"),
+                },
+                Err(e) => {
+                    failures = 1;
+                    let delay = backoff.backoff(failures);
+                    log::warn!("lifeguard notify: connection lost, reconnecting in {delay:?}: {e}");
+                    if !delay.is_zero() {
+                        may::coroutine::sleep(delay);
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::query::NotifyDefinition;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    #[test]
+    fn parses_trigger_payload() {
+        let e = NotifyEvent::parse(
+            "orders_changed",
+            r#"{"table" : "orders", "op" : "UPDATE", "id" : 42}"#,
+        )
+        .unwrap();
+        assert_eq!(e.channel, "orders_changed");
+        assert_eq!(e.table, "orders");
+        assert_eq!(e.operation, NotifyOperation::Update);
+        assert_eq!(e.id, serde_json::json!(42));
+        assert_eq!(e.id_string(), "42");
+
+        let e = NotifyEvent::parse("c", r#"{"table":"t","op":"DELETE","id":"a-b"}"#).unwrap();
+        assert_eq!(e.operation, NotifyOperation::Delete);
+        assert_eq!(e.id_string(), "a-b");
+    }
+
+    #[test]
+    fn rejects_malformed_payloads() {
+        for payload in [
+            "not json",
+            r#"{"op":"INSERT","id":1}"#,
+            r#"{"table":"t","op":"TRUNCATE","id":1}"#,
+            r#"{"table":"t","op":"INSERT"}"#,
+        ] {
+            assert!(
+                matches!(
+                    NotifyEvent::parse("c", payload),
+                    Err(LifeError::ParseError(_))
+                ),
+                "{payload}"
+            );
+        }
+    }
+
+    #[test]
+    fn listen_sql_quotes_channel() {
+        assert_eq!(listen_sql("Orders"), "LISTEN \"Orders\"");
+        assert_eq!(listen_sql("a\"b"), "LISTEN \"a\"\"b\"");
+    }
+
+    #[test]
+    fn listen_table_uses_notify_channel_once() {
+        let mut table = TableDefinition::default();
+        let listener = NotificationListener::new("postgresql://unused").listen_table(&table);
+        assert!(listener.channels().is_empty());
+
+        table.notify = Some(NotifyDefinition {
+            channel: "orders_changed".to_string(),
+            on_insert: true,
+            on_update: true,
+            on_delete: false,
+        });
+        let listener = listener
+            .listen_table(&table)
+            .listen("orders_changed")
+            .listen("audit");
+        assert_eq!(listener.channels(), ["orders_changed", "audit"]);
+    }
+
+    /// One scripted session: each `poll` pops the next step; an exhausted script idles.
+    enum Step {
+        Payload(&'static str),
+        Fail,
+    }
+
+    struct FakeSource {
+        script: VecDeque<Step>,
+        listens: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl NotificationSource for FakeSource {
+        fn listen(&mut self, channel: &str) -> Result<(), LifeError> {
+            self.listens.lock().unwrap().push(channel.to_string());
+            Ok(())
+        }
+
+        fn poll(&mut self, timeout: Duration) -> Result<Option<RawNotification>, LifeError> {
+            match self.script.pop_front() {
+                Some(Step::Payload(p)) => Ok(Some(RawNotification {
+                    channel: "orders_changed".to_string(),
+                    payload: p.to_string(),
+                })),
+                Some(Step::Fail) => Err(LifeError::Other("connection reset".to_string())),
+                None => {
+                    may::coroutine::sleep(timeout);
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    struct FakeConnector {
+        sessions: Mutex<VecDeque<Result<Vec<Step>, LifeError>>>,
+        listens: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl NotificationConnector for FakeConnector {
+        type Source = FakeSource;
+
+        fn connect(&self) -> Result<FakeSource, LifeError> {
+            let next = self
+                .sessions
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| Ok(Vec::new()));
+            next.map(|steps| FakeSource {
+                script: steps.into_iter().collect(),
+                listens: Arc::clone(&self.listens),
+            })
+        }
+    }
+
+    fn next_event(handle: &NotificationHandle) -> ListenerEvent {
+        handle.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+
+    #[test]
+    #[allow(clippy::panic)] // Test code - panic is acceptable
+    fn reconnects_resubscribes_and_skips_bad_payloads() {
+        let listens = Arc::new(Mutex::new(Vec::new()));
+        let connector = FakeConnector {
+            sessions: Mutex::new(VecDeque::from(vec![
+                Ok(vec![
+                    Step::Payload(r#"{"table":"orders","op":"INSERT","id":1}"#),
+                    Step::Payload("garbage"),
+                    Step::Fail,
+                ]),
+                Err(LifeError::Other("refused".to_string())),
+                Ok(vec![Step::Payload(
+                    r#"{"table":"orders","op":"DELETE","id":1}"#,
+                )]),
+            ])),
+            listens: Arc::clone(&listens),
+        };
+
+        let handle = NotificationListener::with_connector(connector)
+            .listen("orders_changed")
+            .listen("audit")
+            .with_reconnect_backoff(Duration::from_millis(1), Duration::from_millis(5))
+            .with_poll_interval(Duration::from_millis(10))
+            .spawn();
+
+        match next_event(&handle) {
+            ListenerEvent::Notify(e) => assert_eq!(e.operation, NotifyOperation::Insert),
+            other => panic!("expected insert, got {other:?}"),
+        }
+        assert_eq!(next_event(&handle), ListenerEvent::Reconnected);
+        match next_event(&handle) {
+            ListenerEvent::Notify(e) => assert_eq!(e.operation, NotifyOperation::Delete),
+            other => panic!("expected delete, got {other:?}"),
+        }
+
+        assert_eq!(
+            *listens.lock().unwrap(),
+            ["orders_changed", "audit", "orders_changed", "audit"]
+        );
+        handle.stop();
+    }
+}
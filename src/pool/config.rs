@@ -133,8 +133,13 @@ impl DatabaseConfig {
     ///
     /// The environment layer is merged **after** the file and overrides matching keys (PRD R2.2).
     pub fn load() -> Result<Self, ConfigError> {
+        Self::load_from("config/config")
+    }
+
+    /// Like [`load`](Self::load) with an explicit config file path (extension optional).
+    pub fn load_from(path: &str) -> Result<Self, ConfigError> {
         let root: ConfigRoot = Config::builder()
-            .add_source(File::with_name("config/config").required(false))
+            .add_source(File::with_name(path).required(false))
             .add_source(Environment::with_prefix("LIFEGUARD").separator("__"))
             .build()?
             .try_deserialize()?;
@@ -40,5 +40,8 @@ pub mod wal;
 
 pub use config::{DatabaseConfig, LifeguardPoolSettings};
 pub use owned_param::OwnedParam;
-pub use pooled::{ExclusivePrimaryLifeExecutor, LifeguardPool, PooledLifeExecutor, ReadPreference};
+pub use pooled::{
+    ExclusivePrimaryLifeExecutor, LifeguardPool, PooledLifeExecutor, PooledTransaction,
+    ReadPreference,
+};
 pub use wal::WalLagPolicy;
@@ -3,12 +3,14 @@
 //! [`crate::executor::LifeExecutor`] uses `&[&dyn ToSql]`, which cannot be sent across channels.
 //! [`OwnedParam`] mirrors `sea_query::Value` variants used for pool dispatch (including
 //! **`Value::Decimal`** when the `with-rust_decimal` feature is enabled on `sea-query`) so workers
-//! can rebuild `ToSql` references on their stack.
+//! can rebuild `ToSql` references on their stack. `Value::Array` of `String`, `Int`, `BigInt`
+//! and `Uuid` elements (`postgres-array`) is carried as the matching `Vec<T>`. Typed values
+//! (including `LifeValue` newtypes) go through [`OwnedParam::from_value_type`].
 
 use crate::executor::LifeError;
-use crate::value::TextParam;
+use crate::value::{TextParam, ValueType};
 use may_postgres::types::ToSql;
-use sea_query::Value;
+use sea_query::{ArrayType, Value};
 
 /// Single bind parameter in owned form for pool dispatch.
 #[derive(Clone, Debug)]
@@ -34,11 +36,29 @@ pub enum OwnedParam {
     Decimal(Option<rust_decimal::Decimal>),
     /// JSON / JSONB; `None` is SQL NULL (typed JSON null, not `GenericNull`).
     Json(Option<serde_json::Value>),
+    /// `TEXT[]`; `None` is SQL NULL.
+    StringArray(Option<Vec<String>>),
+    /// `INTEGER[]`; `None` is SQL NULL.
+    IntArray(Option<Vec<i32>>),
+    /// `BIGINT[]`; `None` is SQL NULL.
+    BigIntArray(Option<Vec<i64>>),
+    /// `UUID[]`; `None` is SQL NULL.
+    UuidArray(Option<Vec<uuid::Uuid>>),
     /// Legacy bucket for `Value` variants that still map to a shared placeholder (see `converted_params`).
     GenericNull,
 }
 
 impl OwnedParam {
+    /// Owned parameter for any [`ValueType`] (newtypes from `#[derive(LifeValue)]`, enums, ranges, …),
+    /// carried as the `sea_query::Value` it converts into.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`OwnedParam::try_from`] for that value.
+    pub fn from_value_type<T: ValueType>(value: T) -> Result<Self, LifeError> {
+        Self::try_from(&value.into_value())
+    }
+
     /// Borrow as `dyn ToSql` for the current statement bind.
     pub(crate) fn as_sql_ref(&self) -> &dyn ToSql {
         match self {
@@ -58,6 +78,10 @@ impl OwnedParam {
             OwnedParam::Uuid(u) => u as &dyn ToSql,
             OwnedParam::Decimal(d) => d as &dyn ToSql,
             OwnedParam::Json(v) => v as &dyn ToSql,
+            OwnedParam::StringArray(a) => a as &dyn ToSql,
+            OwnedParam::IntArray(a) => a as &dyn ToSql,
+            OwnedParam::BigIntArray(a) => a as &dyn ToSql,
+            OwnedParam::UuidArray(a) => a as &dyn ToSql,
             OwnedParam::GenericNull => {
                 static C: Option<i32> = None;
                 &C as &dyn ToSql
@@ -132,6 +156,11 @@ impl TryFrom<&Value> for OwnedParam {
             Value::Json(Some(j)) => Ok(OwnedParam::Json(Some((**j).clone()))),
             Value::Json(None) => Ok(OwnedParam::Json(None)),
 
+            Value::Array(ArrayType::String, _) => array(value).map(OwnedParam::StringArray),
+            Value::Array(ArrayType::Int, _) => array(value).map(OwnedParam::IntArray),
+            Value::Array(ArrayType::BigInt, _) => array(value).map(OwnedParam::BigIntArray),
+            Value::Array(ArrayType::Uuid, _) => array(value).map(OwnedParam::UuidArray),
+
             _ => Err(LifeError::Other(format!(
                 "Unsupported value type for pool parameter: {value:?}"
             ))),
@@ -139,6 +168,18 @@ impl TryFrom<&Value> for OwnedParam {
     }
 }
 
+/// Owned `Option<Vec<T>>` of an array value; NULL elements are rejected.
+fn array<T>(value: &Value) -> Result<Option<Vec<T>>, LifeError>
+where
+    Vec<T>: ValueType,
+{
+    <Option<Vec<T>> as ValueType>::from_value(value.clone()).ok_or_else(|| {
+        LifeError::Other(format!(
+            "Array pool parameter with NULL or mismatched elements is not supported: {value:?}"
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)] // test-only unwraps
@@ -268,6 +309,29 @@ mod tests {
         assert!(buf2.len() > 1);
     }
 
+    #[test]
+    fn try_from_arrays_encode_as_postgres_arrays() {
+        use bytes::BytesMut;
+        use postgres_types::{IsNull, Type};
+
+        let tags = OwnedParam::try_from(&Value::from(vec!["a".to_string()])).expect("text[]");
+        assert!(matches!(tags, OwnedParam::StringArray(Some(ref v)) if v == &["a"]));
+        let mut buf = BytesMut::new();
+        assert!(matches!(
+            tags.as_sql_ref()
+                .to_sql_checked(&Type::TEXT_ARRAY, &mut buf),
+            Ok(IsNull::No)
+        ));
+
+        let ids = OwnedParam::try_from(&Value::Array(ArrayType::Uuid, None)).expect("uuid[] null");
+        assert!(matches!(ids, OwnedParam::UuidArray(None)));
+        let mut buf = BytesMut::new();
+        assert!(matches!(
+            ids.as_sql_ref().to_sql_checked(&Type::UUID_ARRAY, &mut buf),
+            Ok(IsNull::Yes)
+        ));
+    }
+
     #[test]
     fn try_from_string_none_and_json_none_use_typed_nulls() {
         use bytes::BytesMut;
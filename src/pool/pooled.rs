@@ -31,7 +31,7 @@ use crossbeam_channel::{RecvTimeoutError, SendTimeoutError};
 use may_postgres::types::ToSql;
 use may_postgres::{Client, Row};
 use std::fmt;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -157,6 +157,20 @@ impl WorkerPool {
         self.next_worker.fetch_add(1, Ordering::Relaxed) % self.pool_size
     }
 
+    /// Take the next slot's mutex and keep it until the returned [`PinnedSlot`] is dropped.
+    fn pin(&self) -> PinnedSlot<'_> {
+        let slot = self.pick_worker_index();
+        let guard = self.slot_locks[slot]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        PinnedSlot {
+            tier: self,
+            slot,
+            transaction_open: AtomicBool::new(false),
+            _guard: guard,
+        }
+    }
+
     /// Enqueue work on a worker after taking that slot’s [`Mutex`].
     ///
     /// **Latency:** The slot index is chosen **before** the lock ([`Self::pick_worker_index`],
@@ -498,17 +512,18 @@ impl LifeguardPool {
     pub fn exclusive_primary_write_executor(
         &self,
     ) -> Result<ExclusivePrimaryLifeExecutor<'_>, LifeError> {
-        let slot = self.primary.pick_worker_index();
-        let _guard = self.primary.slot_locks[slot]
-            .lock()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
         Ok(ExclusivePrimaryLifeExecutor {
-            pool: self,
-            slot,
-            _guard,
+            pinned: self.primary.pin(),
         })
     }
 
+    /// Pin one worker slot of the tier `preference` routes reads to (a replica when configured
+    /// and not lagging, unless [`ReadPreference::Primary`]), for a read that spans several
+    /// statements such as a server-side cursor.
+    pub(crate) fn pin_read_slot(&self, preference: ReadPreference) -> PinnedSlot<'_> {
+        self.read_pool_for(preference).pin()
+    }
+
     /// Run a multi-statement unit of work on one pinned primary connection with
     /// transaction-local RLS context.
     ///
@@ -583,12 +598,37 @@ fn session_context_values(context: &SessionContext) -> Result<sea_query::Values,
 ///
 /// Both reads and writes go through the **primary** tier (no replica routing), which matches
 /// PostgreSQL transaction semantics for `BEGIN`/`COMMIT` on a single connection.
+/// [`LifeExecutor::in_transaction`] is `true` between a `BEGIN` sent through this handle and the
+/// matching `COMMIT` / `ROLLBACK`; row-locking queries are refused outside that window.
 pub struct ExclusivePrimaryLifeExecutor<'a> {
-    pool: &'a LifeguardPool,
+    pinned: PinnedSlot<'a>,
+}
+
+/// One worker slot of a tier, locked so only its holder dispatches to that connection until
+/// dropped.
+///
+/// Workers autocommit each job, so the slot follows the transaction-control statements sent
+/// through it (`BEGIN` … `COMMIT` / `ROLLBACK`) to answer [`LifeExecutor::in_transaction`].
+pub(crate) struct PinnedSlot<'a> {
+    tier: &'a WorkerPool,
     slot: usize,
+    transaction_open: AtomicBool,
     _guard: MutexGuard<'a, ()>,
 }
 
+impl PinnedSlot<'_> {
+    /// `primary` or `replica`.
+    pub(crate) fn tier_name(&self) -> &'static str {
+        self.tier.metrics_tier
+    }
+
+    /// Run `public.rls_set_session(...)` on this slot (inside a transaction the caller opened).
+    pub(crate) fn set_session_context(&self, context: &SessionContext) -> Result<(), LifeError> {
+        self.execute_values(RLS_SET_SESSION_SQL, &session_context_values(context)?)
+            .map(|_| ())
+    }
+}
+
 struct PoolTransactionGuard<'executor, 'pool> {
     executor: &'executor ExclusivePrimaryLifeExecutor<'pool>,
     active: bool,
@@ -631,10 +671,224 @@ impl Drop for PoolTransactionGuard<'_, '_> {
     }
 }
 
+/// Transaction (or savepoint) on a pinned primary slot, handed to
+/// [`PooledLifeExecutor::transaction`] closures.
+///
+/// Mirrors [`crate::transaction::Transaction`] for pooled connections: it implements
+/// [`LifeExecutor`] (use the `*_values` / ORM paths), and [`Self::transaction`] /
+/// [`Self::begin_nested`] open `SAVEPOINT sp_N` on the same connection. Dropping an unfinished
+/// scope rolls it back.
+pub struct PooledTransaction<'executor, 'pool> {
+    executor: &'executor ExclusivePrimaryLifeExecutor<'pool>,
+    depth: u32,
+    active: bool,
+    cache: Option<Arc<dyn crate::cache::CacheProvider>>,
+    pending_invalidations: crate::cache::write_through::PendingInvalidations,
+    pending_mark: usize,
+}
+
+impl<'executor, 'pool> PooledTransaction<'executor, 'pool> {
+    fn begin(
+        executor: &'executor ExclusivePrimaryLifeExecutor<'pool>,
+        isolation_level: crate::transaction::IsolationLevel,
+        context: Option<&SessionContext>,
+        cache: Option<Arc<dyn crate::cache::CacheProvider>>,
+    ) -> Result<Self, LifeError> {
+        let begin_sql = match isolation_level {
+            crate::transaction::IsolationLevel::ReadCommitted => "BEGIN".to_string(),
+            level => format!("BEGIN ISOLATION LEVEL {}", level.to_sql()),
+        };
+        executor.execute(&begin_sql, &[])?;
+        // From here on, Drop rolls back if session injection fails.
+        let transaction = Self {
+            executor,
+            depth: 0,
+            active: true,
+            cache,
+            pending_invalidations: Default::default(),
+            pending_mark: 0,
+        };
+        if let Some(context) = context {
+            let values = session_context_values(context)?;
+            transaction
+                .executor
+                .execute_values(RLS_SET_SESSION_SQL, &values)?;
+        }
+        Ok(transaction)
+    }
+
+    /// Savepoint depth: `0` for the outer transaction.
+    #[must_use]
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Open a savepoint on the same pinned connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if this scope is finished or `SAVEPOINT` fails.
+    pub fn begin_nested(&mut self) -> Result<PooledTransaction<'executor, 'pool>, LifeError> {
+        self.savepoint()
+    }
+
+    fn savepoint(&self) -> Result<PooledTransaction<'executor, 'pool>, LifeError> {
+        if !self.active {
+            return Err(LifeError::Other("Transaction is closed".to_string()));
+        }
+        let depth = self.depth + 1;
+        self.executor
+            .execute(&format!("SAVEPOINT sp_{depth}"), &[])?;
+        Ok(PooledTransaction {
+            executor: self.executor,
+            depth,
+            active: true,
+            cache: self.cache.clone(),
+            pending_invalidations: self.pending_invalidations.clone(),
+            pending_mark: self.pending_invalidations.mark(),
+        })
+    }
+
+    /// Run `f` inside a savepoint; see [`crate::transaction::Transaction::transaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error, or a [`LifeError`] if the savepoint cannot be created or
+    /// released.
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut PooledTransaction<'executor, 'pool>) -> Result<T, E>,
+        E: From<LifeError>,
+    {
+        let nested = self.begin_nested()?;
+        crate::transaction::run_in_scope(nested, f)
+    }
+
+    /// `COMMIT` (outer) or `RELEASE SAVEPOINT` (nested).
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the statement fails.
+    pub fn commit(mut self) -> Result<(), LifeError> {
+        self.active = false;
+        let sql = if self.depth == 0 {
+            "COMMIT".to_string()
+        } else {
+            format!("RELEASE SAVEPOINT sp_{}", self.depth)
+        };
+        self.executor.execute(&sql, &[])?;
+        if self.depth == 0 {
+            self.pending_invalidations.flush(self.cache.as_deref());
+        }
+        Ok(())
+    }
+
+    /// `ROLLBACK` (outer) or `ROLLBACK TO SAVEPOINT` (nested).
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the statement fails.
+    pub fn rollback(mut self) -> Result<(), LifeError> {
+        self.active = false;
+        self.pending_invalidations.truncate(self.pending_mark);
+        self.executor.execute(&self.rollback_sql(), &[]).map(|_| ())
+    }
+
+    fn rollback_sql(&self) -> String {
+        if self.depth == 0 {
+            "ROLLBACK".to_string()
+        } else {
+            format!("ROLLBACK TO SAVEPOINT sp_{}", self.depth)
+        }
+    }
+}
+
+impl crate::transaction::TransactionScope for PooledTransaction<'_, '_> {
+    fn commit_scope(self) -> Result<(), LifeError> {
+        self.commit()
+    }
+
+    fn rollback_scope(self) -> Result<(), LifeError> {
+        self.rollback()
+    }
+}
+
+impl Drop for PooledTransaction<'_, '_> {
+    fn drop(&mut self) {
+        if self.active {
+            self.pending_invalidations.truncate(self.pending_mark);
+            if let Err(error) = self.executor.execute(&self.rollback_sql(), &[]) {
+                log::error!("lifeguard pool: transaction cleanup rollback failed: {error}");
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PooledTransaction<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledTransaction")
+            .field("slot", &self.executor.pinned.slot)
+            .field("depth", &self.depth)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+impl LifeExecutor for PooledTransaction<'_, '_> {
+    fn execute(&self, query: &str, params: &[&dyn ToSql]) -> Result<u64, LifeError> {
+        self.executor.execute(query, params)
+    }
+
+    fn query_one(&self, query: &str, params: &[&dyn ToSql]) -> Result<Row, LifeError> {
+        self.executor.query_one(query, params)
+    }
+
+    fn query_all(&self, query: &str, params: &[&dyn ToSql]) -> Result<Vec<Row>, LifeError> {
+        self.executor.query_all(query, params)
+    }
+
+    fn execute_values(&self, query: &str, values: &sea_query::Values) -> Result<u64, LifeError> {
+        self.executor.execute_values(query, values)
+    }
+
+    fn query_one_values(&self, query: &str, values: &sea_query::Values) -> Result<Row, LifeError> {
+        self.executor.query_one_values(query, values)
+    }
+
+    fn query_all_values(
+        &self,
+        query: &str,
+        values: &sea_query::Values,
+    ) -> Result<Vec<Row>, LifeError> {
+        self.executor.query_all_values(query, values)
+    }
+
+    fn cache_provider(&self) -> Option<Arc<dyn crate::cache::CacheProvider>> {
+        self.cache.clone()
+    }
+
+    fn in_transaction(&self) -> bool {
+        true
+    }
+
+    fn invalidate_cache_key(&self, key: &str) {
+        if self.cache.is_some() {
+            self.pending_invalidations.push(key);
+        }
+    }
+
+    fn atomically(
+        &self,
+        work: &mut dyn FnMut(&dyn LifeExecutor) -> Result<(), LifeError>,
+    ) -> Result<(), LifeError> {
+        crate::transaction::run_in_scope(self.savepoint()?, |sp| work(&*sp))
+    }
+}
+
 impl fmt::Debug for ExclusivePrimaryLifeExecutor<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExclusivePrimaryLifeExecutor")
-            .field("slot", &self.slot)
+            .field("slot", &self.pinned.slot)
             .finish_non_exhaustive()
     }
 }
@@ -667,25 +921,114 @@ impl LifeExecutor for ExclusivePrimaryLifeExecutor<'_> {
         ))
     }
 
+    fn execute_values(&self, query: &str, values: &sea_query::Values) -> Result<u64, LifeError> {
+        self.pinned.execute_values(query, values)
+    }
+
+    fn query_one_values(&self, query: &str, values: &sea_query::Values) -> Result<Row, LifeError> {
+        self.pinned.query_one_values(query, values)
+    }
+
+    fn query_all_values(
+        &self,
+        query: &str,
+        values: &sea_query::Values,
+    ) -> Result<Vec<Row>, LifeError> {
+        self.pinned.query_all_values(query, values)
+    }
+
+    fn in_transaction(&self) -> bool {
+        self.pinned.in_transaction()
+    }
+
+    fn check_row_locking(&self) -> Result<(), LifeError> {
+        self.pinned.check_row_locking()
+    }
+
+    /// `BEGIN` … `COMMIT` on the pinned slot, or a savepoint when a transaction was already opened
+    /// through this handle.
+    fn atomically(
+        &self,
+        work: &mut dyn FnMut(&dyn LifeExecutor) -> Result<(), LifeError>,
+    ) -> Result<(), LifeError> {
+        let scope = if self.in_transaction() {
+            // Postgres resolves a reused savepoint name to the newest one, so `sp_1` is safe even
+            // if the caller has savepoints of its own.
+            self.execute("SAVEPOINT sp_1", &[])?;
+            PooledTransaction {
+                executor: self,
+                depth: 1,
+                active: true,
+                cache: None,
+                pending_invalidations: Default::default(),
+                pending_mark: 0,
+            }
+        } else {
+            PooledTransaction::begin(
+                self,
+                crate::transaction::IsolationLevel::ReadCommitted,
+                None,
+                None,
+            )?
+        };
+        crate::transaction::run_in_scope(scope, |tx| work(&*tx))
+    }
+}
+
+impl LifeExecutor for PinnedSlot<'_> {
+    fn execute(&self, query: &str, params: &[&dyn ToSql]) -> Result<u64, LifeError> {
+        if params.is_empty() {
+            return self.execute_values(query, &sea_query::Values(Vec::new()));
+        }
+        Err(LifeError::Pool(
+            "pinned pool slot: dynamic &dyn ToSql cannot cross the pool channel; use execute_values".to_string(),
+        ))
+    }
+
+    fn query_one(&self, query: &str, params: &[&dyn ToSql]) -> Result<Row, LifeError> {
+        if params.is_empty() {
+            return self.query_one_values(query, &sea_query::Values(Vec::new()));
+        }
+        Err(LifeError::Pool(
+            "pinned pool slot: dynamic &dyn ToSql cannot cross the pool channel; use query_one_values".to_string(),
+        ))
+    }
+
+    fn query_all(&self, query: &str, params: &[&dyn ToSql]) -> Result<Vec<Row>, LifeError> {
+        if params.is_empty() {
+            return self.query_all_values(query, &sea_query::Values(Vec::new()));
+        }
+        Err(LifeError::Pool(
+            "pinned pool slot: dynamic &dyn ToSql cannot cross the pool channel; use query_all_values".to_string(),
+        ))
+    }
+
     fn execute_values(&self, query: &str, values: &sea_query::Values) -> Result<u64, LifeError> {
         let params = values_to_owned(values)?;
-        let query = query.to_string();
-        self.pool
-            .primary
+        let control = transaction_control(query);
+        let sql = query.to_string();
+        let result = self
+            .tier
             .dispatch_locked(self.slot, |reply| WorkerJob::Execute {
                 enqueued_at: Instant::now(),
-                query,
+                query: sql,
                 params,
                 reply,
                 session: None,
-            })
+            });
+        // A failed COMMIT still ends the transaction; a failed BEGIN never opened one.
+        match control {
+            Some(true) if result.is_ok() => self.transaction_open.store(true, Ordering::Relaxed),
+            Some(false) => self.transaction_open.store(false, Ordering::Relaxed),
+            _ => {}
+        }
+        result
     }
 
     fn query_one_values(&self, query: &str, values: &sea_query::Values) -> Result<Row, LifeError> {
         let params = values_to_owned(values)?;
         let query = query.to_string();
-        self.pool
-            .primary
+        self.tier
             .dispatch_locked(self.slot, |reply| WorkerJob::QueryOne {
                 enqueued_at: Instant::now(),
                 query,
@@ -702,8 +1045,7 @@ impl LifeExecutor for ExclusivePrimaryLifeExecutor<'_> {
     ) -> Result<Vec<Row>, LifeError> {
         let params = values_to_owned(values)?;
         let query = query.to_string();
-        self.pool
-            .primary
+        self.tier
             .dispatch_locked(self.slot, |reply| WorkerJob::QueryAll {
                 enqueued_at: Instant::now(),
                 query,
@@ -712,6 +1054,43 @@ impl LifeExecutor for ExclusivePrimaryLifeExecutor<'_> {
                 session: None,
             })
     }
+
+    fn in_transaction(&self) -> bool {
+        self.transaction_open.load(Ordering::Relaxed)
+    }
+
+    /// Locks need an open transaction on the primary: a replica rejects `FOR UPDATE`, and an
+    /// autocommitted statement releases its locks as soon as it returns.
+    fn check_row_locking(&self) -> Result<(), LifeError> {
+        if self.tier.metrics_tier != "primary" {
+            return Err(LifeError::Pool(format!(
+                "pinned {} slot: row-locking SELECT (FOR UPDATE / FOR SHARE) must run on the primary",
+                self.tier.metrics_tier
+            )));
+        }
+        if !self.in_transaction() {
+            return Err(LifeError::Pool(
+                "pinned pool slot: row-locking SELECT (FOR UPDATE / FOR SHARE) outside BEGIN … COMMIT releases its locks immediately; open a transaction first".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `Some(true)` for a statement that opens a transaction, `Some(false)` for one that ends it
+/// (`ROLLBACK TO SAVEPOINT` does not), `None` otherwise.
+fn transaction_control(query: &str) -> Option<bool> {
+    let mut words = query
+        .split(|c: char| c.is_whitespace() || c == ';')
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_uppercase);
+    match words.next()?.as_str() {
+        "BEGIN" => Some(true),
+        "START" => (words.next()?.as_str() == "TRANSACTION").then_some(true),
+        "COMMIT" | "END" | "ABORT" => Some(false),
+        "ROLLBACK" => (!words.any(|w| w == "TO")).then_some(false),
+        _ => None,
+    }
 }
 
 /// One reconnect attempt after a connectivity-class failure (PRD R5.2).
@@ -1159,6 +1538,7 @@ pub struct PooledLifeExecutor {
     pool: Arc<LifeguardPool>,
     read_preference: ReadPreference,
     session_context: Option<crate::executor::SessionContext>,
+    cache: Option<Arc<dyn crate::cache::CacheProvider>>,
 }
 
 impl PooledLifeExecutor {
@@ -1168,6 +1548,7 @@ impl PooledLifeExecutor {
             pool,
             read_preference: ReadPreference::default(),
             session_context: None,
+            cache: None,
         }
     }
 
@@ -1247,6 +1628,69 @@ impl PooledLifeExecutor {
         self.session_context = Some(ctx);
         self
     }
+
+    /// The attached RLS context, if any.
+    pub(crate) fn session_context(&self) -> Option<&SessionContext> {
+        self.session_context.as_ref()
+    }
+
+    /// Attach a write-through cache for [`crate::ModelManager::find_by_id`] and `LifeRecord`
+    /// writes; [`Self::transaction`] scopes defer evictions to commit.
+    #[must_use]
+    pub fn with_cache_provider(mut self, cache: Arc<dyn crate::cache::CacheProvider>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Run `f` in a transaction on one pinned primary slot: commit on `Ok`, roll back on `Err`
+    /// or panic.
+    ///
+    /// Same contract as [`crate::MayPostgresExecutor::transaction`]: serialization failures and
+    /// deadlocks re-run the closure per `retry` (the slot is released between attempts), nested
+    /// [`PooledTransaction::transaction`] calls become savepoints, and an attached
+    /// [`SessionContext`] is injected once after `BEGIN`. Reads inside the closure go to the
+    /// primary regardless of [`Self::read_preference`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use lifeguard::{IsolationLevel, LifeError, LifeExecutor, LifeguardPool, PooledLifeExecutor, RetryPolicy};
+    /// use sea_query::Values;
+    /// use std::sync::Arc;
+    ///
+    /// # fn take_pool() -> Arc<LifeguardPool> { todo!() }
+    /// let executor = PooledLifeExecutor::new(take_pool());
+    /// let moved = executor.transaction(IsolationLevel::Serializable, RetryPolicy::attempts(5), |tx| {
+    ///     tx.execute_values("UPDATE stock SET qty = qty - 1 WHERE id = 1", &Values(Vec::new()))
+    /// })?;
+    /// # Ok::<(), LifeError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error, or a [`LifeError`] converted into `E` if pinning a slot,
+    /// `BEGIN`, session injection or `COMMIT` fails.
+    pub fn transaction<T, E, F>(
+        &self,
+        isolation_level: crate::transaction::IsolationLevel,
+        retry: crate::transaction::RetryPolicy,
+        mut f: F,
+    ) -> Result<T, E>
+    where
+        F: FnMut(&mut PooledTransaction<'_, '_>) -> Result<T, E>,
+        E: From<LifeError> + crate::transaction::RetryableError,
+    {
+        retry.run(|| {
+            let exclusive = self.pool.exclusive_primary_write_executor()?;
+            let transaction = PooledTransaction::begin(
+                &exclusive,
+                isolation_level,
+                self.session_context.as_ref(),
+                self.cache.clone(),
+            )?;
+            crate::transaction::run_in_scope(transaction, &mut f)
+        })
+    }
 }
 
 impl LifeExecutor for PooledLifeExecutor {
@@ -1319,6 +1763,53 @@ impl LifeExecutor for PooledLifeExecutor {
             session,
         })
     }
+
+    /// Each call runs on whichever worker is free and commits on return, and reads may go to a
+    /// replica (where `FOR UPDATE` is rejected), so any row lock would be gone before the caller
+    /// could act on it.
+    fn check_row_locking(&self) -> Result<(), LifeError> {
+        Err(LifeError::Pool(
+            "PooledLifeExecutor: row-locking SELECT (FOR UPDATE / FOR SHARE) outside a pinned transaction releases its locks immediately and may be routed to a replica; run it inside PooledLifeExecutor::transaction".to_string(),
+        ))
+    }
+
+    fn cache_provider(&self) -> Option<Arc<dyn crate::cache::CacheProvider>> {
+        self.cache.clone()
+    }
+
+    fn atomically(
+        &self,
+        work: &mut dyn FnMut(&dyn LifeExecutor) -> Result<(), LifeError>,
+    ) -> Result<(), LifeError> {
+        self.transaction(
+            crate::transaction::IsolationLevel::ReadCommitted,
+            crate::transaction::RetryPolicy::default(),
+            |tx| work(&*tx),
+        )
+    }
+}
+
+#[cfg(test)]
+mod transaction_control_tests {
+    use super::transaction_control;
+
+    #[test]
+    fn pinned_slots_follow_transaction_control_statements() {
+        assert_eq!(transaction_control("BEGIN"), Some(true));
+        assert_eq!(
+            transaction_control("begin isolation level serializable"),
+            Some(true)
+        );
+        assert_eq!(transaction_control("BEGIN READ ONLY"), Some(true));
+        assert_eq!(transaction_control("START TRANSACTION;"), Some(true));
+        assert_eq!(transaction_control("COMMIT"), Some(false));
+        assert_eq!(transaction_control("  rollback;"), Some(false));
+        assert_eq!(transaction_control("ROLLBACK TO SAVEPOINT sp_1"), None);
+        assert_eq!(transaction_control("ROLLBACK WORK TO sp_1"), None);
+        assert_eq!(transaction_control("SAVEPOINT sp_1"), None);
+        assert_eq!(transaction_control("SELECT 1"), None);
+        assert_eq!(transaction_control(""), None);
+    }
 }
 
 #[cfg(test)]
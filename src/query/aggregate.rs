@@ -32,6 +32,8 @@ where
     R: LifeAggregate,
 {
     pub(crate) query: SelectStatement,
+    /// Builder error carried over from the [`crate::SelectQuery`], returned by [`Self::one`].
+    pub(crate) build_error: Option<String>,
     _phantom_model: PhantomData<E>,
     _phantom_return: PhantomData<R>,
 }
@@ -45,6 +47,7 @@ where
     pub fn new(query: SelectStatement) -> Self {
         Self {
             query,
+            build_error: None,
             _phantom_model: PhantomData,
             _phantom_return: PhantomData,
         }
@@ -52,6 +55,9 @@ where
 
     /// Execute the aggregate query returning a single scalar result
     pub fn one(self, executor: &dyn LifeExecutor) -> Result<R, crate::LifeError> {
+        if let Some(message) = self.build_error {
+            return Err(crate::LifeError::Other(message));
+        }
         let (sql, values) = self.query.build(PostgresQueryBuilder);
 
         // Execute resolving exactly one row via scalar execution pattern
@@ -113,6 +113,52 @@ pub trait ColumnTrait: IntoColumnRef {
         Expr::col(self).binary(sea_query::BinOper::Custom("<@"), Expr::val(value.into()))
     }
 
+    /// Check if the array column contains every element of `value` (`column @> value`)
+    ///
+    /// `value` is a `Vec<T>` (e.g. `Vec<String>` for a `TEXT[]` column), bound as one array
+    /// parameter. For range columns `value` is a [`PgRange`](crate::PgRange) and the filter keeps
+    /// rows whose range contains it; use [`ColumnTrait::contains_element`] for a single point.
+    fn contains<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        Expr::col(self).binary(sea_query::BinOper::Custom("@>"), Expr::val(value))
+    }
+
+    /// Check if every element of the array column is in `value` (`column <@ value`); for range
+    /// columns, if the column's range lies within the [`PgRange`](crate::PgRange) `value`
+    fn contained_by<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        Expr::col(self).binary(sea_query::BinOper::Custom("<@"), Expr::val(value))
+    }
+
+    /// Check if the array column shares at least one element with `value` (`column && value`);
+    /// for range columns, if the column's range overlaps the [`PgRange`](crate::PgRange) `value`
+    fn overlaps<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        Expr::col(self).binary(sea_query::BinOper::Custom("&&"), Expr::val(value))
+    }
+
+    /// Check if the range column is adjacent to the [`PgRange`](crate::PgRange) `value`
+    /// (`column -|- value`): they touch without overlapping
+    fn adjacent_to<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        Expr::col(self).binary(sea_query::BinOper::Custom("-|-"), Expr::val(value))
+    }
+
+    /// Check if the range column contains the single element `value`
+    /// (`column @> CAST($1 AS <element type>)`), e.g. a `DateTime<Utc>` against a `tstzrange`
+    ///
+    /// The cast keeps PostgreSQL from resolving the parameter as the range type itself; values
+    /// that are not a [`RangeElement`](crate::RangeElement) type are bound uncast.
+    fn contains_element<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        let value = value.into();
+        let element = match crate::value::range::element_cast_type(&value) {
+            Some(element_type) => Expr::val(value).cast_as(sea_query::Alias::new(element_type)),
+            None => Expr::val(value),
+        };
+        Expr::col(self).binary(sea_query::BinOper::Custom("@>"), element)
+    }
+
+    /// Check if any element of the array column equals the scalar `value` (`value = ANY(column)`)
+    fn any_eq<T: Into<sea_query::Value>>(self, value: T) -> Expr {
+        Expr::val(value).eq(sea_query::extension::postgres::PgFunc::any(Expr::col(self)))
+    }
+
     /// Database-side **add** for this column: `column + rhs` ([`sea_query::SimpleExpr`]).
     ///
     /// Use with `UPDATE ... SET col = col + $1` by passing the result to
@@ -435,6 +481,67 @@ mod tests {
         let _ = expr;
     }
 
+    #[test]
+    fn test_array_operators_sql() {
+        use sea_query::{PostgresQueryBuilder, Query};
+
+        let tags = vec!["a".to_string(), "b".to_string()];
+        let cases = [
+            (TestColumn::Name.contains(tags.clone()), r#""name" @> $1"#),
+            (
+                TestColumn::Name.contained_by(tags.clone()),
+                r#""name" <@ $1"#,
+            ),
+            (TestColumn::Name.overlaps(tags), r#""name" && $1"#),
+            (TestColumn::Age.any_eq(7), r#"$1 = ANY("age")"#),
+        ];
+        for (expr, expected) in cases {
+            let (sql, values) = Query::select()
+                .column(TestColumn::Id)
+                .from("t")
+                .and_where(expr)
+                .build(PostgresQueryBuilder);
+            assert!(sql.ends_with(expected), "{sql}");
+            assert_eq!(values.0.len(), 1, "the array is one bind parameter");
+        }
+    }
+
+    #[test]
+    fn test_range_operators_sql() {
+        use sea_query::{PostgresQueryBuilder, Query};
+
+        let week = crate::PgRange::closed_open(1_i64, 8);
+        let cases = [
+            (TestColumn::Age.overlaps(week.clone()), r#""age" && $1"#),
+            (TestColumn::Age.contains(week.clone()), r#""age" @> $1"#),
+            (TestColumn::Age.contained_by(week.clone()), r#""age" <@ $1"#),
+            (TestColumn::Age.adjacent_to(week), r#""age" -|- $1"#),
+            (
+                TestColumn::Age.contains_element(3_i64),
+                r#""age" @> CAST($1 AS int8)"#,
+            ),
+        ];
+        for (expr, expected) in cases {
+            let (sql, values) = Query::select()
+                .column(TestColumn::Id)
+                .from("t")
+                .and_where(expr)
+                .build(PostgresQueryBuilder);
+            assert!(sql.ends_with(expected), "{sql}");
+            assert_eq!(values.0.len(), 1);
+        }
+        let (_, values) = Query::select()
+            .column(TestColumn::Id)
+            .from("t")
+            .and_where(TestColumn::Age.overlaps(crate::PgRange::closed_open(1_i64, 8)))
+            .build(PostgresQueryBuilder);
+        assert_eq!(
+            values.0[0],
+            sea_query::Value::String(Some("[1,8)".to_string())),
+            "ranges bind as their literal"
+        );
+    }
+
     #[test]
     fn test_column_trait_def_default() {
         let def = TestColumn::Id.def();
@@ -99,6 +99,8 @@ pub struct ColumnDefinition {
     pub foreign_key: Option<String>,
     /// CHECK constraint expression (column-level)
     pub check: Option<String>,
+    /// Labels of the native `ENUM` type in `column_type` (`#[life_enum]` fields), in sort order
+    pub enum_values: Option<Vec<String>>,
 }
 
 impl ColumnDefinition {
@@ -469,6 +471,7 @@ mod tests {
         assert!(!def.auto_increment);
         assert_eq!(def.foreign_key, None);
         assert_eq!(def.check, None);
+        assert_eq!(def.enum_values, None);
     }
 
     #[test]
@@ -489,6 +492,7 @@ mod tests {
             auto_increment: false,
             foreign_key: None,
             check: None,
+            enum_values: None,
         };
 
         assert_eq!(def.column_type, Some("String".to_string()));
@@ -520,6 +524,7 @@ mod tests {
             auto_increment: true,
             foreign_key: None,
             check: None,
+            enum_values: None,
         };
 
         // Test that to_column_def compiles and works
@@ -555,6 +560,7 @@ mod tests {
             auto_increment: false,
             foreign_key: None,
             check: None,
+            enum_values: None,
         };
 
         #[allow(clippy::items_after_statements)] // Test code - struct definition after statement is acceptable
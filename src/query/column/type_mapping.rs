@@ -3,7 +3,7 @@
 //! This module provides the type mapping logic that converts string-based column type
 //! definitions (e.g., "Integer", "String") into `SeaQuery`'s `ColumnDef` types.
 
-use sea_query::ColumnDef;
+use sea_query::{Alias, ColumnDef};
 
 /// Map a column type string to `SeaQuery` `ColumnDef` type
 ///
@@ -29,6 +29,8 @@ use sea_query::ColumnDef;
 /// - "Time" → `.time()`
 /// - "Uuid" → `.uuid()`
 /// - "Binary" / "Bytes" → `.binary()`
+/// - "Inet" / "Cidr" / "Macaddr" → `.inet()` / `.cidr()` / `.mac_address()`
+/// - "Interval" / "Citext" / "Varbit" / "Bit" → the PostgreSQL type by name
 pub(crate) fn apply_column_type(col_type: &str, def: &mut ColumnDef) {
     let col_type_lower = col_type.to_lowercase();
     match col_type_lower.as_str() {
@@ -80,6 +82,18 @@ pub(crate) fn apply_column_type(col_type: &str, def: &mut ColumnDef) {
         "decimal" | "numeric" => {
             def.decimal_len(10, 2); // Default precision/scale, can be overridden
         }
+        "inet" => {
+            def.inet();
+        }
+        "cidr" => {
+            def.cidr();
+        }
+        "macaddr" | "mac_address" => {
+            def.mac_address();
+        }
+        "interval" | "citext" | "varbit" | "bit varying" | "bit" => {
+            def.custom(Alias::new(col_type_lower.to_uppercase()));
+        }
         _ => {
             // Unknown type, default to text
             def.text();
@@ -27,11 +27,31 @@
 //! - `Bytes(None)` → `null_bytes: Vec<Option<Vec<u8>>>`
 //! - `Json(None)` → `null_json_values: Vec<Option<serde_json::Value>>`
 //! - Chrono / `Uuid` variants → their existing typed-null vectors
+//! - `Array(String | Int | BigInt | Uuid, None)` → `Option<Vec<T>>` per element type
+//!
+//! ## Arrays
+//!
+//! `Value::Array` of `String`, `Int`, `BigInt` and `Uuid` elements binds as `Vec<T>` (`TEXT[]`,
+//! `INTEGER[]`, `BIGINT[]`, `UUID[]`). A NULL element is rejected rather than silently dropped.
 //!
 //! See `docs/CHRONO_AND_POSTGRES_TYPES.md` and PRD §5.2 / Iteration D4.
 
 use may_postgres::types::ToSql;
-use sea_query::Value;
+use sea_query::{ArrayType, Value};
+
+use crate::value::ValueType;
+
+/// The `Vec<T>` bind parameter of a non-NULL `Value::Array`.
+fn array_param<T, E>(value: &Value, into_err: impl Fn(String) -> E) -> Result<T, E>
+where
+    T: ValueType,
+{
+    T::from_value(value.clone()).ok_or_else(|| {
+        into_err(format!(
+            "Array parameter with NULL or mismatched elements is not supported: {value:?}"
+        ))
+    })
+}
 
 /// Two-pass conversion: collect typed storage, then `&dyn ToSql` refs for one statement.
 pub(crate) fn with_converted_value_slice<F, R, E, IE>(
@@ -85,6 +105,15 @@ where
     let mut null_chrono_datetimes_utc: Vec<Option<chrono::DateTime<chrono::Utc>>> = Vec::new();
     let mut null_chrono_datetimes_local: Vec<Option<chrono::DateTime<chrono::Local>>> = Vec::new();
 
+    let mut string_arrays: Vec<Vec<String>> = Vec::new();
+    let mut int_arrays: Vec<Vec<i32>> = Vec::new();
+    let mut big_int_arrays: Vec<Vec<i64>> = Vec::new();
+    let mut uuid_arrays: Vec<Vec<uuid::Uuid>> = Vec::new();
+    let mut null_string_arrays: Vec<Option<Vec<String>>> = Vec::new();
+    let mut null_int_arrays: Vec<Option<Vec<i32>>> = Vec::new();
+    let mut null_big_int_arrays: Vec<Option<Vec<i64>>> = Vec::new();
+    let mut null_uuid_arrays: Vec<Option<Vec<uuid::Uuid>>> = Vec::new();
+
     for value in values {
         match value {
             Value::Bool(Some(b)) => bools.push(*b),
@@ -154,6 +183,23 @@ where
             Value::Json(Some(j)) => json_values.push((**j).clone()),
             Value::Json(None) => null_json_values.push(None),
 
+            Value::Array(ArrayType::String, Some(_)) => {
+                string_arrays.push(array_param(value, &into_err)?);
+            }
+            Value::Array(ArrayType::Int, Some(_)) => {
+                int_arrays.push(array_param(value, &into_err)?)
+            }
+            Value::Array(ArrayType::BigInt, Some(_)) => {
+                big_int_arrays.push(array_param(value, &into_err)?);
+            }
+            Value::Array(ArrayType::Uuid, Some(_)) => {
+                uuid_arrays.push(array_param(value, &into_err)?);
+            }
+            Value::Array(ArrayType::String, None) => null_string_arrays.push(None),
+            Value::Array(ArrayType::Int, None) => null_int_arrays.push(None),
+            Value::Array(ArrayType::BigInt, None) => null_big_int_arrays.push(None),
+            Value::Array(ArrayType::Uuid, None) => null_uuid_arrays.push(None),
+
             _ => {
                 return Err(into_err(format!(
                     "Unsupported value type in query: {value:?}"
@@ -195,6 +241,15 @@ where
 
     let mut decimal_idx = 0;
 
+    let mut string_array_idx = 0;
+    let mut int_array_idx = 0;
+    let mut big_int_array_idx = 0;
+    let mut uuid_array_idx = 0;
+    let mut string_array_null_idx = 0;
+    let mut int_array_null_idx = 0;
+    let mut big_int_array_null_idx = 0;
+    let mut uuid_array_null_idx = 0;
+
     let mut params: Vec<&dyn ToSql> = Vec::new();
 
     // Second pass: same iteration order as above; index *_idx mirrors consumption from each bucket.
@@ -333,6 +388,39 @@ where
                 params.push(&null_uuids[uuid_null_idx] as &dyn ToSql);
                 uuid_null_idx += 1;
             }
+
+            Value::Array(ArrayType::String, Some(_)) => {
+                params.push(&string_arrays[string_array_idx] as &dyn ToSql);
+                string_array_idx += 1;
+            }
+            Value::Array(ArrayType::Int, Some(_)) => {
+                params.push(&int_arrays[int_array_idx] as &dyn ToSql);
+                int_array_idx += 1;
+            }
+            Value::Array(ArrayType::BigInt, Some(_)) => {
+                params.push(&big_int_arrays[big_int_array_idx] as &dyn ToSql);
+                big_int_array_idx += 1;
+            }
+            Value::Array(ArrayType::Uuid, Some(_)) => {
+                params.push(&uuid_arrays[uuid_array_idx] as &dyn ToSql);
+                uuid_array_idx += 1;
+            }
+            Value::Array(ArrayType::String, None) => {
+                params.push(&null_string_arrays[string_array_null_idx] as &dyn ToSql);
+                string_array_null_idx += 1;
+            }
+            Value::Array(ArrayType::Int, None) => {
+                params.push(&null_int_arrays[int_array_null_idx] as &dyn ToSql);
+                int_array_null_idx += 1;
+            }
+            Value::Array(ArrayType::BigInt, None) => {
+                params.push(&null_big_int_arrays[big_int_array_null_idx] as &dyn ToSql);
+                big_int_array_null_idx += 1;
+            }
+            Value::Array(ArrayType::Uuid, None) => {
+                params.push(&null_uuid_arrays[uuid_array_null_idx] as &dyn ToSql);
+                uuid_array_null_idx += 1;
+            }
             _ => {
                 return Err(into_err(format!(
                     "Unsupported value type in query: {value:?}"
@@ -511,6 +599,50 @@ mod tests {
         assert!(result.is_ok(), "{:?}", result.err());
     }
 
+    #[test]
+    fn arrays_bind_as_typed_postgres_arrays() {
+        use bytes::BytesMut;
+        use postgres_types::{IsNull, Type};
+
+        let values = vec![
+            Value::from(vec!["a".to_string(), "b".to_string()]),
+            Value::from(vec![1i32, 2]),
+            Value::Array(ArrayType::BigInt, None),
+            Value::from(vec![uuid::Uuid::nil()]),
+        ];
+        let result = with_converted_value_slice(
+            &values,
+            |e| e,
+            |params| {
+                assert_eq!(params.len(), 4);
+                for (param, ty) in params.iter().zip([
+                    Type::TEXT_ARRAY,
+                    Type::INT4_ARRAY,
+                    Type::INT8_ARRAY,
+                    Type::UUID_ARRAY,
+                ]) {
+                    let mut b = BytesMut::new();
+                    let bound = param.to_sql_checked(&ty, &mut b);
+                    assert!(bound.is_ok(), "{ty}: {:?}", bound.err());
+                }
+                let mut b = BytesMut::new();
+                assert!(matches!(
+                    params[2].to_sql_checked(&Type::INT8_ARRAY, &mut b),
+                    Ok(IsNull::Yes)
+                ));
+                Ok::<(), String>(())
+            },
+        );
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        let with_null_element = vec![Value::Array(
+            ArrayType::Int,
+            Some(Box::new(vec![Value::Int(Some(1)), Value::Int(None)])),
+        )];
+        let result = with_converted_value_slice(&with_null_element, |e| e, |_| Ok(()));
+        assert!(result.is_err(), "NULL array elements are rejected");
+    }
+
     #[test]
     fn string_none_bytea_none_json_none_use_typed_null_buckets() {
         use bytes::BytesMut;
@@ -56,6 +56,16 @@ pub(crate) fn is_no_rows_error(error: &LifeError) -> bool {
                 || error_msg.contains("no rows returned")
                 || error_msg.contains("expected one row")
         }
-        LifeError::Pool(_) | LifeError::PoolAcquireTimeout { .. } => false,
+        LifeError::Pool(_)
+        | LifeError::PoolAcquireTimeout { .. }
+        | LifeError::UniqueViolation(_)
+        | LifeError::ForeignKeyViolation(_)
+        | LifeError::NotNullViolation(_)
+        | LifeError::CheckViolation(_)
+        | LifeError::ExclusionViolation(_)
+        | LifeError::SerializationFailure(_)
+        | LifeError::Deadlock(_)
+        | LifeError::LockTimeout(_)
+        | LifeError::QueryCanceled(_) => false,
     }
 }
@@ -43,6 +43,7 @@ where
     where
         E::Model: FromRow,
     {
+        self.check_executable(executor)?;
         let loaders = std::mem::take(&mut self.loaders);
         let (sql, values) = self.apply_soft_delete().build(PostgresQueryBuilder);
 
@@ -90,6 +91,7 @@ where
     where
         E::Model: FromRow,
     {
+        self.check_executable(executor)?;
         let loaders = std::mem::take(&mut self.loaders);
         let (sql, values) = self.apply_soft_delete().build(PostgresQueryBuilder);
 
@@ -223,11 +225,15 @@ where
 {
     /// Execute the query and return all results as the specified Model type
     ///
+    /// Like [`SelectQuery::all`], soft-deleted rows are left out unless the query was built
+    /// with [`SelectQuery::with_trashed`].
+    ///
     /// # Errors
     ///
     /// Returns `LifeError` if the query execution or row parsing fails.
     pub fn all<Ex: LifeExecutor>(self, executor: &Ex) -> Result<Vec<M>, LifeError> {
-        let (sql, values) = self.query.query.build(PostgresQueryBuilder);
+        self.query.check_executable(executor)?;
+        let (sql, values) = self.query.apply_soft_delete().build(PostgresQueryBuilder);
 
         let rows = executor.query_all_values(&sql, &values)?;
 
@@ -303,13 +309,8 @@ where
     pub fn fetch_page(&mut self, page: usize) -> Result<Vec<E::Model>, LifeError> {
         let offset = (page.saturating_sub(1)) * self.page_size;
         // Clone the query to avoid moving it
-        let query = SelectQuery {
-            query: self.query.query.clone(),
-            with_trashed: self.query.with_trashed,
-            loaders: self.query.loaders.clone(),
-            _phantom: self.query._phantom,
-        };
-        query
+        self.query
+            .clone()
             .limit(self.page_size as u64)
             .offset(offset as u64)
             .all(self.executor)
@@ -378,13 +379,8 @@ where
     pub fn fetch_page(&mut self, page: usize) -> Result<Vec<E::Model>, LifeError> {
         let offset = (page.saturating_sub(1)) * self.page_size;
         // Clone the query to avoid moving it
-        let query = SelectQuery {
-            query: self.query.query.clone(),
-            with_trashed: self.query.with_trashed,
-            loaders: self.query.loaders.clone(),
-            _phantom: self.query._phantom,
-        };
-        query
+        self.query
+            .clone()
             .limit(self.page_size as u64)
             .offset(offset as u64)
             .all(self.executor)
@@ -0,0 +1,428 @@
+//! Single-query joined loading: [`SelectQuery::find_also_related`] and
+//! [`SelectQuery::find_with_related`].
+//!
+//! Both run the parent query with one extra `LEFT JOIN`, built from the relation's
+//! [`RelationDef`] with [`join_tbl_on_condition`]:
+//!
+//! ```sql
+//! SELECT * FROM "orders"
+//! LEFT JOIN LATERAL (
+//!     SELECT TRUE AS "__lg_related", "__lg_rt"."id" AS "__lg_r_id", "__lg_rt"."name" AS "__lg_r_name"
+//!     FROM "customers" AS "__lg_rt"
+//!     WHERE orders.customer_id = __lg_rt.id
+//!     LIMIT 1
+//! ) AS "__lg_rel" ON TRUE
+//! WHERE …
+//! ```
+//!
+//! The related columns are only visible under their `__lg_r_` aliases, so they cannot clash with
+//! the parent's: unqualified filters and ordering on the parent query keep resolving against the
+//! parent table, and the parent model is read with [`FromRow`] as usual. The related model is read
+//! with [`FromRowPrefixed`]; a NULL `__lg_related` marker means the parent had no match.
+//! `has_many_through` relations join the junction table inside the same subquery. To-one
+//! relations (`belongs_to` / `has_one`) cap the subquery at `LIMIT 1`, so a parent is never
+//! repeated when the data holds more than one match.
+//!
+//! Compared with [`SelectQuery::load`], which runs one batched query per relation after the main
+//! query, this is a single round trip. `find_with_related` repeats the parent columns once per
+//! child, and `limit` / `offset` count joined rows rather than parents. Soft-deleted related rows
+//! are left out.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use sea_query::{
+    Alias, DynIden, Expr, ExprTrait, IdenStatic, JoinType, PostgresQueryBuilder, SelectStatement,
+    TableRef,
+};
+
+use crate::executor::{LifeError, LifeExecutor};
+use crate::model::ModelTrait;
+use crate::query::select::SelectQuery;
+use crate::query::traits::{FromRow, FromRowPrefixed, LifeModelTrait};
+use crate::relation::def::condition::join_tbl_on_condition;
+use crate::relation::def::{RelationDef, RelationType};
+use crate::relation::identity::Identity;
+use crate::relation::traits::Related;
+
+/// Alias prefix of the related columns in the joined row.
+const RELATED_PREFIX: &str = "__lg_r_";
+/// `TRUE` when the joined row carries a related row, NULL otherwise.
+const RELATED_MARKER: &str = "__lg_related";
+/// Related table inside the lateral subquery.
+const RELATED_ALIAS: &str = "__lg_rt";
+/// Junction table inside the lateral subquery (`has_many_through`).
+const JUNCTION_ALIAS: &str = "__lg_jt";
+/// The lateral subquery itself.
+const LATERAL_ALIAS: &str = "__lg_rel";
+
+/// Query returned by [`SelectQuery::find_also_related`]
+///
+/// Runs with [`all`](Self::all): one `(parent, Option<related>)` pair per parent row.
+#[must_use]
+pub struct SelectAlsoRelated<E, R>
+where
+    E: LifeModelTrait,
+    R: LifeModelTrait,
+{
+    query: SelectQuery<E>,
+    _related: PhantomData<R>,
+}
+
+/// Query returned by [`SelectQuery::find_with_related`]
+///
+/// Runs with [`all`](Self::all): one `(parent, Vec<related>)` pair per parent, grouped by the
+/// parent's primary key.
+#[must_use]
+pub struct SelectWithRelated<E, R>
+where
+    E: LifeModelTrait,
+    R: LifeModelTrait,
+{
+    query: SelectQuery<E>,
+    _related: PhantomData<R>,
+}
+
+impl<E> SelectQuery<E>
+where
+    E: LifeModelTrait,
+{
+    /// Load each row together with its to-one related `R` (`belongs_to` / `has_one`) in the same
+    /// query, e.g. `Order::find().find_also_related(Customer).all(executor)` for
+    /// `Vec<(OrderModel, Option<CustomerModel>)>`.
+    ///
+    /// Filters, ordering, pagination and loaders chained on this query before the call apply to
+    /// the parent rows. See [`crate::query::joined`] for the SQL.
+    pub fn find_also_related<R>(self, _related: R) -> SelectAlsoRelated<E, R>
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        SelectAlsoRelated {
+            query: self,
+            _related: PhantomData,
+        }
+    }
+
+    /// Load each row together with all its related `R` (any relation, including
+    /// `has_many_through`) in the same query, e.g.
+    /// `User::find().find_with_related(Post).all(executor)` for `Vec<(UserModel, Vec<PostModel>)>`.
+    ///
+    /// Joined rows are grouped by the parent's primary key, in the order parents first appear.
+    /// `limit` / `offset` on this query count joined rows; paginate parents with
+    /// [`load`](Self::load) instead.
+    pub fn find_with_related<R>(self, _related: R) -> SelectWithRelated<E, R>
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        SelectWithRelated {
+            query: self,
+            _related: PhantomData,
+        }
+    }
+}
+
+impl<E, R> SelectAlsoRelated<E, R>
+where
+    E: LifeModelTrait + Related<R>,
+    R: LifeModelTrait,
+{
+    /// Execute the query and return every parent with its related row, `None` when it has none
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::Other`] if the relation is not `belongs_to` / `has_one`, and
+    /// `LifeError` if the query execution or row parsing fails.
+    pub fn all<Ex: LifeExecutor>(
+        self,
+        executor: &Ex,
+    ) -> Result<Vec<(E::Model, Option<R::Model>)>, LifeError>
+    where
+        E::Model: FromRow,
+        R::Model: FromRowPrefixed,
+    {
+        let def = E::to();
+        if !matches!(def.rel_type, RelationType::BelongsTo | RelationType::HasOne) {
+            return Err(LifeError::Other(format!(
+                "find_also_related needs a belongs_to or has_one relation, got {:?}; use find_with_related",
+                def.rel_type
+            )));
+        }
+
+        let mut query = self.query;
+        query.check_executable(executor)?;
+        let loaders = std::mem::take(&mut query.loaders);
+        let (sql, values) = joined_statement::<E, R>(query, &def)?.build(PostgresQueryBuilder);
+        let rows = executor.query_all_values(&sql, &values)?;
+
+        let mut parents = Vec::with_capacity(rows.len());
+        let mut related = Vec::with_capacity(rows.len());
+        for row in &rows {
+            parents.push(parse_parent::<E>(row)?);
+            related.push(parse_related::<R>(row)?);
+        }
+
+        for loader in &loaders {
+            loader.execute(&mut parents, executor)?;
+        }
+
+        Ok(parents.into_iter().zip(related).collect())
+    }
+}
+
+impl<E, R> SelectWithRelated<E, R>
+where
+    E: LifeModelTrait + Related<R>,
+    R: LifeModelTrait,
+{
+    /// Execute the query and return every parent with its related rows
+    ///
+    /// A parent without related rows comes back with an empty `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the relation keys are inconsistent, or if the query execution or
+    /// row parsing fails.
+    pub fn all<Ex: LifeExecutor>(
+        self,
+        executor: &Ex,
+    ) -> Result<Vec<(E::Model, Vec<R::Model>)>, LifeError>
+    where
+        E::Model: FromRow + ModelTrait,
+        R::Model: FromRowPrefixed,
+    {
+        let def = E::to();
+        let mut query = self.query;
+        query.check_executable(executor)?;
+        let loaders = std::mem::take(&mut query.loaders);
+        let (sql, values) = joined_statement::<E, R>(query, &def)?.build(PostgresQueryBuilder);
+        let rows = executor.query_all_values(&sql, &values)?;
+
+        let mut parents: Vec<E::Model> = Vec::new();
+        let mut children: Vec<Vec<R::Model>> = Vec::new();
+        let mut slots: HashMap<String, usize> = HashMap::new();
+        for row in &rows {
+            let parent = parse_parent::<E>(row)?;
+            let key = format!("{:?}", parent.get_primary_key_values());
+            let slot = match slots.entry(key) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    parents.push(parent);
+                    children.push(Vec::new());
+                    *entry.insert(parents.len() - 1)
+                }
+            };
+            if let (Some(child), Some(group)) = (parse_related::<R>(row)?, children.get_mut(slot)) {
+                group.push(child);
+            }
+        }
+
+        for loader in &loaders {
+            loader.execute(&mut parents, executor)?;
+        }
+
+        Ok(parents.into_iter().zip(children).collect())
+    }
+}
+
+/// The parent query (soft delete applied) with the related rows joined in.
+fn joined_statement<E, R>(
+    query: SelectQuery<E>,
+    def: &RelationDef,
+) -> Result<SelectStatement, LifeError>
+where
+    E: LifeModelTrait,
+    R: LifeModelTrait,
+{
+    let columns: Vec<&'static str> = R::all_columns().iter().map(IdenStatic::as_str).collect();
+    let soft_delete = R::soft_delete_column().map(|column| column.as_str());
+    let lateral = lateral_subquery(def, &columns, soft_delete)?;
+
+    let mut stmt = query.apply_soft_delete();
+    // `SELECT *` already covers the lateral columns; explicit `select_as` columns do not.
+    if SelectQuery::<E>::has_select_as() {
+        stmt.column((Alias::new(LATERAL_ALIAS), sea_query::Asterisk));
+    }
+    stmt.join_lateral(
+        JoinType::LeftJoin,
+        lateral,
+        Alias::new(LATERAL_ALIAS),
+        Expr::cust("TRUE"),
+    );
+    Ok(stmt)
+}
+
+/// `SELECT TRUE AS marker, <related columns AS prefixed> FROM related WHERE <parent keys match>`,
+/// correlated with the parent table through `def.from_tbl`.
+fn lateral_subquery(
+    def: &RelationDef,
+    columns: &[&'static str],
+    soft_delete: Option<&'static str>,
+) -> Result<SelectStatement, LifeError> {
+    let related: TableRef = DynIden::from(RELATED_ALIAS).into();
+
+    let mut sub = SelectStatement::default();
+    sub.expr_as(Expr::cust("TRUE"), Alias::new(RELATED_MARKER));
+    for column in columns {
+        sub.expr_as(
+            Expr::col((Alias::new(RELATED_ALIAS), Alias::new(*column))),
+            Alias::new(format!("{RELATED_PREFIX}{column}")),
+        );
+    }
+    sub.from_as(def.to_tbl.clone(), Alias::new(RELATED_ALIAS));
+
+    if def.rel_type == RelationType::HasManyThrough {
+        let (Some(through), Some(through_from), Some(through_to)) =
+            (&def.through_tbl, &def.through_from_col, &def.through_to_col)
+        else {
+            return Err(LifeError::Other(
+                "has_many_through relation is missing its through table or columns".to_string(),
+            ));
+        };
+        let junction: TableRef = DynIden::from(JUNCTION_ALIAS).into();
+        check_arity(through_to, &def.to_col)?;
+        check_arity(&def.from_col, through_from)?;
+        sub.join_as(
+            JoinType::InnerJoin,
+            through.clone(),
+            Alias::new(JUNCTION_ALIAS),
+            join_tbl_on_condition(&junction, &related, through_to, &def.to_col),
+        );
+        sub.cond_where(join_tbl_on_condition(
+            &def.from_tbl,
+            &junction,
+            &def.from_col,
+            through_from,
+        ));
+    } else {
+        check_arity(&def.from_col, &def.to_col)?;
+        sub.cond_where(join_tbl_on_condition(
+            &def.from_tbl,
+            &related,
+            &def.from_col,
+            &def.to_col,
+        ));
+    }
+
+    if let Some(column) = soft_delete {
+        sub.and_where(Expr::col((Alias::new(RELATED_ALIAS), Alias::new(column))).is_null());
+    }
+    if matches!(def.rel_type, RelationType::BelongsTo | RelationType::HasOne) {
+        sub.limit(1);
+    }
+    Ok(sub)
+}
+
+/// Reject key column lists [`join_tbl_on_condition`] cannot pair up.
+pub(crate) fn check_arity(from: &Identity, to: &Identity) -> Result<(), LifeError> {
+    if from.arity() == to.arity() {
+        return Ok(());
+    }
+    Err(LifeError::Other(format!(
+        "Relation key arity mismatch: {} column(s) joined to {}",
+        from.arity(),
+        to.arity()
+    )))
+}
+
+fn parse_parent<E>(row: &may_postgres::Row) -> Result<E::Model, LifeError>
+where
+    E: LifeModelTrait,
+    E::Model: FromRow,
+{
+    <E::Model as FromRow>::from_row(row)
+        .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))
+}
+
+fn parse_related<R>(row: &may_postgres::Row) -> Result<Option<R::Model>, LifeError>
+where
+    R: LifeModelTrait,
+    R::Model: FromRowPrefixed,
+{
+    let present = row
+        .try_get::<&str, Option<bool>>(RELATED_MARKER)
+        .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))?;
+    if present != Some(true) {
+        return Ok(None);
+    }
+    <R::Model as FromRowPrefixed>::from_row_prefixed(row, RELATED_PREFIX)
+        .map(Some)
+        .map_err(|e| LifeError::ParseError(format!("Failed to parse related row: {e}")))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::relation::def::fixtures;
+
+    fn relation(rel_type: RelationType) -> RelationDef {
+        fixtures::relation(rel_type, ("orders", "customer_id"), ("customers", "id"))
+    }
+
+    #[test]
+    fn lateral_subquery_aliases_related_columns() {
+        let sub = lateral_subquery(
+            &relation(RelationType::BelongsTo),
+            &["id", "name"],
+            Some("deleted_at"),
+        )
+        .expect("belongs_to subquery");
+        let sql = sub.to_string(PostgresQueryBuilder);
+        assert!(
+            sql.starts_with(
+                r#"SELECT TRUE AS "__lg_related", "__lg_rt"."id" AS "__lg_r_id", "__lg_rt"."name" AS "__lg_r_name" FROM "customers" AS "__lg_rt" WHERE"#
+            ),
+            "{sql}"
+        );
+        assert!(sql.contains("orders.customer_id = __lg_rt.id"), "{sql}");
+        assert!(sql.contains(r#""__lg_rt"."deleted_at" IS NULL"#), "{sql}");
+    }
+
+    #[test]
+    fn lateral_subquery_limits_to_one_relations_to_a_single_row() {
+        for rel_type in [RelationType::BelongsTo, RelationType::HasOne] {
+            let sql = lateral_subquery(&relation(rel_type), &["id"], None)
+                .expect("to-one subquery")
+                .to_string(PostgresQueryBuilder);
+            assert!(
+                sql.starts_with(
+                    r#"SELECT TRUE AS "__lg_related", "__lg_rt"."id" AS "__lg_r_id" FROM "customers" AS "__lg_rt" WHERE"#
+                ),
+                "{sql}"
+            );
+            assert!(sql.ends_with("__lg_rt.id LIMIT 1"), "{sql}");
+        }
+        let sql = lateral_subquery(&relation(RelationType::HasMany), &["id"], None)
+            .expect("has_many subquery")
+            .to_string(PostgresQueryBuilder);
+        assert!(!sql.contains("LIMIT"), "{sql}");
+    }
+
+    #[test]
+    fn lateral_subquery_joins_the_junction_table() {
+        let def = fixtures::has_many_through(
+            ("posts", "id"),
+            ("tags", "id"),
+            ("post_tags", "post_id", "tag_id"),
+        );
+
+        let sql = lateral_subquery(&def, &["id"], None)
+            .expect("through subquery")
+            .to_string(PostgresQueryBuilder);
+        assert!(
+            sql.contains(r#"INNER JOIN "post_tags" AS "__lg_jt" ON __lg_jt.tag_id = __lg_rt.id"#),
+            "{sql}"
+        );
+        assert!(sql.contains("WHERE posts.id = __lg_jt.post_id"), "{sql}");
+    }
+
+    #[test]
+    fn lateral_subquery_rejects_incomplete_through_relation() {
+        let err = lateral_subquery(&relation(RelationType::HasManyThrough), &["id"], None)
+            .expect_err("no through table");
+        assert!(matches!(err, LifeError::Other(_)));
+    }
+}
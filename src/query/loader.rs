@@ -1,11 +1,25 @@
 //! `DataLoader` architecture for resolving N+1 queries.
+//!
+//! [`SelectQuery::load`](crate::query::SelectQuery::load) takes a related entity or a nested
+//! [`LoadPath`]; each relation level is fetched with one batched `IN (...)` query (composite keys
+//! as `OR`ed tuples, `has_many_through` via the junction table) and injected with
+//! [`RelationInjector`].
+//!
+//! [`LoadThen::top`] limits a relation to its first N rows per parent ("the latest 3 comments of
+//! each post"): the batched query numbers the rows with `ROW_NUMBER() OVER (PARTITION BY <foreign
+//! key> ORDER BY …)` and keeps those numbered up to N.
 use crate::executor::LifeError;
 use crate::executor::LifeExecutor;
 use crate::model::ModelTrait;
 use crate::query::traits::{FromRow, LifeModelTrait};
+use crate::relation::def::RelationType;
 use crate::relation::identity::Identity;
-use sea_query::{Condition, Expr, ExprTrait, PostgresQueryBuilder};
+use sea_query::{
+    Alias, Condition, DynIden, Expr, ExprTrait, IntoIden, Order, PostgresQueryBuilder,
+    SelectStatement, WindowStatement,
+};
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 /// Trait to inject loaded relationships back into the parent model's nested fields.
 /// This is typically auto-generated by the `lifeguard-derive` macro for any fields annotated
@@ -23,6 +37,9 @@ pub trait LoaderExecutor<E: LifeModelTrait> {
 }
 
 /// Generic Relation Loader for batch-fetching N+1 data arrays map back onto models
+///
+/// Loaders registered under it (see [`LoadPath`]) run on the fetched `R` models before they are
+/// injected, so every level of a nested load is one batched query.
 pub struct RelationLoader<E, R>
 where
     E: LifeModelTrait + crate::Related<R> + 'static,
@@ -30,10 +47,22 @@ where
     E::Model: RelationInjector<R> + ModelTrait,
     R::Model: crate::query::traits::FromRow + ModelTrait + Clone,
 {
+    nested: Vec<Rc<dyn LoaderExecutor<R>>>,
+    top: Option<Top>,
     _e: std::marker::PhantomData<E>,
-    _r: std::marker::PhantomData<R>,
 }
 
+/// Per-parent row limit of a [`LoadTop`] load.
+struct Top {
+    limit: u64,
+    order: Vec<(DynIden, Order)>,
+}
+
+/// Row number of a child within its parent's partition (top-N loads).
+const ROW_NUMBER_ALIAS: &str = "__lg_rn";
+/// The numbered children, filtered by [`ROW_NUMBER_ALIAS`].
+const TOP_ALIAS: &str = "__lg_top";
+
 impl<E, R> Default for RelationLoader<E, R>
 where
     E: LifeModelTrait + crate::Related<R> + 'static,
@@ -55,43 +84,199 @@ where
 {
     #[must_use]
     pub fn new() -> Self {
+        Self::with_nested(Vec::new())
+    }
+
+    /// Loader that also runs `nested` on the loaded `R` models before injecting them.
+    #[must_use]
+    pub fn with_nested(nested: Vec<Rc<dyn LoaderExecutor<R>>>) -> Self {
         Self {
+            nested,
+            top: None,
             _e: std::marker::PhantomData,
-            _r: std::marker::PhantomData,
         }
     }
 }
 
-/// `true` if the value is suitable for a non-null foreign-key side in batch loading.
+/// Something [`SelectQuery::load`](crate::query::SelectQuery::load) can eager-load under `E`.
+///
+/// Implemented for every related entity (`.load(post::Entity)`, one hop) and for [`LoadPath`]
+/// (`.load(post::Entity.then(comment::Entity))`, several hops).
+pub trait EagerLoad<E: LifeModelTrait> {
+    /// The loader that resolves this spec for a batch of `E` models.
+    fn into_loader(self) -> Rc<dyn LoaderExecutor<E>>;
+}
+
+impl<E, R> EagerLoad<E> for R
+where
+    E: LifeModelTrait + crate::Related<R> + 'static,
+    R: LifeModelTrait + 'static,
+    E::Model: RelationInjector<R> + ModelTrait,
+    R::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+{
+    fn into_loader(self) -> Rc<dyn LoaderExecutor<E>> {
+        Rc::new(RelationLoader::<E, R>::new())
+    }
+}
+
+type WrapNested<R, L> =
+    Box<dyn FnOnce(Vec<Rc<dyn LoaderExecutor<L>>>) -> Vec<Rc<dyn LoaderExecutor<R>>>>;
+
+/// A nested eager-load spec: relation `R` of the queried entity, then further relations below
+/// it, ending at the current leaf `L`.
+///
+/// Built with [`LoadThen`] on an entity value:
+///
+/// ```ignore
+/// // Users -> Posts -> Comments -> Author: one batched query per level
+/// let users = user::Entity::find()
+///     .load(post::Entity.then(comment::Entity).then(author::Entity))
+///     .all(executor)?;
+///
+/// // Posts with both their comments (and each comment's author) and their tags
+/// let users = user::Entity::find()
+///     .load(post::Entity.with(comment::Entity.then(author::Entity)).with(tag::Entity))
+///     .all(executor)?;
+/// ```
+pub struct LoadPath<R: LifeModelTrait, L: LifeModelTrait> {
+    /// Loaders registered under the current leaf `L`.
+    leaf: Vec<Rc<dyn LoaderExecutor<L>>>,
+    /// Wraps the leaf loaders into the chain of loaders from `R` down to `L`.
+    wrap: WrapNested<R, L>,
+}
+
+impl<R: LifeModelTrait + 'static> LoadPath<R, R> {
+    /// A path that loads `relation` only; extend it with [`then`](Self::then) / [`with`](Self::with).
+    #[must_use]
+    pub fn new(_relation: R) -> Self {
+        Self {
+            leaf: Vec::new(),
+            wrap: Box::new(|leaf| leaf),
+        }
+    }
+}
+
+impl<R: LifeModelTrait + 'static, L: LifeModelTrait + 'static> LoadPath<R, L> {
+    /// Load `next` under the current leaf and make it the new leaf (`Post` → `Comment`).
+    #[must_use]
+    pub fn then<N>(self, _next: N) -> LoadPath<R, N>
+    where
+        L: crate::Related<N>,
+        N: LifeModelTrait + 'static,
+        L::Model: RelationInjector<N> + ModelTrait,
+        N::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+    {
+        let Self { mut leaf, wrap } = self;
+        LoadPath {
+            leaf: Vec::new(),
+            wrap: Box::new(move |next_leaf| {
+                leaf.push(Rc::new(RelationLoader::<L, N>::with_nested(next_leaf)));
+                wrap(leaf)
+            }),
+        }
+    }
+
+    /// Load `spec` under the current leaf, keeping the leaf (siblings such as comments and tags
+    /// of the same posts).
+    #[must_use]
+    pub fn with<S: EagerLoad<L>>(mut self, spec: S) -> Self {
+        self.leaf.push(spec.into_loader());
+        self
+    }
+}
+
+impl<E, R, L> EagerLoad<E> for LoadPath<R, L>
+where
+    E: LifeModelTrait + crate::Related<R> + 'static,
+    R: LifeModelTrait + 'static,
+    L: LifeModelTrait + 'static,
+    E::Model: RelationInjector<R> + ModelTrait,
+    R::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+{
+    fn into_loader(self) -> Rc<dyn LoaderExecutor<E>> {
+        Rc::new(RelationLoader::<E, R>::with_nested((self.wrap)(self.leaf)))
+    }
+}
+
+/// Start a [`LoadPath`] from an entity value: `post::Entity.then(comment::Entity)`.
+pub trait LoadThen: LifeModelTrait + Sized + 'static {
+    /// [`LoadPath::new`] then [`LoadPath::then`].
+    #[must_use]
+    fn then<N>(self, next: N) -> LoadPath<Self, N>
+    where
+        Self: crate::Related<N>,
+        N: LifeModelTrait + 'static,
+        Self::Model: RelationInjector<N> + ModelTrait,
+        N::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+    {
+        LoadPath::new(self).then(next)
+    }
+
+    /// [`LoadPath::new`] then [`LoadPath::with`].
+    #[must_use]
+    fn with<S: EagerLoad<Self>>(self, spec: S) -> LoadPath<Self, Self> {
+        LoadPath::new(self).with(spec)
+    }
+
+    /// [`LoadTop::new`]: at most `limit` rows of this relation per parent.
+    #[must_use]
+    fn top(self, limit: u64) -> LoadTop<Self> {
+        LoadTop::new(self, limit)
+    }
+}
+
+impl<T: LifeModelTrait + 'static> LoadThen for T {}
+
+/// An eager-load spec that keeps at most `limit` related rows per parent, built with
+/// [`LoadThen::top`]:
 ///
-/// Lists every `Variant(None)` for the `sea-query` features enabled on this crate. If lifeguard
-/// enables additional `sea-query` features (e.g. `with-uuid`, `postgres-array`), extend this
-/// match so `None` inner values are not treated as present.
-fn relation_side_value_present(v: &sea_query::Value) -> bool {
-    !matches!(
-        v,
-        sea_query::Value::Bool(None)
-            | sea_query::Value::TinyInt(None)
-            | sea_query::Value::SmallInt(None)
-            | sea_query::Value::Int(None)
-            | sea_query::Value::BigInt(None)
-            | sea_query::Value::TinyUnsigned(None)
-            | sea_query::Value::SmallUnsigned(None)
-            | sea_query::Value::Unsigned(None)
-            | sea_query::Value::BigUnsigned(None)
-            | sea_query::Value::Float(None)
-            | sea_query::Value::Double(None)
-            | sea_query::Value::String(None)
-            | sea_query::Value::Char(None)
-            | sea_query::Value::Bytes(None)
-            | sea_query::Value::Json(None)
-            | sea_query::Value::ChronoDate(None)
-            | sea_query::Value::ChronoTime(None)
-            | sea_query::Value::ChronoDateTime(None)
-            | sea_query::Value::ChronoDateTimeUtc(None)
-            | sea_query::Value::ChronoDateTimeLocal(None)
-            | sea_query::Value::ChronoDateTimeWithTimeZone(None)
-    )
+/// ```ignore
+/// // Each post with its 3 latest comments
+/// let posts = post::Entity::find()
+///     .load(comment::Entity.top(3).order_by(comment::Column::CreatedAt, Order::Desc))
+///     .all(executor)?;
+/// ```
+///
+/// Rows are injected in `order_by` order; without an `order_by` which N rows are kept is up to
+/// the database. Not supported for `has_many_through` relations.
+pub struct LoadTop<R: LifeModelTrait> {
+    top: Top,
+    _r: std::marker::PhantomData<R>,
+}
+
+impl<R: LifeModelTrait> LoadTop<R> {
+    /// Keep at most `limit` rows of `relation` per parent.
+    #[must_use]
+    pub fn new(_relation: R, limit: u64) -> Self {
+        Self {
+            top: Top {
+                limit,
+                order: Vec::new(),
+            },
+            _r: std::marker::PhantomData,
+        }
+    }
+
+    /// Rank each parent's rows by `column` (repeat for tie-breakers).
+    #[must_use]
+    pub fn order_by<C: IntoIden>(mut self, column: C, order: Order) -> Self {
+        self.top.order.push((column.into_iden(), order));
+        self
+    }
+}
+
+impl<E, R> EagerLoad<E> for LoadTop<R>
+where
+    E: LifeModelTrait + crate::Related<R> + 'static,
+    R: LifeModelTrait + 'static,
+    E::Model: RelationInjector<R> + ModelTrait,
+    R::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+{
+    fn into_loader(self) -> Rc<dyn LoaderExecutor<E>> {
+        let mut loader = RelationLoader::<E, R>::new();
+        loader.top = Some(self.top);
+        Rc::new(loader)
+    }
 }
 
 fn identity_column_names(id: &Identity) -> Result<Vec<String>, LifeError> {
@@ -104,10 +289,22 @@ fn identity_column_names(id: &Identity) -> Result<Vec<String>, LifeError> {
     Ok(id.iter().map(ToString::to_string).collect())
 }
 
+/// Grouping key for a key tuple. Integer variants render as the bare number so an `INTEGER`
+/// key matches a `BIGINT` foreign key (and junction columns read back from rows).
 fn fk_tuple_map_key(values: &[sea_query::Value]) -> String {
     values
         .iter()
-        .map(|v| format!("{v:?}"))
+        .map(|v| match v {
+            sea_query::Value::TinyInt(Some(n)) => n.to_string(),
+            sea_query::Value::SmallInt(Some(n)) => n.to_string(),
+            sea_query::Value::Int(Some(n)) => n.to_string(),
+            sea_query::Value::BigInt(Some(n)) => n.to_string(),
+            sea_query::Value::TinyUnsigned(Some(n)) => n.to_string(),
+            sea_query::Value::SmallUnsigned(Some(n)) => n.to_string(),
+            sea_query::Value::Unsigned(Some(n)) => n.to_string(),
+            sea_query::Value::BigUnsigned(Some(n)) => n.to_string(),
+            other => format!("{other:?}"),
+        })
         .collect::<Vec<_>>()
         .join("\x1f")
 }
@@ -117,7 +314,7 @@ fn extract_fk_tuple(model: &impl ModelTrait, cols: &[String]) -> Option<Vec<sea_
     let mut out = Vec::with_capacity(cols.len());
     for c in cols {
         let v = model.get_by_column_name(c)?;
-        if !relation_side_value_present(&v) {
+        if crate::value::types::is_null(&v) {
             return None;
         }
         out.push(v);
@@ -125,6 +322,205 @@ fn extract_fk_tuple(model: &impl ModelTrait, cols: &[String]) -> Option<Vec<sea_
     Some(out)
 }
 
+/// `col IN (...)` for single-column keys, `(c1 = .. AND c2 = ..) OR ...` for composite keys.
+fn key_tuples_condition(cols: &[String], tuples: &[Vec<sea_query::Value>]) -> Condition {
+    if let [col] = cols {
+        let flat: Vec<sea_query::Value> = tuples.iter().map(|t| t[0].clone()).collect();
+        return Condition::all().add(Expr::col(sea_query::Alias::new(col)).is_in(flat));
+    }
+    let mut or_cond = Condition::any();
+    for tup in tuples {
+        let mut and_cond = Condition::all();
+        for (col_name, val) in cols.iter().zip(tup.iter()) {
+            and_cond = and_cond.add(Expr::col(sea_query::Alias::new(col_name)).eq(val.clone()));
+        }
+        or_cond = or_cond.add(and_cond);
+    }
+    or_cond
+}
+
+/// Key column `idx` of a junction-table row. Key columns are integers, text or UUIDs; any other
+/// type (or NULL) yields `None` and the row is skipped.
+fn junction_key_value(row: &may_postgres::Row, idx: usize) -> Option<sea_query::Value> {
+    if let Ok(Some(v)) = row.try_get::<usize, Option<i32>>(idx) {
+        Some(sea_query::Value::Int(Some(v)))
+    } else if let Ok(Some(v)) = row.try_get::<usize, Option<i64>>(idx) {
+        Some(sea_query::Value::BigInt(Some(v)))
+    } else if let Ok(Some(v)) = row.try_get::<usize, Option<i16>>(idx) {
+        Some(sea_query::Value::SmallInt(Some(v)))
+    } else if let Ok(Some(v)) = row.try_get::<usize, Option<String>>(idx) {
+        Some(sea_query::Value::String(Some(v)))
+    } else if let Ok(Some(v)) = row.try_get::<usize, Option<uuid::Uuid>>(idx) {
+        Some(sea_query::Value::Uuid(Some(v)))
+    } else {
+        None
+    }
+}
+
+/// `SELECT * FROM (<query>, ROW_NUMBER() OVER (PARTITION BY <partition> ORDER BY …) AS __lg_rn)
+/// AS __lg_top WHERE __lg_rn <= limit ORDER BY __lg_rn`.
+fn top_n_statement<R: LifeModelTrait>(
+    query: crate::query::SelectQuery<R>,
+    top: &Top,
+    partition: &[String],
+) -> SelectStatement {
+    let mut window = WindowStatement::new();
+    for col in partition {
+        window.add_partition_by(Expr::col(Alias::new(col)));
+    }
+    for (col, order) in &top.order {
+        window.order_by(col.clone(), order.clone());
+    }
+    let numbered = query
+        .expr_window_as(
+            Expr::cust("ROW_NUMBER()"),
+            window,
+            Alias::new(ROW_NUMBER_ALIAS),
+        )
+        .apply_soft_delete();
+
+    let mut outer = SelectStatement::default();
+    outer
+        .column(sea_query::Asterisk)
+        .from_subquery(numbered, Alias::new(TOP_ALIAS))
+        .and_where(Expr::col(Alias::new(ROW_NUMBER_ALIAS)).lte(top.limit))
+        .order_by(Alias::new(ROW_NUMBER_ALIAS), Order::Asc);
+    outer
+}
+
+impl<E, R> RelationLoader<E, R>
+where
+    E: LifeModelTrait + crate::Related<R> + 'static,
+    R: LifeModelTrait + 'static,
+    E::Model: RelationInjector<R> + ModelTrait,
+    R::Model: crate::query::traits::FromRow + ModelTrait + Clone,
+{
+    /// Fetch `R` rows matching `condition`, then run the nested loaders on them.
+    ///
+    /// For a top-N load, only the first `limit` rows of each `partition` key are fetched, in
+    /// rank order.
+    fn fetch_children(
+        &self,
+        condition: Condition,
+        partition: &[String],
+        exec: &dyn LifeExecutor,
+    ) -> Result<Vec<R::Model>, LifeError> {
+        let query = R::find().filter(condition);
+        let (sql, values) = match &self.top {
+            None => query.apply_soft_delete(),
+            Some(top) => top_n_statement(query, top, partition),
+        }
+        .build(PostgresQueryBuilder);
+        let rows = exec.query_all_values(&sql, &values)?;
+        let mut children = Vec::with_capacity(rows.len());
+        for row in rows {
+            let model = <R::Model as FromRow>::from_row(&row)
+                .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))?;
+            children.push(model);
+        }
+        for loader in &self.nested {
+            loader.execute(&mut children, exec)?;
+        }
+        Ok(children)
+    }
+
+    /// `has_many_through`: one query on the junction table for the (parent, target) key pairs,
+    /// one for the distinct targets. A target linked to several parents is loaded once and
+    /// injected into each.
+    fn execute_through(
+        &self,
+        models: &mut [E::Model],
+        exec: &dyn LifeExecutor,
+        rel_def: &crate::relation::RelationDef,
+        parent_tuples: &[Vec<sea_query::Value>],
+        from_cols: &[String],
+        to_cols: &[String],
+    ) -> Result<(), LifeError> {
+        let missing = |what: &str| {
+            LifeError::Other(format!(
+                "HasManyThrough relationship must have {what} set (DataLoader)"
+            ))
+        };
+        let through_tbl = rel_def
+            .through_tbl
+            .as_ref()
+            .ok_or_else(|| missing("through_tbl"))?;
+        let through_from = identity_column_names(
+            rel_def
+                .through_from_col
+                .as_ref()
+                .ok_or_else(|| missing("through_from_col"))?,
+        )?;
+        let through_to = identity_column_names(
+            rel_def
+                .through_to_col
+                .as_ref()
+                .ok_or_else(|| missing("through_to_col"))?,
+        )?;
+        if through_from.len() != from_cols.len() || through_to.len() != to_cols.len() {
+            return Err(LifeError::Other(
+                "HasManyThrough through columns do not match the relation key arity (DataLoader)"
+                    .to_string(),
+            ));
+        }
+
+        let mut junction = sea_query::Query::select();
+        for col in through_from.iter().chain(through_to.iter()) {
+            junction.column(sea_query::Alias::new(col));
+        }
+        junction
+            .from(through_tbl.clone())
+            .cond_where(key_tuples_condition(&through_from, parent_tuples));
+        let (sql, values) = junction.build(PostgresQueryBuilder);
+        let rows = exec.query_all_values(&sql, &values)?;
+
+        let mut targets_by_parent: HashMap<String, Vec<String>> = HashMap::new();
+        let mut target_tuples: Vec<Vec<sea_query::Value>> = Vec::new();
+        let mut seen_targets: HashSet<String> = HashSet::new();
+        'rows: for row in &rows {
+            let mut keys = Vec::with_capacity(through_from.len() + through_to.len());
+            for idx in 0..through_from.len() + through_to.len() {
+                let Some(v) = junction_key_value(row, idx) else {
+                    continue 'rows;
+                };
+                keys.push(v);
+            }
+            let target = keys.split_off(through_from.len());
+            let target_key = fk_tuple_map_key(&target);
+            targets_by_parent
+                .entry(fk_tuple_map_key(&keys))
+                .or_default()
+                .push(target_key.clone());
+            if seen_targets.insert(target_key) {
+                target_tuples.push(target);
+            }
+        }
+
+        let mut children_by_key: HashMap<String, R::Model> = HashMap::new();
+        if !target_tuples.is_empty() {
+            let condition = key_tuples_condition(to_cols, &target_tuples);
+            for child in self.fetch_children(condition, to_cols, exec)? {
+                if let Some(t) = extract_fk_tuple(&child, to_cols) {
+                    children_by_key.insert(fk_tuple_map_key(&t), child);
+                }
+            }
+        }
+
+        for model in models.iter_mut() {
+            let items = extract_fk_tuple(model, from_cols)
+                .and_then(|t| targets_by_parent.get(&fk_tuple_map_key(&t)))
+                .map(|keys| {
+                    keys.iter()
+                        .filter_map(|k| children_by_key.get(k).cloned())
+                        .collect()
+                })
+                .unwrap_or_default();
+            model.inject(items);
+        }
+        Ok(())
+    }
+}
+
 impl<E, R> LoaderExecutor<E> for RelationLoader<E, R>
 where
     E: LifeModelTrait + crate::Related<R> + 'static,
@@ -140,14 +536,20 @@ where
         let rel_def = E::to();
         let from_cols = identity_column_names(&rel_def.from_col)?;
         let to_cols = identity_column_names(&rel_def.to_col)?;
-        if from_cols.len() != to_cols.len() {
+        let is_through = rel_def.rel_type == RelationType::HasManyThrough;
+        if is_through && self.top.is_some() {
+            return Err(LifeError::Other(
+                "Top-N loading is not supported for has_many_through relations (DataLoader)"
+                    .to_string(),
+            ));
+        }
+        if !is_through && from_cols.len() != to_cols.len() {
             return Err(LifeError::Other(format!(
                 "Relation from_col arity {} does not match to_col arity {} (DataLoader)",
                 from_cols.len(),
                 to_cols.len()
             )));
         }
-        let arity = from_cols.len();
 
         let mut unique_tuples: Vec<Vec<sea_query::Value>> = Vec::new();
         let mut seen_keys: HashSet<String> = HashSet::new();
@@ -168,37 +570,22 @@ where
             return Ok(());
         }
 
-        let mut query = R::find();
-
-        if arity == 1 {
-            let col = &to_cols[0];
-            let flat: Vec<sea_query::Value> = unique_tuples.iter().map(|t| t[0].clone()).collect();
-            query = query.filter(Expr::col(sea_query::Alias::new(col)).is_in(flat));
-        } else {
-            let mut or_cond = Condition::any();
-            for tup in &unique_tuples {
-                let mut and_cond = Condition::all();
-                for (col_name, val) in to_cols.iter().zip(tup.iter()) {
-                    and_cond =
-                        and_cond.add(Expr::col(sea_query::Alias::new(col_name)).eq(val.clone()));
-                }
-                or_cond = or_cond.add(and_cond);
-            }
-            query = query.filter(or_cond);
+        if is_through {
+            return self.execute_through(
+                models,
+                exec,
+                &rel_def,
+                &unique_tuples,
+                &from_cols,
+                &to_cols,
+            );
         }
 
-        let loaders = std::mem::take(&mut query.loaders);
-        let (sql, values) = query.apply_soft_delete().build(PostgresQueryBuilder);
-        let rows = exec.query_all_values(&sql, &values)?;
-        let mut children = Vec::new();
-        for row in rows {
-            let model = <R::Model as FromRow>::from_row(&row)
-                .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))?;
-            children.push(model);
-        }
-        for loader in &loaders {
-            loader.execute(&mut children, exec)?;
-        }
+        let children = self.fetch_children(
+            key_tuples_condition(&to_cols, &unique_tuples),
+            &to_cols,
+            exec,
+        )?;
 
         let mut grouped_children: HashMap<String, Vec<R::Model>> = HashMap::new();
         for child in children {
@@ -229,30 +616,6 @@ where
 mod tests {
     use super::*;
 
-    #[test]
-    fn relation_side_value_present_filters_null_variants() {
-        assert!(!relation_side_value_present(&sea_query::Value::Int(None)));
-        assert!(!relation_side_value_present(&sea_query::Value::String(
-            None
-        )));
-        assert!(!relation_side_value_present(&sea_query::Value::BigInt(
-            None
-        )));
-        assert!(!relation_side_value_present(&sea_query::Value::Float(None)));
-        assert!(!relation_side_value_present(&sea_query::Value::Double(
-            None
-        )));
-        assert!(!relation_side_value_present(&sea_query::Value::Bool(None)));
-        assert!(!relation_side_value_present(&sea_query::Value::Json(None)));
-        assert!(!relation_side_value_present(
-            &sea_query::Value::ChronoDateTimeUtc(None)
-        ));
-        assert!(relation_side_value_present(&sea_query::Value::Int(Some(1))));
-        assert!(relation_side_value_present(&sea_query::Value::String(
-            Some("x".into())
-        )));
-    }
-
     #[test]
     fn fk_tuple_map_key_distinct() {
         let a = vec![
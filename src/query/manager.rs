@@ -85,30 +85,63 @@
 //!
 //! ```no_run
 //! use lifeguard::query::manager::ModelManager;
-//! use lifeguard::{LifeModelTrait, LifeExecutor, LifeError};
+//! use lifeguard::{LifeError, MayPostgresExecutor};
+//! use lifeguard_derive::LifeModel;
 //!
-//! # struct User;
-//! # struct UserModel { id: i32, email: String };
-//! # impl lifeguard::LifeModelTrait for User {
-//! #     type Model = UserModel;
-//! #     type Column = ();
-//! # }
+//! #[derive(LifeModel)]
+//! #[table_name = "users"]
+//! pub struct User {
+//!     #[primary_key]
+//!     pub id: i32,
+//!     pub email: String,
+//! }
 //!
-//! impl ModelManager for User {
-//!     type Model = UserModel;
+//! // The default methods work off the entity's `#[primary_key]` columns.
+//! impl ModelManager for Entity {}
 //!
-//!     fn find_by_id(executor: &dyn LifeExecutor, id: i64) -> Result<Option<Self::Model>, LifeError> {
-//!         Self::find()
-//!             .filter(sea_query::Expr::col("id").eq(id))
-//!             .find_one(executor)
-//!     }
-//! }
+//! # fn main() -> Result<(), LifeError> {
+//! # let executor: MayPostgresExecutor = todo!();
+//! let user = Entity::find_by_id(&executor, 42)?;
+//! # Ok(())
+//! # }
 //! ```
 
 use crate::executor::{LifeError, LifeExecutor};
+use crate::query::column::column_trait::{ColumnDefHelper, ColumnTrait};
+use crate::query::primary_key::{EntityPrimaryKey, IntoPrimaryKeyValues, PrimaryKeyValue};
 use crate::query::traits::{FromRow, LifeModelTrait};
 use may_postgres::types::ToSql;
 
+/// `pk1 = $1 AND pk2 = $2 ...` over `E`'s `#[primary_key]` columns, in declaration order.
+///
+/// # Errors
+///
+/// Returns [`LifeError::Other`] if `E` declares no primary key columns or `pk_values` has a
+/// different length.
+pub(crate) fn primary_key_condition<E: LifeModelTrait>(
+    pk_values: &[sea_query::Value],
+) -> Result<sea_query::Condition, LifeError> {
+    let columns: Vec<E::Column> = E::all_columns()
+        .iter()
+        .copied()
+        .filter(|c| c.column_def().primary_key)
+        .collect();
+    if columns.is_empty() || columns.len() != pk_values.len() {
+        return Err(LifeError::Other(format!(
+            "{}: primary key has {} column(s), got {} value(s)",
+            E::default().table_name(),
+            columns.len(),
+            pk_values.len()
+        )));
+    }
+    Ok(columns
+        .into_iter()
+        .zip(pk_values.iter().cloned())
+        .fold(sea_query::Condition::all(), |cond, (column, value)| {
+            cond.add(column.eq(value))
+        }))
+}
+
 /// Trait for Model Manager patterns.
 ///
 /// This trait provides common query patterns that entities can implement.
@@ -118,101 +151,108 @@ use may_postgres::types::ToSql;
 ///
 /// ```no_run
 /// use lifeguard::query::manager::ModelManager;
-/// use lifeguard::{LifeModelTrait, LifeExecutor, LifeError};
+/// use lifeguard::{ColumnTrait, LifeError, MayPostgresExecutor};
+/// use lifeguard_derive::LifeModel;
 ///
-/// # struct User;
-/// # struct UserModel { id: i32, email: String };
-/// # impl lifeguard::LifeModelTrait for User {
-/// #     type Model = UserModel;
-/// #     type Column = ();
-/// # }
+/// #[derive(LifeModel)]
+/// #[table_name = "users"]
+/// pub struct User {
+///     #[primary_key]
+///     pub id: i32,
+///     pub is_active: bool,
+/// }
 ///
-/// impl ModelManager for User {
-///     type Model = UserModel;
+/// impl ModelManager for Entity {}
 ///
-///     fn find_by_id(executor: &dyn LifeExecutor, id: i64) -> Result<Option<Self::Model>, LifeError> {
-///         Self::find()
-///             .filter(sea_query::Expr::col("id").eq(id))
-///             .find_one(executor)
-///     }
-/// }
+/// # fn main() -> Result<(), LifeError> {
+/// # let executor: MayPostgresExecutor = todo!();
+/// let active = Entity::count_where(&executor, Column::IsActive.eq(true))?;
+/// # Ok(())
+/// # }
 /// ```
 pub trait ModelManager: LifeModelTrait
 where
     <Self as LifeModelTrait>::Model: FromRow,
 {
-    /// Find a model by its primary key ID.
-    ///
-    /// This is a convenience method that assumes the primary key column is named "id".
-    /// Implement this method for your specific primary key type.
+    /// Find a model by its primary key.
     ///
-    /// # Arguments
+    /// `id` is the entity's [`PrimaryKeyValue`]: the key column's type (`i32`, `i64`, `String`,
+    /// `Uuid`, ...) or a tuple in column order for composite keys. The filter is built from the
+    /// `#[primary_key]` columns, so it works whatever the key columns are named.
     ///
-    /// * `executor` - The database executor
-    /// * `id` - The primary key value
+    /// When the executor has a [`crate::cache::CacheProvider`], hits are served from
+    /// [`crate::cache::cache_key`] and misses are written back with the entity's
+    /// `#[cache_ttl]`. Inside a transaction the cache is bypassed.
     ///
-    /// # Returns
+    /// # Example
     ///
-    /// Returns `Ok(Some(model))` if found, `Ok(None)` if not found, or an error.
+    /// ```no_run
+    /// use lifeguard::query::manager::ModelManager;
+    /// use lifeguard::{LifeError, MayPostgresExecutor};
+    /// use lifeguard_derive::LifeModel;
+    ///
+    /// #[derive(LifeModel)]
+    /// #[table_name = "translations"]
+    /// pub struct Translation {
+    ///     #[primary_key]
+    ///     pub post_id: i64,
+    ///     #[primary_key]
+    ///     pub locale: String,
+    ///     pub body: String,
+    /// }
     ///
-    /// # Example Implementation
+    /// impl ModelManager for Entity {}
     ///
-    /// ```no_run
-    /// # use lifeguard::query::manager::ModelManager;
-    /// # use lifeguard::{LifeModelTrait, LifeExecutor, LifeError};
-    /// # struct User;
-    /// # struct UserModel { id: i32 };
-    /// # impl lifeguard::LifeModelTrait for User {
-    /// #     type Model = UserModel;
-    /// #     type Column = ();
+    /// # fn main() -> Result<(), LifeError> {
+    /// # let executor: MayPostgresExecutor = todo!();
+    /// // Composite key: a tuple in `#[primary_key]` declaration order.
+    /// let row = Entity::find_by_id(&executor, (42, "en".to_string()))?;
+    /// # Ok(())
     /// # }
-    /// impl ModelManager for User {
-    ///     type Model = UserModel;
-    ///     
-    ///     fn find_by_id<Ex: LifeExecutor>(executor: &Ex, id: i64) -> Result<Option<Self::Model>, LifeError> {
-    ///         Self::find()
-    ///             .filter(sea_query::Expr::col("id").eq(id as i32))
-    ///             .find_one(executor)
-    ///     }
-    /// }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns `LifeError` if the query execution or row parsing fails.
+    /// Returns `LifeError` if the entity has no primary key columns, `id` does not match their
+    /// number, or the query execution or row parsing fails.
     fn find_by_id<Ex: LifeExecutor>(
         executor: &Ex,
-        id: i64,
+        id: PrimaryKeyValue<Self>,
     ) -> Result<Option<<Self as LifeModelTrait>::Model>, LifeError>
     where
+        Self: EntityPrimaryKey,
+        PrimaryKeyValue<Self>: IntoPrimaryKeyValues,
         <Self as LifeModelTrait>::Model: serde::Serialize + serde::de::DeserializeOwned,
     {
-        use sea_query::ExprTrait;
-
+        let pk_values = id.into_primary_key_values();
+        let condition = primary_key_condition::<Self>(&pk_values)?;
         let table_name = Self::default().table_name();
-        let cache_key = format!("lifeguard:model:{table_name}:{id}");
+        let ttl = Self::cache_ttl_seconds();
+
+        // Inside a transaction the cache may predate this transaction's own writes (evictions are
+        // deferred to commit), and rows read here may never commit: bypass it both ways.
+        let cache = executor
+            .cache_provider()
+            .filter(|_| ttl > 0 && !executor.in_transaction())
+            .zip(crate::cache::cache_key(table_name, &pk_values));
 
         // 1. Transparent Cache Verification
-        if let Some(cache) = executor.cache_provider() {
-            if let Ok(crate::cache::CachedResult::Hit(json_str)) = cache.get(&cache_key) {
+        if let Some((ref cache, ref cache_key)) = cache {
+            if let Ok(crate::cache::CachedResult::Hit(json_str)) = cache.get(cache_key) {
                 if let Ok(model) = serde_json::from_str(&json_str) {
                     return Ok(Some(model));
                 }
             }
         }
 
-        // 2. Fallback to Primary DB Layer (using hardcoded "id" column)
-        let model = Self::find()
-            .filter(
-                sea_query::Expr::col(sea_query::Alias::new("id")).eq(sea_query::Expr::value(id)),
-            )
-            .find_one(executor)?;
+        // 2. Fallback to Primary DB Layer
+        let model = Self::find().filter(condition).find_one(executor)?;
 
         // 3. Transparent Cache Write-Through
         if let Some(ref m) = model {
-            if let Some(cache) = executor.cache_provider() {
+            if let Some((cache, cache_key)) = cache {
                 if let Ok(json_str) = serde_json::to_string(m) {
-                    let _ = cache.set(&cache_key, &json_str, Some(3600)); // Default TTL 1 hour
+                    let _ = cache.set(&cache_key, &json_str, Some(ttl));
                 }
             }
         }
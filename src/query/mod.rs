@@ -16,8 +16,12 @@
 //! The query module follows `Sea-ORM`'s organizational patterns:
 //! - **Traits**: Core entity and model traits (`LifeModelTrait`, `LifeEntityName`)
 //! - **Select**: SELECT query builder (`SelectQuery`)
+//! - **Related filters**: Correlated `EXISTS` on related rows ([`SelectQuery::has_related`], [`SelectQuery::doesnt_have_related`])
+//! - **Relation aggregates**: Correlated `COUNT` / `SUM` / … columns ([`SelectQuery::with_count`], [`SelectQuery::with_aggregate`])
+//! - **Joined**: Parent + related rows from one `LEFT JOIN` ([`SelectQuery::find_also_related`], [`SelectQuery::find_with_related`])
 //! - **Scopes**: Named composable predicates (`scope` module, `SelectQuery::scope`, `IntoScope`)
 //! - **SQL extras on `SelectQuery`**: [`SelectQuery::with_cte`](select::SelectQuery::with_cte) (CTE + lifeguard `all`/`one`), [`join_subquery`](select::SelectQuery::join_subquery), typed [`window`](select::SelectQuery::window) / [`expr_window_as`](select::SelectQuery::expr_window_as) (see also [`subquery_column`](select::SelectQuery::subquery_column), [`window_function_cust`](select::SelectQuery::window_function_cust))
+//! - **Mutation**: Set-based `UPDATE` / `DELETE` builders (`UpdateMany`, `DeleteMany`), soft-delete aware
 //! - **Execution**: Query execution methods (`all`, `one`, `first`)
 //! - **Value Conversion**: `SeaQuery` `Value` to `ToSql` (`converted_params` + `value_conversion`)
 //! - **Error Handling**: Error detection and classification utilities
@@ -82,6 +86,11 @@ pub mod select;
 #[doc(inline)]
 pub use select::{SelectModel, SelectQuery};
 
+// Set-based UPDATE / DELETE builders
+pub mod mutation;
+#[doc(inline)]
+pub use mutation::{DeleteMany, UpdateMany};
+
 // Named scopes (composable predicates; see `scope` module)
 pub mod scope;
 #[doc(inline)]
@@ -90,6 +99,19 @@ pub use scope::IntoScope;
 // Dataloader N+1 resolution
 pub mod loader;
 
+// Relation-based `EXISTS` filters (`has_related` / `doesnt_have_related`)
+pub mod related_filter;
+
+// Relation aggregates as result columns (`with_count` / `with_aggregate`)
+pub mod relation_aggregate;
+#[doc(inline)]
+pub use relation_aggregate::{RelationAggregate, RelationAggregates};
+
+// Single-query joined loading (`find_also_related` / `find_with_related`)
+pub mod joined;
+#[doc(inline)]
+pub use joined::{SelectAlsoRelated, SelectWithRelated};
+
 // Aggregation endpoints
 pub mod aggregate;
 #[doc(inline)]
@@ -110,14 +132,18 @@ pub mod table;
 #[doc(inline)]
 pub use table::{
     format_index_key_list_derive_value, format_index_key_list_sql,
-    index_definition_to_derive_index_value, index_key_parts_coverage_columns, IndexBtreeNulls,
-    IndexBtreeSort, IndexDefinition, IndexKeyPart, NotifyDefinition, TableDefinition,
+    index_definition_to_derive_index_value, index_key_parts_coverage_columns, ExclusionDefinition,
+    IndexBtreeNulls, IndexBtreeSort, IndexDefinition, IndexKeyPart, NotifyDefinition,
+    TableDefinition,
 };
 
 // Primary key operations
 pub mod primary_key;
 #[doc(inline)]
-pub use primary_key::{PrimaryKeyArity, PrimaryKeyArityTrait, PrimaryKeyToColumn, PrimaryKeyTrait};
+pub use primary_key::{
+    EntityPrimaryKey, IntoPrimaryKeyValues, PrimaryKeyArity, PrimaryKeyArityTrait,
+    PrimaryKeyToColumn, PrimaryKeyTrait, PrimaryKeyValue,
+};
 
 // Model Manager pattern for custom query methods
 pub mod manager;
@@ -125,4 +151,4 @@ pub mod manager;
 pub use manager::{ModelManager, StoredProcedure};
 
 // FromRow trait is in traits module
-pub use traits::{from_row_unsigned_try_from_failed, FromRow};
+pub use traits::{from_row_unsigned_try_from_failed, FromRow, FromRowPrefixed};
@@ -0,0 +1,471 @@
+//! Set-based `UPDATE` / `DELETE` builders for `LifeModel` entities.
+//!
+//! [`ActiveModelTrait::update`](crate::ActiveModelTrait::update) and
+//! [`ActiveModelTrait::delete`](crate::ActiveModelTrait::delete) write one record by
+//! primary key. The builders here compile to a **single** statement over every row that
+//! matches their filters:
+//!
+//! ```ignore
+//! // UPDATE "jobs" SET "status" = $1, "retries" = "retries" + $2 WHERE "status" = $3
+//! let n = Job::update_many()
+//!     .set(Column::Status, "queued")
+//!     .set(Column::Retries, Column::Retries.f_add(1))
+//!     .filter(Column::Status.eq("failed"))
+//!     .exec(executor)?;
+//! ```
+//!
+//! # Soft delete
+//!
+//! For `#[soft_delete]` entities both builders follow [`LifeModelTrait::find`]:
+//! [`UpdateMany`] skips rows whose `deleted_at` is set unless [`UpdateMany::with_trashed`] is
+//! called, and [`DeleteMany`] becomes `UPDATE ... SET deleted_at = now()` over live rows.
+//! [`DeleteMany::force`] issues a real `DELETE` that also removes already-trashed rows.
+//!
+//! When the entity has `#[auto_timestamp]`, both the update and the soft delete also set
+//! `updated_at = now()` unless the caller assigned it explicitly. Timestamps are taken on
+//! the server, so one statement stamps every row with the same instant.
+//!
+//! Hooks, validators, and the per-record cache write-through do **not** run: the rows are
+//! never loaded into the process.
+
+use crate::executor::{LifeError, LifeExecutor};
+use crate::query::column::column_trait::ColumnTrait;
+use crate::query::traits::{FromRow, LifeModelTrait};
+use sea_query::{
+    Condition, DeleteStatement, Expr, IdenStatic, PostgresQueryBuilder, Query, UpdateStatement,
+    Values,
+};
+use std::marker::PhantomData;
+
+/// SQL evaluated by the server for soft-delete and `updated_at` stamps.
+const NOW: &str = "now()";
+
+fn table_ref<E: LifeModelTrait>() -> sea_query::TableName {
+    use sea_query::{IntoIden, SchemaName, TableName};
+
+    let entity = E::default();
+    match entity.schema_name() {
+        Some(schema) => TableName(
+            Some(SchemaName::from(schema)),
+            entity.table_name().into_iden(),
+        ),
+        None => TableName(None, entity.table_name().into_iden()),
+    }
+}
+
+fn parse_returned<E>(rows: Vec<may_postgres::Row>) -> Result<Vec<E::Model>, LifeError>
+where
+    E: LifeModelTrait,
+    E::Model: FromRow,
+{
+    rows.iter()
+        .map(|row| {
+            <E::Model as FromRow>::from_row(row)
+                .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))
+        })
+        .collect()
+}
+
+/// Set-based `UPDATE` builder, returned by [`LifeModelTrait::update_many`].
+pub struct UpdateMany<E>
+where
+    E: LifeModelTrait,
+{
+    assignments: Vec<(E::Column, Expr)>,
+    condition: Condition,
+    with_trashed: bool,
+    _phantom: PhantomData<E>,
+}
+
+impl<E> Default for UpdateMany<E>
+where
+    E: LifeModelTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> UpdateMany<E>
+where
+    E: LifeModelTrait,
+{
+    /// Create an update over every row of `E` (narrow it with [`UpdateMany::filter`]).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            assignments: Vec::new(),
+            condition: Condition::all(),
+            with_trashed: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Add `SET column = value`.
+    ///
+    /// `value` may be a literal or any expression, including the F-style helpers on
+    /// [`ColumnTrait`] such as [`ColumnTrait::f_add`].
+    #[must_use]
+    pub fn set<V>(mut self, column: E::Column, value: V) -> Self
+    where
+        V: Into<Expr>,
+    {
+        self.assignments.push((column, value.into()));
+        self
+    }
+
+    /// Add a `WHERE` condition (`AND`-ed with earlier ones).
+    #[must_use]
+    pub fn filter<F>(mut self, condition: F) -> Self
+    where
+        F: sea_query::IntoCondition,
+    {
+        self.condition = self.condition.add(condition.into_condition());
+        self
+    }
+
+    /// Also update soft-deleted rows.
+    #[must_use]
+    pub fn with_trashed(mut self) -> Self {
+        self.with_trashed = true;
+        self
+    }
+
+    fn statement(&self) -> Result<UpdateStatement, LifeError> {
+        if self.assignments.is_empty() {
+            return Err(LifeError::Other(
+                "update_many: no columns were set".to_string(),
+            ));
+        }
+
+        let mut query = Query::update();
+        query.table(table_ref::<E>());
+        for (column, value) in &self.assignments {
+            query.value(*column, value.clone());
+        }
+        if let Some(updated_at) = E::updated_at_column() {
+            let assigned = self
+                .assignments
+                .iter()
+                .any(|(column, _)| column.as_str() == updated_at.as_str());
+            if !assigned {
+                query.value(updated_at, Expr::cust(NOW));
+            }
+        }
+
+        let mut condition = self.condition.clone();
+        if !self.with_trashed {
+            if let Some(deleted_at) = E::soft_delete_column() {
+                condition = condition.add(deleted_at.is_null());
+            }
+        }
+        query.cond_where(condition);
+        Ok(query)
+    }
+
+    /// Build the SQL and bind values without executing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::Other`] if no column was [`set`](UpdateMany::set).
+    pub fn build(&self) -> Result<(String, Values), LifeError> {
+        Ok(self.statement()?.build(PostgresQueryBuilder))
+    }
+
+    /// Execute and return the number of rows updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if no column was set or the statement fails.
+    pub fn exec<Ex: LifeExecutor>(self, executor: &Ex) -> Result<u64, LifeError> {
+        let (sql, values) = self.build()?;
+        executor.execute_values(&sql, &values)
+    }
+
+    /// Execute with `RETURNING *` and return the updated models.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if no column was set, the statement fails, or a row cannot be
+    /// parsed.
+    pub fn exec_with_returning<Ex: LifeExecutor>(
+        self,
+        executor: &Ex,
+    ) -> Result<Vec<E::Model>, LifeError>
+    where
+        E::Model: FromRow,
+    {
+        let mut query = self.statement()?;
+        query.returning_all();
+        let (sql, values) = query.build(PostgresQueryBuilder);
+        parse_returned::<E>(executor.query_all_values(&sql, &values)?)
+    }
+}
+
+/// Set-based `DELETE` builder, returned by [`LifeModelTrait::delete_many`].
+///
+/// On `#[soft_delete]` entities this is a soft delete unless [`DeleteMany::force`] is set;
+/// see the [module docs](crate::query::mutation#soft-delete).
+pub struct DeleteMany<E>
+where
+    E: LifeModelTrait,
+{
+    condition: Condition,
+    force: bool,
+    _phantom: PhantomData<E>,
+}
+
+impl<E> Default for DeleteMany<E>
+where
+    E: LifeModelTrait,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The two statement shapes a [`DeleteMany`] can compile to.
+enum DeleteStatementKind {
+    Soft(UpdateStatement),
+    Hard(DeleteStatement),
+}
+
+impl<E> DeleteMany<E>
+where
+    E: LifeModelTrait,
+{
+    /// Create a delete over every row of `E` (narrow it with [`DeleteMany::filter`]).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            condition: Condition::all(),
+            force: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Add a `WHERE` condition (`AND`-ed with earlier ones).
+    #[must_use]
+    pub fn filter<F>(mut self, condition: F) -> Self
+    where
+        F: sea_query::IntoCondition,
+    {
+        self.condition = self.condition.add(condition.into_condition());
+        self
+    }
+
+    /// Issue a real `DELETE` even for `#[soft_delete]` entities.
+    ///
+    /// Matching rows are removed whether or not they were already soft-deleted.
+    #[must_use]
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    fn statement(&self) -> DeleteStatementKind {
+        match E::soft_delete_column() {
+            Some(deleted_at) if !self.force => {
+                let mut query = Query::update();
+                query.table(table_ref::<E>());
+                query.value(deleted_at, Expr::cust(NOW));
+                if let Some(updated_at) = E::updated_at_column() {
+                    query.value(updated_at, Expr::cust(NOW));
+                }
+                // Already-trashed rows keep their original deletion time.
+                query.cond_where(self.condition.clone().add(deleted_at.is_null()));
+                DeleteStatementKind::Soft(query)
+            }
+            _ => {
+                let mut query = Query::delete();
+                query.from_table(table_ref::<E>());
+                query.cond_where(self.condition.clone());
+                DeleteStatementKind::Hard(query)
+            }
+        }
+    }
+
+    /// Build the SQL and bind values without executing.
+    #[must_use]
+    pub fn build(&self) -> (String, Values) {
+        match self.statement() {
+            DeleteStatementKind::Soft(query) => query.build(PostgresQueryBuilder),
+            DeleteStatementKind::Hard(query) => query.build(PostgresQueryBuilder),
+        }
+    }
+
+    /// Execute and return the number of rows deleted (or soft-deleted).
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the statement fails.
+    pub fn exec<Ex: LifeExecutor>(self, executor: &Ex) -> Result<u64, LifeError> {
+        let (sql, values) = self.build();
+        executor.execute_values(&sql, &values)
+    }
+
+    /// Execute with `RETURNING *` and return the deleted (or soft-deleted) models.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the statement fails or a row cannot be parsed.
+    pub fn exec_with_returning<Ex: LifeExecutor>(
+        self,
+        executor: &Ex,
+    ) -> Result<Vec<E::Model>, LifeError>
+    where
+        E::Model: FromRow,
+    {
+        let (sql, values) = match self.statement() {
+            DeleteStatementKind::Soft(mut query) => {
+                query.returning_all();
+                query.build(PostgresQueryBuilder)
+            }
+            DeleteStatementKind::Hard(mut query) => {
+                query.returning_all();
+                query.build(PostgresQueryBuilder)
+            }
+        };
+        parse_returned::<E>(executor.query_all_values(&sql, &values)?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::LifeEntityName;
+    use sea_query::Iden;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    enum JobColumn {
+        Id,
+        Status,
+        Retries,
+        DeletedAt,
+        UpdatedAt,
+    }
+
+    impl Iden for JobColumn {
+        fn unquoted(&self) -> &'static str {
+            self.as_str()
+        }
+    }
+
+    impl IdenStatic for JobColumn {
+        fn as_str(&self) -> &'static str {
+            match self {
+                JobColumn::Id => "id",
+                JobColumn::Status => "status",
+                JobColumn::Retries => "retries",
+                JobColumn::DeletedAt => "deleted_at",
+                JobColumn::UpdatedAt => "updated_at",
+            }
+        }
+    }
+
+    crate::impl_column_def_helper_for_test!(JobColumn);
+
+    #[derive(Copy, Clone, Default)]
+    struct Job;
+
+    impl LifeEntityName for Job {
+        fn table_name(&self) -> &'static str {
+            "jobs"
+        }
+    }
+
+    impl LifeModelTrait for Job {
+        type Model = ();
+        type Column = JobColumn;
+    }
+
+    #[derive(Copy, Clone, Default)]
+    struct SoftJob;
+
+    impl LifeEntityName for SoftJob {
+        fn table_name(&self) -> &'static str {
+            "soft_jobs"
+        }
+    }
+
+    impl LifeModelTrait for SoftJob {
+        type Model = ();
+        type Column = JobColumn;
+
+        fn soft_delete_column() -> Option<JobColumn> {
+            Some(JobColumn::DeletedAt)
+        }
+
+        fn updated_at_column() -> Option<JobColumn> {
+            Some(JobColumn::UpdatedAt)
+        }
+    }
+
+    #[test]
+    fn update_many_compiles_to_one_statement() {
+        let (sql, values) = UpdateMany::<Job>::new()
+            .set(JobColumn::Status, "queued")
+            .set(JobColumn::Retries, JobColumn::Retries.f_add(1i32))
+            .filter(JobColumn::Status.eq("failed"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "jobs" SET "status" = $1, "retries" = "retries" + $2 WHERE "status" = $3"#
+        );
+        assert_eq!(values.0.len(), 3);
+    }
+
+    #[test]
+    fn update_many_without_assignments_is_an_error() {
+        assert!(UpdateMany::<Job>::new().build().is_err());
+    }
+
+    #[test]
+    fn update_many_skips_trashed_and_bumps_updated_at() {
+        let (sql, _) = UpdateMany::<SoftJob>::new()
+            .set(JobColumn::Status, "queued")
+            .build()
+            .unwrap();
+        assert_eq!(
+            sql,
+            r#"UPDATE "soft_jobs" SET "status" = $1, "updated_at" = now() WHERE "deleted_at" IS NULL"#
+        );
+
+        let (sql, _) = UpdateMany::<SoftJob>::new()
+            .set(JobColumn::UpdatedAt, Expr::cust("'epoch'"))
+            .with_trashed()
+            .build()
+            .unwrap();
+        assert_eq!(sql, r#"UPDATE "soft_jobs" SET "updated_at" = 'epoch'"#);
+    }
+
+    #[test]
+    fn delete_many_is_a_plain_delete_without_soft_delete() {
+        let (sql, _) = DeleteMany::<Job>::new()
+            .filter(JobColumn::Id.gt(10i32))
+            .build();
+        assert_eq!(sql, r#"DELETE FROM "jobs" WHERE "id" > $1"#);
+    }
+
+    #[test]
+    fn delete_many_soft_deletes_live_rows() {
+        let (sql, _) = DeleteMany::<SoftJob>::new()
+            .filter(JobColumn::Status.eq("done"))
+            .build();
+        assert_eq!(
+            sql,
+            r#"UPDATE "soft_jobs" SET "deleted_at" = now(), "updated_at" = now() WHERE "status" = $1 AND "deleted_at" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn delete_many_force_hard_deletes() {
+        let (sql, _) = DeleteMany::<SoftJob>::new()
+            .filter(JobColumn::Status.eq("done"))
+            .force()
+            .build();
+        assert_eq!(sql, r#"DELETE FROM "soft_jobs" WHERE "status" = $1"#);
+    }
+}
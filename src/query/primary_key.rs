@@ -3,6 +3,8 @@
 //! This module provides traits and implementations for type-safe primary key operations
 //! that match `SeaORM`'s API. Primary keys can be queried and manipulated with compile-time type checking.
 
+use crate::value::ValueType;
+
 /// Trait for primary key operations
 ///
 /// This trait provides methods for working with primary keys at compile time.
@@ -198,6 +200,60 @@ pub trait PrimaryKeyArityTrait {
     fn arity() -> PrimaryKeyArity;
 }
 
+/// Links an entity to its `PrimaryKey` enum.
+///
+/// Generated by `LifeModel` for every entity with at least one `#[primary_key]` field, so
+/// typed lookups such as [`crate::ModelManager::find_by_id`] can accept
+/// `<E::PrimaryKey as PrimaryKeyTrait>::ValueType` (`i32`, `Uuid`, `(i32, String)`, ...).
+///
+/// ```no_run
+/// // Generated by the macro:
+/// // impl lifeguard::EntityPrimaryKey for Entity {
+/// //     type PrimaryKey = PrimaryKey;
+/// // }
+/// ```
+pub trait EntityPrimaryKey: crate::query::traits::LifeModelTrait {
+    /// The entity's `PrimaryKey` enum.
+    type PrimaryKey: PrimaryKeyTrait;
+}
+
+/// `E`'s primary key value type: the column's type, or a tuple for composite keys.
+pub type PrimaryKeyValue<E> = <<E as EntityPrimaryKey>::PrimaryKey as PrimaryKeyTrait>::ValueType;
+
+/// Conversion of a primary key value into one `sea_query::Value` per key column, in column
+/// order.
+///
+/// Implemented for every [`ValueType`] (single-column keys) and for tuples of 2-6 of them
+/// (composite keys), matching the shapes of [`PrimaryKeyTrait::ValueType`].
+pub trait IntoPrimaryKeyValues {
+    /// The key's column values, in primary-key column order.
+    fn into_primary_key_values(self) -> Vec<sea_query::Value>;
+}
+
+impl<T: ValueType> IntoPrimaryKeyValues for T {
+    fn into_primary_key_values(self) -> Vec<sea_query::Value> {
+        vec![self.into_value()]
+    }
+}
+
+macro_rules! impl_into_primary_key_values_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: ValueType),+> IntoPrimaryKeyValues for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn into_primary_key_values(self) -> Vec<sea_query::Value> {
+                let ($($name,)+) = self;
+                vec![$($name.into_value()),+]
+            }
+        }
+    };
+}
+
+impl_into_primary_key_values_for_tuple!(A, B);
+impl_into_primary_key_values_for_tuple!(A, B, C);
+impl_into_primary_key_values_for_tuple!(A, B, C, D);
+impl_into_primary_key_values_for_tuple!(A, B, C, D, E);
+impl_into_primary_key_values_for_tuple!(A, B, C, D, E, F);
+
 #[cfg(test)]
 #[allow(dead_code)]
 mod tests {
@@ -310,4 +366,20 @@ mod tests {
     fn test_composite_primary_key_arity() {
         assert_eq!(CompositePrimaryKey::arity(), PrimaryKeyArity::Tuple2);
     }
+
+    #[test]
+    fn test_into_primary_key_values_single_and_composite() {
+        use sea_query::Value;
+
+        assert_eq!(7i32.into_primary_key_values(), vec![Value::Int(Some(7))]);
+        let id = uuid::Uuid::nil();
+        assert_eq!(id.into_primary_key_values(), vec![Value::Uuid(Some(id))]);
+        assert_eq!(
+            (1i64, "en".to_string()).into_primary_key_values(),
+            vec![
+                Value::BigInt(Some(1)),
+                Value::String(Some("en".to_string()))
+            ]
+        );
+    }
 }
@@ -0,0 +1,226 @@
+//! Relation-based `EXISTS` filters: [`SelectQuery::has_related`] and
+//! [`SelectQuery::doesnt_have_related`].
+//!
+//! Keep parents by properties of their related rows without hand-written subqueries:
+//!
+//! ```ignore
+//! // Users with at least one published post
+//! User::find().has_related::<Post>(|q| q.filter(PostColumn::Published.eq(true)))
+//! // Users without any post
+//! User::find().doesnt_have_related::<Post>(|q| q)
+//! ```
+//!
+//! The closure narrows a plain `R::find()`, so filters, [`scopes`](SelectQuery::scope) and
+//! [`with_trashed`](SelectQuery::with_trashed) work as on any query, and `R`'s soft-delete column
+//! is respected unless the closure opts out. The result is correlated with the parent table
+//! through `Related<R>::to()` and [`join_tbl_on_condition`]:
+//!
+//! ```sql
+//! EXISTS (SELECT 1 FROM (SELECT * FROM "posts" WHERE "published" = $1 AND "deleted_at" IS NULL) AS "__lg_has"
+//!         WHERE users.id = __lg_has.user_id)
+//! ```
+//!
+//! `has_many_through` relations go through the join table. [`related_exists`] returns the same
+//! predicate as an [`Expr`] for use in [`SelectQuery::scope_or`] or a named scope.
+
+use sea_query::{Alias, DynIden, Expr, ExprTrait, JoinType, SelectStatement, TableRef};
+
+use crate::executor::LifeError;
+use crate::query::joined::check_arity;
+use crate::query::select::SelectQuery;
+use crate::query::traits::LifeModelTrait;
+use crate::relation::def::condition::join_tbl_on_condition;
+use crate::relation::def::{RelationDef, RelationType};
+use crate::relation::traits::Related;
+
+/// The narrowed related query inside the `EXISTS`.
+pub(crate) const RELATED_ALIAS: &str = "__lg_has";
+/// Junction table inside the `EXISTS` (`has_many_through`).
+const JUNCTION_ALIAS: &str = "__lg_has_jt";
+
+impl<E> SelectQuery<E>
+where
+    E: LifeModelTrait,
+{
+    /// Keep rows with at least one related `R` matching `related` (`EXISTS`).
+    ///
+    /// `related` receives `R::find()` and returns it narrowed; pass `|q| q` for "has any".
+    ///
+    /// If the relation cannot be correlated (see [`related_exists`]), the error is kept on the
+    /// query and returned by `all` / `one` / `count` instead of running it.
+    #[must_use]
+    pub fn has_related<R>(self, related: impl FnOnce(SelectQuery<R>) -> SelectQuery<R>) -> Self
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        match related_exists::<E, R>(related) {
+            Ok(exists) => self.filter(exists),
+            Err(e) => self.defer_error(e),
+        }
+    }
+
+    /// Keep rows without any related `R` matching `related` (`NOT EXISTS`).
+    ///
+    /// Errors are deferred as for [`has_related`](Self::has_related).
+    #[must_use]
+    pub fn doesnt_have_related<R>(
+        self,
+        related: impl FnOnce(SelectQuery<R>) -> SelectQuery<R>,
+    ) -> Self
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        match related_exists::<E, R>(related) {
+            Ok(exists) => self.filter(exists.not()),
+            Err(e) => self.defer_error(e),
+        }
+    }
+}
+
+/// `EXISTS (…)` over the `R` rows matching `related` that belong to the current `E` row.
+///
+/// # Errors
+///
+/// Returns [`LifeError::Other`] if a `has_many_through` relation is missing its through table or
+/// columns, the relation's key column lists have different arities, or `related` left a deferred
+/// error on the narrowed query.
+pub fn related_exists<E, R>(
+    related: impl FnOnce(SelectQuery<R>) -> SelectQuery<R>,
+) -> Result<Expr, LifeError>
+where
+    E: LifeModelTrait + Related<R>,
+    R: LifeModelTrait,
+{
+    let narrowed = related(R::find());
+    narrowed.check_build_error()?;
+    Ok(Expr::exists(correlated_subquery(
+        &E::to(),
+        narrowed.apply_soft_delete(),
+        Expr::cust("1"),
+    )?))
+}
+
+/// `SELECT <select> FROM (<related>) AS __lg_has [JOIN through] WHERE <parent keys match>`.
+///
+/// `select` may refer to the related columns as `__lg_has.<column>` ([`RELATED_ALIAS`]).
+pub(crate) fn correlated_subquery(
+    def: &RelationDef,
+    related: SelectStatement,
+    select: Expr,
+) -> Result<SelectStatement, LifeError> {
+    let narrowed: TableRef = DynIden::from(RELATED_ALIAS).into();
+    let mut sub = SelectStatement::default();
+    sub.expr(select);
+
+    if def.rel_type == RelationType::HasManyThrough {
+        let (Some(through), Some(through_from), Some(through_to)) =
+            (&def.through_tbl, &def.through_from_col, &def.through_to_col)
+        else {
+            return Err(LifeError::Other(
+                "has_many_through relation is missing its through table or columns".to_string(),
+            ));
+        };
+        let junction: TableRef = DynIden::from(JUNCTION_ALIAS).into();
+        check_arity(through_to, &def.to_col)?;
+        check_arity(&def.from_col, through_from)?;
+        sub.from_as(through.clone(), Alias::new(JUNCTION_ALIAS));
+        sub.join_subquery(
+            JoinType::InnerJoin,
+            related,
+            Alias::new(RELATED_ALIAS),
+            join_tbl_on_condition(&junction, &narrowed, through_to, &def.to_col),
+        );
+        sub.cond_where(join_tbl_on_condition(
+            &def.from_tbl,
+            &junction,
+            &def.from_col,
+            through_from,
+        ));
+    } else {
+        check_arity(&def.from_col, &def.to_col)?;
+        sub.from_subquery(related, Alias::new(RELATED_ALIAS));
+        sub.cond_where(join_tbl_on_condition(
+            &def.from_tbl,
+            &narrowed,
+            &def.from_col,
+            &def.to_col,
+        ));
+    }
+    Ok(sub)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::relation::def::fixtures;
+    use crate::relation::identity::Identity;
+    use sea_query::PostgresQueryBuilder;
+
+    fn relation(rel_type: RelationType) -> RelationDef {
+        fixtures::relation(rel_type, ("users", "id"), ("posts", "user_id"))
+    }
+
+    fn posts() -> SelectStatement {
+        let mut q = SelectStatement::default();
+        q.column(sea_query::Asterisk)
+            .from("posts")
+            .and_where(Expr::col("author_id").eq(7));
+        q
+    }
+
+    #[test]
+    fn correlates_the_narrowed_query_with_the_parent_table() {
+        let sql = correlated_subquery(&relation(RelationType::HasMany), posts(), Expr::cust("1"))
+            .expect("has_many subquery")
+            .to_string(PostgresQueryBuilder);
+        assert_eq!(
+            sql,
+            r#"SELECT 1 FROM (SELECT * FROM "posts" WHERE "author_id" = 7) AS "__lg_has" WHERE users.id = __lg_has.user_id"#
+        );
+    }
+
+    #[test]
+    fn has_many_through_goes_through_the_join_table() {
+        let def = fixtures::has_many_through(
+            ("users", "id"),
+            ("tags", "id"),
+            ("user_tags", "user_id", "tag_id"),
+        );
+
+        let sql = correlated_subquery(&def, posts(), Expr::cust("1"))
+            .expect("through subquery")
+            .to_string(PostgresQueryBuilder);
+        assert!(
+            sql.starts_with(r#"SELECT 1 FROM "user_tags" AS "__lg_has_jt" INNER JOIN (SELECT"#),
+            "{sql}"
+        );
+        assert!(
+            sql.ends_with(
+                r#"AS "__lg_has" ON __lg_has_jt.tag_id = __lg_has.id WHERE users.id = __lg_has_jt.user_id"#
+            ),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_through_relation() {
+        let err = correlated_subquery(
+            &relation(RelationType::HasManyThrough),
+            posts(),
+            Expr::cust("1"),
+        )
+        .expect_err("no through table");
+        assert!(matches!(err, LifeError::Other(_)));
+    }
+
+    #[test]
+    fn rejects_mismatched_key_arity() {
+        let mut def = relation(RelationType::HasMany);
+        def.to_col = Identity::Binary(DynIden::from("user_id"), DynIden::from("org_id"));
+        let err = correlated_subquery(&def, posts(), Expr::cust("1")).expect_err("1 column vs 2");
+        assert!(matches!(err, LifeError::Other(_)));
+    }
+}
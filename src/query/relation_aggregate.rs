@@ -0,0 +1,214 @@
+//! Relation aggregates as extra result columns: [`SelectQuery::with_count`] and
+//! [`SelectQuery::with_aggregate`].
+//!
+//! "Users with their number of posts" without loading the posts:
+//!
+//! ```ignore
+//! use lifeguard::RelationAggregate::Sum;
+//!
+//! let rows = User::find()
+//!     .with_count::<Post>("post_count")
+//!     .with_aggregate::<Order>(Sum(OrderColumn::Total), "lifetime_value")
+//!     .all_with_aggregates(executor)?;
+//! for (user, aggregates) in rows {
+//!     let posts: i64 = aggregates.get("post_count")?;
+//!     let value: Option<i64> = aggregates.get("lifetime_value")?;
+//! }
+//! ```
+//!
+//! Each aggregate is a correlated scalar subquery over `R::find()` (so `R`'s soft-delete column
+//! is respected), correlated with the parent through `Related<R>::to()` like
+//! [`has_related`](SelectQuery::has_related):
+//!
+//! ```sql
+//! SELECT *, (SELECT COUNT(*) FROM (SELECT * FROM "posts" WHERE "deleted_at" IS NULL) AS "__lg_has"
+//!            WHERE users.id = __lg_has.user_id) AS "post_count"
+//! FROM "users"
+//! ```
+//!
+//! The values come back as a pair with [`SelectQuery::all_with_aggregates`], or as plain columns
+//! of a partial model read through [`SelectQuery::into_model`]. `COUNT` is a `BIGINT`; the other
+//! aggregates have PostgreSQL's result type for the column (e.g. `SUM` of an `INTEGER` is a
+//! `BIGINT`) and are NULL for parents without related rows.
+
+use may_postgres::types::FromSql;
+use may_postgres::Row;
+use sea_query::{Alias, Expr, Func, IntoIden, PostgresQueryBuilder, SubQueryStatement};
+
+use crate::executor::{LifeError, LifeExecutor};
+use crate::query::related_filter::{correlated_subquery, RELATED_ALIAS};
+use crate::query::select::SelectQuery;
+use crate::query::traits::{FromRow, LifeModelTrait};
+use crate::relation::traits::Related;
+
+/// Aggregate of a related column for [`SelectQuery::with_aggregate`]
+#[derive(Clone, Debug)]
+pub enum RelationAggregate<C> {
+    /// `COUNT(*)` of the related rows
+    Count,
+    /// `SUM(column)`
+    Sum(C),
+    /// `AVG(column)`
+    Avg(C),
+    /// `MIN(column)`
+    Min(C),
+    /// `MAX(column)`
+    Max(C),
+}
+
+impl<C: IntoIden> RelationAggregate<C> {
+    /// The aggregate over the related columns inside the correlated subquery.
+    fn into_expr(self) -> Expr {
+        let column = |c: C| Expr::col((Alias::new(RELATED_ALIAS), c.into_iden()));
+        match self {
+            Self::Count => Expr::cust("COUNT(*)"),
+            Self::Sum(c) => Func::sum(column(c)).into(),
+            Self::Avg(c) => Func::avg(column(c)).into(),
+            Self::Min(c) => Func::min(column(c)).into(),
+            Self::Max(c) => Func::max(column(c)).into(),
+        }
+    }
+}
+
+/// The aggregate columns of one row returned by [`SelectQuery::all_with_aggregates`]
+pub struct RelationAggregates {
+    row: Row,
+}
+
+impl RelationAggregates {
+    /// Read the aggregate selected as `alias`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LifeError::PostgresError`] if there is no such column or it does not convert
+    /// to `T` (use `Option<T>` for aggregates that may be NULL).
+    pub fn get<'a, T: FromSql<'a>>(&'a self, alias: &str) -> Result<T, LifeError> {
+        self.row.try_get(alias).map_err(LifeError::PostgresError)
+    }
+}
+
+impl<E> SelectQuery<E>
+where
+    E: LifeModelTrait,
+{
+    /// Select the number of related `R` rows of each row as the `BIGINT` column `alias`.
+    ///
+    /// Shorthand for `with_aggregate::<R>(RelationAggregate::Count, alias)`.
+    #[must_use]
+    pub fn with_count<R>(self, alias: &str) -> Self
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        self.with_aggregate::<R>(RelationAggregate::<Alias>::Count, alias)
+    }
+
+    /// Select `aggregate` over each row's related `R` rows as the column `alias`.
+    ///
+    /// If a `has_many_through` relation is missing its through table or columns, or the
+    /// relation's key column lists have different arities, the error is kept on the query and
+    /// returned when it is executed.
+    #[must_use]
+    pub fn with_aggregate<R>(
+        mut self,
+        aggregate: RelationAggregate<impl IntoIden>,
+        alias: &str,
+    ) -> Self
+    where
+        R: LifeModelTrait,
+        E: Related<R>,
+    {
+        let related = R::find().apply_soft_delete();
+        match correlated_subquery(&E::to(), related, aggregate.into_expr()) {
+            Ok(sub) => {
+                self.query.expr_as(
+                    Expr::SubQuery(None, Box::new(SubQueryStatement::SelectStatement(sub))),
+                    Alias::new(alias),
+                );
+                self
+            }
+            Err(e) => self.defer_error(e),
+        }
+    }
+
+    /// Execute the query and return every row with its aggregate columns
+    ///
+    /// # Errors
+    ///
+    /// Returns `LifeError` if the query execution or row parsing fails.
+    pub fn all_with_aggregates<Ex: LifeExecutor>(
+        mut self,
+        executor: &Ex,
+    ) -> Result<Vec<(E::Model, RelationAggregates)>, LifeError>
+    where
+        E::Model: FromRow,
+    {
+        self.check_executable(executor)?;
+        let loaders = std::mem::take(&mut self.loaders);
+        let (sql, values) = self.apply_soft_delete().build(PostgresQueryBuilder);
+        let rows = executor.query_all_values(&sql, &values)?;
+
+        let mut models = Vec::with_capacity(rows.len());
+        for row in &rows {
+            models.push(
+                <E::Model as FromRow>::from_row(row)
+                    .map_err(|e| LifeError::ParseError(format!("Failed to parse row: {e}")))?,
+            );
+        }
+
+        for loader in &loaders {
+            loader.execute(&mut models, executor)?;
+        }
+
+        Ok(models
+            .into_iter()
+            .zip(rows.into_iter().map(|row| RelationAggregates { row }))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::relation::def::{fixtures, RelationDef, RelationType};
+    use sea_query::SelectStatement;
+
+    fn user_orders() -> RelationDef {
+        fixtures::relation(
+            RelationType::HasMany,
+            ("users", "id"),
+            ("orders", "user_id"),
+        )
+    }
+
+    fn orders() -> SelectStatement {
+        let mut q = SelectStatement::default();
+        q.column(sea_query::Asterisk).from("orders");
+        q
+    }
+
+    #[test]
+    fn aggregates_read_the_narrowed_related_columns() {
+        let sum = correlated_subquery(
+            &user_orders(),
+            orders(),
+            RelationAggregate::Sum(Alias::new("total")).into_expr(),
+        )
+        .expect("sum subquery")
+        .to_string(PostgresQueryBuilder);
+        assert_eq!(
+            sum,
+            r#"SELECT SUM("__lg_has"."total") FROM (SELECT * FROM "orders") AS "__lg_has" WHERE users.id = __lg_has.user_id"#
+        );
+
+        let count = correlated_subquery(
+            &user_orders(),
+            orders(),
+            RelationAggregate::<Alias>::Count.into_expr(),
+        )
+        .expect("count subquery")
+        .to_string(PostgresQueryBuilder);
+        assert!(count.starts_with("SELECT COUNT(*) FROM ("), "{count}");
+    }
+}
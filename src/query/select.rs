@@ -20,6 +20,8 @@
 //! - **Subquery as a SELECT column** — [`SelectQuery::subquery_column`].
 //! - **Window functions (`OVER`, `WINDOW`)** — [`SelectQuery::window`], [`SelectQuery::expr_window_as`],
 //!   [`SelectQuery::expr_window_name_as`], or raw SQL via [`SelectQuery::window_function_cust`].
+//! - **Row locks (`FOR UPDATE`, `SKIP LOCKED`, …)** — [`SelectQuery::for_update`] and siblings; only
+//!   inside a transaction, see [`crate::LifeExecutor::check_row_locking`].
 //!
 //! Bring in [`sea_query`] types as needed: [`CommonTableExpression`](sea_query::CommonTableExpression),
 //! [`WithClause`](sea_query::WithClause), [`WindowStatement`](sea_query::WindowStatement),
@@ -74,9 +76,38 @@ where
     pub(crate) query: SelectStatement, // Made pub(crate) for testing
     pub(crate) with_trashed: bool,
     pub(crate) loaders: Vec<Rc<dyn LoaderExecutor<E>>>,
+    /// Row-locking clause, kept alongside `query` so execution can refuse executors that
+    /// would drop the locks immediately (see [`crate::LifeExecutor::check_row_locking`]).
+    pub(crate) lock: Option<RowLock>,
+    /// First error a builder method could not return itself (see [`Self::has_related`]); the
+    /// executing methods return it instead of running the query.
+    pub(crate) build_error: Option<String>,
     pub(crate) _phantom: PhantomData<E>,
 }
 
+/// Lock strength of a `SELECT ... FOR <strength>` clause.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum LockStrength {
+    Update,
+    NoKeyUpdate,
+    Share,
+    KeyShare,
+}
+
+/// What a locking `SELECT` does when a matching row is already locked.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum LockWait {
+    Nowait,
+    SkipLocked,
+}
+
+/// The row-locking clause requested on a [`SelectQuery`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RowLock {
+    pub(crate) strength: LockStrength,
+    pub(crate) wait: Option<LockWait>,
+}
+
 impl<E> Clone for SelectQuery<E>
 where
     E: LifeModelTrait,
@@ -86,6 +117,8 @@ where
             query: self.query.clone(),
             with_trashed: self.with_trashed,
             loaders: self.loaders.clone(),
+            lock: self.lock,
+            build_error: self.build_error.clone(),
             _phantom: PhantomData,
         }
     }
@@ -163,16 +196,8 @@ where
         // Check if any column has a select_as expression
         // If so, we need to build individual column selections instead of using Asterisk
         let columns = E::all_columns();
-        let has_select_as = columns.iter().any(|col| {
-            // Use ColumnDefHelper trait to access column_def() method (generated by macro)
-            // ColumnTrait::def() uses blanket impl that returns ColumnDefinition::default()
-            // Use fully qualified syntax to call trait method
-            <E::Column as ColumnDefHelper>::column_def(*col)
-                .select_as
-                .is_some()
-        });
 
-        if has_select_as {
+        if Self::has_select_as() {
             // Build individual column selections, using select_as expressions when available
             for col in columns {
                 // Use ColumnDefHelper trait to access column_def() method (generated by macro)
@@ -200,20 +225,33 @@ where
             query,
             with_trashed: false,
             loaders: Vec::new(),
+            lock: None,
+            build_error: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Whether any column has a `select_as` expression, so the query lists its columns instead of
+    /// selecting `*`.
+    pub(crate) fn has_select_as() -> bool {
+        E::all_columns().iter().any(|col| {
+            // Use ColumnDefHelper trait to access column_def() method (generated by macro)
+            // ColumnTrait::def() uses blanket impl that returns ColumnDefinition::default()
+            // Use fully qualified syntax to call trait method
+            <E::Column as ColumnDefHelper>::column_def(*col)
+                .select_as
+                .is_some()
+        })
+    }
+
     /// Register a relation loader to automatically intercept and resolve N+1 patterns
-    pub fn load<R: LifeModelTrait + 'static>(mut self, _relation: R) -> Self
-    where
-        E: crate::Related<R> + 'static,
-        E::Model: crate::query::loader::RelationInjector<R> + crate::model::ModelTrait,
-        R::Model: crate::query::traits::FromRow + crate::model::ModelTrait + Clone,
-    {
-        self.loaders
-            .push(Rc::new(crate::query::loader::RelationLoader::<E, R>::new())
-                as Rc<dyn LoaderExecutor<E>>);
+    ///
+    /// `spec` is a related entity (`.load(post::Entity)`) or a nested
+    /// [`LoadPath`](crate::query::loader::LoadPath) (`.load(post::Entity.then(comment::Entity))`,
+    /// with [`LoadThen`](crate::query::loader::LoadThen) in scope). Each level is one batched
+    /// query, run after the main query.
+    pub fn load<S: crate::query::loader::EagerLoad<E>>(mut self, spec: S) -> Self {
+        self.loaders.push(spec.into_loader());
         self
     }
 
@@ -402,6 +440,147 @@ where
         self
     }
 
+    /// Lock the selected rows with `FOR UPDATE`.
+    ///
+    /// Row locks last until the surrounding transaction ends, so run locking queries on a
+    /// [`crate::transaction::Transaction`] or inside [`crate::PooledLifeExecutor::transaction`].
+    /// A plain [`crate::PooledLifeExecutor`] autocommits each statement (and may route reads to
+    /// a replica), so executing a locking query on it returns [`crate::LifeError::Pool`].
+    ///
+    /// ```ignore
+    /// let batch = Job::find()
+    ///     .filter(Column::Status.eq("queued"))
+    ///     .order_by(Column::Id, Order::Asc)
+    ///     .limit(10)
+    ///     .for_update()
+    ///     .skip_locked()
+    ///     .all(tx)?;
+    /// ```
+    #[must_use]
+    pub fn for_update(self) -> Self {
+        self.with_lock_strength(LockStrength::Update)
+    }
+
+    /// Lock the selected rows with `FOR NO KEY UPDATE`.
+    ///
+    /// Weaker than [`Self::for_update`]: it does not block `FOR KEY SHARE` lockers such as
+    /// foreign-key checks, which suits updates that leave the key columns alone.
+    #[must_use]
+    pub fn for_no_key_update(self) -> Self {
+        self.with_lock_strength(LockStrength::NoKeyUpdate)
+    }
+
+    /// Lock the selected rows with `FOR SHARE`.
+    #[must_use]
+    pub fn for_share(self) -> Self {
+        self.with_lock_strength(LockStrength::Share)
+    }
+
+    /// Lock the selected rows with `FOR KEY SHARE`.
+    #[must_use]
+    pub fn for_key_share(self) -> Self {
+        self.with_lock_strength(LockStrength::KeyShare)
+    }
+
+    /// Skip rows another transaction has locked instead of waiting (`SKIP LOCKED`).
+    ///
+    /// Implies `FOR UPDATE` when no lock strength was chosen.
+    #[must_use]
+    pub fn skip_locked(self) -> Self {
+        self.with_lock_wait(LockWait::SkipLocked)
+    }
+
+    /// Fail with an error instead of waiting when a row is locked (`NOWAIT`).
+    ///
+    /// Implies `FOR UPDATE` when no lock strength was chosen.
+    #[must_use]
+    pub fn nowait(self) -> Self {
+        self.with_lock_wait(LockWait::Nowait)
+    }
+
+    /// `true` when a row-locking clause has been requested.
+    #[must_use]
+    pub fn is_locking(&self) -> bool {
+        self.lock.is_some()
+    }
+
+    fn with_lock_strength(mut self, strength: LockStrength) -> Self {
+        let wait = self.lock.and_then(|lock| lock.wait);
+        self.lock = Some(RowLock { strength, wait });
+        self.apply_lock();
+        self
+    }
+
+    fn with_lock_wait(mut self, wait: LockWait) -> Self {
+        let strength = self.lock.map_or(LockStrength::Update, |lock| lock.strength);
+        self.lock = Some(RowLock {
+            strength,
+            wait: Some(wait),
+        });
+        self.apply_lock();
+        self
+    }
+
+    fn apply_lock(&mut self) {
+        use sea_query::{LockBehavior, LockType};
+
+        let Some(lock) = self.lock else {
+            return;
+        };
+        let lock_type = match lock.strength {
+            LockStrength::Update => LockType::Update,
+            LockStrength::NoKeyUpdate => LockType::NoKeyUpdate,
+            LockStrength::Share => LockType::Share,
+            LockStrength::KeyShare => LockType::KeyShare,
+        };
+        match lock.wait {
+            None => {
+                self.query.lock(lock_type);
+            }
+            Some(LockWait::Nowait) => {
+                self.query
+                    .lock_with_behavior(lock_type, LockBehavior::Nowait);
+            }
+            Some(LockWait::SkipLocked) => {
+                self.query
+                    .lock_with_behavior(lock_type, LockBehavior::SkipLocked);
+            }
+        }
+    }
+
+    /// Keep `error` until the query runs; only the first one is kept.
+    #[must_use]
+    pub(crate) fn defer_error(mut self, error: crate::executor::LifeError) -> Self {
+        if self.build_error.is_none() {
+            self.build_error = Some(match error {
+                crate::executor::LifeError::Other(message) => message,
+                other => other.to_string(),
+            });
+        }
+        self
+    }
+
+    /// The error a builder method deferred, as [`LifeError::Other`](crate::LifeError::Other).
+    pub(crate) fn check_build_error(&self) -> Result<(), crate::executor::LifeError> {
+        match &self.build_error {
+            Some(message) => Err(crate::executor::LifeError::Other(message.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Return a deferred builder error, and refuse executors on which the requested row locks
+    /// would not outlive the statement.
+    pub(crate) fn check_executable<Ex: crate::executor::LifeExecutor>(
+        &self,
+        executor: &Ex,
+    ) -> Result<(), crate::executor::LifeError> {
+        self.check_build_error()?;
+        if self.lock.is_some() {
+            executor.check_row_locking()?;
+        }
+        Ok(())
+    }
+
     /// Append soft delete filter if present and not `with_trashed`
     pub(crate) fn apply_soft_delete(mut self) -> SelectStatement {
         if !self.with_trashed {
@@ -835,6 +1014,26 @@ where
         self
     }
 
+    /// Read the rows as `M` instead of `E::Model`
+    ///
+    /// `M` is any [`FromRow`] type, typically a partial model whose fields name the selected
+    /// columns, including extra ones such as [`with_count`](Self::with_count) aliases.
+    #[must_use]
+    pub fn into_model<M: FromRow>(self) -> SelectModel<E, M> {
+        SelectModel::new(self)
+    }
+
+    /// Wrap the filtered statement in an [`AggregateQuery`](crate::query::aggregate::AggregateQuery),
+    /// carrying over a deferred builder error.
+    fn into_aggregate<R: crate::query::aggregate::LifeAggregate>(
+        mut self,
+    ) -> crate::query::aggregate::AggregateQuery<E, R> {
+        let build_error = self.build_error.take();
+        let mut aggregate = crate::query::aggregate::AggregateQuery::new(self.apply_soft_delete());
+        aggregate.build_error = build_error;
+        aggregate
+    }
+
     /// Create a COUNT aggregation query
     ///
     /// Clears any selected columns and ordering, replaces with COUNT(*),
@@ -848,7 +1047,7 @@ where
         // Add COUNT(*) using a custom expression to avoid quoting '*' as a literal column name
         self.query.expr(sea_query::Expr::cust("COUNT(*)"));
 
-        crate::query::aggregate::AggregateQuery::new(self.apply_soft_delete())
+        self.into_aggregate()
     }
 
     /// Create a SUM aggregation query
@@ -866,7 +1065,7 @@ where
         self.query
             .expr(sea_query::Expr::col(column.into_column_ref()).sum());
 
-        crate::query::aggregate::AggregateQuery::new(self.apply_soft_delete())
+        self.into_aggregate()
     }
 }
 
@@ -877,7 +1076,6 @@ where
     M: FromRow,
 {
     /// Create a new `SelectModel` from a `SelectQuery`
-    #[allow(dead_code)]
     pub(crate) fn new(query: SelectQuery<E>) -> Self {
         Self {
             query,
@@ -1172,4 +1370,77 @@ mod tests {
             "SQL should have raw unquoted asterisk. SQL: {sql}"
         );
     }
+
+    #[test]
+    fn test_row_locking_clauses() {
+        use sea_query::PostgresQueryBuilder;
+
+        let sql = |q: SelectQuery<TestSelectAsEntity>| q.query.build(PostgresQueryBuilder).0;
+
+        let q = SelectQuery::<TestSelectAsEntity>::new().limit(10);
+        assert!(!q.is_locking());
+        assert!(sql(q.clone().for_update()).ends_with("LIMIT $1 FOR UPDATE"));
+        assert!(sql(q.clone().for_no_key_update()).ends_with("FOR NO KEY UPDATE"));
+        assert!(sql(q.clone().for_share()).ends_with("FOR SHARE"));
+        assert!(sql(q.clone().for_key_share()).ends_with("FOR KEY SHARE"));
+        assert!(sql(q.clone().for_update().skip_locked()).ends_with("FOR UPDATE SKIP LOCKED"));
+        assert!(sql(q.clone().for_share().nowait()).ends_with("FOR SHARE NOWAIT"));
+        // Wait behaviour survives a later change of strength, and implies FOR UPDATE alone.
+        assert!(sql(q.clone().skip_locked().for_no_key_update())
+            .ends_with("FOR NO KEY UPDATE SKIP LOCKED"));
+        let locked = q.skip_locked();
+        assert!(locked.is_locking());
+        assert!(sql(locked).ends_with("FOR UPDATE SKIP LOCKED"));
+    }
+
+    #[test]
+    fn test_locking_query_refuses_executor_without_row_locks() {
+        use crate::executor::{LifeError, LifeExecutor};
+        use may_postgres::types::ToSql;
+        use may_postgres::Row;
+
+        struct AutocommitOnly;
+
+        impl LifeExecutor for AutocommitOnly {
+            fn execute(&self, _: &str, _: &[&dyn ToSql]) -> Result<u64, LifeError> {
+                Ok(0)
+            }
+            fn query_one(&self, _: &str, _: &[&dyn ToSql]) -> Result<Row, LifeError> {
+                Err(LifeError::Other("unused".to_string()))
+            }
+            fn query_all(&self, _: &str, _: &[&dyn ToSql]) -> Result<Vec<Row>, LifeError> {
+                Ok(Vec::new())
+            }
+            fn check_row_locking(&self) -> Result<(), LifeError> {
+                Err(LifeError::Pool("no row locks here".to_string()))
+            }
+        }
+
+        let q = SelectQuery::<TestSelectAsEntity>::new();
+        assert!(q.check_executable(&AutocommitOnly).is_ok());
+        assert!(matches!(
+            q.for_update().check_executable(&AutocommitOnly),
+            Err(LifeError::Pool(_))
+        ));
+    }
+
+    #[test]
+    fn deferred_builder_errors_surface_on_execution() {
+        use crate::executor::LifeError;
+
+        let q = SelectQuery::<TestSelectAsEntity>::new()
+            .defer_error(LifeError::Other("relation is incomplete".to_string()))
+            .defer_error(LifeError::Other("later".to_string()));
+        assert!(matches!(
+            q.clone().limit(10).check_build_error(),
+            Err(LifeError::Other(m)) if m == "relation is incomplete"
+        ));
+        assert_eq!(
+            q.count().build_error.as_deref(),
+            Some("relation is incomplete")
+        );
+        assert!(SelectQuery::<TestSelectAsEntity>::new()
+            .check_build_error()
+            .is_ok());
+    }
 }
@@ -1,16 +1,23 @@
 //! Server-Side Cursor Coroutine Streaming functionality.
 //!
 //! Provides the `SelectQueryStreamEx` trait providing `may` channel streaming
-//! capabilities to `SelectQuery`.
+//! capabilities to `SelectQuery`, either on a dedicated [`MayPostgresExecutor`] connection
+//! ([`SelectQueryStreamEx::stream_all`]) or on one pinned [`LifeguardPool`] worker slot
+//! ([`SelectQueryStreamEx::stream_all_pooled`]).
 
 use sea_query::PostgresQueryBuilder;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
+use crate::pool::pooled::PinnedSlot;
 use crate::query::traits::FromRow;
 use crate::query::traits::LifeModelTrait;
 use crate::query::SelectQuery;
 use crate::transaction::Transaction;
-use crate::{LifeError, LifeExecutor, MayPostgresExecutor};
+use crate::{
+    LifeError, LifeExecutor, LifeguardPool, MayPostgresExecutor, PooledLifeExecutor,
+    ReadPreference, SessionContext,
+};
 use sea_query::Values;
 
 static UUID_COUNTER: AtomicUsize = AtomicUsize::new(0);
@@ -41,6 +48,47 @@ impl Drop for StreamingTxnGuard {
     }
 }
 
+/// `BEGIN READ ONLY` … `COMMIT` around a cursor on a pinned pool slot.
+///
+/// Pool workers run each job in autocommit mode, so the cursor transaction is issued as plain
+/// statements on the pinned slot. `Drop` rolls back an unfinished transaction before the slot is
+/// released, so the next job on that connection never starts inside it.
+struct PooledCursorTxn<'a> {
+    slot: &'a PinnedSlot<'a>,
+    open: bool,
+}
+
+impl<'a> PooledCursorTxn<'a> {
+    fn begin(
+        slot: &'a PinnedSlot<'a>,
+        session: Option<&SessionContext>,
+    ) -> Result<Self, LifeError> {
+        slot.execute_values("BEGIN READ ONLY", &Values(Vec::new()))?;
+        let txn = Self { slot, open: true };
+        if let Some(context) = session {
+            slot.set_session_context(context)?;
+        }
+        Ok(txn)
+    }
+
+    fn finish(mut self, sql: &str) -> Result<(), LifeError> {
+        self.open = false;
+        self.slot
+            .execute_values(sql, &Values(Vec::new()))
+            .map(|_| ())
+    }
+}
+
+impl Drop for PooledCursorTxn<'_> {
+    fn drop(&mut self) {
+        if self.open {
+            if let Err(e) = self.slot.execute_values("ROLLBACK", &Values(Vec::new())) {
+                log::error!("stream cursor: rollback on pinned pool slot failed: {e}");
+            }
+        }
+    }
+}
+
 fn next_cursor_id() -> usize {
     UUID_COUNTER.fetch_add(1, Ordering::SeqCst)
 }
@@ -51,7 +99,7 @@ fn next_cursor_id() -> usize {
 /// closure would capture `&StreamCursorValues`, and `&StreamCursorValues` is not `Send` because
 /// `sea_query::Values` may contain `Rc<str>` (`StreamCursorValues` is `Send` but not `Sync`).
 fn run_stream_cursor_body<E>(
-    guard: &mut StreamingTxnGuard,
+    executor: &dyn LifeExecutor,
     cursor_name: &str,
     sql: &str,
     batch_size: usize,
@@ -62,20 +110,14 @@ where
     E: LifeModelTrait + 'static,
     E::Model: FromRow + Send + Sync + 'static,
 {
-    let Some(txn) = guard.txn.as_mut() else {
-        return Err(LifeError::Other(
-            "streaming transaction: internal guard state missing transaction".into(),
-        ));
-    };
-
     let declare_statement = format!("DECLARE {cursor_name} CURSOR FOR {sql}");
-    txn.execute_values(&declare_statement, &values.0)?;
+    executor.execute_values(&declare_statement, &values.0)?;
 
     let fetch_statement = format!("FETCH FORWARD {batch_size} FROM {cursor_name}");
     let empty = Values(Vec::new());
 
     loop {
-        let rows = txn.query_all_values(&fetch_statement, &empty)?;
+        let rows = executor.query_all_values(&fetch_statement, &empty)?;
         if rows.is_empty() {
             break;
         }
@@ -107,7 +149,8 @@ pub trait SelectQueryStreamEx<E: LifeModelTrait> {
     /// transactional session that survives continuously across multiple polls over a socket connection.
     /// Dynamic trait boundaries (`&dyn LifeExecutor`) abstract away connection pools meaning we
     /// cannot safely acquire localized connection-lock bindings needed natively to orchestrate
-    /// a coroutine safely looping fetches without intercepting parallel requests.
+    /// a coroutine safely looping fetches without intercepting parallel requests. For a
+    /// [`LifeguardPool`], use [`Self::stream_all_pooled`], which pins one worker slot instead.
     ///
     /// **Why yield `Vec<E::Model>` chunks?**
     /// For massive analytics processing, extracting standard `Vec` arrays reduces channel iteration
@@ -121,6 +164,24 @@ pub trait SelectQueryStreamEx<E: LifeModelTrait> {
         executor: &MayPostgresExecutor,
         batch_size: usize,
     ) -> may::sync::mpsc::Receiver<Result<Vec<E::Model>, LifeError>>;
+
+    /// [`Self::stream_all`] through a [`LifeguardPool`] instead of a dedicated connection.
+    ///
+    /// The coroutine pins one worker slot of the tier the executor's [`ReadPreference`] routes
+    /// reads to — a replica when one is configured and not lagging, the primary otherwise or with
+    /// [`ReadPreference::Primary`] — and holds it for the whole `BEGIN READ ONLY` … `COMMIT`
+    /// cursor. A [`SessionContext`] on the executor is applied inside that transaction. Dropping
+    /// the receiver ends the cursor at the next batch and releases the slot.
+    ///
+    /// While the slot is pinned, other pool traffic that round-robins onto it waits, as with
+    /// [`LifeguardPool::exclusive_primary_write_executor`]; size the pool accordingly for long
+    /// exports. Cursors on a hot standby can also be cancelled by recovery conflicts
+    /// (`max_standby_streaming_delay`); such errors arrive on the channel like any other.
+    fn stream_all_pooled(
+        self,
+        executor: &PooledLifeExecutor,
+        batch_size: usize,
+    ) -> may::sync::mpsc::Receiver<Result<Vec<E::Model>, LifeError>>;
 }
 
 impl<E: LifeModelTrait + 'static> SelectQueryStreamEx<E> for SelectQuery<E>
@@ -133,6 +194,10 @@ where
         batch_size: usize,
     ) -> may::sync::mpsc::Receiver<Result<Vec<E::Model>, LifeError>> {
         let (tx, rx) = may::sync::mpsc::channel();
+        if let Err(e) = self.check_build_error() {
+            let _ = tx.send(Err(e));
+            return rx;
+        }
 
         // Generate deterministic localized cursor UUID string
         let cursor_name = format!("lifeguard_stream_{}", next_cursor_id());
@@ -160,14 +225,14 @@ where
 
             let mut guard = StreamingTxnGuard { txn: Some(txn) };
 
-            let stream_result: Result<(), LifeError> = run_stream_cursor_body::<E>(
-                &mut guard,
-                &cursor_name,
-                &sql,
-                batch_size,
-                &values,
-                &tx,
-            );
+            let stream_result: Result<(), LifeError> = match guard.txn.as_ref() {
+                Some(txn) => {
+                    run_stream_cursor_body::<E>(txn, &cursor_name, &sql, batch_size, &values, &tx)
+                }
+                None => Err(LifeError::Other(
+                    "streaming transaction: internal guard state missing transaction".into(),
+                )),
+            };
 
             // Standardize any parsing boundary crashes outwards; end the transaction explicitly so
             // `StreamingTxnGuard` does not double-rollback after a successful commit.
@@ -198,4 +263,69 @@ where
 
         rx
     }
+
+    fn stream_all_pooled(
+        self,
+        executor: &PooledLifeExecutor,
+        batch_size: usize,
+    ) -> may::sync::mpsc::Receiver<Result<Vec<E::Model>, LifeError>> {
+        let (tx, rx) = may::sync::mpsc::channel();
+        if let Err(e) = self.check_build_error() {
+            let _ = tx.send(Err(e));
+            return rx;
+        }
+
+        let cursor_name = format!("lifeguard_stream_{}", next_cursor_id());
+        let (sql, values) = self.apply_soft_delete().build(PostgresQueryBuilder);
+        let values = StreamCursorValues(values);
+
+        let pool: Arc<LifeguardPool> = Arc::clone(executor.pool());
+        let preference: ReadPreference = executor.read_preference();
+        let session: Option<SessionContext> = executor.session_context().cloned();
+
+        // The slot is pinned inside the coroutine so it is held exactly as long as the cursor:
+        // the guard drops (and the slot unlocks) when the coroutine returns, including when the
+        // receiver is dropped early and the next `send` fails.
+        let _stream_co = may::go!(move || {
+            let slot = pool.pin_read_slot(preference);
+            let txn = match PooledCursorTxn::begin(&slot, session.as_ref()) {
+                Ok(t) => t,
+                Err(e) => {
+                    let _ = tx.send(Err(LifeError::Other(format!(
+                        "Failed to establish stream transaction: {e}"
+                    ))));
+                    return;
+                }
+            };
+
+            let stream_result =
+                run_stream_cursor_body::<E>(&slot, &cursor_name, &sql, batch_size, &values, &tx);
+
+            match stream_result {
+                Ok(()) => {
+                    if let Err(err) = txn.finish("COMMIT") {
+                        let notify = LifeError::Other(format!(
+                            "stream cursor `{cursor_name}`: commit failed after successful fetch loop: {err}"
+                        ));
+                        if tx.send(Err(notify)).is_err() {
+                            log::warn!(
+                                "stream_all_pooled cursor {cursor_name}: commit failed after streaming (receiver dropped): {err}"
+                            );
+                        }
+                    }
+                }
+                Err(unwound) => {
+                    let _ = tx.send(Err(unwound));
+                    if let Err(e) = txn.finish("ROLLBACK") {
+                        log::error!(
+                            "stream_all_pooled cursor {cursor_name}: rollback on {} slot failed: {e}",
+                            slot.tier_name()
+                        );
+                    }
+                }
+            }
+        });
+
+        rx
+    }
 }
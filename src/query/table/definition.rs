@@ -1,7 +1,8 @@
 //! Table definition metadata for entity-driven migrations.
 //!
 //! This module provides `TableDefinition` which stores table-level metadata
-//! including composite unique constraints, indexes, CHECK constraints, and table comments.
+//! including composite unique constraints, indexes, CHECK and EXCLUDE constraints, and table
+//! comments.
 
 /// Btree key sort direction for index metadata (matches PostgreSQL `ASC` / `DESC`).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -229,6 +230,8 @@ pub struct TableDefinition {
     pub view_query: Option<String>,
     /// Declarative `pg_notify` emission on row change. See [`NotifyDefinition`].
     pub notify: Option<NotifyDefinition>,
+    /// Table-level `EXCLUDE` constraints (`#[exclude(...)]`). See [`ExclusionDefinition`].
+    pub exclusion_constraints: Vec<ExclusionDefinition>,
 }
 
 /// **SPIKE — the shape of this is expected to change with use.**
@@ -291,6 +294,50 @@ impl NotifyDefinition {
     }
 }
 
+/// `EXCLUDE` constraint metadata: no two rows may have every element's operator return true,
+/// e.g. `room_id WITH =, during WITH &&` rejects overlapping bookings of the same room.
+///
+/// `=` on a scalar column under `gist` needs the `btree_gist` extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExclusionDefinition {
+    /// Constraint name; `None` leaves it to PostgreSQL (`{table}_{col1}_{col2}_excl`)
+    pub name: Option<String>,
+    /// Index access method (`gist`, `spgist`, `btree`, …)
+    pub using: String,
+    /// `(column, operator)` pairs, in order
+    pub elements: Vec<(String, String)>,
+    /// Partial constraint `WHERE` predicate (if any)
+    pub partial_where: Option<String>,
+}
+
+impl ExclusionDefinition {
+    /// The table-constraint clause for `CREATE TABLE`:
+    /// `[CONSTRAINT name ]EXCLUDE USING gist (a WITH =, b WITH &&)[ WHERE (…)]`.
+    #[must_use]
+    pub fn constraint_sql(&self) -> String {
+        let mut sql = String::new();
+        if let Some(name) = &self.name {
+            sql.push_str("CONSTRAINT ");
+            sql.push_str(name);
+            sql.push(' ');
+        }
+        let elements: Vec<String> = self
+            .elements
+            .iter()
+            .map(|(column, operator)| format!("{column} WITH {operator}"))
+            .collect();
+        sql.push_str(&format!(
+            "EXCLUDE USING {} ({})",
+            self.using,
+            elements.join(", ")
+        ));
+        if let Some(predicate) = &self.partial_where {
+            sql.push_str(&format!(" WHERE ({predicate})"));
+        }
+        sql
+    }
+}
+
 /// Index definition metadata
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IndexDefinition {
@@ -318,6 +365,30 @@ pub struct IndexDefinition {
 mod tests {
     use super::*;
 
+    #[test]
+    fn exclusion_constraint_sql() {
+        let mut excl = ExclusionDefinition {
+            name: None,
+            using: "gist".into(),
+            elements: vec![
+                ("room_id".into(), "=".into()),
+                ("during".into(), "&&".into()),
+            ],
+            partial_where: None,
+        };
+        assert_eq!(
+            excl.constraint_sql(),
+            "EXCLUDE USING gist (room_id WITH =, during WITH &&)"
+        );
+        excl.name = Some("bookings_no_overlap".into());
+        excl.partial_where = Some("NOT cancelled".into());
+        assert_eq!(
+            excl.constraint_sql(),
+            "CONSTRAINT bookings_no_overlap EXCLUDE USING gist (room_id WITH =, during WITH &&) \
+             WHERE (NOT cancelled)"
+        );
+    }
+
     #[test]
     fn format_key_parts_column_with_opclass_sort_nulls() {
         let parts = vec![IndexKeyPart::Column {
@@ -131,6 +131,23 @@ pub trait LifeModelTrait: LifeEntityName {
         None
     }
 
+    /// Returns the `updated_at` column if this entity has `#[auto_timestamp]`
+    ///
+    /// Set-based writes ([`UpdateMany`](crate::query::UpdateMany),
+    /// [`DeleteMany`](crate::query::DeleteMany)) stamp it with `now()`.
+    #[must_use]
+    fn updated_at_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// Seconds a write-through cache entry for this entity lives (`#[cache_ttl = N]`).
+    ///
+    /// `0` keeps the entity out of the cache: reads do not populate it and writes only evict.
+    #[must_use]
+    fn cache_ttl_seconds() -> u64 {
+        crate::cache::DEFAULT_CACHE_TTL_SECONDS
+    }
+
     /// Get all column variants for this entity.
     ///
     /// Returns a static slice of all `Column` enum variants.
@@ -306,6 +323,43 @@ pub trait LifeModelTrait: LifeEntityName {
         SelectQuery::new()
     }
 
+    /// Start a set-based `UPDATE` over this entity's table.
+    ///
+    /// Compiles to a single statement; see [`crate::query::mutation`] for soft-delete and
+    /// `#[auto_timestamp]` behaviour.
+    ///
+    /// ```ignore
+    /// let n = User::update_many()
+    ///     .set(Column::Status, "inactive")
+    ///     .filter(Column::LastLogin.lt(cutoff))
+    ///     .exec(executor)?;
+    /// ```
+    #[must_use]
+    fn update_many() -> crate::query::UpdateMany<Self>
+    where
+        Self: Sized,
+    {
+        crate::query::UpdateMany::new()
+    }
+
+    /// Start a set-based `DELETE` over this entity's table.
+    ///
+    /// On `#[soft_delete]` entities this sets `deleted_at = now()` instead; call
+    /// [`DeleteMany::force`](crate::query::DeleteMany::force) for a hard delete.
+    ///
+    /// ```ignore
+    /// let n = Session::delete_many()
+    ///     .filter(Column::ExpiresAt.lt(now))
+    ///     .exec(executor)?;
+    /// ```
+    #[must_use]
+    fn delete_many() -> crate::query::DeleteMany<Self>
+    where
+        Self: Sized,
+    {
+        crate::query::DeleteMany::new()
+    }
+
     /// Insert an `ActiveModel` (`Record`) into the database.
     ///
     ///
@@ -430,6 +484,54 @@ pub trait LifeModelTrait: LifeEntityName {
         active_model.insert(executor)
     }
 
+    /// Insert many `ActiveModel`s (`Record`s) with multi-row `INSERT` statements.
+    ///
+    /// This is a convenience method that delegates to `ActiveModelTrait::insert_many()`.
+    /// Rows are chunked below PostgreSQL's bind-parameter limit, hooks and
+    /// validators run per record, and the models come back in input order.
+    ///
+    /// ```ignore
+    /// let models = User::insert_many(vec![alice, bob], &executor)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if validation, a hook, or the insert fails.
+    fn insert_many<AM, I, E>(
+        records: I,
+        executor: &E,
+    ) -> Result<Vec<Self::Model>, crate::active_model::ActiveModelError>
+    where
+        Self: Sized,
+        AM: crate::active_model::ActiveModelTrait<Model = Self::Model>,
+        I: IntoIterator<Item = AM>,
+        E: LifeExecutor,
+    {
+        AM::insert_many(records, executor)
+    }
+
+    /// [`LifeModelTrait::insert_many`] with a PostgreSQL `ON CONFLICT` clause.
+    ///
+    /// Delegates to `ActiveModelTrait::insert_many_on_conflict()`; entries are
+    /// `None` for records that `DO NOTHING` skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ActiveModelError` if validation, a hook, or the insert fails.
+    fn insert_many_on_conflict<AM, I, E>(
+        records: I,
+        executor: &E,
+        on_conflict: sea_query::OnConflict,
+    ) -> Result<Vec<Option<Self::Model>>, crate::active_model::ActiveModelError>
+    where
+        Self: Sized,
+        AM: crate::active_model::ActiveModelTrait<Model = Self::Model>,
+        I: IntoIterator<Item = AM>,
+        E: LifeExecutor,
+    {
+        AM::insert_many_on_conflict(records, executor, on_conflict)
+    }
+
     /// Update an `ActiveModel` (`Record`) in the database.
     ///
     ///
@@ -724,6 +826,23 @@ pub trait FromRow: Sized {
     fn from_row(row: &may_postgres::Row) -> Result<Self, may_postgres::Error>;
 }
 
+/// Trait for types that can be read from columns aliased `<prefix><column>`
+///
+/// Generated by `LifeModel` next to [`FromRow`]. Joined queries such as
+/// [`SelectQuery::find_also_related`](crate::SelectQuery::find_also_related) alias the related
+/// table's columns with a prefix so they cannot clash with the parent's.
+pub trait FromRowPrefixed: Sized {
+    /// Convert the `prefix`-aliased columns of a database row to this type
+    ///
+    /// # Errors
+    ///
+    /// Returns `may_postgres::Error` if a prefixed column is missing or the conversion fails.
+    fn from_row_prefixed(
+        row: &may_postgres::Row,
+        prefix: &str,
+    ) -> Result<Self, may_postgres::Error>;
+}
+
 // FromRow implementation for serde_json::Value
 use serde_json::Value as JsonValue;
 impl FromRow for JsonValue {
@@ -0,0 +1,42 @@
+//! `RelationDef`s shared by unit tests that build SQL from a relation.
+
+use super::{RelationDef, RelationType};
+use crate::relation::identity::Identity;
+use sea_query::{ConditionType, DynIden};
+
+/// `from.0.from.1 = to.0.to.1`, with no join table.
+pub(crate) fn relation(
+    rel_type: RelationType,
+    from: (&'static str, &'static str),
+    to: (&'static str, &'static str),
+) -> RelationDef {
+    RelationDef {
+        rel_type,
+        from_tbl: DynIden::from(from.0).into(),
+        to_tbl: DynIden::from(to.0).into(),
+        from_col: Identity::Unary(DynIden::from(from.1)),
+        to_col: Identity::Unary(DynIden::from(to.1)),
+        through_tbl: None,
+        through_from_col: None,
+        through_to_col: None,
+        is_owner: rel_type != RelationType::BelongsTo,
+        skip_fk: false,
+        on_condition: None,
+        condition_type: ConditionType::All,
+    }
+}
+
+/// `has_many_through` from `from` to `to` via the join table `through.0`, whose `through.1`
+/// references `from` and `through.2` references `to`.
+pub(crate) fn has_many_through(
+    from: (&'static str, &'static str),
+    to: (&'static str, &'static str),
+    through: (&'static str, &'static str, &'static str),
+) -> RelationDef {
+    RelationDef {
+        through_tbl: Some(DynIden::from(through.0).into()),
+        through_from_col: Some(Identity::Unary(DynIden::from(through.1))),
+        through_to_col: Some(Identity::Unary(DynIden::from(through.2))),
+        ..relation(RelationType::HasManyThrough, from, to)
+    }
+}
@@ -4,6 +4,8 @@
 //! including the `RelationDef` struct, `RelationType` enum, and condition building functions.
 
 pub mod condition;
+#[cfg(test)]
+pub(crate) mod fixtures;
 pub mod struct_def;
 pub mod types;
 
@@ -222,6 +222,10 @@ where
     /// [`PENDING_INSERT_KEY_PREFIX`], then normal PK fingerprints) by calling `f` with the executor,
     /// the shared [`Rc`], and the internal map key string (use [`is_pending_insert_key`] to branch
     /// insert vs update).
+    ///
+    /// An optimistic-lock conflict from `f` ([`ActiveModelError::StaleRecord`], raised by
+    /// `#[version]` entities) is returned with the failing row's map key attached, so callers can
+    /// reload exactly that identity.
     pub fn flush_dirty_with_map_key<F>(
         &mut self,
         executor: &dyn LifeExecutor,
@@ -244,7 +248,7 @@ where
                 Ok(()) => {
                     self.dirty.remove(&key);
                 }
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.with_identity_key(&key)),
             }
         }
         Ok(())
@@ -491,6 +495,31 @@ mod tests {
         assert!(map.is_marked_dirty(&SessModel { id: 2, label: "x" }));
     }
 
+    #[test]
+    fn flush_dirty_stale_record_names_identity_key() {
+        let mut map = ModelIdentityMap::<SessEntity>::new();
+        let _ = map.register_loaded(SessModel { id: 7, label: "a" });
+        map.mark_dirty(&SessModel { id: 7, label: "a" });
+        let ex = NopExecutor;
+        let ex_ref: &dyn LifeExecutor = &ex;
+        let flush_result = map.flush_dirty(ex_ref, |_, _| {
+            Err(ActiveModelError::StaleRecord {
+                table: "sess".into(),
+                expected_version: 3,
+                identity_key: None,
+            })
+        });
+        assert_eq!(
+            flush_result,
+            Err(ActiveModelError::StaleRecord {
+                table: "sess".into(),
+                expected_version: 3,
+                identity_key: Some(fingerprint_pk_values(&[Value::Int(Some(7))])),
+            })
+        );
+        assert!(map.is_marked_dirty(&SessModel { id: 7, label: "a" }));
+    }
+
     #[test]
     #[allow(clippy::expect_used)] // Test code - expect is acceptable
     fn register_pending_insert_flush_with_map_key_and_promote() {
@@ -1,15 +1,18 @@
-//! Stable primary-key fingerprints for identity-map keys.
+//! Stable primary-key fingerprints for identity-map and cache keys.
 //!
 //! [`sea_query::Value`] does not implement [`Hash`], so we use a deterministic string
 //! representation of [`ModelTrait::get_primary_key_values`] for map keys.
 
 use sea_query::Value;
 
-/// Build a stable key string from primary-key values (for [`super::ModelIdentityMap`]).
+/// Build a stable key string from primary-key values (for [`super::ModelIdentityMap`] and the
+/// `lifeguard:model:{table}:{key}` cache entries).
 ///
-/// A subset of [`Value`] variants is encoded explicitly for stability; all others use
-/// [`Debug`](std::fmt::Debug) (stable enough for debugging; prefer extending the `match` for
-/// production keys if you rely on exotic types).
+/// Every [`Value`] variant a [`crate::ValueType`] produces is encoded explicitly, with a type tag
+/// so `1` and `"1"` differ. Integers share the `i:` tag whatever their width, and date-times with
+/// an offset are keyed by their UTC instant, so the key depends on the value rather than on which
+/// Rust type carried it; NULL is `null` whatever its type. Variants from `sea-query` features
+/// lifeguard does not enable fall back to [`Debug`](std::fmt::Debug).
 #[must_use]
 pub fn fingerprint_pk_values(values: &[Value]) -> String {
     values
@@ -22,28 +25,46 @@ pub fn fingerprint_pk_values(values: &[Value]) -> String {
 fn fingerprint_one_value(v: &Value) -> String {
     match v {
         Value::Bool(Some(b)) => format!("bool:{b}"),
-        Value::Bool(None) => "bool:null".to_string(),
-        Value::TinyInt(Some(i)) => format!("ti:{i}"),
-        Value::TinyInt(None) => "ti:null".to_string(),
-        Value::SmallInt(Some(i)) => format!("si:{i}"),
-        Value::SmallInt(None) => "si:null".to_string(),
+        Value::TinyInt(Some(i)) => format!("i:{i}"),
+        Value::SmallInt(Some(i)) => format!("i:{i}"),
         Value::Int(Some(i)) => format!("i:{i}"),
-        Value::Int(None) => "i:null".to_string(),
-        Value::BigInt(Some(i)) => format!("bi:{i}"),
-        Value::BigInt(None) => "bi:null".to_string(),
+        Value::BigInt(Some(i)) => format!("i:{i}"),
+        Value::TinyUnsigned(Some(i)) => format!("i:{i}"),
+        Value::SmallUnsigned(Some(i)) => format!("i:{i}"),
+        Value::Unsigned(Some(i)) => format!("i:{i}"),
+        Value::BigUnsigned(Some(i)) => format!("i:{i}"),
         Value::Float(Some(f)) => format!("f:{f:?}"),
-        Value::Float(None) => "f:null".to_string(),
         Value::Double(Some(d)) => format!("d:{d:?}"),
-        Value::Double(None) => "d:null".to_string(),
         Value::String(Some(s)) => format!("s:{}", s.escape_debug()),
-        Value::String(None) => "s:null".to_string(),
+        Value::Char(Some(c)) => format!("c:{}", c.escape_debug()),
+        Value::Bytes(Some(b)) => b.iter().fold("b:".to_string(), |mut out, byte| {
+            out.push_str(&format!("{byte:02x}"));
+            out
+        }),
+        Value::Json(Some(j)) => format!("j:{j}"),
         Value::Uuid(Some(u)) => format!("u:{u}"),
-        Value::Uuid(None) => "u:null".to_string(),
+        Value::Decimal(Some(d)) => format!("dec:{}", d.normalize()),
+        Value::ChronoDate(Some(d)) => format!("date:{d}"),
+        Value::ChronoTime(Some(t)) => format!("time:{t}"),
+        Value::ChronoDateTime(Some(dt)) => format!("ts:{dt}"),
+        Value::ChronoDateTimeUtc(Some(dt)) => format!("tstz:{}", dt.naive_utc()),
+        Value::ChronoDateTimeLocal(Some(dt)) => format!("tstz:{}", dt.naive_utc()),
+        Value::ChronoDateTimeWithTimeZone(Some(dt)) => format!("tstz:{}", dt.naive_utc()),
+        Value::Array(_, Some(items)) => format!(
+            "a:[{}]",
+            items
+                .iter()
+                .map(fingerprint_one_value)
+                .collect::<Vec<_>>()
+                .join(",")
+        ),
+        v if crate::value::types::is_null(v) => "null".to_string(),
         _ => format!("dbg:{v:?}"),
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -58,4 +79,52 @@ mod tests {
         let b = fingerprint_pk_values(&[Value::Int(Some(2)), Value::Int(Some(1))]);
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn fingerprint_ignores_integer_width_but_not_type() {
+        assert_eq!(
+            fingerprint_pk_values(&[Value::BigInt(Some(7))]),
+            fingerprint_pk_values(&[Value::Int(Some(7))])
+        );
+        assert_ne!(
+            fingerprint_pk_values(&[Value::Int(Some(7))]),
+            fingerprint_pk_values(&[Value::String(Some("7".into()))])
+        );
+        assert_eq!(fingerprint_pk_values(&[Value::BigInt(None)]), "null");
+    }
+
+    #[test]
+    fn fingerprint_encodes_every_value_type_explicitly() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+        let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let noon = day.and_hms_opt(12, 0, 0).unwrap();
+        let cases = [
+            (Value::Bool(Some(true)), "bool:true"),
+            (Value::Char(Some('x')), "c:x"),
+            (Value::Bytes(Some(vec![0x01, 0xab])), "b:01ab"),
+            (
+                Value::Decimal(Some(rust_decimal::Decimal::new(1250, 2))),
+                "dec:12.5",
+            ),
+            (Value::ChronoDate(Some(day)), "date:2024-02-29"),
+            (Value::ChronoDateTime(Some(noon)), "ts:2024-02-29 12:00:00"),
+            (
+                Value::ChronoDateTimeUtc(Some(Utc.from_utc_datetime(&noon))),
+                "tstz:2024-02-29 12:00:00",
+            ),
+            (
+                Value::ChronoDateTimeWithTimeZone(Some(
+                    FixedOffset::east_opt(3600)
+                        .unwrap()
+                        .from_local_datetime(&noon)
+                        .unwrap(),
+                )),
+                "tstz:2024-02-29 11:00:00",
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(fingerprint_pk_values(&[value]), expected);
+        }
+    }
 }
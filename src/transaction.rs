@@ -7,12 +7,21 @@
 //! - Transaction isolation levels
 //! - Nested transaction support (savepoints)
 //! - Commit/rollback operations
-
+//! - Closure-scoped transactions with [`RetryPolicy`] (see
+//!   [`MayPostgresExecutor::transaction`](crate::MayPostgresExecutor::transaction) and
+//!   [`PooledLifeExecutor::transaction`](crate::PooledLifeExecutor::transaction))
+
+use crate::active_model::ActiveModelError;
+use crate::cache::write_through::PendingInvalidations;
+use crate::cache::CacheProvider;
+use crate::db_error::DbErrorKind;
 use crate::executor::{LifeError, LifeExecutor};
 use may_postgres::types::ToSql;
 use may_postgres::{Client, Error as PostgresError, Row};
 use std::fmt;
-use std::time::Instant;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "tracing")]
 use crate::metrics::tracing_helpers;
@@ -35,7 +44,7 @@ pub enum IsolationLevel {
 impl IsolationLevel {
     /// Convert to `PostgreSQL` SQL syntax
     #[allow(clippy::wrong_self_convention)]
-    fn to_sql(self) -> &'static str {
+    pub(crate) fn to_sql(self) -> &'static str {
         match self {
             IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
             IsolationLevel::ReadCommitted => "READ COMMITTED",
@@ -88,7 +97,7 @@ impl From<PostgresError> for TransactionError {
 impl From<TransactionError> for LifeError {
     fn from(err: TransactionError) -> Self {
         match err {
-            TransactionError::PostgresError(e) => LifeError::PostgresError(e),
+            TransactionError::PostgresError(e) => LifeError::from(e),
             TransactionError::TransactionClosed => {
                 LifeError::Other("Transaction closed".to_string())
             }
@@ -99,6 +108,145 @@ impl From<TransactionError> for LifeError {
     }
 }
 
+/// How often a closure-scoped transaction is re-run after a serialization failure (`40001`) or
+/// deadlock (`40P01`).
+///
+/// Attempt `n` (1-based retry count) waits `initial_backoff * 2^(n-1)`, capped at `max_backoff`,
+/// before re-running the closure from a fresh `BEGIN`. The sleep yields the coroutine under `may`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one. `1` disables retry.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for any single delay.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Run once; retryable errors are returned to the caller.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Up to `max_attempts` attempts with the default backoff (10ms doubling, capped at 1s).
+    pub fn attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Replace the backoff bounds.
+    #[must_use]
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Delay before retry number `retry` (1-based).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let shift = retry.saturating_sub(1).min(31);
+        self.initial_backoff
+            .saturating_mul(1u32 << shift)
+            .min(self.max_backoff)
+    }
+
+    /// Call `attempt` until it succeeds, fails with a non-retryable error, or attempts run out.
+    pub(crate) fn run<T, E: RetryableError>(
+        &self,
+        mut attempt: impl FnMut() -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut tries = 1;
+        loop {
+            match attempt() {
+                Err(e) if e.is_retryable() && tries < self.max_attempts => {
+                    let delay = self.backoff(tries);
+                    log::debug!(
+                        "lifeguard transaction: retryable failure on attempt {tries}, retrying in {delay:?}"
+                    );
+                    if !delay.is_zero() {
+                        may::coroutine::sleep(delay);
+                    }
+                    tries += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, 10ms initial backoff, 1s cap.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Errors a transaction closure may return. [`RetryPolicy`] re-runs the closure only when this
+/// reports `true` (SQLSTATE `40001` / `40P01`).
+pub trait RetryableError {
+    /// Whether re-running the whole transaction may succeed.
+    fn is_retryable(&self) -> bool;
+}
+
+impl RetryableError for LifeError {
+    fn is_retryable(&self) -> bool {
+        self.db_error_kind().is_some_and(DbErrorKind::is_retryable)
+    }
+}
+
+impl RetryableError for ActiveModelError {
+    fn is_retryable(&self) -> bool {
+        self.db_error_kind().is_some_and(DbErrorKind::is_retryable)
+    }
+}
+
+/// Open transaction or savepoint that [`run_in_scope`] can finish.
+pub(crate) trait TransactionScope: Sized {
+    fn commit_scope(self) -> Result<(), LifeError>;
+    fn rollback_scope(self) -> Result<(), LifeError>;
+}
+
+/// Run `f` against an open scope: commit on `Ok`, roll back on `Err` or panic (the panic is
+/// resumed after the rollback).
+pub(crate) fn run_in_scope<S, T, E>(
+    mut scope: S,
+    f: impl FnOnce(&mut S) -> Result<T, E>,
+) -> Result<T, E>
+where
+    S: TransactionScope,
+    E: From<LifeError>,
+{
+    match catch_unwind(AssertUnwindSafe(|| f(&mut scope))) {
+        Ok(Ok(value)) => {
+            scope.commit_scope()?;
+            Ok(value)
+        }
+        Ok(Err(error)) => {
+            if let Err(rollback_error) = scope.rollback_scope() {
+                log::warn!("lifeguard transaction: rollback after error failed: {rollback_error}");
+            }
+            Err(error)
+        }
+        Err(panic) => {
+            if let Err(rollback_error) = scope.rollback_scope() {
+                log::error!("lifeguard transaction: rollback after panic failed: {rollback_error}");
+            }
+            resume_unwind(panic)
+        }
+    }
+}
+
 /// A database transaction
 ///
 /// Transactions provide atomicity, consistency, isolation, and durability (ACID)
@@ -133,6 +281,11 @@ pub struct Transaction {
     depth: u32,
     closed: bool,
     session_context: Option<crate::executor::SessionContext>,
+    cache: Option<Arc<dyn CacheProvider>>,
+    /// Cache keys evicted after the outermost `COMMIT`; shared with savepoints.
+    pending_invalidations: PendingInvalidations,
+    /// Queue length when this savepoint opened (`0` for the outer transaction).
+    pending_mark: usize,
 }
 
 impl Transaction {
@@ -171,6 +324,9 @@ impl Transaction {
             depth: 0,
             closed: false,
             session_context: None,
+            cache: None,
+            pending_invalidations: PendingInvalidations::default(),
+            pending_mark: 0,
         })
     }
 
@@ -236,9 +392,19 @@ impl Transaction {
             depth: 0,
             closed: false,
             session_context: ctx,
+            cache: None,
+            pending_invalidations: PendingInvalidations::default(),
+            pending_mark: 0,
         })
     }
 
+    /// Use `cache` for write-through evictions deferred to commit (inherited from the executor
+    /// that began this transaction).
+    pub(crate) fn with_cache_provider(mut self, cache: Option<Arc<dyn CacheProvider>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
     /// Start a nested transaction (savepoint)
     ///
     /// Nested transactions are implemented using `PostgreSQL` savepoints.
@@ -276,6 +442,10 @@ impl Transaction {
     /// - The transaction is already closed
     /// - The savepoint creation fails
     pub fn begin_nested(&mut self) -> Result<Transaction, TransactionError> {
+        self.savepoint()
+    }
+
+    fn savepoint(&self) -> Result<Transaction, TransactionError> {
         if self.closed {
             return Err(TransactionError::TransactionClosed);
         }
@@ -291,9 +461,32 @@ impl Transaction {
             depth: self.depth + 1,
             closed: false,
             session_context: self.session_context.clone(),
+            cache: self.cache.clone(),
+            pending_invalidations: self.pending_invalidations.clone(),
+            pending_mark: self.pending_invalidations.mark(),
         })
     }
 
+    /// Run `f` inside a savepoint of this transaction.
+    ///
+    /// The savepoint is released when `f` returns `Ok` and rolled back to when it returns `Err`
+    /// or panics; the outer transaction stays open either way. Retry is not attempted here — a
+    /// serialization failure aborts the whole transaction, so propagate it and let the outer
+    /// [`MayPostgresExecutor::transaction`](crate::MayPostgresExecutor::transaction) re-run.
+    ///
+    /// # Errors
+    ///
+    /// Returns the closure's error, or a [`LifeError`] if the savepoint cannot be created or
+    /// released.
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Transaction) -> Result<T, E>,
+        E: From<LifeError>,
+    {
+        let nested = self.begin_nested().map_err(LifeError::from)?;
+        run_in_scope(nested, f)
+    }
+
     /// Commit the transaction
     ///
     /// All changes made within the transaction are permanently saved to the database.
@@ -315,6 +508,7 @@ impl Transaction {
             self.client
                 .execute("COMMIT", &[])
                 .map_err(TransactionError::from)?;
+            self.pending_invalidations.flush(self.cache.as_deref());
         } else {
             // Nested transaction: release savepoint
             let savepoint_name = format!("sp_{}", self.depth);
@@ -349,6 +543,7 @@ impl Transaction {
             self.client
                 .execute("ROLLBACK", &[])
                 .map_err(TransactionError::from)?;
+            self.pending_invalidations.truncate(0);
         } else {
             // Nested transaction: rollback to savepoint
             let savepoint_name = format!("sp_{}", self.depth);
@@ -356,6 +551,7 @@ impl Transaction {
             self.client
                 .execute(rollback_sql.as_str(), &[])
                 .map_err(TransactionError::from)?;
+            self.pending_invalidations.truncate(self.pending_mark);
         }
 
         self.closed = true;
@@ -373,6 +569,16 @@ impl Transaction {
     }
 }
 
+impl TransactionScope for Transaction {
+    fn commit_scope(self) -> Result<(), LifeError> {
+        self.commit().map_err(LifeError::from)
+    }
+
+    fn rollback_scope(self) -> Result<(), LifeError> {
+        self.rollback().map_err(LifeError::from)
+    }
+}
+
 impl LifeExecutor for Transaction {
     fn execute(&self, query: &str, params: &[&dyn ToSql]) -> Result<u64, LifeError> {
         if self.closed {
@@ -386,7 +592,7 @@ impl LifeExecutor for Transaction {
         let result = self.client.execute(query, params).map_err(|e| {
             #[cfg(feature = "metrics")]
             METRICS.record_query_error(None);
-            LifeError::PostgresError(e)
+            LifeError::from(e)
         });
 
         let duration = start.elapsed();
@@ -408,7 +614,7 @@ impl LifeExecutor for Transaction {
         let result = self.client.query_one(query, params).map_err(|e| {
             #[cfg(feature = "metrics")]
             METRICS.record_query_error(None);
-            LifeError::PostgresError(e)
+            LifeError::from(e)
         });
 
         let duration = start.elapsed();
@@ -430,7 +636,7 @@ impl LifeExecutor for Transaction {
         let result = self.client.query(query, params).map_err(|e| {
             #[cfg(feature = "metrics")]
             METRICS.record_query_error(None);
-            LifeError::PostgresError(e)
+            LifeError::from(e)
         });
 
         let duration = start.elapsed();
@@ -439,12 +645,121 @@ impl LifeExecutor for Transaction {
 
         result
     }
+
+    fn cache_provider(&self) -> Option<Arc<dyn CacheProvider>> {
+        self.cache.clone()
+    }
+
+    fn in_transaction(&self) -> bool {
+        true
+    }
+
+    fn invalidate_cache_key(&self, key: &str) {
+        if self.cache.is_some() {
+            self.pending_invalidations.push(key);
+        }
+    }
+
+    fn atomically(
+        &self,
+        work: &mut dyn FnMut(&dyn LifeExecutor) -> Result<(), LifeError>,
+    ) -> Result<(), LifeError> {
+        let savepoint = self.savepoint().map_err(LifeError::from)?;
+        run_in_scope(savepoint, |sp| work(&*sp))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[derive(Debug)]
+    struct Scope<'a> {
+        log: &'a std::cell::RefCell<Vec<&'static str>>,
+    }
+
+    impl TransactionScope for Scope<'_> {
+        fn commit_scope(self) -> Result<(), LifeError> {
+            self.log.borrow_mut().push("commit");
+            Ok(())
+        }
+
+        fn rollback_scope(self) -> Result<(), LifeError> {
+            self.log.borrow_mut().push("rollback");
+            Ok(())
+        }
+    }
+
+    fn serialization_failure() -> LifeError {
+        LifeError::SerializationFailure(crate::db_error::DbErrorInfo::new(
+            "40001",
+            "could not serialize access",
+        ))
+    }
+
+    #[test]
+    fn test_run_in_scope_commits_on_ok_and_rolls_back_on_err() {
+        let log = std::cell::RefCell::new(Vec::new());
+        let ok: Result<i32, LifeError> = run_in_scope(Scope { log: &log }, |_| Ok(1));
+        assert_eq!(ok.ok(), Some(1));
+        let err: Result<i32, LifeError> =
+            run_in_scope(Scope { log: &log }, |_| Err(LifeError::Other("no".into())));
+        assert!(err.is_err());
+        assert_eq!(*log.borrow(), vec!["commit", "rollback"]);
+    }
+
+    #[test]
+    fn test_run_in_scope_rolls_back_on_panic() {
+        let log = std::cell::RefCell::new(Vec::new());
+        let caught = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let _: Result<(), LifeError> =
+                run_in_scope(Scope { log: &log }, |_| std::panic::panic_any("boom"));
+        }));
+        assert!(caught.is_err());
+        assert_eq!(*log.borrow(), vec!["rollback"]);
+    }
+
+    #[test]
+    fn test_retry_policy_retries_only_retryable_errors() {
+        let policy = RetryPolicy::attempts(3).with_backoff(Duration::ZERO, Duration::ZERO);
+        let mut calls = 0;
+        let result: Result<(), LifeError> = policy.run(|| {
+            calls += 1;
+            Err(serialization_failure())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result: Result<u32, LifeError> = policy.run(|| {
+            calls += 1;
+            if calls < 2 {
+                Err(serialization_failure())
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.ok(), Some(2));
+
+        let mut calls = 0;
+        let _: Result<(), LifeError> = policy.run(|| {
+            calls += 1;
+            Err(LifeError::Other("not retryable".into()))
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_doubles_and_caps() {
+        let policy = RetryPolicy::default()
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(35));
+        assert_eq!(policy.backoff(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff(2), Duration::from_millis(20));
+        assert_eq!(policy.backoff(3), Duration::from_millis(35));
+        assert_eq!(RetryPolicy::none().max_attempts, 1);
+        assert_eq!(RetryPolicy::attempts(0).max_attempts, 1);
+    }
+
     #[test]
     fn test_isolation_level_to_sql() {
         assert_eq!(IsolationLevel::ReadUncommitted.to_sql(), "READ UNCOMMITTED");
@@ -0,0 +1,193 @@
+//! Native PostgreSQL `ENUM` types mapped to fieldless Rust enums.
+//!
+//! `#[derive(LifeEnum)]` implements [`LifeEnum`] plus `ToSql` / `FromSql`, `ValueType` and
+//! `From<E> for sea_query::Value` for a fieldless enum:
+//!
+//! ```ignore
+//! #[derive(LifeEnum, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+//! #[enum_name = "order_status"]
+//! pub enum OrderStatus {
+//!     Pending,
+//!     #[enum_value = "in_transit"]
+//!     Shipped,
+//!     Delivered,
+//! }
+//! ```
+//!
+//! Each variant maps to one label (`snake_case` of the variant unless `#[enum_value]` is given);
+//! the type name defaults to the `snake_case` enum name. In queries the enum is a
+//! `Value::String` holding the label, which binds to the enum column (see
+//! [`TextParam`](crate::value::TextParam)); reading a row decodes the label back with
+//! [`LifeEnum::from_label`] and fails on labels the Rust enum does not know.
+//!
+//! `LifeModel` fields of an enum type (or `Option` of one) are marked `#[life_enum]`: the column
+//! type becomes the enum type, and `lifeguard-migrate` emits `CREATE TYPE … AS ENUM` for it
+//! before the tables that use it.
+
+use may_postgres::types::{IsNull, Kind, Type, WrongType};
+
+use bytes::BytesMut;
+
+/// Boxed error returned by the generated `ToSql` / `FromSql` impls.
+type SqlError = Box<dyn std::error::Error + Sync + Send>;
+
+/// A fieldless Rust enum stored as a PostgreSQL `ENUM` (implemented by `#[derive(LifeEnum)]`)
+pub trait LifeEnum: Sized + Copy + 'static {
+    /// PostgreSQL type name, optionally schema-qualified (`billing.order_status`)
+    const TYPE_NAME: &'static str;
+
+    /// Every variant, in declaration order (the enum's sort order in PostgreSQL)
+    const VARIANTS: &'static [Self];
+
+    /// The PostgreSQL label of this variant
+    fn to_label(self) -> &'static str;
+
+    /// The variant with PostgreSQL label `label`
+    #[must_use]
+    fn from_label(label: &str) -> Option<Self> {
+        Self::VARIANTS
+            .iter()
+            .copied()
+            .find(|variant| variant.to_label() == label)
+    }
+
+    /// All labels, in declaration order
+    #[must_use]
+    fn labels() -> Vec<String> {
+        Self::VARIANTS
+            .iter()
+            .map(|variant| variant.to_label().to_string())
+            .collect()
+    }
+}
+
+/// Whether `ty` is the enum type `type_name` (compared on the schema too when it is qualified).
+fn is_enum_type(ty: &Type, type_name: &str) -> bool {
+    if !matches!(ty.kind(), Kind::Enum(_)) {
+        return false;
+    }
+    match type_name.rsplit_once('.') {
+        Some((schema, name)) => ty.schema() == schema && ty.name() == name,
+        None => ty.name() == type_name,
+    }
+}
+
+/// `ToSql::accepts` / `FromSql::accepts` for a [`LifeEnum`]: its own enum type, or a text column.
+#[doc(hidden)]
+#[must_use]
+pub fn enum_accepts<E: LifeEnum>(ty: &Type) -> bool {
+    is_enum_type(ty, E::TYPE_NAME) || <&str as may_postgres::types::ToSql>::accepts(ty)
+}
+
+/// `ToSql::to_sql` for a [`LifeEnum`]: enums and text share the label's UTF-8 encoding.
+///
+/// # Errors
+///
+/// Never fails; the signature matches `ToSql`.
+#[doc(hidden)]
+pub fn enum_to_sql<E: LifeEnum>(
+    value: E,
+    _ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, SqlError> {
+    out.extend_from_slice(value.to_label().as_bytes());
+    Ok(IsNull::No)
+}
+
+/// `ToSql::to_sql_checked` for a [`LifeEnum`].
+///
+/// # Errors
+///
+/// Returns `WrongType` if `ty` is neither `E`'s enum type nor a text type.
+#[doc(hidden)]
+pub fn enum_to_sql_checked<E: LifeEnum>(
+    value: E,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, SqlError> {
+    if !enum_accepts::<E>(ty) {
+        return Err(Box::new(WrongType::new::<E>(ty.clone())));
+    }
+    enum_to_sql(value, ty, out)
+}
+
+/// `FromSql::from_sql` for a [`LifeEnum`].
+///
+/// # Errors
+///
+/// Returns an error if the bytes are not UTF-8 or not one of `E`'s labels.
+#[doc(hidden)]
+pub fn enum_from_sql<E: LifeEnum>(ty: &Type, raw: &[u8]) -> Result<E, SqlError> {
+    let label = std::str::from_utf8(raw)?;
+    E::from_label(label).ok_or_else(|| {
+        format!(
+            "unknown label {label:?} for {} (column type {ty})",
+            E::TYPE_NAME
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)] // test-only unwraps
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Mood {
+        Happy,
+        Sad,
+    }
+
+    impl LifeEnum for Mood {
+        const TYPE_NAME: &'static str = "mood";
+        const VARIANTS: &'static [Self] = &[Self::Happy, Self::Sad];
+
+        fn to_label(self) -> &'static str {
+            match self {
+                Self::Happy => "happy",
+                Self::Sad => "sad",
+            }
+        }
+    }
+
+    fn enum_type(schema: &str, name: &str) -> Type {
+        Type::new(
+            name.to_string(),
+            16_384,
+            Kind::Enum(vec!["happy".into(), "sad".into()]),
+            schema.to_string(),
+        )
+    }
+
+    #[test]
+    fn labels_round_trip() {
+        assert_eq!(Mood::labels(), ["happy", "sad"]);
+        assert_eq!(Mood::from_label("sad"), Some(Mood::Sad));
+        assert_eq!(Mood::from_label("Sad"), None);
+    }
+
+    #[test]
+    fn accepts_its_enum_type_and_text_only() {
+        assert!(enum_accepts::<Mood>(&enum_type("public", "mood")));
+        assert!(enum_accepts::<Mood>(&Type::TEXT));
+        assert!(!enum_accepts::<Mood>(&enum_type("public", "other")));
+        assert!(!enum_accepts::<Mood>(&Type::INT4));
+    }
+
+    #[test]
+    fn encodes_and_decodes_labels() {
+        let ty = enum_type("public", "mood");
+        let mut buf = BytesMut::new();
+        enum_to_sql_checked(Mood::Sad, &ty, &mut buf).expect("encode");
+        assert_eq!(&buf[..], b"sad");
+        assert_eq!(
+            enum_from_sql::<Mood>(&ty, b"happy").expect("decode"),
+            Mood::Happy
+        );
+        let err = enum_from_sql::<Mood>(&ty, b"angry").expect_err("unknown label");
+        assert!(err.to_string().contains("\"angry\""), "{err}");
+        assert!(enum_to_sql_checked(Mood::Sad, &Type::INT4, &mut buf).is_err());
+    }
+}
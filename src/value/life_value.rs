@@ -0,0 +1,48 @@
+//! Domain newtypes stored as a single column.
+//!
+//! `#[derive(LifeValue)]` implements [`LifeValue`] plus `ToSql` / `FromSql`, `ValueType` and
+//! `From<T> for sea_query::Value` for a single-field tuple struct, delegating each to the
+//! wrapped type:
+//!
+//! ```ignore
+//! #[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! #[column_type = "VARCHAR(320)"]
+//! pub struct Email(String);
+//!
+//! #[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! pub struct OrderId(uuid::Uuid);
+//! ```
+//!
+//! The column type is inferred from the wrapped type the same way `LifeModel` infers it for a
+//! plain field (`Uuid` → `UUID`, `Decimal` → `NUMERIC(19, 4)`, …); `#[column_type = "..."]`
+//! overrides it. In queries the newtype is the wrapped type's `sea_query::Value`, so
+//! [`ColumnTrait`](crate::ColumnTrait) comparisons, pool parameters
+//! ([`OwnedParam`](crate::OwnedParam)) and `#[validate(...)]` rules see exactly what they would
+//! for the bare type.
+//!
+//! `LifeModel` fields of a newtype (or `Option` of one) are marked `#[life_value]`: they convert
+//! through [`ValueType`], decode rows with the newtype's `FromSql`, and the column type becomes
+//! [`LifeValue::COLUMN_TYPE`] unless the field sets its own `#[column_type]`.
+
+use may_postgres::types::{FromSql, ToSql};
+use sea_query::Value;
+
+use crate::value::ValueType;
+
+/// A newtype over one column value (implemented by `#[derive(LifeValue)]`)
+pub trait LifeValue: ValueType + Into<Value> + ToSql + for<'a> FromSql<'a> {
+    /// The wrapped type, whose `sea_query::Value` variant the newtype uses
+    type Inner: ValueType;
+
+    /// PostgreSQL column type (`TEXT`, `UUID`, `NUMERIC(19, 4)`, …)
+    const COLUMN_TYPE: &'static str;
+
+    /// Wrap `inner`
+    fn from_inner(inner: Self::Inner) -> Self;
+
+    /// The wrapped value
+    fn as_inner(&self) -> &Self::Inner;
+
+    /// Unwrap into the wrapped value
+    fn into_inner(self) -> Self::Inner;
+}
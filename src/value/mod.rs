@@ -12,7 +12,17 @@
 //! - **`IntoValueTuple`** - Convert composite keys to `ValueTuple`
 //! - **`FromValueTuple`** - Convert `ValueTuple` to composite keys
 //! - **`TryFromU64`** - Safe conversion from `u64` for primary keys
+//! - **`LifeEnum`** - Fieldless Rust enums stored as PostgreSQL `ENUM` types
+//! - **`LifeValue`** - Single-field newtypes (`Email(String)`, `OrderId(Uuid)`) stored as their inner type
+//! - **`RangeElement`** - Element types of PostgreSQL ranges ([`PgRange`])
+//!
+//! The [`scalar`] module adds `interval`, `inet` / `cidr`, `macaddr`, `citext` and bit string
+//! column types ([`PgInterval`], [`PgCidr`], [`PgMacAddr`], [`CiText`], [`PgBitString`]).
 
+pub mod life_enum;
+pub mod life_value;
+pub mod range;
+pub mod scalar;
 pub mod text_param;
 pub mod try_getable;
 pub mod tuple;
@@ -22,6 +32,13 @@ pub mod u64;
 #[cfg(test)]
 mod integration_tests;
 
+// Buffer type in the `ToSql` impls `#[derive(LifeEnum)]` / `#[derive(LifeValue)]` generate.
+#[doc(hidden)]
+pub use bytes::BytesMut;
+pub use life_enum::LifeEnum;
+pub use life_value::LifeValue;
+pub use range::{PgRange, RangeElement};
+pub use scalar::{CiText, PgBitString, PgCidr, PgInterval, PgMacAddr};
 pub use text_param::TextParam;
 pub use try_getable::{TryGetable, TryGetableMany, ValueExtractionError};
 pub use tuple::{FromValueTuple, IntoValueTuple};
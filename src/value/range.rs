@@ -0,0 +1,610 @@
+//! PostgreSQL range types as [`PgRange<T>`].
+//!
+//! | Rust element ([`RangeElement`]) | Range type |
+//! |---|---|
+//! | `i32` | `int4range` |
+//! | `i64` | `int8range` |
+//! | `rust_decimal::Decimal` | `numrange` |
+//! | `chrono::NaiveDate` | `daterange` |
+//! | `chrono::NaiveDateTime` | `tsrange` |
+//! | `chrono::DateTime<Utc>` | `tstzrange` |
+//!
+//! Reading a row decodes the binary range directly (`FromSql`). In queries a range is a
+//! `Value::String` holding its literal (`[2024-01-01,2024-01-08)`, `empty`), like a
+//! [`LifeEnum`](crate::value::LifeEnum) label; [`TextParam`](crate::value::TextParam) encodes the
+//! literal for the range column it is bound to, so inserts, updates and the
+//! [`ColumnTrait`](crate::ColumnTrait) range operators (`overlaps`, `contains`, `contained_by`,
+//! `adjacent_to`) all take a `PgRange<T>` directly.
+//!
+//! `LifeModel` fields of type `PgRange<T>` (or `Option` of one) get the matching range column type.
+
+use std::ops::Bound;
+
+use bytes::BytesMut;
+use may_postgres::types::{FromSql, IsNull, Kind, ToSql, Type};
+use sea_query::Value;
+
+use crate::value::ValueType;
+
+/// Boxed error returned by the `ToSql` / `FromSql` impls.
+type SqlError = Box<dyn std::error::Error + Sync + Send>;
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// Element type of a PostgreSQL range (see the module table)
+pub trait RangeElement: Sized + Clone + ToSql + for<'a> FromSql<'a> {
+    /// The range type over this element (`tstzrange` for `DateTime<Utc>`)
+    const RANGE_TYPE: &'static str;
+
+    /// The element's own PostgreSQL type (`timestamptz`), used to cast a bare element
+    const ELEMENT_TYPE: &'static str;
+
+    /// Text of one bound in a range literal
+    fn to_range_text(&self) -> String;
+
+    /// Parse one (unquoted) bound of a range literal
+    fn from_range_text(text: &str) -> Option<Self>;
+}
+
+impl RangeElement for i32 {
+    const RANGE_TYPE: &'static str = "int4range";
+    const ELEMENT_TYPE: &'static str = "int4";
+
+    fn to_range_text(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+}
+
+impl RangeElement for i64 {
+    const RANGE_TYPE: &'static str = "int8range";
+    const ELEMENT_TYPE: &'static str = "int8";
+
+    fn to_range_text(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+}
+
+impl RangeElement for rust_decimal::Decimal {
+    const RANGE_TYPE: &'static str = "numrange";
+    const ELEMENT_TYPE: &'static str = "numeric";
+
+    fn to_range_text(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+}
+
+impl RangeElement for chrono::NaiveDate {
+    const RANGE_TYPE: &'static str = "daterange";
+    const ELEMENT_TYPE: &'static str = "date";
+
+    fn to_range_text(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        chrono::NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").ok()
+    }
+}
+
+impl RangeElement for chrono::NaiveDateTime {
+    const RANGE_TYPE: &'static str = "tsrange";
+    const ELEMENT_TYPE: &'static str = "timestamp";
+
+    fn to_range_text(&self) -> String {
+        self.format("%Y-%m-%d %H:%M:%S%.f").to_string()
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        let text = text.trim();
+        chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+            .ok()
+    }
+}
+
+impl RangeElement for chrono::DateTime<chrono::Utc> {
+    const RANGE_TYPE: &'static str = "tstzrange";
+    const ELEMENT_TYPE: &'static str = "timestamptz";
+
+    fn to_range_text(&self) -> String {
+        self.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+
+    fn from_range_text(text: &str) -> Option<Self> {
+        let text = text.trim();
+        chrono::DateTime::parse_from_rfc3339(text)
+            .or_else(|_| chrono::DateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f%#z"))
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Utc))
+    }
+}
+
+/// A PostgreSQL range value: empty, or between two bounds where `Bound::Unbounded` is an
+/// infinite side.
+///
+/// Discrete ranges (`int4range`, `int8range`, `daterange`) come back from the server in canonical
+/// `[lower, upper)` form, so compare against [`PgRange::closed_open`] values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PgRange<T> {
+    /// The empty range (`'empty'`)
+    Empty,
+    /// A non-empty range
+    Bounds {
+        /// Lower bound
+        lower: Bound<T>,
+        /// Upper bound
+        upper: Bound<T>,
+    },
+}
+
+impl<T> PgRange<T> {
+    /// Range between `lower` and `upper`
+    #[must_use]
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        Self::Bounds { lower, upper }
+    }
+
+    /// `[lower, upper)`, the canonical form PostgreSQL uses for discrete ranges
+    #[must_use]
+    pub fn closed_open(lower: T, upper: T) -> Self {
+        Self::new(Bound::Included(lower), Bound::Excluded(upper))
+    }
+
+    /// `[lower, upper]`
+    #[must_use]
+    pub fn closed(lower: T, upper: T) -> Self {
+        Self::new(Bound::Included(lower), Bound::Included(upper))
+    }
+
+    /// `[lower,)`: everything from `lower` on
+    #[must_use]
+    pub fn at_least(lower: T) -> Self {
+        Self::new(Bound::Included(lower), Bound::Unbounded)
+    }
+
+    /// The empty range
+    #[must_use]
+    pub fn empty() -> Self {
+        Self::Empty
+    }
+
+    /// Whether this is the empty range
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Lower bound, `None` for the empty range
+    #[must_use]
+    pub fn lower(&self) -> Option<Bound<&T>> {
+        match self {
+            Self::Empty => None,
+            Self::Bounds { lower, .. } => Some(lower.as_ref()),
+        }
+    }
+
+    /// Upper bound, `None` for the empty range
+    #[must_use]
+    pub fn upper(&self) -> Option<Bound<&T>> {
+        match self {
+            Self::Empty => None,
+            Self::Bounds { upper, .. } => Some(upper.as_ref()),
+        }
+    }
+}
+
+impl<T: RangeElement> PgRange<T> {
+    /// PostgreSQL range literal: `empty`, `[1,5)`, `(,2024-01-01]`; bounds are quoted when needed.
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        let Self::Bounds { lower, upper } = self else {
+            return "empty".to_string();
+        };
+        let mut out = String::new();
+        out.push(match lower {
+            Bound::Included(_) => '[',
+            Bound::Excluded(_) | Bound::Unbounded => '(',
+        });
+        if let Bound::Included(v) | Bound::Excluded(v) = lower {
+            push_bound_text(&mut out, &v.to_range_text());
+        }
+        out.push(',');
+        if let Bound::Included(v) | Bound::Excluded(v) = upper {
+            push_bound_text(&mut out, &v.to_range_text());
+        }
+        out.push(match upper {
+            Bound::Included(_) => ']',
+            Bound::Excluded(_) | Bound::Unbounded => ')',
+        });
+        out
+    }
+
+    /// Parse a range literal (the format of [`PgRange::to_literal`] and PostgreSQL's own output).
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        let (lower, upper) = match parse_literal(literal)? {
+            None => return Some(Self::Empty),
+            Some(bounds) => bounds,
+        };
+        let convert = |bound: Bound<String>| -> Option<Bound<T>> {
+            Some(match bound {
+                Bound::Included(text) => Bound::Included(T::from_range_text(&text)?),
+                Bound::Excluded(text) => Bound::Excluded(T::from_range_text(&text)?),
+                Bound::Unbounded => Bound::Unbounded,
+            })
+        };
+        Some(Self::new(convert(lower)?, convert(upper)?))
+    }
+}
+
+/// Append a bound, double-quoted when it contains characters the range parser treats specially.
+fn push_bound_text(out: &mut String, text: &str) {
+    let needs_quotes = text.is_empty()
+        || text
+            .chars()
+            .any(|c| matches!(c, ',' | '(' | ')' | '[' | ']' | '"' | '\\') || c.is_whitespace());
+    if !needs_quotes {
+        out.push_str(text);
+        return;
+    }
+    out.push('"');
+    for c in text.chars() {
+        if matches!(c, '"' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+/// Split a range literal into raw bound texts; `None` inside for `empty`.
+#[allow(clippy::type_complexity)]
+fn parse_literal(literal: &str) -> Option<Option<(Bound<String>, Bound<String>)>> {
+    let literal = literal.trim();
+    if literal.eq_ignore_ascii_case("empty") {
+        return Some(None);
+    }
+    let lower_inclusive = match literal.chars().next()? {
+        '[' => true,
+        '(' => false,
+        _ => return None,
+    };
+    let upper_inclusive = match literal.chars().last()? {
+        ']' => true,
+        ')' => false,
+        _ => return None,
+    };
+    let inner = literal.get(1..literal.len().checked_sub(1)?)?;
+
+    let mut bounds: Vec<Option<String>> = Vec::with_capacity(2);
+    let mut text = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.push(chars.next()?),
+            '"' if in_quotes && chars.as_str().starts_with('"') => {
+                chars.next();
+                text.push('"');
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                quoted = true;
+            }
+            ',' if !in_quotes => {
+                bounds.push((quoted || !text.is_empty()).then(|| std::mem::take(&mut text)));
+                quoted = false;
+            }
+            c => text.push(c),
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    bounds.push((quoted || !text.is_empty()).then_some(text));
+
+    let [lower, upper]: [Option<String>; 2] = bounds.try_into().ok()?;
+    let bound = |text: Option<String>, inclusive: bool| match text {
+        None => Bound::Unbounded,
+        Some(text) if inclusive => Bound::Included(text),
+        Some(text) => Bound::Excluded(text),
+    };
+    Some(Some((
+        bound(lower, lower_inclusive),
+        bound(upper, upper_inclusive),
+    )))
+}
+
+/// Element type of the range type `ty`.
+fn range_element_type(ty: &Type) -> Option<&Type> {
+    match ty.kind() {
+        Kind::Range(element) => Some(element),
+        _ => None,
+    }
+}
+
+impl<T: RangeElement> ToSql for PgRange<T> {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        let element = range_element_type(ty).ok_or_else(|| format!("{ty} is not a range type"))?;
+        let Self::Bounds { lower, upper } = self else {
+            out.extend_from_slice(&[RANGE_EMPTY]);
+            return Ok(IsNull::No);
+        };
+        let mut flags = 0;
+        flags |= match lower {
+            Bound::Included(_) => RANGE_LB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_LB_INF,
+        };
+        flags |= match upper {
+            Bound::Included(_) => RANGE_UB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_UB_INF,
+        };
+        out.extend_from_slice(&[flags]);
+        for bound in [lower, upper] {
+            if let Bound::Included(v) | Bound::Excluded(v) = bound {
+                write_bound(v, element, out)?;
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        range_element_type(ty).is_some_and(<T as ToSql>::accepts)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+/// One length-prefixed bound.
+fn write_bound<T: ToSql>(value: &T, element: &Type, out: &mut BytesMut) -> Result<(), SqlError> {
+    let len_at = out.len();
+    out.extend_from_slice(&[0; 4]);
+    if let IsNull::Yes = value.to_sql(element, out)? {
+        return Err("range bounds cannot be NULL".into());
+    }
+    let len = i32::try_from(out.len() - len_at - 4)?;
+    out[len_at..len_at + 4].copy_from_slice(&len.to_be_bytes());
+    Ok(())
+}
+
+impl<'a, T: RangeElement> FromSql<'a> for PgRange<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        let element = range_element_type(ty).ok_or_else(|| format!("{ty} is not a range type"))?;
+        let (&flags, mut rest) = raw.split_first().ok_or("empty range buffer")?;
+        if flags & RANGE_EMPTY != 0 {
+            return Ok(Self::Empty);
+        }
+        let mut read = |inclusive: u8, infinite: u8| -> Result<Bound<T>, SqlError> {
+            if flags & infinite != 0 {
+                return Ok(Bound::Unbounded);
+            }
+            let value = read_bound(element, &mut rest)?;
+            Ok(if flags & inclusive != 0 {
+                Bound::Included(value)
+            } else {
+                Bound::Excluded(value)
+            })
+        };
+        let lower = read(RANGE_LB_INC, RANGE_LB_INF)?;
+        let upper = read(RANGE_UB_INC, RANGE_UB_INF)?;
+        if !rest.is_empty() {
+            return Err("trailing bytes after range bounds".into());
+        }
+        Ok(Self::new(lower, upper))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        range_element_type(ty).is_some_and(<T as FromSql<'a>>::accepts)
+    }
+}
+
+/// One length-prefixed bound, advancing `buf`.
+fn read_bound<'a, T: FromSql<'a>>(element: &Type, buf: &mut &'a [u8]) -> Result<T, SqlError> {
+    let (len, rest) = buf
+        .split_first_chunk::<4>()
+        .ok_or("truncated range bound length")?;
+    let len = usize::try_from(i32::from_be_bytes(*len)).map_err(|_| "NULL range bound")?;
+    if rest.len() < len {
+        return Err("truncated range bound".into());
+    }
+    let (value, rest) = rest.split_at(len);
+    *buf = rest;
+    T::from_sql(element, value)
+}
+
+/// Encode the range literal `literal` for the range column type `ty` (`TextParam` binds).
+///
+/// # Errors
+///
+/// Returns an error if `ty`'s element type has no [`RangeElement`] or `literal` does not parse.
+pub(crate) fn range_literal_to_sql(
+    literal: &str,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, SqlError> {
+    fn encode<T: RangeElement>(
+        literal: &str,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, SqlError> {
+        PgRange::<T>::from_literal(literal)
+            .ok_or_else(|| format!("{literal:?} is not a valid {ty} literal"))?
+            .to_sql(ty, out)
+    }
+
+    let element = range_element_type(ty).ok_or_else(|| format!("{ty} is not a range type"))?;
+    match *element {
+        Type::INT4 => encode::<i32>(literal, ty, out),
+        Type::INT8 => encode::<i64>(literal, ty, out),
+        Type::NUMERIC => encode::<rust_decimal::Decimal>(literal, ty, out),
+        Type::DATE => encode::<chrono::NaiveDate>(literal, ty, out),
+        Type::TIMESTAMP => encode::<chrono::NaiveDateTime>(literal, ty, out),
+        Type::TIMESTAMPTZ => encode::<chrono::DateTime<chrono::Utc>>(literal, ty, out),
+        _ => Err(format!("string parameters cannot bind to {ty} (element type {element})").into()),
+    }
+}
+
+impl<T: RangeElement> From<PgRange<T>> for Value {
+    fn from(range: PgRange<T>) -> Self {
+        Value::String(Some(range.to_literal()))
+    }
+}
+
+impl<T: RangeElement> ValueType for PgRange<T> {
+    fn into_value(self) -> Value {
+        self.into()
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::String(Some(literal)) => Self::from_literal(&literal),
+            _ => None,
+        }
+    }
+
+    fn null_value() -> Value {
+        Value::String(None)
+    }
+}
+
+impl<T: RangeElement> serde::Serialize for PgRange<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_literal())
+    }
+}
+
+impl<'de, T: RangeElement> serde::Deserialize<'de> for PgRange<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let literal = String::deserialize(deserializer)?;
+        Self::from_literal(&literal).ok_or_else(|| {
+            serde::de::Error::custom(format!("invalid {} literal {literal:?}", T::RANGE_TYPE))
+        })
+    }
+}
+
+impl<T: RangeElement> std::fmt::Display for PgRange<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_literal())
+    }
+}
+
+/// `CAST($n AS <element type>)` for a bare element bound next to a range (`range @> element`):
+/// without it PostgreSQL resolves the parameter as the range type itself.
+pub(crate) fn element_cast_type(value: &Value) -> Option<&'static str> {
+    Some(match value {
+        Value::Int(_) => i32::ELEMENT_TYPE,
+        Value::BigInt(_) => i64::ELEMENT_TYPE,
+        Value::Decimal(_) => rust_decimal::Decimal::ELEMENT_TYPE,
+        Value::ChronoDate(_) => chrono::NaiveDate::ELEMENT_TYPE,
+        Value::ChronoDateTime(_) => chrono::NaiveDateTime::ELEMENT_TYPE,
+        Value::ChronoDateTimeUtc(_)
+        | Value::ChronoDateTimeLocal(_)
+        | Value::ChronoDateTimeWithTimeZone(_) => chrono::DateTime::<chrono::Utc>::ELEMENT_TYPE,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)] // test-only unwraps
+
+    use super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    fn range_type(name: &str, element: Type) -> Type {
+        Type::new(
+            name.to_string(),
+            3_904,
+            Kind::Range(element),
+            "pg_catalog".to_string(),
+        )
+    }
+
+    #[test]
+    fn literals_round_trip() {
+        let r = PgRange::closed_open(1_i64, 5);
+        assert_eq!(r.to_literal(), "[1,5)");
+        assert_eq!(PgRange::<i64>::from_literal("[1,5)"), Some(r));
+        assert_eq!(
+            PgRange::<i64>::from_literal("(,7]"),
+            Some(PgRange::new(Bound::Unbounded, Bound::Included(7)))
+        );
+        assert_eq!(PgRange::<i32>::from_literal("empty"), Some(PgRange::Empty));
+        assert_eq!(PgRange::<i32>::from_literal("[1,2,3)"), None);
+        assert_eq!(PgRange::<i32>::from_literal("[a,2)"), None);
+
+        let start = Utc
+            .with_ymd_and_hms(2024, 3, 1, 9, 0, 0)
+            .single()
+            .expect("ts");
+        let end = Utc
+            .with_ymd_and_hms(2024, 3, 1, 10, 30, 0)
+            .single()
+            .expect("ts");
+        let r = PgRange::closed_open(start, end);
+        assert_eq!(
+            r.to_literal(),
+            "[2024-03-01T09:00:00Z,2024-03-01T10:30:00Z)"
+        );
+        assert_eq!(PgRange::from_literal(&r.to_literal()), Some(r.clone()));
+        // PostgreSQL's own output quotes timestamps.
+        assert_eq!(
+            PgRange::from_literal(r#"["2024-03-01 09:00:00+00","2024-03-01 10:30:00+00")"#),
+            Some(r)
+        );
+    }
+
+    #[test]
+    fn binary_encoding_round_trips() {
+        let ty = range_type("daterange", Type::DATE);
+        let d = |day| NaiveDate::from_ymd_opt(2024, 1, day).expect("date");
+        for r in [
+            PgRange::closed_open(d(1), d(8)),
+            PgRange::at_least(d(3)),
+            PgRange::Empty,
+        ] {
+            let mut buf = BytesMut::new();
+            r.to_sql_checked(&ty, &mut buf).expect("encode");
+            assert_eq!(
+                PgRange::<NaiveDate>::from_sql(&ty, &buf).expect("decode"),
+                r
+            );
+        }
+        let mut buf = BytesMut::new();
+        assert!(PgRange::closed(1_i32, 2)
+            .to_sql_checked(&ty, &mut buf)
+            .is_err());
+    }
+
+    #[test]
+    fn literal_binds_through_the_element_type() {
+        let ty = range_type("int8range", Type::INT8);
+        let mut ours = BytesMut::new();
+        range_literal_to_sql("[1,5)", &ty, &mut ours).expect("literal");
+        let mut theirs = BytesMut::new();
+        PgRange::closed_open(1_i64, 5)
+            .to_sql(&ty, &mut theirs)
+            .expect("range");
+        assert_eq!(ours, theirs);
+        assert!(range_literal_to_sql("[x,5)", &ty, &mut ours).is_err());
+    }
+}
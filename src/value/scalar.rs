@@ -0,0 +1,772 @@
+//! PostgreSQL scalar types without a built-in `sea_query::Value` variant.
+//!
+//! | Rust type | Column type |
+//! |---|---|
+//! | [`PgInterval`] | `interval` |
+//! | `std::net::IpAddr` | `inet` |
+//! | [`PgCidr`] | `cidr` |
+//! | [`PgMacAddr`] | `macaddr` |
+//! | [`CiText`] | `citext` (extension) |
+//! | [`PgBitString`] | `bit varying` / `bit(n)` |
+//!
+//! Like [`PgRange`](crate::value::PgRange), reading a row decodes the binary value directly
+//! (`FromSql`), while in queries each value is a `Value::String` holding its text form
+//! (`P1DT2H`, `10.0.0.0/8`, `08:00:2b:01:02:03`, `1011`).
+//! [`TextParam`](crate::value::TextParam) parses that text for the column it is bound to, so
+//! inserts, updates and [`ColumnTrait`](crate::ColumnTrait) filters take these types directly.
+//! `IpAddr` is a foreign type, so filters convert it with [`ValueType::into_value`].
+//!
+//! `LifeModel` fields of these types (or `Option` of one) get the matching column type.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use bytes::BytesMut;
+use may_postgres::types::{FromSql, IsNull, ToSql, Type};
+use sea_query::Value;
+
+use crate::value::ValueType;
+
+/// Boxed error returned by the `ToSql` / `FromSql` impls.
+type SqlError = Box<dyn std::error::Error + Sync + Send>;
+
+/// `inet` / `cidr` address family bytes (`PGSQL_AF_INET`, `PGSQL_AF_INET6`).
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+/// A PostgreSQL `interval`: months, days and microseconds are kept apart, as the server does,
+/// because a month or a day has no fixed length.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PgInterval {
+    /// Whole months (a year is 12)
+    pub months: i32,
+    /// Whole days
+    pub days: i32,
+    /// Time of day part, in microseconds
+    pub microseconds: i64,
+}
+
+impl PgInterval {
+    /// An interval of `months`, `days` and `microseconds`.
+    #[must_use]
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// An interval of exactly `duration`; `None` if it does not fit in microseconds.
+    #[must_use]
+    pub fn from_duration(duration: chrono::Duration) -> Option<Self> {
+        Some(Self::new(0, 0, duration.num_microseconds()?))
+    }
+
+    /// ISO 8601 text (`P1Y2M3DT4H5M6.5S`), accepted by PostgreSQL as interval input.
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        use std::fmt::Write;
+
+        if *self == Self::default() {
+            return "PT0S".to_string();
+        }
+        let mut out = "P".to_string();
+        for (value, unit) in [
+            (i64::from(self.months / 12), 'Y'),
+            (i64::from(self.months % 12), 'M'),
+            (i64::from(self.days), 'D'),
+        ] {
+            if value != 0 {
+                let _ = write!(out, "{value}{unit}");
+            }
+        }
+        if self.microseconds != 0 {
+            out.push('T');
+            let seconds = self.microseconds / MICROS_PER_SECOND;
+            for (value, unit) in [(seconds / 3_600, 'H'), (seconds % 3_600 / 60, 'M')] {
+                if value != 0 {
+                    let _ = write!(out, "{value}{unit}");
+                }
+            }
+            let (seconds, fraction) = (seconds % 60, self.microseconds % MICROS_PER_SECOND);
+            if seconds != 0 || fraction != 0 {
+                if seconds == 0 && fraction < 0 {
+                    out.push('-');
+                }
+                let _ = write!(out, "{seconds}");
+                if fraction != 0 {
+                    let digits = format!("{:06}", fraction.unsigned_abs());
+                    let _ = write!(out, ".{}", digits.trim_end_matches('0'));
+                }
+                out.push('S');
+            }
+        }
+        out
+    }
+
+    /// Parse ISO 8601 interval text (`P1Y2M3DT4H5M6.5S`, `P2W`, `-` on any field); only seconds
+    /// may have a fraction.
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        let rest = literal.trim().strip_prefix('P')?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) if !time.is_empty() => (date, Some(time)),
+            Some(_) => return None,
+            None => (rest, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return None;
+        }
+        let mut interval = Self::default();
+        for (number, unit) in iso_fields(date)? {
+            let n = i32::try_from(parse_whole(number)?).ok()?;
+            let (field, factor) = match unit {
+                'Y' => (&mut interval.months, 12),
+                'M' => (&mut interval.months, 1),
+                'W' => (&mut interval.days, 7),
+                'D' => (&mut interval.days, 1),
+                _ => return None,
+            };
+            *field = field.checked_add(n.checked_mul(factor)?)?;
+        }
+        for (number, unit) in time.map_or(Some(Vec::new()), iso_fields)? {
+            let micros = match unit {
+                'H' => parse_whole(number)?.checked_mul(3_600 * MICROS_PER_SECOND)?,
+                'M' => parse_whole(number)?.checked_mul(60 * MICROS_PER_SECOND)?,
+                'S' => parse_seconds(number)?,
+                _ => return None,
+            };
+            interval.microseconds = interval.microseconds.checked_add(micros)?;
+        }
+        Some(interval)
+    }
+}
+
+/// `<number><designator>` pairs of one ISO 8601 part (`1Y-2M3D`).
+fn iso_fields(part: &str) -> Option<Vec<(&str, char)>> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    for (i, c) in part.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if i == start {
+                return None;
+            }
+            fields.push((&part[start..i], c));
+            start = i + 1;
+        }
+    }
+    (start == part.len()).then_some(fields)
+}
+
+/// A signed whole number (`-3`).
+fn parse_whole(number: &str) -> Option<i64> {
+    let digits = number.strip_prefix('-').unwrap_or(number);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Signed seconds with up to six fractional digits (`-6.5`), in microseconds.
+fn parse_seconds(number: &str) -> Option<i64> {
+    let (negative, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, number),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if unsigned.starts_with('-')
+        || fraction.len() > 6
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let micros = parse_whole(whole)?
+        .checked_mul(MICROS_PER_SECOND)?
+        .checked_add(format!("{fraction:0<6}").parse::<i64>().ok()?)?;
+    Some(if negative { -micros } else { micros })
+}
+
+impl ToSql for PgInterval {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        out.extend_from_slice(&self.microseconds.to_be_bytes());
+        out.extend_from_slice(&self.days.to_be_bytes());
+        out.extend_from_slice(&self.months.to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for PgInterval {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        let raw: &[u8; 16] = raw.try_into().map_err(|_| "interval must be 16 bytes")?;
+        let (microseconds, rest) = raw.split_at(8);
+        let (days, months) = rest.split_at(4);
+        Ok(Self::new(
+            i32::from_be_bytes(months.try_into()?),
+            i32::from_be_bytes(days.try_into()?),
+            i64::from_be_bytes(microseconds.try_into()?),
+        ))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
+
+/// A PostgreSQL `cidr` network: an address whose bits past the prefix are all zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PgCidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl PgCidr {
+    /// The network `addr/prefix`; `None` if the prefix is too long for the address family or
+    /// `addr` has bits set past it (PostgreSQL rejects those as `cidr` input).
+    #[must_use]
+    pub fn new(addr: IpAddr, prefix: u8) -> Option<Self> {
+        let (bits, max) = match addr {
+            IpAddr::V4(a) => (u128::from(u32::from(a)) << 96, 32),
+            IpAddr::V6(a) => (u128::from(a), 128),
+        };
+        if prefix > max || bits.checked_shl(u32::from(prefix)).unwrap_or(0) != 0 {
+            return None;
+        }
+        Some(Self { addr, prefix })
+    }
+
+    /// The network address.
+    #[must_use]
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// The prefix length in bits.
+    #[must_use]
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// `addr/prefix` text (`10.0.0.0/8`).
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        format!("{}/{}", self.addr, self.prefix)
+    }
+
+    /// Parse `addr/prefix`; a bare address is a single-host network, as in PostgreSQL.
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        let (addr, prefix) = parse_inet(literal)?;
+        Self::new(addr, prefix)
+    }
+}
+
+/// `addr` or `addr/prefix`; a missing prefix is the full address length.
+fn parse_inet(literal: &str) -> Option<(IpAddr, u8)> {
+    let literal = literal.trim();
+    let (addr, prefix) = match literal.split_once('/') {
+        Some((addr, prefix)) => (addr.parse().ok()?, Some(prefix.parse().ok()?)),
+        None => (literal.parse().ok()?, None),
+    };
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix = prefix.unwrap_or(max);
+    (prefix <= max).then_some((addr, prefix))
+}
+
+/// The shared `inet` / `cidr` wire format.
+fn write_inet(addr: IpAddr, prefix: u8, is_cidr: bool, out: &mut BytesMut) {
+    match addr {
+        IpAddr::V4(a) => {
+            out.extend_from_slice(&[PGSQL_AF_INET, prefix, u8::from(is_cidr), 4]);
+            out.extend_from_slice(&a.octets());
+        }
+        IpAddr::V6(a) => {
+            out.extend_from_slice(&[PGSQL_AF_INET6, prefix, u8::from(is_cidr), 16]);
+            out.extend_from_slice(&a.octets());
+        }
+    }
+}
+
+fn read_inet(raw: &[u8]) -> Result<(IpAddr, u8), SqlError> {
+    let ([family, prefix, _is_cidr, len], addr) = raw
+        .split_first_chunk::<4>()
+        .map(|(head, addr)| (*head, addr))
+        .ok_or("truncated inet header")?;
+    if usize::from(len) != addr.len() {
+        return Err("inet address length mismatch".into());
+    }
+    let addr = match family {
+        PGSQL_AF_INET => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(addr)?)),
+        PGSQL_AF_INET6 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(addr)?)),
+        _ => return Err(format!("unknown inet address family {family}").into()),
+    };
+    Ok((addr, prefix))
+}
+
+impl ToSql for PgCidr {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        write_inet(self.addr, self.prefix, true, out);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::CIDR
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for PgCidr {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        let (addr, prefix) = read_inet(raw)?;
+        Self::new(addr, prefix).ok_or_else(|| format!("{addr}/{prefix} is not a network").into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::CIDR
+    }
+}
+
+/// A PostgreSQL `macaddr` (EUI-48).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PgMacAddr(pub [u8; 6]);
+
+impl PgMacAddr {
+    /// Lowercase colon-separated text (`08:00:2b:01:02:03`), PostgreSQL's output format.
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        let [a, b, c, d, e, f] = self.0;
+        format!("{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{f:02x}")
+    }
+
+    /// Parse six hex pairs separated by `:` or `-`, or twelve bare hex digits.
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        let literal = literal.trim();
+        let digits: String = if literal.len() == 12 {
+            literal.to_string()
+        } else {
+            let sep = if literal.contains('-') { '-' } else { ':' };
+            let pairs: Vec<&str> = literal.split(sep).collect();
+            if pairs.len() != 6 || pairs.iter().any(|p| p.len() != 2) {
+                return None;
+            }
+            pairs.concat()
+        };
+        if digits.len() != 12 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let mut bytes = [0; 6];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).ok()?;
+        }
+        Some(Self(bytes))
+    }
+}
+
+impl ToSql for PgMacAddr {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        out.extend_from_slice(&self.0);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::MACADDR
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for PgMacAddr {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        Ok(Self(raw.try_into().map_err(|_| "macaddr must be 6 bytes")?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::MACADDR
+    }
+}
+
+/// Text from a `citext` column (the `citext` extension): compares, hashes and filters
+/// case-insensitively, like the column itself.
+#[derive(Clone, Debug, Default)]
+pub struct CiText(pub String);
+
+impl CiText {
+    /// The text as written.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The text as written.
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        self.0.clone()
+    }
+
+    /// Any text is a `citext` value.
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        Some(Self(literal.to_string()))
+    }
+}
+
+impl PartialEq for CiText {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_lowercase() == other.0.to_lowercase()
+    }
+}
+
+impl Eq for CiText {}
+
+impl std::hash::Hash for CiText {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_lowercase().hash(state);
+    }
+}
+
+impl From<String> for CiText {
+    fn from(text: String) -> Self {
+        Self(text)
+    }
+}
+
+impl From<&str> for CiText {
+    fn from(text: &str) -> Self {
+        Self(text.to_string())
+    }
+}
+
+impl ToSql for CiText {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        self.0.to_sql(ty, out)
+    }
+
+    // `String` accepts the TEXT family and `citext`.
+    fn accepts(ty: &Type) -> bool {
+        <String as ToSql>::accepts(ty)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for CiText {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        String::from_sql(ty, raw).map(Self)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <String as FromSql<'a>>::accepts(ty)
+    }
+}
+
+/// A PostgreSQL `bit varying` / `bit(n)` value, one `bool` per bit.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PgBitString(pub Vec<bool>);
+
+impl PgBitString {
+    /// `0` / `1` text (`1011`).
+    #[must_use]
+    pub fn to_literal(&self) -> String {
+        self.0
+            .iter()
+            .map(|&bit| if bit { '1' } else { '0' })
+            .collect()
+    }
+
+    /// Parse `0` / `1` text.
+    #[must_use]
+    pub fn from_literal(literal: &str) -> Option<Self> {
+        literal
+            .trim()
+            .chars()
+            .map(|c| match c {
+                '0' => Some(false),
+                '1' => Some(true),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .map(Self)
+    }
+}
+
+impl ToSql for PgBitString {
+    fn to_sql(&self, _ty: &Type, out: &mut BytesMut) -> Result<IsNull, SqlError> {
+        out.extend_from_slice(&i32::try_from(self.0.len())?.to_be_bytes());
+        for chunk in self.0.chunks(8) {
+            let byte = chunk
+                .iter()
+                .enumerate()
+                .fold(0_u8, |byte, (i, &bit)| byte | (u8::from(bit) << (7 - i)));
+            out.extend_from_slice(&[byte]);
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::BIT || *ty == Type::VARBIT
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for PgBitString {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, SqlError> {
+        let (len, bytes) = raw
+            .split_first_chunk::<4>()
+            .ok_or("truncated bit string length")?;
+        let len = usize::try_from(i32::from_be_bytes(*len))?;
+        if bytes.len() != len.div_ceil(8) {
+            return Err("bit string length mismatch".into());
+        }
+        Ok(Self(
+            (0..len)
+                .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+                .collect(),
+        ))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::BIT || *ty == Type::VARBIT
+    }
+}
+
+/// `Value::String` text, `ValueType`, serde and `Display` through `to_literal` / `from_literal`.
+macro_rules! impl_literal_carrier {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Value::String(Some(value.to_literal()))
+                }
+            }
+
+            impl ValueType for $ty {
+                fn into_value(self) -> Value {
+                    self.into()
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::String(Some(literal)) => Self::from_literal(&literal),
+                        _ => None,
+                    }
+                }
+
+                fn null_value() -> Value {
+                    Value::String(None)
+                }
+            }
+
+            impl serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.to_literal())
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $ty {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let literal = String::deserialize(deserializer)?;
+                    Self::from_literal(&literal).ok_or_else(|| {
+                        serde::de::Error::custom(format!("invalid {} literal {literal:?}", $name))
+                    })
+                }
+            }
+
+            impl std::fmt::Display for $ty {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&self.to_literal())
+                }
+            }
+        )*
+    };
+}
+
+impl_literal_carrier! {
+    PgInterval => "interval",
+    PgCidr => "cidr",
+    PgMacAddr => "macaddr",
+    CiText => "citext",
+    PgBitString => "bit string",
+}
+
+// `From<IpAddr> for Value` would be an orphan impl; `ValueType` carries the address instead.
+impl ValueType for IpAddr {
+    fn into_value(self) -> Value {
+        Value::String(Some(self.to_string()))
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::String(Some(text)) => text.parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn null_value() -> Value {
+        Value::String(None)
+    }
+}
+
+/// Whether [`literal_to_sql`] encodes string parameters for `ty` (`TextParam` binds).
+pub(crate) fn accepts_literal(ty: &Type) -> bool {
+    [
+        Type::INTERVAL,
+        Type::INET,
+        Type::CIDR,
+        Type::MACADDR,
+        Type::BIT,
+        Type::VARBIT,
+    ]
+    .contains(ty)
+}
+
+/// Encode the text form `literal` for the column type `ty` (`TextParam` binds).
+///
+/// # Errors
+///
+/// Returns an error if `ty` is not one of [`accepts_literal`]'s types or `literal` does not parse.
+pub(crate) fn literal_to_sql(
+    literal: &str,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, SqlError> {
+    let invalid = || format!("{literal:?} is not a valid {ty} literal");
+    match *ty {
+        Type::INTERVAL => PgInterval::from_literal(literal)
+            .ok_or_else(invalid)?
+            .to_sql(ty, out),
+        Type::INET => {
+            let (addr, prefix) = parse_inet(literal).ok_or_else(invalid)?;
+            write_inet(addr, prefix, false, out);
+            Ok(IsNull::No)
+        }
+        Type::CIDR => PgCidr::from_literal(literal)
+            .ok_or_else(invalid)?
+            .to_sql(ty, out),
+        Type::MACADDR => PgMacAddr::from_literal(literal)
+            .ok_or_else(invalid)?
+            .to_sql(ty, out),
+        Type::BIT | Type::VARBIT => PgBitString::from_literal(literal)
+            .ok_or_else(invalid)?
+            .to_sql(ty, out),
+        _ => Err(format!("string parameters cannot bind to {ty}").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)] // test-only unwraps
+
+    use super::*;
+
+    #[test]
+    fn interval_literals_round_trip() {
+        let i = PgInterval::new(14, 3, 4 * 3_600_000_000 + 5 * 60_000_000 + 6_500_000);
+        assert_eq!(i.to_literal(), "P1Y2M3DT4H5M6.5S");
+        assert_eq!(PgInterval::from_literal("P1Y2M3DT4H5M6.5S"), Some(i));
+        assert_eq!(PgInterval::default().to_literal(), "PT0S");
+        assert_eq!(
+            PgInterval::from_literal("P2W"),
+            Some(PgInterval::new(0, 14, 0))
+        );
+        let negative = PgInterval::new(-1, 0, -500_000);
+        assert_eq!(negative.to_literal(), "P-1MT-0.5S");
+        assert_eq!(PgInterval::from_literal("P-1MT-0.5S"), Some(negative));
+        for bad in ["", "P", "PT", "1D", "P1.5D", "P1X", "PT1.0000001S", "PD"] {
+            assert_eq!(PgInterval::from_literal(bad), None, "{bad:?}");
+        }
+        assert_eq!(
+            PgInterval::from_duration(chrono::Duration::minutes(90)),
+            Some(PgInterval::new(0, 0, 5_400_000_000))
+        );
+    }
+
+    #[test]
+    fn network_and_mac_literals() {
+        let net = PgCidr::from_literal("10.1.0.0/16").expect("cidr");
+        assert_eq!(net.prefix(), 16);
+        assert_eq!(net.to_literal(), "10.1.0.0/16");
+        assert_eq!(PgCidr::from_literal("10.1.0.1/16"), None, "host bits set");
+        assert_eq!(PgCidr::from_literal("10.0.0.0/33"), None);
+        assert_eq!(PgCidr::from_literal("::1").map(|n| n.prefix()), Some(128));
+
+        let mac = PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+        assert_eq!(mac.to_literal(), "08:00:2b:01:02:03");
+        for text in ["08:00:2B:01:02:03", "08-00-2b-01-02-03", "08002b010203"] {
+            assert_eq!(PgMacAddr::from_literal(text), Some(mac), "{text}");
+        }
+        assert_eq!(PgMacAddr::from_literal("08:00:2b:01:02"), None);
+
+        assert_eq!(CiText::from("Alice"), CiText::from("ALICE"));
+        assert_eq!(
+            PgBitString::from_literal("1011"),
+            Some(PgBitString(vec![true, false, true, true]))
+        );
+        assert_eq!(PgBitString::from_literal("10a"), None);
+    }
+
+    #[test]
+    fn binary_encodings_round_trip() {
+        fn round_trip<T>(value: &T, ty: &Type) -> T
+        where
+            T: ToSql + for<'a> FromSql<'a>,
+        {
+            let mut buf = BytesMut::new();
+            value.to_sql_checked(ty, &mut buf).expect("encode");
+            T::from_sql(ty, &buf).expect("decode")
+        }
+
+        let i = PgInterval::new(1, -2, 3);
+        assert_eq!(round_trip(&i, &Type::INTERVAL), i);
+        let net = PgCidr::from_literal("2001:db8::/32").expect("cidr");
+        assert_eq!(round_trip(&net, &Type::CIDR), net);
+        let mac = PgMacAddr([1, 2, 3, 4, 5, 6]);
+        assert_eq!(round_trip(&mac, &Type::MACADDR), mac);
+        let bits = PgBitString::from_literal("101100101").expect("bits");
+        assert_eq!(round_trip(&bits, &Type::VARBIT), bits);
+
+        let mut buf = BytesMut::new();
+        assert!(net.to_sql_checked(&Type::INET, &mut buf).is_err());
+    }
+
+    #[test]
+    fn literals_bind_like_the_typed_values() {
+        fn same(literal: &str, typed: &dyn ToSql, ty: &Type) {
+            let mut ours = BytesMut::new();
+            literal_to_sql(literal, ty, &mut ours).expect("literal");
+            let mut theirs = BytesMut::new();
+            typed.to_sql_checked(ty, &mut theirs).expect("typed");
+            assert_eq!(ours, theirs, "{literal} as {ty}");
+        }
+
+        same(
+            "P1DT2H",
+            &PgInterval::new(0, 1, 7_200_000_000),
+            &Type::INTERVAL,
+        );
+        let addr: IpAddr = "192.168.0.10".parse().expect("ip");
+        same("192.168.0.10", &addr, &Type::INET);
+        same(
+            "10.0.0.0/8",
+            &PgCidr::from_literal("10.0.0.0/8").expect("cidr"),
+            &Type::CIDR,
+        );
+        same(
+            "01:02:03:04:05:06",
+            &PgMacAddr([1, 2, 3, 4, 5, 6]),
+            &Type::MACADDR,
+        );
+        assert!(accepts_literal(&Type::VARBIT));
+        assert!(!accepts_literal(&Type::TEXT));
+        assert!(literal_to_sql("10.0.0.1/8", &Type::CIDR, &mut BytesMut::new()).is_err());
+    }
+}
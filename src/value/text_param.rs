@@ -14,13 +14,23 @@
 //! `text::jsonb` cast semantics: parse the string as a JSON document and
 //! bind that, erroring on invalid JSON (matching what `($n::text)::jsonb`
 //! would do server-side).
+//!
+//! It also binds to native `ENUM` columns (the carrier of a
+//! [`LifeEnum`](crate::value::LifeEnum) label), rejecting strings that are not
+//! one of the type's labels before the statement is sent, to range columns
+//! (the carrier of a [`PgRange`](crate::value::PgRange) literal), encoding the
+//! literal through the range's element type, and to the `interval`, `inet`,
+//! `cidr`, `macaddr` and bit string columns of the [`scalar`](crate::value::scalar)
+//! types, parsing their text form.
 
 use bytes::BytesMut;
-use may_postgres::types::{IsNull, ToSql, Type};
+use may_postgres::types::{IsNull, Kind, ToSql, Type};
 
-/// Owned text parameter: binds as TEXT, and as a parsed JSON document when
-/// the target column is JSON/JSONB. `None` is a typed SQL NULL for all
-/// accepted column types.
+/// Owned text parameter: binds as TEXT, as a parsed JSON document when the
+/// target column is JSON/JSONB, as a label for `ENUM` columns, as a range
+/// literal for range columns and as the text form of the other
+/// [`scalar`](crate::value::scalar) columns. `None` is a typed SQL NULL for
+/// all accepted column types.
 #[derive(Clone, Debug)]
 pub struct TextParam(pub Option<String>);
 
@@ -59,13 +69,27 @@ impl ToSql for TextParam {
                 )
             })?;
             doc.to_sql(ty, out)
+        } else if let Kind::Enum(labels) = ty.kind() {
+            if !labels.iter().any(|label| label == s) {
+                return Err(format!("{s:?} is not a label of enum type {ty}").into());
+            }
+            out.extend_from_slice(s.as_bytes());
+            Ok(IsNull::No)
+        } else if let Kind::Range(_) = ty.kind() {
+            crate::value::range::range_literal_to_sql(s, ty, out)
+        } else if crate::value::scalar::accepts_literal(ty) {
+            crate::value::scalar::literal_to_sql(s, ty, out)
         } else {
             <String as ToSql>::to_sql(s, ty, out)
         }
     }
 
     fn accepts(ty: &Type) -> bool {
-        <String as ToSql>::accepts(ty) || *ty == Type::JSON || *ty == Type::JSONB
+        <String as ToSql>::accepts(ty)
+            || *ty == Type::JSON
+            || *ty == Type::JSONB
+            || matches!(ty.kind(), Kind::Enum(_) | Kind::Range(_))
+            || crate::value::scalar::accepts_literal(ty)
     }
 
     postgres_types::to_sql_checked!();
@@ -141,6 +165,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enum_labels_bind_and_unknown_labels_error() {
+        let ty = Type::new(
+            "order_status".to_string(),
+            16_384,
+            Kind::Enum(vec!["pending".into(), "shipped".into()]),
+            "public".to_string(),
+        );
+        assert!(TextParam::accepts(&ty));
+        let mut buf = BytesMut::new();
+        TextParam::some("shipped".into())
+            .to_sql_checked(&ty, &mut buf)
+            .expect("label");
+        assert_eq!(&buf[..], b"shipped");
+        let err = checked(&TextParam::some("lost".into()), &ty).expect_err("not a label");
+        assert!(err.contains("order_status"), "{err}");
+        assert!(matches!(checked(&TextParam::null(), &ty), Ok(true)));
+    }
+
+    #[test]
+    fn range_literals_bind_through_the_element_type() {
+        let ty = Type::new(
+            "int4range".to_string(),
+            3_904,
+            Kind::Range(Type::INT4),
+            "pg_catalog".to_string(),
+        );
+        assert!(TextParam::accepts(&ty));
+        let mut ours = BytesMut::new();
+        TextParam::some("[1,10)".into())
+            .to_sql_checked(&ty, &mut ours)
+            .expect("range literal");
+        let mut theirs = BytesMut::new();
+        crate::PgRange::closed_open(1_i32, 10)
+            .to_sql_checked(&ty, &mut theirs)
+            .expect("range");
+        assert_eq!(ours, theirs);
+        let err = checked(&TextParam::some("[1,".into()), &ty).expect_err("bad literal");
+        assert!(err.contains("int4range"), "{err}");
+        assert!(matches!(checked(&TextParam::null(), &ty), Ok(true)));
+    }
+
+    #[test]
+    fn scalar_text_binds_through_the_column_type() {
+        assert!(TextParam::accepts(&Type::INET));
+        assert!(TextParam::accepts(&Type::INTERVAL));
+        let mut ours = BytesMut::new();
+        TextParam::some("08:00:2b:01:02:03".into())
+            .to_sql_checked(&Type::MACADDR, &mut ours)
+            .expect("macaddr");
+        assert_eq!(&ours[..], &[0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+        let err = checked(&TextParam::some("300.1.1.1".into()), &Type::INET).expect_err("bad ip");
+        assert!(err.contains("inet"), "{err}");
+        assert!(matches!(checked(&TextParam::null(), &Type::CIDR), Ok(true)));
+    }
+
     #[test]
     fn null_binds_to_text_and_jsonb() {
         let p = TextParam::null();
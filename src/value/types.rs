@@ -25,6 +25,9 @@
 //! - String: `String`
 //! - Binary: `Vec<u8>`
 //! - JSON: `serde_json::Value`
+//! - Numeric: `rust_decimal::Decimal`
+//! - Date/time: `chrono::NaiveDate`, `NaiveTime`, `NaiveDateTime`, `DateTime<Utc>`
+//! - Arrays: `Vec<String>`, `Vec<i32>`, `Vec<i64>`, `Vec<uuid::Uuid>`
 //! - Option<T> for all above types
 
 use sea_query::Value;
@@ -74,6 +77,41 @@ pub trait ValueType: Sized {
     fn null_value() -> Value;
 }
 
+/// `true` for the NULL (`Variant(None)`) form of every `Value` variant the enabled `sea-query`
+/// features provide, including `postgres-array`'s `Array(_, None)`.
+///
+/// Keep this in step with the `sea-query` features in `Cargo.toml`: a variant missing here is
+/// treated as a present value by foreign-key batching and database-backed validators.
+pub(crate) fn is_null(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Bool(None)
+            | Value::TinyInt(None)
+            | Value::SmallInt(None)
+            | Value::Int(None)
+            | Value::BigInt(None)
+            | Value::TinyUnsigned(None)
+            | Value::SmallUnsigned(None)
+            | Value::Unsigned(None)
+            | Value::BigUnsigned(None)
+            | Value::Float(None)
+            | Value::Double(None)
+            | Value::String(None)
+            | Value::Char(None)
+            | Value::Bytes(None)
+            | Value::Json(None)
+            | Value::Uuid(None)
+            | Value::Decimal(None)
+            | Value::ChronoDate(None)
+            | Value::ChronoTime(None)
+            | Value::ChronoDateTime(None)
+            | Value::ChronoDateTimeUtc(None)
+            | Value::ChronoDateTimeLocal(None)
+            | Value::ChronoDateTimeWithTimeZone(None)
+            | Value::Array(_, None)
+    )
+}
+
 // Implementations for primitive types
 
 impl ValueType for i8 {
@@ -298,6 +336,23 @@ impl ValueType for String {
     }
 }
 
+impl ValueType for uuid::Uuid {
+    fn into_value(self) -> Value {
+        Value::Uuid(Some(self))
+    }
+
+    fn from_value(value: Value) -> Option<Self> {
+        match value {
+            Value::Uuid(Some(v)) => Some(v),
+            _ => None,
+        }
+    }
+
+    fn null_value() -> Value {
+        Value::Uuid(None)
+    }
+}
+
 impl ValueType for Vec<u8> {
     fn into_value(self) -> Value {
         Value::Bytes(Some(self))
@@ -332,6 +387,69 @@ impl ValueType for serde_json::Value {
     }
 }
 
+// `NUMERIC` and chrono date/time types map one-to-one onto their sea-query variant.
+
+macro_rules! impl_variant_value_type {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl ValueType for $ty {
+                fn into_value(self) -> Value {
+                    Value::$variant(Some(self))
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::$variant(Some(v)) => Some(v),
+                        _ => None,
+                    }
+                }
+
+                fn null_value() -> Value {
+                    Value::$variant(None)
+                }
+            }
+        )*
+    };
+}
+
+impl_variant_value_type! {
+    rust_decimal::Decimal => Decimal,
+    chrono::NaiveDate => ChronoDate,
+    chrono::NaiveTime => ChronoTime,
+    chrono::NaiveDateTime => ChronoDateTime,
+    chrono::DateTime<chrono::Utc> => ChronoDateTimeUtc,
+}
+
+// Postgres arrays (`TEXT[]`, `INTEGER[]`, `BIGINT[]`, `UUID[]`) map to `Value::Array`; the
+// element conversions are sea-query's own (`postgres-array` feature).
+
+macro_rules! impl_array_value_type {
+    ($($element:ty => $array_type:ident),* $(,)?) => {
+        $(
+            impl ValueType for Vec<$element> {
+                fn into_value(self) -> Value {
+                    Value::from(self)
+                }
+
+                fn from_value(value: Value) -> Option<Self> {
+                    <Self as sea_query::ValueType>::try_from(value).ok()
+                }
+
+                fn null_value() -> Value {
+                    Value::Array(sea_query::ArrayType::$array_type, None)
+                }
+            }
+        )*
+    };
+}
+
+impl_array_value_type! {
+    String => String,
+    i32 => Int,
+    i64 => BigInt,
+    uuid::Uuid => Uuid,
+}
+
 // Implementations for Option<T> where T: ValueType
 //
 // Note: For None values, we need a way to create the appropriate null variant.
@@ -374,6 +492,40 @@ impl<T: ValueType> ValueType for Option<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn is_null_covers_every_none_variant() {
+        for value in [
+            Value::Bool(None),
+            Value::TinyInt(None),
+            Value::SmallInt(None),
+            Value::Int(None),
+            Value::BigInt(None),
+            Value::TinyUnsigned(None),
+            Value::SmallUnsigned(None),
+            Value::Unsigned(None),
+            Value::BigUnsigned(None),
+            Value::Float(None),
+            Value::Double(None),
+            Value::String(None),
+            Value::Char(None),
+            Value::Bytes(None),
+            Value::Json(None),
+            Value::Uuid(None),
+            Value::Decimal(None),
+            Value::ChronoDate(None),
+            Value::ChronoTime(None),
+            Value::ChronoDateTime(None),
+            Value::ChronoDateTimeUtc(None),
+            Value::ChronoDateTimeLocal(None),
+            Value::ChronoDateTimeWithTimeZone(None),
+            Value::Array(sea_query::ArrayType::Int, None),
+        ] {
+            assert!(is_null(&value), "{value:?}");
+        }
+        assert!(!is_null(&Value::Int(Some(1))));
+        assert!(!is_null(&Value::String(Some("x".into()))));
+    }
+
     // Integer type tests
 
     #[test]
@@ -574,6 +726,22 @@ mod tests {
         assert!(matches!(null, Value::String(None)));
     }
 
+    #[test]
+    fn test_uuid_value_type() {
+        let id = uuid::Uuid::new_v4();
+        let value = id.into_value();
+        assert_eq!(value, Value::Uuid(Some(id)));
+        assert_eq!(<uuid::Uuid as ValueType>::from_value(value), Some(id));
+        assert_eq!(
+            <uuid::Uuid as ValueType>::from_value(Value::String(Some(id.to_string()))),
+            None
+        );
+        assert!(matches!(
+            <uuid::Uuid as ValueType>::null_value(),
+            Value::Uuid(None)
+        ));
+    }
+
     // Binary tests
 
     #[test]
@@ -608,6 +776,84 @@ mod tests {
         assert!(matches!(null, Value::Json(None)));
     }
 
+    // Decimal and chrono tests
+
+    #[test]
+    fn test_decimal_and_chrono_value_types() {
+        let amount = rust_decimal::Decimal::new(1999, 2);
+        let value = amount.into_value();
+        assert!(matches!(value, Value::Decimal(Some(_))));
+        assert_eq!(
+            <rust_decimal::Decimal as ValueType>::from_value(value),
+            Some(amount)
+        );
+        assert!(matches!(
+            <rust_decimal::Decimal as ValueType>::null_value(),
+            Value::Decimal(None)
+        ));
+
+        let day = chrono::NaiveDate::from_ymd_opt(2024, 2, 29);
+        let value = day.into_value();
+        assert!(matches!(value, Value::ChronoDate(Some(_))));
+        assert_eq!(
+            <Option<chrono::NaiveDate> as ValueType>::from_value(value),
+            Some(day)
+        );
+        assert_eq!(
+            <Option<chrono::NaiveTime> as ValueType>::from_value(Value::ChronoTime(None)),
+            Some(None)
+        );
+        assert_eq!(
+            <chrono::DateTime<chrono::Utc> as ValueType>::from_value(Value::ChronoDate(None)),
+            None
+        );
+    }
+
+    // Array tests
+
+    #[test]
+    fn test_vec_string_value_type() {
+        let tags = vec!["a".to_string(), "b".to_string()];
+        let value = tags.clone().into_value();
+        assert!(matches!(
+            value,
+            Value::Array(sea_query::ArrayType::String, Some(_))
+        ));
+        let extracted = <Vec<String> as ValueType>::from_value(value);
+        assert_eq!(extracted, Some(tags));
+
+        // A BYTEA value is not a TEXT[]
+        let extracted = <Vec<String> as ValueType>::from_value(vec![1u8].into_value());
+        assert_eq!(extracted, None);
+
+        // Test null
+        let null = <Vec<String> as ValueType>::null_value();
+        assert!(matches!(
+            null,
+            Value::Array(sea_query::ArrayType::String, None)
+        ));
+    }
+
+    #[test]
+    fn test_option_vec_i64_value_type() {
+        let value = Some(vec![1i64, 2]).into_value();
+        let extracted = <Option<Vec<i64>> as ValueType>::from_value(value);
+        assert_eq!(extracted, Some(Some(vec![1i64, 2])));
+
+        // Test None case
+        let none_value = None::<Vec<i64>>.into_value();
+        assert!(matches!(
+            none_value,
+            Value::Array(sea_query::ArrayType::BigInt, None)
+        ));
+        let extracted = <Option<Vec<i64>> as ValueType>::from_value(none_value);
+        assert_eq!(extracted, Some(None));
+
+        // An INTEGER[] is not a BIGINT[]
+        let extracted = <Option<Vec<i64>> as ValueType>::from_value(vec![1i32].into_value());
+        assert_eq!(extracted, None);
+    }
+
     // Option<T> tests for all types
 
     #[test]
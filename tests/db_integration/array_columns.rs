@@ -0,0 +1,158 @@
+//! Postgres array columns: `Vec<String>` / `Vec<i32>` / `Vec<i64>` / `Vec<Uuid>` and
+//! `Option<Vec<..>>` fields round-trip through `LifeRecord` inserts, `FromRow` and
+//! `ModelTrait::get` / `set`, and filter with the `ColumnTrait` array operators.
+
+use std::sync::Mutex;
+
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelTrait, ColumnTrait, LifeExecutor, MayPostgresExecutor,
+    ModelTrait,
+};
+use sea_query::Order;
+use uuid::Uuid;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod arr_item {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+    use uuid::Uuid;
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_array_items"]
+    pub struct Item {
+        #[primary_key]
+        pub id: i32,
+        pub tags: Vec<String>,
+        pub scores: Vec<i32>,
+        pub counters: Vec<i64>,
+        pub refs: Vec<Uuid>,
+        pub labels: Option<Vec<String>>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    executor.execute("DROP TABLE IF EXISTS lg_array_items CASCADE", &[])?;
+    executor.execute(
+        "CREATE TABLE lg_array_items (id INTEGER PRIMARY KEY, tags TEXT[] NOT NULL, \
+         scores INTEGER[] NOT NULL, counters BIGINT[] NOT NULL, refs UUID[] NOT NULL, \
+         labels TEXT[])",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+fn insert(
+    executor: &MayPostgresExecutor,
+    id: i32,
+    tags: &[&str],
+    scores: Vec<i32>,
+    labels: Option<Vec<String>>,
+) -> arr_item::ItemModel {
+    let mut record = arr_item::ItemRecord::new();
+    record
+        .set_id(id)
+        .set_tags(strings(tags))
+        .set_scores(scores)
+        .set_counters(vec![i64::from(id) << 40])
+        .set_refs(vec![Uuid::from_u128(id.unsigned_abs().into())])
+        .set_labels(labels);
+    record.insert(executor).expect("insert item")
+}
+
+#[test]
+fn array_fields_round_trip_through_record_row_and_model() {
+    let _guard = LOCK.lock().expect("array_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let inserted = insert(&executor, 1, &["rust", "sql"], vec![3, 1], None);
+    assert_eq!(inserted.tags, strings(&["rust", "sql"]));
+    assert_eq!(inserted.labels, None);
+
+    let mut found = arr_item::Entity::find()
+        .filter(arr_item::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(found.scores, vec![3, 1]);
+    assert_eq!(found.counters, vec![1_i64 << 40]);
+    assert_eq!(found.refs, vec![Uuid::from_u128(1)]);
+    assert_eq!(found.labels, None);
+
+    let tags = found.get(arr_item::Column::Tags);
+    assert!(
+        matches!(
+            tags,
+            sea_query::Value::Array(sea_query::ArrayType::String, Some(_))
+        ),
+        "{tags:?}"
+    );
+    found
+        .set(arr_item::Column::Labels, strings(&["new"]).into())
+        .expect("set labels");
+    assert_eq!(found.labels, Some(strings(&["new"])));
+    found
+        .set(
+            arr_item::Column::Labels,
+            sea_query::Value::Array(sea_query::ArrayType::String, None),
+        )
+        .expect("set labels to NULL");
+    assert_eq!(found.labels, None);
+    assert!(
+        found
+            .set(arr_item::Column::Scores, sea_query::Value::Int(Some(1)))
+            .is_err(),
+        "a scalar is not an INTEGER[]"
+    );
+
+    let mut record = arr_item::ItemRecord::from_model(&found);
+    record.set_labels(Some(strings(&["a", "b"])));
+    record.update(&executor).expect("update labels");
+    let reloaded = arr_item::Entity::find()
+        .filter(arr_item::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(reloaded.labels, Some(strings(&["a", "b"])));
+}
+
+#[test]
+fn array_operators_filter_rows() {
+    let _guard = LOCK.lock().expect("array_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    insert(&executor, 1, &["rust", "sql"], vec![1, 2], None);
+    insert(&executor, 2, &["rust"], vec![2, 3], None);
+    insert(&executor, 3, &["go"], vec![4], Some(strings(&["x"])));
+
+    let ids = |filter: sea_query::Expr| -> Vec<i32> {
+        arr_item::Entity::find()
+            .filter(filter)
+            .order_by(arr_item::Column::Id, Order::Asc)
+            .all(&executor)
+            .expect("filter")
+            .into_iter()
+            .map(|item| item.id)
+            .collect()
+    };
+
+    assert_eq!(
+        ids(arr_item::Column::Tags.contains(strings(&["rust"]))),
+        [1, 2]
+    );
+    assert_eq!(
+        ids(arr_item::Column::Tags.contained_by(strings(&["rust", "go"]))),
+        [2, 3]
+    );
+    assert_eq!(ids(arr_item::Column::Scores.overlaps(vec![3, 4])), [2, 3]);
+    assert_eq!(ids(arr_item::Column::Scores.any_eq(2)), [1, 2]);
+    assert_eq!(ids(arr_item::Column::Refs.any_eq(Uuid::from_u128(3))), [3]);
+}
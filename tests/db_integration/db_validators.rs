@@ -0,0 +1,159 @@
+//! `#[validate(unique)]`, `#[validate(unique_with = "..")]` and `#[validate(exists = "..")]` on
+//! derived `LifeRecord` against a real database: duplicates and dangling references come back as
+//! field `ValidationError`s before any write, and an update does not collide with its own row.
+
+use std::sync::Mutex;
+
+use crate::context::get_test_context;
+use lifeguard::executor::LifeError;
+use lifeguard::test_helpers::TestDatabase;
+use lifeguard::{ActiveModelError, ActiveModelTrait, LifeExecutor, ValidationError};
+use lifeguard_derive::{LifeModel, LifeRecord};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod teams {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_dbv_teams"]
+    pub struct Team {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+#[derive(LifeModel, LifeRecord, Clone, Debug)]
+#[table_name = "lg_dbv_members"]
+#[validation_strategy = "aggregate"]
+pub struct Member {
+    #[primary_key]
+    pub id: i32,
+    #[validate(unique)]
+    pub email: String,
+    #[validate(exists = "teams::Entity")]
+    pub team_id: i32,
+    #[validate(unique_with = "team_id")]
+    pub handle: String,
+}
+
+fn setup(executor: &dyn LifeExecutor) -> Result<(), LifeError> {
+    executor.execute("DROP TABLE IF EXISTS lg_dbv_members CASCADE", &[])?;
+    executor.execute("DROP TABLE IF EXISTS lg_dbv_teams CASCADE", &[])?;
+    executor.execute(
+        "CREATE TABLE lg_dbv_teams (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        &[],
+    )?;
+    executor.execute(
+        "CREATE TABLE lg_dbv_members (id INTEGER PRIMARY KEY, email TEXT NOT NULL UNIQUE, \
+         team_id INTEGER NOT NULL REFERENCES lg_dbv_teams (id), handle TEXT NOT NULL, \
+         UNIQUE (team_id, handle))",
+        &[],
+    )?;
+    executor.execute(
+        "INSERT INTO lg_dbv_teams (id, name) VALUES (1, 'core'), (2, 'web')",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn member(id: i32, email: &str, team_id: i32, handle: &str) -> MemberRecord {
+    let mut rec = MemberRecord::new();
+    rec.set_id(id)
+        .set_email(email.to_string())
+        .set_team_id(team_id)
+        .set_handle(handle.to_string());
+    rec
+}
+
+fn codes(err: &ActiveModelError) -> Vec<(Option<&str>, Option<&str>)> {
+    let ActiveModelError::Validation(errors) = err else {
+        panic!("expected Validation, got {err:?}");
+    };
+    errors
+        .iter()
+        .map(|e: &ValidationError| (e.field.as_deref(), e.code.as_deref()))
+        .collect()
+}
+
+#[test]
+fn insert_reports_duplicates_and_missing_parent_as_field_errors() {
+    let _guard = LOCK.lock().expect("db_validators lock");
+
+    let ctx = get_test_context();
+    let mut db = TestDatabase::with_url(&ctx.pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    member(1, "a@example.com", 1, "ana")
+        .insert(&executor)
+        .expect("first member");
+
+    let err = member(2, "a@example.com", 9, "bo")
+        .insert(&executor)
+        .expect_err("duplicate email and missing team");
+    assert_eq!(
+        codes(&err),
+        vec![
+            (Some("email"), Some("unique")),
+            (Some("team_id"), Some("exists"))
+        ]
+    );
+
+    let err = member(3, "c@example.com", 1, "ana")
+        .insert(&executor)
+        .expect_err("handle taken within the team");
+    assert_eq!(codes(&err), vec![(Some("handle"), Some("unique"))]);
+    let ActiveModelError::Validation(errors) = &err else {
+        unreachable!()
+    };
+    assert_eq!(
+        errors[0].params.get("columns"),
+        Some(&serde_json::json!(["handle", "team_id"]))
+    );
+
+    member(4, "d@example.com", 2, "ana")
+        .insert(&executor)
+        .expect("same handle in another team");
+
+    let row = executor
+        .query_one("SELECT COUNT(*) FROM lg_dbv_members", &[])
+        .expect("count");
+    let count: i64 = row.get(0);
+    assert_eq!(count, 2, "rejected records were never written");
+}
+
+#[test]
+fn update_excludes_own_row_and_checks_staged_columns() {
+    let _guard = LOCK.lock().expect("db_validators lock");
+
+    let ctx = get_test_context();
+    let mut db = TestDatabase::with_url(&ctx.pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let first = member(1, "a@example.com", 1, "ana")
+        .insert(&executor)
+        .expect("first member");
+    member(2, "b@example.com", 1, "bo")
+        .insert(&executor)
+        .expect("second member");
+
+    let mut same = MemberRecord::from_model(&first);
+    same.set_email("a@example.com".to_string());
+    same.update(&executor)
+        .expect("re-saving its own email is not a duplicate");
+
+    let mut clash = MemberRecord::from_model(&first);
+    clash.set_email("b@example.com".to_string());
+    let err = clash
+        .update(&executor)
+        .expect_err("email belongs to another member");
+    assert_eq!(codes(&err), vec![(Some("email"), Some("unique"))]);
+
+    let mut moved = MemberRecord::from_model(&first);
+    moved.set_team_id(5);
+    let err = moved.update(&executor).expect_err("team 5 does not exist");
+    assert_eq!(codes(&err), vec![(Some("team_id"), Some("exists"))]);
+}
@@ -0,0 +1,185 @@
+//! Native Postgres enum columns: `#[derive(LifeEnum)]` types on `#[life_enum]` fields
+//! round-trip through `LifeRecord` inserts, `FromRow` and `ModelTrait::get` / `set`, filter with
+//! `ColumnTrait::eq`, and unknown labels are rejected before they reach the server.
+
+use std::sync::Mutex;
+
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelTrait, ColumnTrait, LifeEnum, LifeExecutor,
+    MayPostgresExecutor, ModelTrait,
+};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(
+    lifeguard_derive::LifeEnum,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[enum_name = "lg_ticket_state"]
+pub enum TicketState {
+    Open,
+    #[enum_value = "in_review"]
+    Reviewing,
+    Closed,
+}
+
+pub mod ticket {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    use super::TicketState;
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_enum_tickets"]
+    pub struct Ticket {
+        #[primary_key]
+        pub id: i32,
+        #[life_enum]
+        pub state: TicketState,
+        #[life_enum]
+        pub previous_state: Option<TicketState>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    executor.execute("DROP TABLE IF EXISTS lg_enum_tickets CASCADE", &[])?;
+    executor.execute("DROP TYPE IF EXISTS lg_ticket_state", &[])?;
+    executor.execute(
+        "CREATE TYPE lg_ticket_state AS ENUM ('open', 'in_review', 'closed')",
+        &[],
+    )?;
+    executor.execute(
+        "CREATE TABLE lg_enum_tickets (id INTEGER PRIMARY KEY, state lg_ticket_state NOT NULL, \
+         previous_state lg_ticket_state)",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn insert(
+    executor: &MayPostgresExecutor,
+    id: i32,
+    state: TicketState,
+    previous_state: Option<TicketState>,
+) -> ticket::TicketModel {
+    let mut record = ticket::TicketRecord::new();
+    record
+        .set_id(id)
+        .set_state(state)
+        .set_previous_state(previous_state);
+    record.insert(executor).expect("insert ticket")
+}
+
+#[test]
+fn enum_fields_round_trip_through_record_row_and_model() {
+    let _guard = LOCK.lock().expect("enum_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let inserted = insert(&executor, 1, TicketState::Reviewing, None);
+    assert_eq!(inserted.state, TicketState::Reviewing);
+    assert_eq!(inserted.previous_state, None);
+
+    let stored: String = executor
+        .query_one("SELECT state::text FROM lg_enum_tickets WHERE id = 1", &[])
+        .expect("raw select")
+        .try_get(0)
+        .expect("state text");
+    assert_eq!(stored, "in_review");
+
+    let mut found = ticket::Entity::find()
+        .filter(ticket::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(found.state, TicketState::Reviewing);
+    assert_eq!(
+        found.get(ticket::Column::State),
+        sea_query::Value::String(Some("in_review".to_string()))
+    );
+
+    found
+        .set(ticket::Column::PreviousState, TicketState::Open.into())
+        .expect("set previous_state");
+    assert_eq!(found.previous_state, Some(TicketState::Open));
+    found
+        .set(
+            ticket::Column::PreviousState,
+            sea_query::Value::String(None),
+        )
+        .expect("set previous_state to NULL");
+    assert_eq!(found.previous_state, None);
+    assert!(
+        found
+            .set(
+                ticket::Column::State,
+                sea_query::Value::String(Some("reopened".to_string()))
+            )
+            .is_err(),
+        "not a TicketState label"
+    );
+
+    let mut record = ticket::TicketRecord::from_model(&found);
+    record
+        .set_state(TicketState::Closed)
+        .set_previous_state(Some(TicketState::Reviewing));
+    record.update(&executor).expect("update state");
+    let reloaded = ticket::Entity::find()
+        .filter(ticket::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(reloaded.state, TicketState::Closed);
+    assert_eq!(reloaded.previous_state, Some(TicketState::Reviewing));
+}
+
+#[test]
+fn enum_columns_filter_and_reject_unknown_labels() {
+    let _guard = LOCK.lock().expect("enum_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    insert(&executor, 1, TicketState::Open, None);
+    insert(&executor, 2, TicketState::Closed, Some(TicketState::Open));
+    insert(&executor, 3, TicketState::Open, Some(TicketState::Closed));
+
+    let ids = |filter: sea_query::Expr| -> Vec<i32> {
+        ticket::Entity::find()
+            .filter(filter)
+            .order_by(ticket::Column::Id, Order::Asc)
+            .all(&executor)
+            .expect("filter")
+            .into_iter()
+            .map(|t| t.id)
+            .collect()
+    };
+
+    assert_eq!(ids(ticket::Column::State.eq(TicketState::Open)), [1, 3]);
+    assert_eq!(
+        ids(ticket::Column::PreviousState.eq(TicketState::Open)),
+        [2]
+    );
+    assert_eq!(ids(ticket::Column::PreviousState.is_null()), [1]);
+    assert_eq!(
+        ids(ticket::Column::State.is_in([TicketState::Closed, TicketState::Reviewing])),
+        [2]
+    );
+
+    let err = executor
+        .execute(
+            "UPDATE lg_enum_tickets SET state = $1 WHERE id = 1",
+            &[&lifeguard::TextParam::some("reopened".to_string())],
+        )
+        .expect_err("unknown label");
+    assert!(err.to_string().contains("reopened"), "{err}");
+    assert_eq!(TicketState::labels(), ["open", "in_review", "closed"]);
+}
@@ -0,0 +1,129 @@
+//! Single-query joined loading: `find_also_related` pairs each order with its optional customer
+//! and `find_with_related` groups a customer's orders, both from one `LEFT JOIN` whose related
+//! columns are aliased so the shared `id` column does not clash.
+
+use std::sync::Mutex;
+
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{test_helpers::TestDatabase, ColumnTrait, LifeExecutor, MayPostgresExecutor};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod j_customer {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_joined_customers"]
+    pub struct Customer {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+        #[has_many(
+            entity = "crate::joined_loading::j_order::Entity",
+            from = "id",
+            to = "customer_id"
+        )]
+        pub rel_orders: Option<Vec<super::j_order::OrderModel>>,
+    }
+}
+
+pub mod j_order {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_joined_orders"]
+    pub struct Order {
+        #[primary_key]
+        pub id: i32,
+        pub customer_id: Option<i32>,
+        pub label: String,
+        #[belongs_to(
+            entity = "crate::joined_loading::j_customer::Entity",
+            from = "customer_id",
+            to = "id"
+        )]
+        pub rel_customer: Option<super::j_customer::CustomerModel>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in ["lg_joined_orders", "lg_joined_customers"] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_joined_customers (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "CREATE TABLE lg_joined_orders (id INTEGER PRIMARY KEY, customer_id INTEGER REFERENCES lg_joined_customers (id), label TEXT NOT NULL)",
+        "INSERT INTO lg_joined_customers VALUES (1, 'ada'), (2, 'bo'), (3, 'cy')",
+        "INSERT INTO lg_joined_orders VALUES (10, 1, 'o10'), (11, 1, 'o11'), (20, 2, 'o20'), (30, NULL, 'walk-in')",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn find_also_related_pairs_each_order_with_its_customer() {
+    let _guard = LOCK.lock().expect("joined_loading lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let pairs = j_order::Entity::find()
+        .filter(j_order::Column::Id.gte(11))
+        .order_by(j_order::Column::Id, Order::Asc)
+        .find_also_related(j_customer::Entity)
+        .all(&executor)
+        .expect("find_also_related");
+
+    let got: Vec<(i32, Option<(i32, String)>)> = pairs
+        .into_iter()
+        .map(|(order, customer)| (order.id, customer.map(|c| (c.id, c.name))))
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            (11, Some((1, "ada".to_string()))),
+            (20, Some((2, "bo".to_string()))),
+            (30, None),
+        ],
+        "unqualified `id` filter and order apply to orders; a NULL key yields None"
+    );
+
+    let err = j_customer::Entity::find()
+        .find_also_related(j_order::Entity)
+        .all(&executor)
+        .expect_err("has_many is not a to-one relation");
+    assert!(matches!(err, lifeguard::executor::LifeError::Other(_)));
+}
+
+#[test]
+fn find_with_related_groups_orders_by_customer() {
+    let _guard = LOCK.lock().expect("joined_loading lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let customers = j_customer::Entity::find()
+        .order_by(j_customer::Column::Id, Order::Asc)
+        .find_with_related(j_order::Entity)
+        .all(&executor)
+        .expect("find_with_related");
+
+    let got: Vec<(String, Vec<i32>)> = customers
+        .into_iter()
+        .map(|(customer, orders)| {
+            let mut ids: Vec<i32> = orders.iter().map(|o| o.id).collect();
+            ids.sort_unstable();
+            (customer.name, ids)
+        })
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            ("ada".to_string(), vec![10, 11]),
+            ("bo".to_string(), vec![20]),
+            ("cy".to_string(), vec![]),
+        ]
+    );
+}
@@ -0,0 +1,172 @@
+//! Newtype columns: `#[derive(LifeValue)]` wrappers over `String`, `Decimal` and `Uuid` round-trip
+//! through `LifeRecord` inserts and `FromRow`, filter with `ColumnTrait`, and run
+//! `#[validate(...)]` rules against the wrapped value.
+
+use std::sync::Mutex;
+
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelError, ActiveModelTrait, ColumnTrait, LifeError,
+    LifeExecutor, LifeValue, MayPostgresExecutor, ModelTrait,
+};
+use rust_decimal::Decimal;
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod invoice {
+    use lifeguard_derive::{LifeModel, LifeRecord, LifeValue};
+    use rust_decimal::Decimal;
+
+    #[derive(LifeValue, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[column_type = "VARCHAR(320)"]
+    pub struct Email(pub String);
+
+    #[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Money(pub Decimal);
+
+    #[derive(LifeValue, Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct OrderId(pub uuid::Uuid);
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_life_value_invoices"]
+    pub struct Invoice {
+        #[primary_key]
+        pub id: i32,
+        #[life_value]
+        pub order_id: OrderId,
+        #[life_value]
+        #[validate(email)]
+        pub customer_email: Email,
+        #[life_value]
+        #[validate(range(min = 0))]
+        pub total: Money,
+        #[life_value]
+        pub discount: Option<Money>,
+    }
+}
+
+use invoice::{Email, Money, OrderId};
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), LifeError> {
+    executor.execute("DROP TABLE IF EXISTS lg_life_value_invoices CASCADE", &[])?;
+    executor.execute(
+        "CREATE TABLE lg_life_value_invoices (id INTEGER PRIMARY KEY, order_id UUID NOT NULL, \
+         customer_email VARCHAR(320) NOT NULL, total NUMERIC(19, 4) NOT NULL, \
+         discount NUMERIC(19, 4))",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn money(cents: i64) -> Money {
+    Money(Decimal::new(cents, 2))
+}
+
+fn record(id: i32, email: &str, total: Money) -> invoice::InvoiceRecord {
+    let mut record = invoice::InvoiceRecord::new();
+    record
+        .set_id(id)
+        .set_order_id(OrderId(uuid::Uuid::new_v4()))
+        .set_customer_email(Email(email.to_string()))
+        .set_total(total)
+        .set_discount(None);
+    record
+}
+
+#[test]
+fn newtype_fields_round_trip_filter_and_validate() {
+    let _guard = LOCK.lock().expect("life_value_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    assert_eq!(
+        invoice::Column::Total.column_def().column_type.as_deref(),
+        Some(Money::COLUMN_TYPE)
+    );
+    assert_eq!(OrderId::COLUMN_TYPE, "UUID");
+
+    let mut first = record(1, "ada@example.com", money(1999));
+    first.set_discount(Some(money(250)));
+    let inserted = first.insert(&executor).expect("insert");
+    assert_eq!(
+        inserted.customer_email,
+        Email("ada@example.com".to_string())
+    );
+    assert_eq!(inserted.total, money(1999));
+    assert_eq!(inserted.discount, Some(money(250)));
+    let second = record(2, "bob@example.com", money(500_000))
+        .insert(&executor)
+        .expect("insert second");
+
+    let stored: String = executor
+        .query_one(
+            "SELECT total::text FROM lg_life_value_invoices WHERE id = 1",
+            &[],
+        )
+        .expect("raw select")
+        .try_get(0)
+        .expect("total text");
+    assert_eq!(stored, "19.9900");
+
+    let ids = |filter: sea_query::Expr| -> Vec<i32> {
+        invoice::Entity::find()
+            .filter(filter)
+            .order_by(invoice::Column::Id, Order::Asc)
+            .all(&executor)
+            .expect("filter")
+            .into_iter()
+            .map(|i| i.id)
+            .collect()
+    };
+
+    assert_eq!(ids(invoice::Column::OrderId.eq(second.order_id)), [2]);
+    assert_eq!(ids(invoice::Column::Total.gt(money(10_000))), [2]);
+    assert_eq!(
+        ids(invoice::Column::CustomerEmail.is_in([
+            Email("ada@example.com".to_string()),
+            Email("eve@example.com".to_string()),
+        ])),
+        [1]
+    );
+    assert_eq!(ids(invoice::Column::Discount.is_null()), [2]);
+
+    let mut found = invoice::Entity::find()
+        .filter(invoice::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(
+        found.get(invoice::Column::Total),
+        sea_query::Value::Decimal(Some(Decimal::new(1999, 2)))
+    );
+    found
+        .set(invoice::Column::Discount, sea_query::Value::Decimal(None))
+        .expect("set discount to NULL");
+    assert_eq!(found.discount, None);
+    assert!(
+        found
+            .set(
+                invoice::Column::Total,
+                sea_query::Value::String(Some("19.99".to_string()))
+            )
+            .is_err(),
+        "not a Decimal"
+    );
+
+    let err = record(3, "not-an-address", money(-1))
+        .insert(&executor)
+        .expect_err("invalid email and negative total");
+    let ActiveModelError::Validation(errors) = &err else {
+        panic!("expected Validation, got {err:?}");
+    };
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.field.as_deref(), e.code.as_deref()))
+            .collect::<Vec<_>>(),
+        [(Some("customer_email"), Some("email"))],
+        "fail-fast stops at the first rule"
+    );
+}
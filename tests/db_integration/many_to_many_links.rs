@@ -0,0 +1,176 @@
+//! `link` / `unlink` / `sync_links` on derived `LifeRecord` for a `has_many_through` relation
+//! (posts ↔ tags through `lg_m2m_post_tags`), and many-to-many children queued on `save_graph`.
+
+use std::sync::Mutex;
+
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelError, ActiveModelTrait, IsolationLevel, LifeExecutor,
+    MayPostgresExecutor, RetryPolicy,
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod m2m_tag {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_m2m_tags"]
+    pub struct Tag {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+pub mod m2m_post_tag {
+    use lifeguard_derive::LifeModel;
+
+    #[derive(LifeModel, Clone, Debug)]
+    #[table_name = "lg_m2m_post_tags"]
+    pub struct PostTag {
+        #[primary_key]
+        pub post_id: i32,
+        #[primary_key]
+        pub tag_id: i32,
+    }
+}
+
+pub mod m2m_post {
+    use lifeguard_derive::{DeriveRelation, LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_m2m_posts"]
+    pub struct Post {
+        #[primary_key]
+        pub id: i32,
+        pub title: String,
+    }
+
+    // `lg_m2m_posts` would guess `lg_m2m_post_id`, so the join columns are named.
+    #[derive(DeriveRelation)]
+    pub enum Relation {
+        #[lifeguard(
+            has_many_through = "super::m2m_tag::Entity",
+            through = "super::m2m_post_tag::Entity",
+            through_from = "super::m2m_post_tag::Column::PostId",
+            through_to = "super::m2m_post_tag::Column::TagId"
+        )]
+        Tags,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in ["lg_m2m_post_tags", "lg_m2m_tags", "lg_m2m_posts"] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_m2m_posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL)",
+        "CREATE TABLE lg_m2m_tags (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "CREATE TABLE lg_m2m_post_tags (post_id INTEGER NOT NULL REFERENCES lg_m2m_posts (id), \
+         tag_id INTEGER NOT NULL REFERENCES lg_m2m_tags (id), PRIMARY KEY (post_id, tag_id))",
+        "INSERT INTO lg_m2m_posts VALUES (1, 'p1')",
+        "INSERT INTO lg_m2m_tags VALUES (1, 'rust'), (2, 'sql'), (3, 'orm')",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+fn linked_tags(executor: &MayPostgresExecutor, post_id: i32) -> Vec<i32> {
+    executor
+        .query_all(
+            "SELECT tag_id FROM lg_m2m_post_tags WHERE post_id = $1 ORDER BY tag_id",
+            &[&post_id],
+        )
+        .expect("join rows")
+        .iter()
+        .map(|row| row.get(0))
+        .collect()
+}
+
+/// `sync_links` on `post`, which runs in a transaction of its own.
+fn sync(
+    executor: &MayPostgresExecutor,
+    post: &m2m_post::PostRecord,
+    ids: &[i32],
+) -> Result<(), ActiveModelError> {
+    post.sync_links::<m2m_tag::Entity, _>(ids.to_vec(), executor)
+}
+
+fn tag(id: i32, name: &str) -> m2m_tag::TagModel {
+    m2m_tag::TagModel {
+        id,
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn link_unlink_and_sync_write_the_join_table() {
+    let _guard = LOCK.lock().expect("many_to_many_links lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let mut post = m2m_post::PostRecord::new();
+    post.set_id(1).set_title("p1".to_string());
+
+    post.link::<m2m_tag::Entity>(&tag(1, "rust"), &executor)
+        .expect("link rust");
+    post.link::<m2m_tag::Entity>(&tag(2, "sql"), &executor)
+        .expect("link sql");
+    post.link::<m2m_tag::Entity>(&tag(1, "rust"), &executor)
+        .expect("linking twice is a no-op");
+    assert_eq!(linked_tags(&executor, 1), [1, 2]);
+
+    post.unlink::<m2m_tag::Entity>(&tag(1, "rust"), &executor)
+        .expect("unlink rust");
+    assert_eq!(linked_tags(&executor, 1), [2]);
+
+    sync(&executor, &post, &[3, 1]).expect("sync to rust + orm");
+    assert_eq!(linked_tags(&executor, 1), [1, 3]);
+
+    sync(&executor, &post, &[]).expect("sync to nothing");
+    assert!(linked_tags(&executor, 1).is_empty());
+
+    sync(&executor, &post, &[2]).expect("sync to sql");
+    let err = sync(&executor, &post, &[1, 99]).expect_err("tag 99 does not exist");
+    assert!(matches!(err, ActiveModelError::Database { .. }), "{err:?}");
+    assert_eq!(
+        linked_tags(&executor, 1),
+        [2],
+        "a failed sync is rolled back, including its delete"
+    );
+
+    executor
+        .transaction(
+            IsolationLevel::ReadCommitted,
+            RetryPolicy::default(),
+            |tx| {
+                let err = post
+                    .sync_links::<m2m_tag::Entity, _>([1, 99], &*tx)
+                    .expect_err("tag 99 does not exist");
+                assert!(matches!(err, ActiveModelError::Database { .. }), "{err:?}");
+                post.sync_links::<m2m_tag::Entity, _>([1], &*tx)
+            },
+        )
+        .expect("a failed sync inside a transaction only rolls back its savepoint");
+    assert_eq!(linked_tags(&executor, 1), [1]);
+}
+
+#[test]
+fn save_graph_links_many_to_many_children() {
+    let _guard = LOCK.lock().expect("many_to_many_links lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let mut post = m2m_post::PostRecord::new();
+    post.set_id(2).set_title("p2".to_string());
+    let mut new_tag = m2m_tag::TagRecord::new();
+    new_tag.set_id(4).set_name("graph".to_string());
+    post.add_child(new_tag);
+
+    let saved = post.save_graph(&executor).expect("save_graph");
+    assert_eq!(saved.id, 2);
+    assert_eq!(linked_tags(&executor, 2), [4]);
+}
@@ -0,0 +1,218 @@
+//! Nested eager loading: `.load(post::Entity.then(comment::Entity))` hydrates every level with
+//! one batched query per relation, including a `has_many_through` hop (posts ↔ tags).
+
+use std::sync::Mutex;
+
+use lifeguard::query::loader::{LoadThen, RelationInjector};
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{test_helpers::TestDatabase, LifeExecutor, MayPostgresExecutor};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod nl_comment {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_nested_comments"]
+    pub struct Comment {
+        #[primary_key]
+        pub id: i32,
+        pub post_id: i32,
+        pub body: String,
+    }
+}
+
+pub mod nl_tag {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_nested_tags"]
+    pub struct Tag {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+    }
+}
+
+pub mod nl_post_tag {
+    use lifeguard_derive::LifeModel;
+
+    #[derive(LifeModel, Clone, Debug)]
+    #[table_name = "lg_nested_post_tags"]
+    pub struct PostTag {
+        #[primary_key]
+        pub post_id: i32,
+        #[primary_key]
+        pub tag_id: i32,
+    }
+}
+
+pub mod nl_post {
+    use lifeguard_derive::{DeriveRelation, LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_nested_posts"]
+    pub struct Post {
+        #[primary_key]
+        pub id: i32,
+        pub author_id: i32,
+        pub title: String,
+        #[has_many(
+            entity = "crate::nested_eager_load::nl_comment::Entity",
+            from = "id",
+            to = "post_id"
+        )]
+        pub rel_comments: Option<Vec<super::nl_comment::CommentModel>>,
+        #[skip]
+        pub rel_tags: Option<Vec<super::nl_tag::TagModel>>,
+    }
+
+    #[derive(DeriveRelation)]
+    pub enum Relation {
+        #[lifeguard(
+            has_many_through = "super::nl_tag::Entity",
+            through = "super::nl_post_tag::Entity",
+            through_from = "super::nl_post_tag::Column::PostId",
+            through_to = "super::nl_post_tag::Column::TagId"
+        )]
+        Tags,
+    }
+}
+
+pub mod nl_user {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_nested_users"]
+    pub struct User {
+        #[primary_key]
+        pub id: i32,
+        pub username: String,
+        #[has_many(
+            entity = "crate::nested_eager_load::nl_post::Entity",
+            from = "id",
+            to = "author_id"
+        )]
+        pub rel_posts: Option<Vec<super::nl_post::PostModel>>,
+    }
+}
+
+impl RelationInjector<nl_tag::Entity> for nl_post::PostModel {
+    fn inject(&mut self, items: Vec<nl_tag::TagModel>) {
+        self.rel_tags = Some(items);
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in [
+        "lg_nested_post_tags",
+        "lg_nested_comments",
+        "lg_nested_tags",
+        "lg_nested_posts",
+        "lg_nested_users",
+    ] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_nested_users (id INTEGER PRIMARY KEY, username TEXT NOT NULL)",
+        "CREATE TABLE lg_nested_posts (id INTEGER PRIMARY KEY, author_id INTEGER NOT NULL, title TEXT NOT NULL)",
+        "CREATE TABLE lg_nested_comments (id INTEGER PRIMARY KEY, post_id INTEGER NOT NULL, body TEXT NOT NULL)",
+        "CREATE TABLE lg_nested_tags (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "CREATE TABLE lg_nested_post_tags (post_id INTEGER NOT NULL, tag_id INTEGER NOT NULL, PRIMARY KEY (post_id, tag_id))",
+        "INSERT INTO lg_nested_users VALUES (1, 'alice'), (2, 'bob'), (3, 'carol')",
+        "INSERT INTO lg_nested_posts VALUES (10, 1, 'a1'), (11, 1, 'a2'), (20, 2, 'b1')",
+        "INSERT INTO lg_nested_comments VALUES (100, 10, 'c1'), (101, 10, 'c2'), (200, 20, 'c3')",
+        "INSERT INTO lg_nested_tags VALUES (1, 'rust'), (2, 'sql')",
+        "INSERT INTO lg_nested_post_tags VALUES (10, 1), (10, 2), (20, 1)",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn users_posts_comments_hydrate_at_every_level() {
+    let _guard = LOCK.lock().expect("nested_eager_load lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let users = nl_user::Entity::find()
+        .load(nl_post::Entity.then(nl_comment::Entity))
+        .all(&executor)
+        .expect("nested load");
+
+    let posts_of = |name: &str| {
+        users
+            .iter()
+            .find(|u| u.username == name)
+            .and_then(|u| u.rel_posts.clone())
+            .expect("posts injected")
+    };
+
+    let alice = posts_of("alice");
+    assert_eq!(alice.len(), 2);
+    let a1 = alice.iter().find(|p| p.title == "a1").expect("a1");
+    let mut bodies: Vec<&str> = a1
+        .rel_comments
+        .as_ref()
+        .expect("comments injected into nested posts")
+        .iter()
+        .map(|c| c.body.as_str())
+        .collect();
+    bodies.sort_unstable();
+    assert_eq!(bodies, ["c1", "c2"]);
+    let a2 = alice.iter().find(|p| p.title == "a2").expect("a2");
+    assert_eq!(a2.rel_comments.as_deref().map(<[_]>::len), Some(0));
+
+    let bob = posts_of("bob");
+    assert_eq!(bob[0].rel_comments.as_ref().map(Vec::len), Some(1));
+
+    assert!(
+        posts_of("carol").is_empty(),
+        "no posts still injects an empty Vec"
+    );
+}
+
+#[test]
+fn has_many_through_and_sibling_loads_under_one_level() {
+    let _guard = LOCK.lock().expect("nested_eager_load lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let users = nl_user::Entity::find()
+        .load(
+            nl_post::Entity
+                .with(nl_comment::Entity)
+                .with(nl_tag::Entity),
+        )
+        .all(&executor)
+        .expect("nested load with a through hop");
+
+    let posts: Vec<nl_post::PostModel> = users
+        .iter()
+        .flat_map(|u| u.rel_posts.clone().unwrap_or_default())
+        .collect();
+    let tags_of = |title: &str| {
+        let mut names: Vec<String> = posts
+            .iter()
+            .find(|p| p.title == title)
+            .and_then(|p| p.rel_tags.clone())
+            .expect("tags injected")
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        names.sort_unstable();
+        names
+    };
+
+    assert_eq!(tags_of("a1"), ["rust", "sql"]);
+    assert_eq!(
+        tags_of("b1"),
+        ["rust"],
+        "a tag shared by two posts is injected into both"
+    );
+    assert!(tags_of("a2").is_empty());
+    assert!(posts.iter().all(|p| p.rel_comments.is_some()));
+}
@@ -264,6 +264,102 @@ fn each_operation_is_reported_with_its_own_op_and_key() {
     assert_eq!(ops, vec!["INSERT", "UPDATE", "DELETE"]);
 }
 
+/// The library's own consumer: a spawned `NotificationListener` receives the
+/// trigger's event on a connection that runs no queries of its own, and picks
+/// the subscription back up after its backend is killed.
+///
+/// The tests above keep the listening connection busy with `SELECT 1` to
+/// surface notifications; `NotificationListener` only waits on
+/// `notifications().timeout_iter()`. This is the test that proves an idle
+/// session is woken by the server.
+#[test]
+fn notification_listener_delivers_events_and_resubscribes_after_termination() {
+    use lifeguard::{ListenerEvent, NotificationListener, NotifyOperation};
+    use std::time::Duration;
+
+    let _guard = serialised();
+
+    let mut test_db = get_db();
+    let _client = test_db.connect().expect("connect");
+    let executor = test_db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let ctx = crate::context::get_test_context();
+    let handle = NotificationListener::new(ctx.pg_url.clone())
+        .listen_table(notify_event::Entity::table_definition())
+        .with_poll_interval(Duration::from_millis(200))
+        .with_reconnect_backoff(Duration::from_millis(50), Duration::from_millis(500))
+        .spawn();
+
+    let insert_and_receive = |pid_before: Option<i32>| {
+        let pid = wait_for_listener(&executor, pid_before)
+            .expect("the listener should LISTEN on the #[notify] channel");
+        let id = uuid::Uuid::new_v4();
+        executor
+            .execute(
+                "INSERT INTO test_notify_events (id, status) VALUES ($1, $2)",
+                &[&id, &"PENDING".to_string()],
+            )
+            .expect("insert");
+        match handle.recv_timeout(Duration::from_secs(5)) {
+            Some(ListenerEvent::Notify(event)) => {
+                assert_eq!(event.channel, "test_lifeguard_events");
+                assert_eq!(event.table, "test_notify_events");
+                assert_eq!(event.operation, NotifyOperation::Insert);
+                assert_eq!(event.id_string(), id.to_string());
+            }
+            other => panic!("expected the INSERT notification, got {other:?}"),
+        }
+        pid
+    };
+
+    let pid = insert_and_receive(None);
+
+    let terminated: bool = executor
+        .query_one("SELECT pg_terminate_backend($1)", &[&pid])
+        .expect("pg_terminate_backend")
+        .get(0);
+    assert!(terminated, "listener backend {pid} should be terminated");
+
+    // Notifications sent while it was down are gone, so the listener must say
+    // so before delivering anything else.
+    match handle.recv_timeout(Duration::from_secs(10)) {
+        Some(ListenerEvent::Reconnected) => {}
+        other => panic!("expected Reconnected after the backend was killed, got {other:?}"),
+    }
+
+    let new_pid = insert_and_receive(Some(pid));
+    assert_ne!(new_pid, pid, "the listener should be on a new backend");
+}
+
+/// Backend pid of the session that last ran `LISTEN "test_lifeguard_events"`,
+/// other than `except`, waiting up to five seconds for it to appear.
+///
+/// `pg_stat_activity.query` keeps a session's last statement, so an idle
+/// listener is still found by the `LISTEN` it issued.
+fn wait_for_listener(executor: &MayPostgresExecutor, except: Option<i32>) -> Option<i32> {
+    use std::time::Duration;
+
+    for _ in 0..100 {
+        let rows = executor
+            .query_all(
+                "SELECT pid FROM pg_stat_activity
+                 WHERE query = 'LISTEN \"test_lifeguard_events\"' AND pid <> pg_backend_pid()",
+                &[],
+            )
+            .ok()?;
+        if let Some(pid) = rows
+            .iter()
+            .map(|row| row.get::<_, i32>(0))
+            .find(|pid| Some(*pid) != except)
+        {
+            return Some(pid);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    None
+}
+
 /// Poll for a notification, giving the connection round trips to surface it.
 ///
 /// Notifications arrive asynchronously: the server sends them when it chooses
@@ -0,0 +1,135 @@
+//! `#[version]` optimistic locking on derived `LifeRecord` against a real database: a second
+//! writer holding the old version gets `ActiveModelError::StaleRecord`, and a `Session` flush
+//! reports the conflicting identity-map key.
+
+use std::sync::Mutex;
+
+use crate::context::get_test_context;
+use lifeguard::executor::LifeError;
+use lifeguard::session::Session;
+use lifeguard::test_helpers::TestDatabase;
+use lifeguard::{ActiveModelError, ActiveModelTrait, LifeExecutor};
+use lifeguard_derive::{LifeModel, LifeRecord};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(LifeModel, LifeRecord, Clone, Debug)]
+#[table_name = "lg_versioned_docs"]
+pub struct Doc {
+    #[primary_key]
+    pub id: i32,
+    pub title: String,
+    #[version]
+    pub version: i32,
+}
+
+fn setup(executor: &dyn LifeExecutor) -> Result<(), LifeError> {
+    executor.execute("DROP TABLE IF EXISTS lg_versioned_docs CASCADE", &[])?;
+    executor.execute(
+        "CREATE TABLE lg_versioned_docs (id INTEGER PRIMARY KEY, title TEXT NOT NULL, version INTEGER NOT NULL)",
+        &[],
+    )?;
+    Ok(())
+}
+
+#[test]
+fn concurrent_update_and_delete_with_old_version_are_stale() {
+    let _guard = LOCK.lock().expect("optimistic_locking lock");
+
+    let ctx = get_test_context();
+    let mut db = TestDatabase::with_url(&ctx.pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let mut rec = DocRecord::new();
+    rec.set_id(1).set_title("draft".to_string());
+    let inserted = rec.insert(&executor).expect("insert");
+    assert_eq!(inserted.version, 1, "insert seeds an unset version with 1");
+
+    let mut first = DocRecord::from_model(&inserted);
+    let mut second = DocRecord::from_model(&inserted);
+
+    first.set_title("first".to_string());
+    let updated = first.update(&executor).expect("first writer wins");
+    assert_eq!(updated.version, 2);
+
+    second.set_title("second".to_string());
+    let err = second
+        .update(&executor)
+        .expect_err("second writer is stale");
+    assert_eq!(
+        err,
+        ActiveModelError::StaleRecord {
+            table: "lg_versioned_docs".to_string(),
+            expected_version: 1,
+            identity_key: None,
+        }
+    );
+
+    let err = DocRecord::from_model(&inserted)
+        .delete(&executor)
+        .expect_err("delete with the old version is stale");
+    assert!(err.is_stale());
+
+    let row = executor
+        .query_one(
+            "SELECT title, version FROM lg_versioned_docs WHERE id = 1",
+            &[],
+        )
+        .expect("select");
+    let title: String = row.get(0);
+    let version: i32 = row.get(1);
+    assert_eq!((title.as_str(), version), ("first", 2));
+
+    DocRecord::from_model(&updated)
+        .delete(&executor)
+        .expect("delete with the current version");
+
+    let mut gone = DocRecord::from_model(&updated);
+    gone.set_title("late".to_string());
+    assert_eq!(
+        gone.update(&executor).expect_err("row is gone"),
+        ActiveModelError::RecordNotFound
+    );
+}
+
+#[test]
+fn session_flush_reports_stale_identity_key() {
+    let _guard = LOCK.lock().expect("optimistic_locking lock");
+
+    let ctx = get_test_context();
+    let mut db = TestDatabase::with_url(&ctx.pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let mut rec = DocRecord::new();
+    rec.set_id(7).set_title("shared".to_string());
+    let loaded = rec.insert(&executor).expect("insert");
+
+    let session = Session::<Entity>::new();
+    let rc = session.register_loaded(loaded.clone());
+    rc.borrow_mut().title = "from session".to_string();
+    session.mark_dirty(&loaded);
+
+    // Another writer bumps the version after the session loaded the row.
+    let mut other = DocRecord::from_model(&loaded);
+    other.set_title("elsewhere".to_string());
+    other.update(&executor).expect("concurrent update");
+
+    let err = session
+        .flush_dirty(&executor, |ex, mrc| {
+            let model = mrc.borrow().clone();
+            DocRecord::overwrite(&model).update(ex)?;
+            Ok(())
+        })
+        .expect_err("flush hits the version conflict");
+
+    let ActiveModelError::StaleRecord { identity_key, .. } = err else {
+        panic!("expected StaleRecord, got {err:?}");
+    };
+    assert_eq!(
+        identity_key,
+        DocRecord::from_model(&loaded).identity_map_key()
+    );
+    assert_eq!(session.dirty_len(), 1, "conflicting row stays dirty");
+}
@@ -0,0 +1,187 @@
+//! Range columns: `PgRange<T>` fields (`tstzrange`, `daterange`, `int8range`) round-trip through
+//! `LifeRecord` inserts and `FromRow`, filter with the `ColumnTrait` range operators, and an
+//! `#[exclude]` constraint turns a double booking into a classified exclusion violation.
+
+use std::sync::Mutex;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelError, ActiveModelTrait, ColumnTrait, DbErrorKind,
+    LifeError, LifeExecutor, MayPostgresExecutor, ModelTrait, PgRange,
+};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod booking {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use lifeguard::PgRange;
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_range_bookings"]
+    #[exclude(using = "gist", elements = "room_id WITH =, during WITH &&")]
+    pub struct Booking {
+        #[primary_key]
+        pub id: i32,
+        pub room_id: i64,
+        pub during: PgRange<DateTime<Utc>>,
+        pub stay: Option<PgRange<NaiveDate>>,
+        pub seats: PgRange<i64>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), LifeError> {
+    executor.execute("CREATE EXTENSION IF NOT EXISTS btree_gist", &[])?;
+    executor.execute("DROP TABLE IF EXISTS lg_range_bookings CASCADE", &[])?;
+    // The same constraint `sql_generator` emits for the entity's `#[exclude]`.
+    executor.execute(
+        "CREATE TABLE lg_range_bookings (id INTEGER PRIMARY KEY, room_id BIGINT NOT NULL, \
+         during tstzrange NOT NULL, stay daterange, seats int8range NOT NULL, \
+         EXCLUDE USING gist (room_id WITH =, during WITH &&))",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn at(hour: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 3, 1, hour, 0, 0)
+        .single()
+        .expect("timestamp")
+}
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 3, d).expect("date")
+}
+
+fn insert(
+    executor: &MayPostgresExecutor,
+    id: i32,
+    room_id: i64,
+    during: PgRange<DateTime<Utc>>,
+) -> Result<booking::BookingModel, ActiveModelError> {
+    let mut record = booking::BookingRecord::new();
+    record
+        .set_id(id)
+        .set_room_id(room_id)
+        .set_during(during)
+        .set_stay(Some(PgRange::closed(day(1), day(3))))
+        .set_seats(PgRange::closed_open(1, 5));
+    record.insert(executor)
+}
+
+#[test]
+fn range_fields_round_trip_and_filter() {
+    let _guard = LOCK.lock().expect("range_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let inserted = insert(&executor, 1, 10, PgRange::closed_open(at(9), at(11))).expect("insert");
+    // Discrete ranges come back canonical: `[1,3]` is stored as `[1,4)`.
+    assert_eq!(inserted.stay, Some(PgRange::closed_open(day(1), day(4))));
+    assert_eq!(inserted.during, PgRange::closed_open(at(9), at(11)));
+    insert(&executor, 2, 10, PgRange::closed_open(at(11), at(12))).expect("adjacent booking");
+    insert(&executor, 3, 20, PgRange::at_least(at(10))).expect("open-ended booking");
+
+    let stored: String = executor
+        .query_one(
+            "SELECT seats::text FROM lg_range_bookings WHERE id = 1",
+            &[],
+        )
+        .expect("raw select")
+        .try_get(0)
+        .expect("seats text");
+    assert_eq!(stored, "[1,5)");
+
+    let ids = |filter: sea_query::Expr| -> Vec<i32> {
+        booking::Entity::find()
+            .filter(filter)
+            .order_by(booking::Column::Id, Order::Asc)
+            .all(&executor)
+            .expect("filter")
+            .into_iter()
+            .map(|b| b.id)
+            .collect()
+    };
+
+    let morning = PgRange::closed_open(at(8), at(10));
+    assert_eq!(ids(booking::Column::During.overlaps(morning)), [1]);
+    assert_eq!(
+        ids(booking::Column::During.contains(PgRange::closed_open(at(10), at(11)))),
+        [1, 3]
+    );
+    assert_eq!(
+        ids(booking::Column::During.contained_by(PgRange::closed_open(at(9), at(12)))),
+        [1, 2]
+    );
+    assert_eq!(
+        ids(booking::Column::During.adjacent_to(PgRange::closed_open(at(8), at(9)))),
+        [1]
+    );
+    assert_eq!(
+        ids(booking::Column::During.contains_element(at(11))),
+        [2, 3]
+    );
+    assert_eq!(
+        ids(booking::Column::Seats.contains_element(4_i64)),
+        [1, 2, 3]
+    );
+
+    let mut found = booking::Entity::find()
+        .filter(booking::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    found
+        .set(booking::Column::Stay, sea_query::Value::String(None))
+        .expect("set stay to NULL");
+    assert_eq!(found.stay, None);
+    assert!(
+        found
+            .set(
+                booking::Column::Seats,
+                sea_query::Value::String(Some("[1,".to_string()))
+            )
+            .is_err(),
+        "not a range literal"
+    );
+}
+
+#[test]
+fn exclusion_constraint_rejects_double_booking() {
+    let _guard = LOCK.lock().expect("range_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    insert(&executor, 1, 10, PgRange::closed_open(at(9), at(11))).expect("first booking");
+    insert(&executor, 2, 20, PgRange::closed_open(at(9), at(11))).expect("other room");
+
+    let err = insert(&executor, 3, 10, PgRange::closed_open(at(10), at(12)))
+        .expect_err("overlapping booking of the same room");
+    match err {
+        ActiveModelError::Database { kind, columns, .. } => {
+            assert_eq!(kind, DbErrorKind::ExclusionViolation);
+            assert!(kind.is_constraint_violation());
+            assert_eq!(columns, ["room_id", "during"]);
+        }
+        other => panic!("expected a classified exclusion violation, got {other:?}"),
+    }
+
+    let err = executor
+        .execute(
+            "INSERT INTO lg_range_bookings (id, room_id, during, seats) VALUES (4, 20, $1, $2)",
+            &[
+                &PgRange::closed(at(10), at(10)),
+                &PgRange::closed_open(1_i64, 2),
+            ],
+        )
+        .expect_err("overlapping booking of room 20");
+    assert!(matches!(err, LifeError::ExclusionViolation(_)), "{err}");
+    assert_eq!(
+        err.constraint(),
+        Some("lg_range_bookings_room_id_during_excl")
+    );
+}
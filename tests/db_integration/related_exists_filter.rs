@@ -0,0 +1,134 @@
+//! `has_related` / `doesnt_have_related`: correlated `EXISTS` / `NOT EXISTS` filters on a
+//! `has_many` relation whose child is soft-deletable, combined with parent and child scopes.
+
+use std::sync::Mutex;
+
+use lifeguard::query::related_filter::related_exists;
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{test_helpers::TestDatabase, ColumnTrait, LifeExecutor, MayPostgresExecutor};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod ex_author {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_exists_authors"]
+    pub struct Author {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+        #[has_many(
+            entity = "crate::related_exists_filter::ex_post::Entity",
+            from = "id",
+            to = "author_id"
+        )]
+        pub rel_posts: Option<Vec<super::ex_post::PostModel>>,
+    }
+}
+
+pub mod ex_post {
+    use chrono::{DateTime, Utc};
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_exists_posts"]
+    #[soft_delete]
+    pub struct Post {
+        #[primary_key]
+        pub id: i32,
+        pub author_id: i32,
+        pub published: bool,
+        pub deleted_at: Option<DateTime<Utc>>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in ["lg_exists_posts", "lg_exists_authors"] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_exists_authors (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "CREATE TABLE lg_exists_posts (id INTEGER PRIMARY KEY, \
+         author_id INTEGER NOT NULL REFERENCES lg_exists_authors (id), \
+         published BOOLEAN NOT NULL, deleted_at TIMESTAMPTZ)",
+        // ada: published; bo: draft only; cy: published but trashed; dee: no posts
+        "INSERT INTO lg_exists_authors VALUES (1, 'ada'), (2, 'bo'), (3, 'cy'), (4, 'dee')",
+        "INSERT INTO lg_exists_posts VALUES (10, 1, TRUE, NULL), (11, 1, FALSE, NULL), \
+         (20, 2, FALSE, NULL), (30, 3, TRUE, now())",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+fn names(
+    executor: &MayPostgresExecutor,
+    query: lifeguard::SelectQuery<ex_author::Entity>,
+) -> Vec<String> {
+    query
+        .order_by(ex_author::Column::Id, Order::Asc)
+        .all(executor)
+        .expect("authors")
+        .into_iter()
+        .map(|author| author.name)
+        .collect()
+}
+
+#[test]
+fn has_related_keeps_parents_with_a_matching_live_child() {
+    let _guard = LOCK.lock().expect("related_exists_filter lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let any = ex_author::Entity::find().has_related::<ex_post::Entity>(|q| q);
+    assert_eq!(
+        names(&executor, any),
+        ["ada", "bo"],
+        "cy's only post is trashed"
+    );
+
+    let published = ex_author::Entity::find().has_related::<ex_post::Entity>(|q| {
+        q.filter(ColumnTrait::eq(ex_post::Column::Published, true))
+    });
+    assert_eq!(names(&executor, published), ["ada"]);
+
+    let with_trashed = ex_author::Entity::find().has_related::<ex_post::Entity>(|q| {
+        q.with_trashed()
+            .filter(ColumnTrait::eq(ex_post::Column::Published, true))
+    });
+    assert_eq!(names(&executor, with_trashed), ["ada", "cy"]);
+}
+
+#[test]
+fn doesnt_have_related_and_scopes_compose() {
+    let _guard = LOCK.lock().expect("related_exists_filter lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let without = ex_author::Entity::find().doesnt_have_related::<ex_post::Entity>(|q| q);
+    assert_eq!(names(&executor, without), ["cy", "dee"]);
+
+    let no_published_drafts_scoped = ex_author::Entity::find()
+        .scope(ColumnTrait::ne(ex_author::Column::Name, "dee"))
+        .doesnt_have_related::<ex_post::Entity>(|q| {
+            q.scope(ColumnTrait::eq(ex_post::Column::Published, true))
+        });
+    assert_eq!(
+        names(&executor, no_published_drafts_scoped),
+        ["bo", "cy"],
+        "parent scope and child scope both apply"
+    );
+
+    let scoped_exists = ex_author::Entity::find().scope_or(
+        related_exists::<ex_author::Entity, ex_post::Entity>(|q| {
+            q.filter(ColumnTrait::eq(ex_post::Column::Published, true))
+        })
+        .expect("related_exists"),
+        ColumnTrait::eq(ex_author::Column::Name, "dee"),
+    );
+    assert_eq!(names(&executor, scoped_exists), ["ada", "dee"]);
+}
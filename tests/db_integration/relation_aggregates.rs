@@ -0,0 +1,138 @@
+//! `with_count` / `with_aggregate`: correlated aggregate columns over a `has_many` relation
+//! whose child is soft-deletable, read as `(model, aggregates)` pairs and into a partial model.
+
+use std::sync::Mutex;
+
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::RelationAggregate::{Max, Sum};
+use lifeguard::{test_helpers::TestDatabase, ColumnTrait, LifeExecutor, MayPostgresExecutor};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod agg_user {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_agg_users"]
+    pub struct User {
+        #[primary_key]
+        pub id: i32,
+        pub name: String,
+        #[has_many(
+            entity = "crate::relation_aggregates::agg_order::Entity",
+            from = "id",
+            to = "user_id"
+        )]
+        pub rel_orders: Option<Vec<super::agg_order::OrderModel>>,
+    }
+}
+
+pub mod agg_order {
+    use chrono::{DateTime, Utc};
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_agg_orders"]
+    #[soft_delete]
+    pub struct Order {
+        #[primary_key]
+        pub id: i32,
+        pub user_id: i32,
+        pub total: i32,
+        pub deleted_at: Option<DateTime<Utc>>,
+    }
+}
+
+/// List-view row: user columns plus the aggregate aliases.
+#[derive(lifeguard_derive::FromRow)]
+struct UserSummary {
+    name: String,
+    order_count: i64,
+    lifetime_value: Option<i64>,
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in ["lg_agg_orders", "lg_agg_users"] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_agg_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        "CREATE TABLE lg_agg_orders (id INTEGER PRIMARY KEY, \
+         user_id INTEGER NOT NULL REFERENCES lg_agg_users (id), \
+         total INTEGER NOT NULL, deleted_at TIMESTAMPTZ)",
+        "INSERT INTO lg_agg_users VALUES (1, 'ada'), (2, 'bo'), (3, 'cy')",
+        // cy's only order is trashed
+        "INSERT INTO lg_agg_orders VALUES (10, 1, 5, NULL), (11, 1, 7, NULL), \
+         (20, 2, 3, NULL), (21, 2, 100, now()), (30, 3, 9, now())",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn aggregates_come_back_alongside_each_model() {
+    let _guard = LOCK.lock().expect("relation_aggregates lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let rows = agg_user::Entity::find()
+        .with_count::<agg_order::Entity>("order_count")
+        .with_aggregate::<agg_order::Entity>(Sum(agg_order::Column::Total), "lifetime_value")
+        .with_aggregate::<agg_order::Entity>(Max(agg_order::Column::Total), "largest")
+        .order_by(agg_user::Column::Id, Order::Asc)
+        .all_with_aggregates(&executor)
+        .expect("all_with_aggregates");
+
+    let got: Vec<(String, i64, Option<i64>, Option<i32>)> = rows
+        .iter()
+        .map(|(user, aggregates)| {
+            (
+                user.name.clone(),
+                aggregates.get("order_count").expect("order_count"),
+                aggregates.get("lifetime_value").expect("lifetime_value"),
+                aggregates.get("largest").expect("largest"),
+            )
+        })
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            ("ada".to_string(), 2, Some(12), Some(7)),
+            ("bo".to_string(), 1, Some(3), Some(3)),
+            ("cy".to_string(), 0, None, None),
+        ],
+        "trashed orders are not aggregated"
+    );
+}
+
+#[test]
+fn aggregates_read_into_a_partial_model() {
+    let _guard = LOCK.lock().expect("relation_aggregates lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let summaries = agg_user::Entity::find()
+        .with_count::<agg_order::Entity>("order_count")
+        .with_aggregate::<agg_order::Entity>(Sum(agg_order::Column::Total), "lifetime_value")
+        .filter(agg_user::Column::Id.lte(2))
+        .order_by(agg_user::Column::Id, Order::Desc)
+        .into_model::<UserSummary>()
+        .all(&executor)
+        .expect("into_model");
+
+    let got: Vec<(String, i64, Option<i64>)> = summaries
+        .into_iter()
+        .map(|s| (s.name, s.order_count, s.lifetime_value))
+        .collect();
+    assert_eq!(
+        got,
+        vec![
+            ("bo".to_string(), 1, Some(3)),
+            ("ada".to_string(), 2, Some(12))
+        ]
+    );
+}
@@ -0,0 +1,164 @@
+//! Time, interval, network, `citext` and bit string columns: `NaiveTime`, `PgInterval`, `IpAddr`,
+//! `PgCidr`, `PgMacAddr`, `CiText` and `PgBitString` fields round-trip through `LifeRecord` inserts
+//! and `FromRow`, and filter with `ColumnTrait`.
+
+use std::net::IpAddr;
+use std::sync::Mutex;
+
+use chrono::NaiveTime;
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{
+    test_helpers::TestDatabase, ActiveModelTrait, CiText, ColumnTrait, LifeError, LifeExecutor,
+    MayPostgresExecutor, ModelTrait, PgBitString, PgCidr, PgInterval, PgMacAddr, ValueType,
+};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod device {
+    use chrono::NaiveTime;
+    use lifeguard::{CiText, PgBitString, PgCidr, PgInterval, PgMacAddr};
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_scalar_devices"]
+    pub struct Device {
+        #[primary_key]
+        pub id: i32,
+        pub opens_at: NaiveTime,
+        pub grace: Option<PgInterval>,
+        pub client_ip: std::net::IpAddr,
+        pub subnet: PgCidr,
+        pub hw_addr: Option<PgMacAddr>,
+        pub owner_email: CiText,
+        pub flags: PgBitString,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), LifeError> {
+    executor.execute("CREATE EXTENSION IF NOT EXISTS citext", &[])?;
+    executor.execute("DROP TABLE IF EXISTS lg_scalar_devices CASCADE", &[])?;
+    executor.execute(
+        "CREATE TABLE lg_scalar_devices (id INTEGER PRIMARY KEY, opens_at TIME NOT NULL, \
+         grace INTERVAL, client_ip INET NOT NULL, subnet CIDR NOT NULL, hw_addr MACADDR, \
+         owner_email CITEXT NOT NULL, flags VARBIT NOT NULL)",
+        &[],
+    )?;
+    Ok(())
+}
+
+fn hm(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).expect("time")
+}
+
+fn ip(text: &str) -> IpAddr {
+    text.parse().expect("ip")
+}
+
+fn insert(
+    executor: &MayPostgresExecutor,
+    id: i32,
+    opens_at: NaiveTime,
+    client_ip: &str,
+    owner_email: &str,
+) -> device::DeviceModel {
+    let mut record = device::DeviceRecord::new();
+    record
+        .set_id(id)
+        .set_opens_at(opens_at)
+        .set_grace(Some(PgInterval::new(1, 2, 90 * 60 * 1_000_000)))
+        .set_client_ip(ip(client_ip))
+        .set_subnet(PgCidr::from_literal("10.0.0.0/8").expect("cidr"))
+        .set_hw_addr(Some(PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03])))
+        .set_owner_email(CiText::from(owner_email))
+        .set_flags(PgBitString::from_literal("1011").expect("bits"));
+    record.insert(executor).expect("insert device")
+}
+
+#[test]
+fn scalar_fields_round_trip_and_filter() {
+    let _guard = LOCK.lock().expect("scalar_columns lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let inserted = insert(&executor, 1, hm(9, 30), "192.168.0.10", "Alice@Example.com");
+    assert_eq!(inserted.opens_at, hm(9, 30));
+    assert_eq!(inserted.grace, Some(PgInterval::new(1, 2, 5_400_000_000)));
+    assert_eq!(inserted.client_ip, ip("192.168.0.10"));
+    assert_eq!(inserted.subnet.to_literal(), "10.0.0.0/8");
+    assert_eq!(
+        inserted.hw_addr.map(|m| m.to_literal()),
+        Some("08:00:2b:01:02:03".to_string())
+    );
+    assert_eq!(inserted.owner_email.as_str(), "Alice@Example.com");
+    assert_eq!(inserted.flags.to_literal(), "1011");
+    insert(&executor, 2, hm(17, 0), "2001:db8::1", "bob@example.com");
+
+    let stored: String = executor
+        .query_one(
+            "SELECT grace::text || ' ' || client_ip::text FROM lg_scalar_devices WHERE id = 1",
+            &[],
+        )
+        .expect("raw select")
+        .try_get(0)
+        .expect("text");
+    assert_eq!(stored, "1 mon 2 days 01:30:00 192.168.0.10");
+
+    let ids = |filter: sea_query::Expr| -> Vec<i32> {
+        device::Entity::find()
+            .filter(filter)
+            .order_by(device::Column::Id, Order::Asc)
+            .all(&executor)
+            .expect("filter")
+            .into_iter()
+            .map(|d| d.id)
+            .collect()
+    };
+
+    assert_eq!(ids(device::Column::OpensAt.lt(hm(12, 0))), [1]);
+    assert_eq!(
+        ids(device::Column::ClientIp.eq(ip("2001:db8::1").into_value())),
+        [2]
+    );
+    // `citext` compares case-insensitively on the server, like `CiText` does in Rust.
+    assert_eq!(
+        ids(device::Column::OwnerEmail.eq(CiText::from("ALICE@EXAMPLE.COM"))),
+        [1]
+    );
+    assert_eq!(
+        ids(device::Column::Grace.gt(PgInterval::new(0, 0, 3_600_000_000))),
+        [1, 2]
+    );
+
+    let mut found = device::Entity::find()
+        .filter(device::Column::Id.eq(1))
+        .find_one(&executor)
+        .expect("find_one")
+        .expect("row");
+    assert_eq!(
+        found.get(device::Column::ClientIp),
+        sea_query::Value::String(Some("192.168.0.10".to_string()))
+    );
+    found
+        .set(device::Column::Grace, sea_query::Value::String(None))
+        .expect("set grace to NULL");
+    assert_eq!(found.grace, None);
+    assert!(
+        found
+            .set(
+                device::Column::HwAddr,
+                sea_query::Value::String(Some("not a mac".to_string()))
+            )
+            .is_err(),
+        "not a MAC address"
+    );
+
+    let err = executor
+        .execute(
+            "UPDATE lg_scalar_devices SET subnet = $1 WHERE id = 1",
+            &[&lifeguard::TextParam::some("10.0.0.1/8".to_string())],
+        )
+        .expect_err("host bits set");
+    assert!(err.to_string().contains("cidr"), "{err}");
+}
@@ -5,9 +5,13 @@
 use lifeguard::query::traits::LifeModelTrait;
 use lifeguard::query::SelectQueryStreamEx;
 use lifeguard::ActiveModelTrait;
-use lifeguard::{test_helpers::TestDatabase, LifeExecutor, MayPostgresExecutor};
+use lifeguard::{
+    test_helpers::TestDatabase, LifeExecutor, LifeguardPool, MayPostgresExecutor,
+    PooledLifeExecutor, ReadPreference,
+};
 use lifeguard_derive::{LifeModel, LifeRecord};
 use sea_query::{Expr, ExprTrait, Order};
+use std::sync::Arc;
 
 fn get_db() -> TestDatabase {
     let ctx = crate::context::get_test_context();
@@ -150,3 +154,56 @@ fn test_pagination_and_streaming() {
         "Mismatched FETCH chunk alignment distributions inside transaction!"
     );
 }
+
+#[test]
+fn test_pooled_streaming_pins_one_slot_and_releases_it_on_early_drop() {
+    let ctx = crate::context::get_test_context();
+    let mut db = get_db();
+    let executor = db.executor().expect("Failed to extract executor interface");
+    setup_schema(&executor);
+
+    for i in 1..=7 {
+        let mut rec = DataPointRecord::new();
+        rec.set_name(format!("Point {i}"));
+        rec.set_val(i);
+        rec.save(&executor).expect("Database insertion crashed");
+    }
+
+    // One primary slot and no replicas: every read preference lands on the same connection, so a
+    // slot left pinned or mid-transaction would hang or fail the follow-up statements below.
+    let pool = Arc::new(LifeguardPool::new(&ctx.pg_url, 1, vec![], 0).expect("pool"));
+    let pooled = PooledLifeExecutor::new(pool).with_read_preference(ReadPreference::Default);
+
+    let receiver = Entity::find()
+        .order_by("id", Order::Asc)
+        .stream_all_pooled(&pooled, 3);
+    let mut chunk_counts = Vec::new();
+    while let Ok(res) = receiver.recv() {
+        chunk_counts.push(res.expect("pooled stream chunk").len());
+    }
+    assert_eq!(chunk_counts, vec![3, 3, 1]);
+
+    // Drop the receiver after the first chunk: the coroutine stops at its next send, ends the
+    // cursor transaction and unpins the slot.
+    let receiver = Entity::find().stream_all_pooled(&pooled, 2);
+    let first = receiver
+        .recv()
+        .expect("first chunk")
+        .expect("first chunk ok");
+    assert_eq!(first.len(), 2);
+    drop(receiver);
+
+    // `BEGIN READ ONLY` must not leak into later pool jobs: this write runs on the same slot.
+    let mut rec = DataPointRecord::new();
+    rec.set_name("after stream".into());
+    rec.set_val(100);
+    rec.save(&pooled)
+        .expect("write after early-dropped pooled stream");
+    assert_eq!(
+        Entity::find()
+            .all(&pooled)
+            .expect("list after stream")
+            .len(),
+        8
+    );
+}
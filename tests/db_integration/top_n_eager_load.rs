@@ -0,0 +1,89 @@
+//! Top-N-per-parent eager loading: `.load(comment::Entity.top(n).order_by(…))` keeps each post's
+//! first `n` comments in rank order, from one batched `ROW_NUMBER()` query.
+
+use std::sync::Mutex;
+
+use lifeguard::query::loader::LoadThen;
+use lifeguard::query::traits::LifeModelTrait;
+use lifeguard::{test_helpers::TestDatabase, LifeExecutor, MayPostgresExecutor};
+use sea_query::Order;
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub mod top_comment {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_top_comments"]
+    pub struct Comment {
+        #[primary_key]
+        pub id: i32,
+        pub post_id: i32,
+        pub body: String,
+    }
+}
+
+pub mod top_post {
+    use lifeguard_derive::{LifeModel, LifeRecord};
+
+    #[derive(LifeModel, LifeRecord, Clone, Debug)]
+    #[table_name = "lg_top_posts"]
+    pub struct Post {
+        #[primary_key]
+        pub id: i32,
+        pub title: String,
+        #[has_many(
+            entity = "crate::top_n_eager_load::top_comment::Entity",
+            from = "id",
+            to = "post_id"
+        )]
+        pub rel_comments: Option<Vec<super::top_comment::CommentModel>>,
+    }
+}
+
+fn setup(executor: &MayPostgresExecutor) -> Result<(), lifeguard::executor::LifeError> {
+    for table in ["lg_top_comments", "lg_top_posts"] {
+        executor.execute(&format!("DROP TABLE IF EXISTS {table} CASCADE"), &[])?;
+    }
+    for ddl in [
+        "CREATE TABLE lg_top_posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL)",
+        "CREATE TABLE lg_top_comments (id INTEGER PRIMARY KEY, \
+         post_id INTEGER NOT NULL REFERENCES lg_top_posts (id), body TEXT NOT NULL)",
+        "INSERT INTO lg_top_posts VALUES (1, 'busy'), (2, 'quiet'), (3, 'empty')",
+        "INSERT INTO lg_top_comments VALUES (11, 1, 'a'), (12, 1, 'b'), (13, 1, 'c'), \
+         (14, 1, 'd'), (15, 1, 'e'), (21, 2, 'x'), (22, 2, 'y')",
+    ] {
+        executor.execute(ddl, &[])?;
+    }
+    Ok(())
+}
+
+#[test]
+fn top_keeps_the_first_n_children_of_each_parent_in_rank_order() {
+    let _guard = LOCK.lock().expect("top_n_eager_load lock");
+    let mut db = TestDatabase::with_url(&crate::context::get_test_context().pg_url);
+    let executor = db.executor().expect("executor");
+    setup(&executor).expect("setup");
+
+    let posts = top_post::Entity::find()
+        .order_by(top_post::Column::Id, Order::Asc)
+        .load(
+            top_comment::Entity
+                .top(3)
+                .order_by(top_comment::Column::Id, Order::Desc),
+        )
+        .all(&executor)
+        .expect("posts with latest comments");
+
+    let got: Vec<(i32, Vec<i32>)> = posts
+        .iter()
+        .map(|post| {
+            let comments = post.rel_comments.as_ref().expect("comments injected");
+            (post.id, comments.iter().map(|c| c.id).collect())
+        })
+        .collect();
+    assert_eq!(
+        got,
+        vec![(1, vec![15, 14, 13]), (2, vec![22, 21]), (3, vec![])]
+    );
+}
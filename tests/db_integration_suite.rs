@@ -46,12 +46,36 @@ mod column_f_where;
 #[path = "db_integration/session_identity_flush.rs"]
 mod session_identity_flush;
 
+#[path = "db_integration/optimistic_locking.rs"]
+mod optimistic_locking;
+
+#[path = "db_integration/db_validators.rs"]
+mod db_validators;
+
 #[path = "db_integration/active_model_graph.rs"]
 mod active_model_graph;
 
 #[path = "db_integration/dataloader_n_plus_one.rs"]
 mod dataloader_n_plus_one;
 
+#[path = "db_integration/nested_eager_load.rs"]
+mod nested_eager_load;
+
+#[path = "db_integration/top_n_eager_load.rs"]
+mod top_n_eager_load;
+
+#[path = "db_integration/joined_loading.rs"]
+mod joined_loading;
+
+#[path = "db_integration/many_to_many_links.rs"]
+mod many_to_many_links;
+
+#[path = "db_integration/related_exists_filter.rs"]
+mod related_exists_filter;
+
+#[path = "db_integration/relation_aggregates.rs"]
+mod relation_aggregates;
+
 #[path = "db_integration/stream_and_cursor.rs"]
 mod stream_and_cursor;
 
@@ -61,6 +85,17 @@ mod related_trait;
 #[path = "db_integration/json_value_from_row.rs"]
 mod json_value_from_row;
 
+#[path = "db_integration/array_columns.rs"]
+mod array_columns;
+#[path = "db_integration/enum_columns.rs"]
+mod enum_columns;
+#[path = "db_integration/life_value_columns.rs"]
+mod life_value_columns;
+#[path = "db_integration/range_columns.rs"]
+mod range_columns;
+#[path = "db_integration/scalar_columns.rs"]
+mod scalar_columns;
+
 #[path = "db_integration/chrono_timestamptz_from_row.rs"]
 mod chrono_timestamptz_from_row;
 